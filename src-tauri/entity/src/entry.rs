@@ -10,9 +10,10 @@ pub struct Model {
     pub id: Uuid,
     pub folder_id: Option<Uuid>,
     pub entity_type: i8,
-    #[sea_orm(unique)]
     pub title: String,
     pub text: String,
+    pub revision: i32,
+    pub deleted_at: Option<DateTimeUtc>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -25,8 +26,24 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Folder,
+    #[sea_orm(has_many = "super::asset_reference::Entity")]
+    AssetReference,
+    #[sea_orm(has_one = "super::calendar::Entity")]
+    Calendar,
+    #[sea_orm(has_many = "super::custom_property_value::Entity")]
+    CustomPropertyValue,
+    #[sea_orm(has_many = "super::entry_alias::Entity")]
+    EntryAlias,
+    #[sea_orm(has_many = "super::entry_revision::Entity")]
+    EntryRevision,
+    #[sea_orm(has_many = "super::entry_tag::Entity")]
+    EntryTag,
     #[sea_orm(has_one = "super::language::Entity")]
     Language,
+    #[sea_orm(has_one = "super::map::Entity")]
+    Map,
+    #[sea_orm(has_many = "super::map_pin::Entity")]
+    MapPin,
     #[sea_orm(has_one = "super::person::Entity")]
     Person,
 }
@@ -37,12 +54,60 @@ impl Related<super::folder::Entity> for Entity {
     }
 }
 
+impl Related<super::asset_reference::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AssetReference.def()
+    }
+}
+
+impl Related<super::calendar::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Calendar.def()
+    }
+}
+
+impl Related<super::custom_property_value::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomPropertyValue.def()
+    }
+}
+
+impl Related<super::entry_alias::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EntryAlias.def()
+    }
+}
+
+impl Related<super::entry_revision::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EntryRevision.def()
+    }
+}
+
+impl Related<super::entry_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EntryTag.def()
+    }
+}
+
 impl Related<super::language::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Language.def()
     }
 }
 
+impl Related<super::map::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Map.def()
+    }
+}
+
+impl Related<super::map_pin::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MapPin.def()
+    }
+}
+
 impl Related<super::person::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Person.def()
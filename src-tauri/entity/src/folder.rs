@@ -10,12 +10,16 @@ pub struct Model {
     pub id: Uuid,
     pub parent_id: Option<Uuid>,
     pub name: String,
+    pub revision: i32,
+    pub deleted_at: Option<DateTimeUtc>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::entry::Entity")]
     Entry,
+    #[sea_orm(has_many = "super::folder_tag::Entity")]
+    FolderTag,
     #[sea_orm(
         belongs_to = "Entity",
         from = "Column::ParentId",
@@ -32,4 +36,10 @@ impl Related<super::entry::Entity> for Entity {
     }
 }
 
+impl Related<super::folder_tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FolderTag.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -1,7 +1,31 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.20
 
+pub use super::asset::Entity as Asset;
+pub use super::asset_reference::Entity as AssetReference;
+pub use super::calendar::Entity as Calendar;
+pub use super::custom_property::Entity as CustomProperty;
+pub use super::custom_property_value::Entity as CustomPropertyValue;
 pub use super::entry::Entity as Entry;
+pub use super::entry_alias::Entity as EntryAlias;
+pub use super::entry_date::Entity as EntryDate;
+pub use super::entry_reference::Entity as EntryReference;
+pub use super::entry_revision::Entity as EntryRevision;
+pub use super::entry_tag::Entity as EntryTag;
+pub use super::entry_template::Entity as EntryTemplate;
+pub use super::event::Entity as Event;
+pub use super::event_participant::Entity as EventParticipant;
 pub use super::folder::Entity as Folder;
+pub use super::folder_tag::Entity as FolderTag;
+pub use super::index_version::Entity as IndexVersion;
+pub use super::inflected_word::Entity as InflectedWord;
+pub use super::journal::Entity as Journal;
 pub use super::language::Entity as Language;
+pub use super::map::Entity as Map;
+pub use super::map_pin::Entity as MapPin;
+pub use super::membership::Entity as Membership;
+pub use super::organization::Entity as Organization;
 pub use super::person::Entity as Person;
+pub use super::person_relationship::Entity as PersonRelationship;
+pub use super::place::Entity as Place;
+pub use super::tag::Entity as Tag;
 pub use super::word::Entity as Word;
@@ -13,10 +13,13 @@ pub struct Model {
     pub spelling: String,
     pub definition: String,
     pub translations: Json,
+    pub revision: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::inflected_word::Entity")]
+    InflectedWord,
     #[sea_orm(
         belongs_to = "super::language::Entity",
         from = "Column::LanguageId",
@@ -27,6 +30,12 @@ pub enum Relation {
     Language,
 }
 
+impl Related<super::inflected_word::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::InflectedWord.def()
+    }
+}
+
 impl Related<super::language::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Language.def()
@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Asset::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Asset::Id).not_null())
+                    // the original name of the file, which can be changed
+                    .col(string(Asset::Name).not_null())
+                    // the SHA-256 of the content, so that the same file is only stored once
+                    .col(string_uniq(Asset::Hash).not_null())
+                    .col(string(Asset::MimeType).not_null())
+                    .col(big_integer(Asset::Size).not_null())
+                    // relative to the project folder
+                    .col(string(Asset::Path).not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Asset::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Asset {
+    Table,
+    Id,
+    Name,
+    Hash,
+    MimeType,
+    Size,
+    Path,
+}
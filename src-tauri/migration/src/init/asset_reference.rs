@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::{asset::Asset, entry::Entry};
+
+const ASSET_REFERENCE_ENTRY_ID_FK_NAME: &str = "fk_asset_reference_entry_id";
+const ASSET_REFERENCE_ASSET_ID_FK_NAME: &str = "fk_asset_reference_asset_id";
+const ASSET_REFERENCE_ASSET_ID_INDEX_NAME: &str = "index_asset_reference_asset_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AssetReference::Table)
+                    .if_not_exists()
+                    .col(uuid(AssetReference::EntryId).not_null())
+                    .col(uuid(AssetReference::AssetId).not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(AssetReference::EntryId)
+                            .col(AssetReference::AssetId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ASSET_REFERENCE_ENTRY_ID_FK_NAME)
+                            .from(AssetReference::Table, AssetReference::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ASSET_REFERENCE_ASSET_ID_FK_NAME)
+                            .from(AssetReference::Table, AssetReference::AssetId)
+                            .to(Asset::Table, Asset::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The primary key already covers lookups by entry.
+        manager
+            .create_index(
+                Index::create()
+                    .name(ASSET_REFERENCE_ASSET_ID_INDEX_NAME)
+                    .table(AssetReference::Table)
+                    .col(AssetReference::AssetId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ASSET_REFERENCE_ASSET_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ASSET_REFERENCE_ASSET_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ASSET_REFERENCE_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(AssetReference::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AssetReference {
+    Table,
+    EntryId,
+    AssetId,
+}
@@ -0,0 +1,79 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const CALENDAR_ENTRY_ID_FK_NAME: &str = "fk_calendar_entry_id";
+const CALENDAR_ENTRY_ID_INDEX_NAME: &str = "index_calendar_entry_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Calendar::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Calendar::Id).not_null())
+                    .col(uuid(Calendar::EntryId).not_null())
+                    .col(json(Calendar::Months).not_null())
+                    .col(json(Calendar::Weekdays).not_null())
+                    .col(json(Calendar::LeapRules).not_null())
+                    .col(json(Calendar::Eras).not_null())
+                    .col(big_integer(Calendar::EpochOffset).not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(CALENDAR_ENTRY_ID_FK_NAME)
+                            .from(Calendar::Table, Calendar::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(CALENDAR_ENTRY_ID_INDEX_NAME)
+                    .table(Calendar::Table)
+                    .col(Calendar::EntryId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(CALENDAR_ENTRY_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(CALENDAR_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Calendar::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Calendar {
+    Table,
+    Id,
+    EntryId,
+    Months,
+    Weekdays,
+    LeapRules,
+    Eras,
+    EpochOffset,
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+const CUSTOM_PROPERTY_ENTITY_TYPE_NAME_INDEX_NAME: &str = "index_custom_property_entity_type_name";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomProperty::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(CustomProperty::Id).not_null())
+                    // the type of the entries that have the property
+                    .col(tiny_integer(CustomProperty::EntityType).not_null())
+                    .col(string(CustomProperty::Name).not_null())
+                    .col(tiny_integer(CustomProperty::PropertyType).not_null())
+                    // the type of the items of list properties
+                    .col(tiny_integer_null(CustomProperty::ItemType))
+                    // the choices of enum properties
+                    .col(json(CustomProperty::Options))
+                    .col(integer(CustomProperty::Position).not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Property names are unique per entity type.
+        manager
+            .create_index(
+                Index::create()
+                    .name(CUSTOM_PROPERTY_ENTITY_TYPE_NAME_INDEX_NAME)
+                    .table(CustomProperty::Table)
+                    .col(CustomProperty::EntityType)
+                    .col(CustomProperty::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(CUSTOM_PROPERTY_ENTITY_TYPE_NAME_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(CustomProperty::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum CustomProperty {
+    Table,
+    Id,
+    EntityType,
+    Name,
+    PropertyType,
+    ItemType,
+    Options,
+    Position,
+}
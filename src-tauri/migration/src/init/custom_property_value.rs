@@ -0,0 +1,96 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::{custom_property::CustomProperty, entry::Entry};
+
+const CUSTOM_PROPERTY_VALUE_ENTRY_ID_FK_NAME: &str = "fk_custom_property_value_entry_id";
+const CUSTOM_PROPERTY_VALUE_PROPERTY_ID_FK_NAME: &str = "fk_custom_property_value_property_id";
+const CUSTOM_PROPERTY_VALUE_PROPERTY_ID_INDEX_NAME: &str =
+    "index_custom_property_value_property_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomPropertyValue::Table)
+                    .if_not_exists()
+                    .col(uuid(CustomPropertyValue::EntryId).not_null())
+                    .col(uuid(CustomPropertyValue::PropertyId).not_null())
+                    .col(json(CustomPropertyValue::Value))
+                    .primary_key(
+                        Index::create()
+                            .col(CustomPropertyValue::EntryId)
+                            .col(CustomPropertyValue::PropertyId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(CUSTOM_PROPERTY_VALUE_ENTRY_ID_FK_NAME)
+                            .from(CustomPropertyValue::Table, CustomPropertyValue::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(CUSTOM_PROPERTY_VALUE_PROPERTY_ID_FK_NAME)
+                            .from(CustomPropertyValue::Table, CustomPropertyValue::PropertyId)
+                            .to(CustomProperty::Table, CustomProperty::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The primary key already covers lookups by entry.
+        manager
+            .create_index(
+                Index::create()
+                    .name(CUSTOM_PROPERTY_VALUE_PROPERTY_ID_INDEX_NAME)
+                    .table(CustomPropertyValue::Table)
+                    .col(CustomPropertyValue::PropertyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(CUSTOM_PROPERTY_VALUE_PROPERTY_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(CUSTOM_PROPERTY_VALUE_PROPERTY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(CUSTOM_PROPERTY_VALUE_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(CustomPropertyValue::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum CustomPropertyValue {
+    Table,
+    EntryId,
+    PropertyId,
+    Value,
+}
@@ -2,9 +2,9 @@ use sea_orm_migration::{prelude::*, schema::*};
 
 use crate::init::folder::Folder;
 
-const ENTRY_TITLE_INDEX_NAME: &str = "index_entry_title";
-const ENTRY_FOLDER_ID_INDEX_NAME: &str = "index_entry_folder_id";
-const ENTRY_FOLDER_ID_FK_NAME: &str = "fk_entry_folder_id";
+pub const ENTRY_TITLE_INDEX_NAME: &str = "index_entry_title";
+pub const ENTRY_FOLDER_ID_INDEX_NAME: &str = "index_entry_folder_id";
+pub const ENTRY_FOLDER_ID_FK_NAME: &str = "fk_entry_folder_id";
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -86,4 +86,6 @@ pub enum Entry {
     EntityType,
     Title,
     Text,
+    Revision,
+    DeletedAt,
 }
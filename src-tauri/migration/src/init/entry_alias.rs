@@ -0,0 +1,76 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const ENTRY_ALIAS_ENTRY_ID_FK_NAME: &str = "fk_entry_alias_entry_id";
+const ENTRY_ALIAS_ENTRY_ID_INDEX_NAME: &str = "index_entry_alias_entry_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EntryAlias::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(EntryAlias::Id).not_null())
+                    .col(uuid(EntryAlias::EntryId).not_null())
+                    // Like titles, aliases are unique across the DB;
+                    // the service also checks them against the titles of other entries.
+                    .col(string_uniq(EntryAlias::Alias).not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_ALIAS_ENTRY_ID_FK_NAME)
+                            .from(EntryAlias::Table, EntryAlias::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_ALIAS_ENTRY_ID_INDEX_NAME)
+                    .table(EntryAlias::Table)
+                    .col(EntryAlias::EntryId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ENTRY_ALIAS_ENTRY_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ENTRY_ALIAS_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EntryAlias::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EntryAlias {
+    Table,
+    Id,
+    EntryId,
+    Alias,
+}
@@ -0,0 +1,137 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const ENTRY_DATE_ENTRY_ID_FK_NAME: &str = "fk_entry_date_entry_id";
+const ENTRY_DATE_CALENDAR_ID_FK_NAME: &str = "fk_entry_date_calendar_id";
+const ENTRY_DATE_ENTRY_ID_INDEX_NAME: &str = "index_entry_date_entry_id";
+const ENTRY_DATE_CALENDAR_ID_INDEX_NAME: &str = "index_entry_date_calendar_id";
+const ENTRY_DATE_DAY_NUMBER_INDEX_NAME: &str = "index_entry_date_day_number";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EntryDate::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(EntryDate::Id).not_null())
+                    .col(uuid(EntryDate::EntryId).not_null())
+                    // the calendar is referenced by the ID of its entry
+                    .col(uuid(EntryDate::CalendarId).not_null())
+                    .col(string(EntryDate::Label))
+                    .col(big_integer(EntryDate::Year).not_null())
+                    .col(integer(EntryDate::Month).not_null())
+                    .col(integer(EntryDate::Day).not_null())
+                    .col(big_integer(EntryDate::DayNumber).not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_DATE_ENTRY_ID_FK_NAME)
+                            .from(EntryDate::Table, EntryDate::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_DATE_CALENDAR_ID_FK_NAME)
+                            .from(EntryDate::Table, EntryDate::CalendarId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_DATE_ENTRY_ID_INDEX_NAME)
+                    .table(EntryDate::Table)
+                    .col(EntryDate::EntryId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_DATE_CALENDAR_ID_INDEX_NAME)
+                    .table(EntryDate::Table)
+                    .col(EntryDate::CalendarId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Dates from different calendars are sorted by their day number.
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_DATE_DAY_NUMBER_INDEX_NAME)
+                    .table(EntryDate::Table)
+                    .col(EntryDate::DayNumber)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ENTRY_DATE_DAY_NUMBER_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ENTRY_DATE_CALENDAR_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ENTRY_DATE_ENTRY_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ENTRY_DATE_CALENDAR_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ENTRY_DATE_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EntryDate::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EntryDate {
+    Table,
+    Id,
+    EntryId,
+    CalendarId,
+    Label,
+    Year,
+    Month,
+    Day,
+    DayNumber,
+}
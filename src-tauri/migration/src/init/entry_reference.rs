@@ -0,0 +1,95 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const ENTRY_REFERENCE_SOURCE_ID_FK_NAME: &str = "fk_entry_reference_source_id";
+const ENTRY_REFERENCE_TARGET_ID_FK_NAME: &str = "fk_entry_reference_target_id";
+const ENTRY_REFERENCE_TARGET_ID_INDEX_NAME: &str = "index_entry_reference_target_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EntryReference::Table)
+                    .if_not_exists()
+                    .col(uuid(EntryReference::SourceId).not_null())
+                    .col(uuid(EntryReference::TargetId).not_null())
+                    .col(integer(EntryReference::MentionCount).not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(EntryReference::SourceId)
+                            .col(EntryReference::TargetId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_REFERENCE_SOURCE_ID_FK_NAME)
+                            .from(EntryReference::Table, EntryReference::SourceId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_REFERENCE_TARGET_ID_FK_NAME)
+                            .from(EntryReference::Table, EntryReference::TargetId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Backlinks are looked up by target; the primary key already covers lookups by source.
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_REFERENCE_TARGET_ID_INDEX_NAME)
+                    .table(EntryReference::Table)
+                    .col(EntryReference::TargetId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ENTRY_REFERENCE_TARGET_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ENTRY_REFERENCE_TARGET_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ENTRY_REFERENCE_SOURCE_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EntryReference::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EntryReference {
+    Table,
+    SourceId,
+    TargetId,
+    MentionCount,
+}
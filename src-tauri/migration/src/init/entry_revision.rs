@@ -0,0 +1,83 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const ENTRY_REVISION_ENTRY_ID_FK_NAME: &str = "fk_entry_revision_entry_id";
+const ENTRY_REVISION_ENTRY_ID_CREATED_AT_INDEX_NAME: &str =
+    "index_entry_revision_entry_id_created_at";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EntryRevision::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(EntryRevision::Id).not_null())
+                    .col(uuid(EntryRevision::EntryId).not_null())
+                    .col(timestamp_with_time_zone(EntryRevision::CreatedAt).not_null())
+                    .col(string(EntryRevision::Title))
+                    .col(string(EntryRevision::Text))
+                    .col(json(EntryRevision::Properties))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_REVISION_ENTRY_ID_FK_NAME)
+                            .from(EntryRevision::Table, EntryRevision::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Revisions are always listed per entry in chronological order.
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_REVISION_ENTRY_ID_CREATED_AT_INDEX_NAME)
+                    .table(EntryRevision::Table)
+                    .col(EntryRevision::EntryId)
+                    .col(EntryRevision::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ENTRY_REVISION_ENTRY_ID_CREATED_AT_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ENTRY_REVISION_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EntryRevision::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EntryRevision {
+    Table,
+    Id,
+    EntryId,
+    CreatedAt,
+    Title,
+    Text,
+    Properties,
+}
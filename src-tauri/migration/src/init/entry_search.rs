@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+const ENTRY_SEARCH_TABLE_NAME: &str = "entry_search";
+const ENTRY_SEARCH_DELETE_TRIGGER_NAME: &str = "trigger_entry_search_delete";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let con = manager.get_connection();
+
+        // sea-query has no support for virtual tables, so the FTS5 index has to be
+        // created with raw SQL. The entry ID is stored alongside the indexed columns
+        // so that search hits can be joined back onto the entry table.
+        // The body column contains the plain text extracted from the entry's article;
+        // the JSON document itself isn't indexed since its markup would pollute the hits.
+        con.execute_unprepared(&format!(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS {ENTRY_SEARCH_TABLE_NAME} USING fts5(\
+                entry_id UNINDEXED, \
+                title, \
+                body, \
+                tokenize = 'unicode61 remove_diacritics 2'\
+            )"
+        ))
+        .await?;
+
+        // Virtual tables can't participate in foreign keys, so a trigger is needed to
+        // purge the index of deleted entries. Triggers also fire on cascading deletes,
+        // which covers entries that are removed along with their folder.
+        con.execute_unprepared(&format!(
+            "CREATE TRIGGER IF NOT EXISTS {ENTRY_SEARCH_DELETE_TRIGGER_NAME} \
+            AFTER DELETE ON entry \
+            BEGIN \
+                DELETE FROM {ENTRY_SEARCH_TABLE_NAME} WHERE entry_id = old.id; \
+            END"
+        ))
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let con = manager.get_connection();
+        con.execute_unprepared(&format!(
+            "DROP TRIGGER IF EXISTS {ENTRY_SEARCH_DELETE_TRIGGER_NAME}"
+        ))
+        .await?;
+        con.execute_unprepared(&format!("DROP TABLE IF EXISTS {ENTRY_SEARCH_TABLE_NAME}"))
+            .await?;
+        Ok(())
+    }
+}
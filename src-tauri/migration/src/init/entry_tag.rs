@@ -0,0 +1,81 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::{entry::Entry, tag::Tag};
+
+const ENTRY_TAG_TAG_ID_FK_NAME: &str = "fk_entry_tag_tag_id";
+const ENTRY_TAG_ENTRY_ID_FK_NAME: &str = "fk_entry_tag_entry_id";
+const ENTRY_TAG_ENTRY_ID_INDEX_NAME: &str = "index_entry_tag_entry_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EntryTag::Table)
+                    .if_not_exists()
+                    .col(uuid(EntryTag::TagId).not_null())
+                    .col(uuid(EntryTag::EntryId).not_null())
+                    .primary_key(Index::create().col(EntryTag::TagId).col(EntryTag::EntryId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_TAG_TAG_ID_FK_NAME)
+                            .from(EntryTag::Table, EntryTag::TagId)
+                            .to(Tag::Table, Tag::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ENTRY_TAG_ENTRY_ID_FK_NAME)
+                            .from(EntryTag::Table, EntryTag::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The primary key already covers lookups by tag.
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_TAG_ENTRY_ID_INDEX_NAME)
+                    .table(EntryTag::Table)
+                    .col(EntryTag::EntryId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(ENTRY_TAG_ENTRY_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ENTRY_TAG_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(ForeignKey::drop().name(ENTRY_TAG_TAG_ID_FK_NAME).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EntryTag::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EntryTag {
+    Table,
+    TagId,
+    EntryId,
+}
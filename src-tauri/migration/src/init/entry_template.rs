@@ -0,0 +1,68 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+const ENTRY_TEMPLATE_ENTITY_TYPE_NAME_INDEX_NAME: &str = "index_entry_template_entity_type_name";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EntryTemplate::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(EntryTemplate::Id).not_null())
+                    // the type of the entries created from the template
+                    .col(tiny_integer(EntryTemplate::EntityType).not_null())
+                    .col(string(EntryTemplate::Name).not_null())
+                    // the starter document, stored like the text of entries
+                    .col(string(EntryTemplate::Text))
+                    .col(json_null(EntryTemplate::Properties))
+                    .col(json(EntryTemplate::CustomProperties))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Template names are unique per entity type.
+        manager
+            .create_index(
+                Index::create()
+                    .name(ENTRY_TEMPLATE_ENTITY_TYPE_NAME_INDEX_NAME)
+                    .table(EntryTemplate::Table)
+                    .col(EntryTemplate::EntityType)
+                    .col(EntryTemplate::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ENTRY_TEMPLATE_ENTITY_TYPE_NAME_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EntryTemplate::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EntryTemplate {
+    Table,
+    Id,
+    EntityType,
+    Name,
+    Text,
+    Properties,
+    CustomProperties,
+}
@@ -0,0 +1,152 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const EVENT_ENTRY_ID_FK_NAME: &str = "fk_event_entry_id";
+const EVENT_CALENDAR_ID_FK_NAME: &str = "fk_event_calendar_id";
+const EVENT_LOCATION_ID_FK_NAME: &str = "fk_event_location_id";
+const EVENT_ENTRY_ID_INDEX_NAME: &str = "index_event_entry_id";
+const EVENT_CALENDAR_ID_INDEX_NAME: &str = "index_event_calendar_id";
+const EVENT_START_DAY_NUMBER_INDEX_NAME: &str = "index_event_start_day_number";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Event::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Event::Id).not_null())
+                    .col(uuid(Event::EntryId).not_null())
+                    // the calendar is referenced by the ID of its entry
+                    .col(uuid(Event::CalendarId).not_null())
+                    .col(big_integer(Event::StartYear).not_null())
+                    .col(integer(Event::StartMonth).not_null())
+                    .col(integer(Event::StartDay).not_null())
+                    .col(big_integer(Event::StartDayNumber).not_null())
+                    .col(big_integer_null(Event::EndYear))
+                    .col(integer_null(Event::EndMonth))
+                    .col(integer_null(Event::EndDay))
+                    .col(big_integer_null(Event::EndDayNumber))
+                    .col(tiny_integer(Event::Precision).not_null())
+                    .col(uuid_null(Event::LocationId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(EVENT_ENTRY_ID_FK_NAME)
+                            .from(Event::Table, Event::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // calendars can't be deleted while events are dated with them
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(EVENT_CALENDAR_ID_FK_NAME)
+                            .from(Event::Table, Event::CalendarId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(EVENT_LOCATION_ID_FK_NAME)
+                            .from(Event::Table, Event::LocationId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(EVENT_ENTRY_ID_INDEX_NAME)
+                    .table(Event::Table)
+                    .col(Event::EntryId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(EVENT_CALENDAR_ID_INDEX_NAME)
+                    .table(Event::Table)
+                    .col(Event::CalendarId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // The timeline is sorted by day number.
+        manager
+            .create_index(
+                Index::create()
+                    .name(EVENT_START_DAY_NUMBER_INDEX_NAME)
+                    .table(Event::Table)
+                    .col(Event::StartDayNumber)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(EVENT_START_DAY_NUMBER_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(Index::drop().name(EVENT_CALENDAR_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_index(Index::drop().name(EVENT_ENTRY_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(EVENT_LOCATION_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(EVENT_CALENDAR_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(ForeignKey::drop().name(EVENT_ENTRY_ID_FK_NAME).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Event::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Event {
+    Table,
+    Id,
+    EntryId,
+    CalendarId,
+    StartYear,
+    StartMonth,
+    StartDay,
+    StartDayNumber,
+    EndYear,
+    EndMonth,
+    EndDay,
+    EndDayNumber,
+    Precision,
+    LocationId,
+}
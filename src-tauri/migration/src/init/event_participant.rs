@@ -0,0 +1,94 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const EVENT_PARTICIPANT_EVENT_ID_FK_NAME: &str = "fk_event_participant_event_id";
+const EVENT_PARTICIPANT_PARTICIPANT_ID_FK_NAME: &str = "fk_event_participant_participant_id";
+const EVENT_PARTICIPANT_PARTICIPANT_ID_INDEX_NAME: &str = "index_event_participant_participant_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EventParticipant::Table)
+                    .if_not_exists()
+                    // the event is referenced by the ID of its entry
+                    .col(uuid(EventParticipant::EventId).not_null())
+                    .col(uuid(EventParticipant::ParticipantId).not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(EventParticipant::EventId)
+                            .col(EventParticipant::ParticipantId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(EVENT_PARTICIPANT_EVENT_ID_FK_NAME)
+                            .from(EventParticipant::Table, EventParticipant::EventId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(EVENT_PARTICIPANT_PARTICIPANT_ID_FK_NAME)
+                            .from(EventParticipant::Table, EventParticipant::ParticipantId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The timeline is filtered by participant; the primary key already covers lookups by event.
+        manager
+            .create_index(
+                Index::create()
+                    .name(EVENT_PARTICIPANT_PARTICIPANT_ID_INDEX_NAME)
+                    .table(EventParticipant::Table)
+                    .col(EventParticipant::ParticipantId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(EVENT_PARTICIPANT_PARTICIPANT_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(EVENT_PARTICIPANT_PARTICIPANT_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(EVENT_PARTICIPANT_EVENT_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(EventParticipant::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EventParticipant {
+    Table,
+    EventId,
+    ParticipantId,
+}
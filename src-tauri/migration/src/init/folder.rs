@@ -6,7 +6,7 @@ pub struct Migration;
 
 pub const ROOT_FOLDER_ID: Uuid = Uuid::nil();
 const FOLDER_PARENT_ID_INDEX_NAME: &str = "index_folder_parent_id";
-const FOLDER_PARENT_ID_NAME_INDEX_NAME: &str = "index_folder_parent_id_name";
+pub const FOLDER_PARENT_ID_NAME_INDEX_NAME: &str = "index_folder_parent_id_name";
 const FOLDER_PARENT_ID_FK_NAME: &str = "fk_folder_parent_id";
 
 #[async_trait::async_trait]
@@ -98,4 +98,6 @@ pub enum Folder {
     ParentId,
     ParentIdNotNull,
     Name,
+    Revision,
+    DeletedAt,
 }
@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::{folder::Folder, tag::Tag};
+
+const FOLDER_TAG_TAG_ID_FK_NAME: &str = "fk_folder_tag_tag_id";
+const FOLDER_TAG_FOLDER_ID_FK_NAME: &str = "fk_folder_tag_folder_id";
+const FOLDER_TAG_FOLDER_ID_INDEX_NAME: &str = "index_folder_tag_folder_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FolderTag::Table)
+                    .if_not_exists()
+                    .col(uuid(FolderTag::TagId).not_null())
+                    .col(uuid(FolderTag::FolderId).not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(FolderTag::TagId)
+                            .col(FolderTag::FolderId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FOLDER_TAG_TAG_ID_FK_NAME)
+                            .from(FolderTag::Table, FolderTag::TagId)
+                            .to(Tag::Table, Tag::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(FOLDER_TAG_FOLDER_ID_FK_NAME)
+                            .from(FolderTag::Table, FolderTag::FolderId)
+                            .to(Folder::Table, Folder::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The primary key already covers lookups by tag.
+        manager
+            .create_index(
+                Index::create()
+                    .name(FOLDER_TAG_FOLDER_ID_INDEX_NAME)
+                    .table(FolderTag::Table)
+                    .col(FolderTag::FolderId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(FOLDER_TAG_FOLDER_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FOLDER_TAG_FOLDER_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FOLDER_TAG_TAG_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(FolderTag::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FolderTag {
+    Table,
+    TagId,
+    FolderId,
+}
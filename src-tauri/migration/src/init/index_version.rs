@@ -0,0 +1,39 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IndexVersion::Table)
+                    .if_not_exists()
+                    // the index that is derived from the entries, e.g., "entry_reference"
+                    .col(string(IndexVersion::Name).primary_key().not_null())
+                    // the version of the code that built the index,
+                    // so that an index that's missing or out of date can be rebuilt
+                    .col(integer(IndexVersion::Version).not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IndexVersion::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum IndexVersion {
+    Table,
+    Name,
+    Version,
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::word::Word;
+
+const INFLECTED_WORD_WORD_ID_FK_NAME: &str = "fk_inflected_word_word_id";
+const INFLECTED_WORD_WORD_ID_INDEX_NAME: &str = "index_inflected_word_word_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(InflectedWord::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(InflectedWord::Id).not_null())
+                    .col(uuid(InflectedWord::WordId).not_null())
+                    .col(string(InflectedWord::Spelling))
+                    // grammatical categories are stored as codes; 0 means that a category doesn't apply
+                    .col(tiny_integer(InflectedWord::Case))
+                    .col(tiny_integer(InflectedWord::Voice))
+                    .col(tiny_integer(InflectedWord::Mood))
+                    .col(tiny_integer(InflectedWord::Tense))
+                    .col(tiny_integer(InflectedWord::Aspect))
+                    .col(tiny_integer(InflectedWord::Definiteness))
+                    .col(tiny_integer(InflectedWord::Number))
+                    .col(tiny_integer(InflectedWord::Person))
+                    .col(tiny_integer(InflectedWord::Gender))
+                    .col(tiny_integer(InflectedWord::Animacy))
+                    .col(tiny_integer(InflectedWord::VerbForm))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(INFLECTED_WORD_WORD_ID_FK_NAME)
+                            .from(InflectedWord::Table, InflectedWord::WordId)
+                            .to(Word::Table, Word::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(INFLECTED_WORD_WORD_ID_INDEX_NAME)
+                    .table(InflectedWord::Table)
+                    .col(InflectedWord::WordId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INFLECTED_WORD_WORD_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(INFLECTED_WORD_WORD_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(InflectedWord::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum InflectedWord {
+    Table,
+    Id,
+    WordId,
+    Spelling,
+    Case,
+    Voice,
+    Mood,
+    Tense,
+    Aspect,
+    Definiteness,
+    Number,
+    Person,
+    Gender,
+    Animacy,
+    VerbForm,
+}
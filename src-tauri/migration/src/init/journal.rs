@@ -0,0 +1,49 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Journal::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Journal::Id).not_null())
+                    // the order in which the operations were made,
+                    // since several of them can share a timestamp
+                    .col(big_integer_uniq(Journal::Position).not_null())
+                    .col(timestamp_with_time_zone(Journal::CreatedAt).not_null())
+                    .col(string(Journal::Label).not_null())
+                    // the operations that revert the change and those that make it again
+                    .col(json(Journal::Undo).not_null())
+                    .col(json(Journal::Redo).not_null())
+                    .col(boolean(Journal::IsUndone).not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Journal::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Journal {
+    Table,
+    Id,
+    Position,
+    CreatedAt,
+    Label,
+    Undo,
+    Redo,
+    IsUndone,
+}
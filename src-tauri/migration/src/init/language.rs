@@ -66,4 +66,5 @@ pub enum Language {
     Table,
     Id,
     EntryId,
+    Grammar,
 }
@@ -0,0 +1,32 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::language::Language;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // languages created before grammars were declared don't restrict their words
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Language::Table)
+                    .add_column(json_null(Language::Grammar))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Language::Table)
+                    .drop_column(Language::Grammar)
+                    .to_owned(),
+            )
+            .await
+    }
+}
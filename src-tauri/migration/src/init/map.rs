@@ -0,0 +1,72 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const MAP_ENTRY_ID_FK_NAME: &str = "fk_map_entry_id";
+const MAP_ENTRY_ID_INDEX_NAME: &str = "index_map_entry_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Map::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Map::Id).not_null())
+                    .col(uuid(Map::EntryId).not_null())
+                    .col(double(Map::Width).not_null())
+                    .col(double(Map::Height).not_null())
+                    // relative to the project folder
+                    .col(string_null(Map::ImagePath))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(MAP_ENTRY_ID_FK_NAME)
+                            .from(Map::Table, Map::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(MAP_ENTRY_ID_INDEX_NAME)
+                    .table(Map::Table)
+                    .col(Map::EntryId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(MAP_ENTRY_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(ForeignKey::drop().name(MAP_ENTRY_ID_FK_NAME).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Map::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Map {
+    Table,
+    Id,
+    EntryId,
+    Width,
+    Height,
+    ImagePath,
+}
@@ -0,0 +1,88 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const MAP_PIN_MAP_ID_FK_NAME: &str = "fk_map_pin_map_id";
+const MAP_PIN_MAP_ID_INDEX_NAME: &str = "index_map_pin_map_id";
+const MAP_PIN_TARGET_ID_INDEX_NAME: &str = "index_map_pin_target_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MapPin::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(MapPin::Id).not_null())
+                    // the map is referenced by the ID of its entry
+                    .col(uuid(MapPin::MapId).not_null())
+                    // like mentions, pins keep the ID of their target after it is deleted,
+                    // so the target isn't a foreign key
+                    .col(uuid(MapPin::TargetId).not_null())
+                    .col(tiny_integer(MapPin::Shape).not_null())
+                    .col(json(MapPin::Points))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(MAP_PIN_MAP_ID_FK_NAME)
+                            .from(MapPin::Table, MapPin::MapId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(MAP_PIN_MAP_ID_INDEX_NAME)
+                    .table(MapPin::Table)
+                    .col(MapPin::MapId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // The maps that show an entry are looked up by target.
+        manager
+            .create_index(
+                Index::create()
+                    .name(MAP_PIN_TARGET_ID_INDEX_NAME)
+                    .table(MapPin::Table)
+                    .col(MapPin::TargetId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(MAP_PIN_TARGET_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_index(Index::drop().name(MAP_PIN_MAP_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(ForeignKey::drop().name(MAP_PIN_MAP_ID_FK_NAME).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(MapPin::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum MapPin {
+    Table,
+    Id,
+    MapId,
+    TargetId,
+    Shape,
+    Points,
+}
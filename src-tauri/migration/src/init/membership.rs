@@ -0,0 +1,143 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const MEMBERSHIP_ORGANIZATION_ID_FK_NAME: &str = "fk_membership_organization_id";
+const MEMBERSHIP_PERSON_ID_FK_NAME: &str = "fk_membership_person_id";
+const MEMBERSHIP_CALENDAR_ID_FK_NAME: &str = "fk_membership_calendar_id";
+const MEMBERSHIP_ORGANIZATION_ID_INDEX_NAME: &str = "index_membership_organization_id";
+const MEMBERSHIP_PERSON_ID_INDEX_NAME: &str = "index_membership_person_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Membership::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Membership::Id).not_null())
+                    // the organization and the person are referenced by the IDs of their entries
+                    .col(uuid(Membership::OrganizationId).not_null())
+                    .col(uuid(Membership::PersonId).not_null())
+                    .col(string(Membership::Role).not_null())
+                    .col(integer_null(Membership::Rank))
+                    // the start and end dates share a calendar
+                    .col(uuid_null(Membership::CalendarId))
+                    .col(big_integer_null(Membership::StartYear))
+                    .col(integer_null(Membership::StartMonth))
+                    .col(integer_null(Membership::StartDay))
+                    .col(big_integer_null(Membership::EndYear))
+                    .col(integer_null(Membership::EndMonth))
+                    .col(integer_null(Membership::EndDay))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(MEMBERSHIP_ORGANIZATION_ID_FK_NAME)
+                            .from(Membership::Table, Membership::OrganizationId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(MEMBERSHIP_PERSON_ID_FK_NAME)
+                            .from(Membership::Table, Membership::PersonId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // calendars can't be deleted while memberships are dated with them
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(MEMBERSHIP_CALENDAR_ID_FK_NAME)
+                            .from(Membership::Table, Membership::CalendarId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(MEMBERSHIP_ORGANIZATION_ID_INDEX_NAME)
+                    .table(Membership::Table)
+                    .col(Membership::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(MEMBERSHIP_PERSON_ID_INDEX_NAME)
+                    .table(Membership::Table)
+                    .col(Membership::PersonId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(MEMBERSHIP_PERSON_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(MEMBERSHIP_ORGANIZATION_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(MEMBERSHIP_CALENDAR_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(MEMBERSHIP_PERSON_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(MEMBERSHIP_ORGANIZATION_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Membership::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Membership {
+    Table,
+    Id,
+    OrganizationId,
+    PersonId,
+    Role,
+    Rank,
+    CalendarId,
+    StartYear,
+    StartMonth,
+    StartDay,
+    EndYear,
+    EndMonth,
+    EndDay,
+}
@@ -1,6 +1,34 @@
 // NOTE: these migrations are subject to change until 1.0.0 is released
+pub mod asset;
+pub mod asset_reference;
+pub mod calendar;
+pub mod custom_property;
+pub mod custom_property_value;
 pub mod entry;
+pub mod entry_alias;
+pub mod entry_date;
+pub mod entry_reference;
+pub mod entry_revision;
+pub mod entry_search;
+pub mod entry_tag;
+pub mod entry_template;
+pub mod event;
+pub mod event_participant;
 pub mod folder;
+pub mod folder_tag;
+pub mod index_version;
+pub mod inflected_word;
+pub mod journal;
 pub mod language;
+pub mod language_grammar;
+pub mod map;
+pub mod map_pin;
+pub mod membership;
+pub mod organization;
 pub mod person;
+pub mod person_relationship;
+pub mod place;
+pub mod revision_counter;
+pub mod tag;
+pub mod trash;
 pub mod word;
@@ -0,0 +1,110 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const ORGANIZATION_ENTRY_ID_FK_NAME: &str = "fk_organization_entry_id";
+const ORGANIZATION_PARENT_ID_FK_NAME: &str = "fk_organization_parent_id";
+const ORGANIZATION_ENTRY_ID_INDEX_NAME: &str = "index_organization_entry_id";
+const ORGANIZATION_PARENT_ID_INDEX_NAME: &str = "index_organization_parent_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Organization::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Organization::Id).not_null())
+                    .col(uuid(Organization::EntryId).not_null())
+                    // the parent organization is referenced by the ID of its entry
+                    .col(uuid_null(Organization::ParentId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ORGANIZATION_ENTRY_ID_FK_NAME)
+                            .from(Organization::Table, Organization::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // organizations become independent when their parent is deleted
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(ORGANIZATION_PARENT_ID_FK_NAME)
+                            .from(Organization::Table, Organization::ParentId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(ORGANIZATION_ENTRY_ID_INDEX_NAME)
+                    .table(Organization::Table)
+                    .col(Organization::EntryId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(ORGANIZATION_PARENT_ID_INDEX_NAME)
+                    .table(Organization::Table)
+                    .col(Organization::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ORGANIZATION_PARENT_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(ORGANIZATION_ENTRY_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ORGANIZATION_PARENT_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(ORGANIZATION_ENTRY_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Organization::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Organization {
+    Table,
+    Id,
+    EntryId,
+    ParentId,
+}
@@ -0,0 +1,144 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const PERSON_RELATIONSHIP_PERSON_ID_FK_NAME: &str = "fk_person_relationship_person_id";
+const PERSON_RELATIONSHIP_RELATIVE_ID_FK_NAME: &str = "fk_person_relationship_relative_id";
+const PERSON_RELATIONSHIP_CALENDAR_ID_FK_NAME: &str = "fk_person_relationship_calendar_id";
+const PERSON_RELATIONSHIP_PERSON_ID_INDEX_NAME: &str = "index_person_relationship_person_id";
+const PERSON_RELATIONSHIP_RELATIVE_ID_INDEX_NAME: &str = "index_person_relationship_relative_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PersonRelationship::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(PersonRelationship::Id).not_null())
+                    // both people are referenced by the IDs of their entries;
+                    // for parent relationships, the person is the parent of the relative
+                    .col(uuid(PersonRelationship::PersonId).not_null())
+                    .col(uuid(PersonRelationship::RelativeId).not_null())
+                    .col(tiny_integer(PersonRelationship::RelationshipType).not_null())
+                    .col(boolean(PersonRelationship::IsAdoptive).not_null())
+                    // the start and end dates share a calendar
+                    .col(uuid_null(PersonRelationship::CalendarId))
+                    .col(big_integer_null(PersonRelationship::StartYear))
+                    .col(integer_null(PersonRelationship::StartMonth))
+                    .col(integer_null(PersonRelationship::StartDay))
+                    .col(big_integer_null(PersonRelationship::EndYear))
+                    .col(integer_null(PersonRelationship::EndMonth))
+                    .col(integer_null(PersonRelationship::EndDay))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(PERSON_RELATIONSHIP_PERSON_ID_FK_NAME)
+                            .from(PersonRelationship::Table, PersonRelationship::PersonId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(PERSON_RELATIONSHIP_RELATIVE_ID_FK_NAME)
+                            .from(PersonRelationship::Table, PersonRelationship::RelativeId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // calendars can't be deleted while relationships are dated with them
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(PERSON_RELATIONSHIP_CALENDAR_ID_FK_NAME)
+                            .from(PersonRelationship::Table, PersonRelationship::CalendarId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(PERSON_RELATIONSHIP_PERSON_ID_INDEX_NAME)
+                    .table(PersonRelationship::Table)
+                    .col(PersonRelationship::PersonId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(PERSON_RELATIONSHIP_RELATIVE_ID_INDEX_NAME)
+                    .table(PersonRelationship::Table)
+                    .col(PersonRelationship::RelativeId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(PERSON_RELATIONSHIP_RELATIVE_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(PERSON_RELATIONSHIP_PERSON_ID_INDEX_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(PERSON_RELATIONSHIP_CALENDAR_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(PERSON_RELATIONSHIP_RELATIVE_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(PERSON_RELATIONSHIP_PERSON_ID_FK_NAME)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(PersonRelationship::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PersonRelationship {
+    Table,
+    Id,
+    PersonId,
+    RelativeId,
+    RelationshipType,
+    IsAdoptive,
+    CalendarId,
+    StartYear,
+    StartMonth,
+    StartDay,
+    EndYear,
+    EndMonth,
+    EndDay,
+}
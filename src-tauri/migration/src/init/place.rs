@@ -0,0 +1,96 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::entry::Entry;
+
+const PLACE_ENTRY_ID_FK_NAME: &str = "fk_place_entry_id";
+const PLACE_PARENT_ID_FK_NAME: &str = "fk_place_parent_id";
+const PLACE_ENTRY_ID_INDEX_NAME: &str = "index_place_entry_id";
+const PLACE_PARENT_ID_INDEX_NAME: &str = "index_place_parent_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Place::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Place::Id).not_null())
+                    .col(uuid(Place::EntryId).not_null())
+                    .col(tiny_integer(Place::PlaceType).not_null())
+                    // the parent place is referenced by the ID of its entry
+                    .col(uuid_null(Place::ParentId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(PLACE_ENTRY_ID_FK_NAME)
+                            .from(Place::Table, Place::EntryId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // places become top-level places when their parent is deleted
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name(PLACE_PARENT_ID_FK_NAME)
+                            .from(Place::Table, Place::ParentId)
+                            .to(Entry::Table, Entry::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(PLACE_ENTRY_ID_INDEX_NAME)
+                    .table(Place::Table)
+                    .col(Place::EntryId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(PLACE_PARENT_ID_INDEX_NAME)
+                    .table(Place::Table)
+                    .col(Place::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(PLACE_PARENT_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_index(Index::drop().name(PLACE_ENTRY_ID_INDEX_NAME).to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(ForeignKey::drop().name(PLACE_PARENT_ID_FK_NAME).to_owned())
+            .await?;
+        manager
+            .drop_foreign_key(ForeignKey::drop().name(PLACE_ENTRY_ID_FK_NAME).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Place::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Place {
+    Table,
+    Id,
+    EntryId,
+    PlaceType,
+    ParentId,
+}
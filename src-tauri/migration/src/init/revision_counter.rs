@@ -0,0 +1,65 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::init::{entry::Entry, folder::Folder, word::Word};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The revision goes up on every write, so that stale updates can be detected.
+        // Rows that predate the counter start at 0. SQLite can only add one column per statement.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entry::Table)
+                    .add_column(integer(Entry::Revision).default(0))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Folder::Table)
+                    .add_column(integer(Folder::Revision).default(0))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Word::Table)
+                    .add_column(integer(Word::Revision).default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Word::Table)
+                    .drop_column(Word::Revision)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Folder::Table)
+                    .drop_column(Folder::Revision)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entry::Table)
+                    .drop_column(Entry::Revision)
+                    .to_owned(),
+            )
+            .await
+    }
+}
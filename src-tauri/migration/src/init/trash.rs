@@ -0,0 +1,204 @@
+use sea_orm_migration::{
+    prelude::*,
+    schema::*,
+    sea_orm::{
+        sqlx::{self, Connection, SqliteConnection},
+        RuntimeErr,
+    },
+    SchemaManagerConnection,
+};
+
+use crate::init::{
+    entry::{Entry, ENTRY_FOLDER_ID_FK_NAME, ENTRY_FOLDER_ID_INDEX_NAME, ENTRY_TITLE_INDEX_NAME},
+    folder::{Folder, FOLDER_PARENT_ID_NAME_INDEX_NAME},
+};
+
+const ENTRY_TEMP_TABLE_NAME: &str = "entry_new";
+/// The columns that the entry table had before the trash; later migrations add their own.
+const ENTRY_COLUMN_NAMES: &str = "id, folder_id, entity_type, title, text";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // folders in the trash are marked with the time at which they were deleted
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Folder::Table)
+                    .add_column(timestamp_with_time_zone_null(Folder::DeletedAt))
+                    .to_owned(),
+            )
+            .await?;
+        // folders in the trash don't take up their names
+        _recreate_folder_name_index(manager, true).await?;
+
+        _rebuild_entry_table(manager, true).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        _rebuild_entry_table(manager, false).await?;
+
+        _recreate_folder_name_index(manager, false).await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Folder::Table)
+                    .drop_column(Folder::DeletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+async fn _recreate_folder_name_index(
+    manager: &SchemaManager<'_>,
+    has_trash: bool,
+) -> Result<(), DbErr> {
+    manager
+        .drop_index(
+            Index::drop()
+                .name(FOLDER_PARENT_ID_NAME_INDEX_NAME)
+                .to_owned(),
+        )
+        .await?;
+
+    let mut index = Index::create()
+        .name(FOLDER_PARENT_ID_NAME_INDEX_NAME)
+        .table(Folder::Table)
+        .col(Folder::ParentIdNotNull)
+        .col(Folder::Name)
+        .unique()
+        .to_owned();
+    if has_trash {
+        index.and_where(Expr::col(Folder::DeletedAt).is_null());
+    }
+    manager.create_index(index).await
+}
+
+/// Rebuilds the entry table with or without the trash.
+/// The unique constraint on the title is part of the table definition, and SQLite can only
+/// drop it by copying the table, see https://www.sqlite.org/lang_altertable.html#otheralter.
+async fn _rebuild_entry_table(manager: &SchemaManager<'_>, has_trash: bool) -> Result<(), DbErr> {
+    let SchemaManagerConnection::Connection(con) = manager.get_connection() else {
+        return Err(DbErr::Migration(
+            "The entry table can't be rebuilt inside a transaction.".to_owned(),
+        ));
+    };
+
+    // Dropping the old table would cascade into every table that references it unless
+    // foreign keys are disabled, which is only possible outside of a transaction and
+    // only for a single connection. The whole rebuild therefore runs on its own connection.
+    let mut con = con
+        .get_sqlite_connection_pool()
+        .acquire()
+        .await
+        .map_err(|e| DbErr::Conn(RuntimeErr::SqlxError(e)))?;
+    _execute(&mut con, "PRAGMA foreign_keys = OFF").await?;
+    let result = _copy_entry_table(&mut con, has_trash).await;
+    _execute(&mut con, "PRAGMA foreign_keys = ON").await?;
+    result
+}
+
+async fn _copy_entry_table(con: &mut SqliteConnection, has_trash: bool) -> Result<(), DbErr> {
+    let entry_table_name = Entry::Table.to_string();
+    let mut txn = con.begin().await.map_err(_exec_err)?;
+
+    // triggers are dropped along with the table, so they have to be recreated afterwards
+    let triggers: Vec<String> =
+        sqlx::query_scalar("SELECT sql FROM sqlite_master WHERE type = 'trigger' AND tbl_name = ?")
+            .bind(&entry_table_name)
+            .fetch_all(&mut *txn)
+            .await
+            .map_err(_exec_err)?;
+
+    let mut table = Table::create()
+        .table(Alias::new(ENTRY_TEMP_TABLE_NAME))
+        .col(pk_uuid(Entry::Id).not_null())
+        .col(uuid_null(Entry::FolderId))
+        .col(tiny_unsigned(Entry::EntityType).not_null())
+        .to_owned();
+    if has_trash {
+        // titles only have to be unique outside of the trash, which the title index enforces
+        table.col(string(Entry::Title).not_null());
+    } else {
+        table.col(string_uniq(Entry::Title).not_null());
+    }
+    table.col(string(Entry::Text));
+    if has_trash {
+        // entries in the trash are marked with the time at which they were deleted
+        table.col(timestamp_with_time_zone_null(Entry::DeletedAt));
+    }
+    table.foreign_key(
+        ForeignKey::create()
+            .name(ENTRY_FOLDER_ID_FK_NAME)
+            .from(Alias::new(ENTRY_TEMP_TABLE_NAME), Entry::FolderId)
+            .to(Folder::Table, Folder::Id)
+            .on_delete(ForeignKeyAction::Cascade),
+    );
+    _execute(&mut txn, &table.to_string(SqliteQueryBuilder)).await?;
+
+    _execute(
+        &mut txn,
+        &format!(
+            "INSERT INTO {ENTRY_TEMP_TABLE_NAME} ({ENTRY_COLUMN_NAMES}) \
+            SELECT {ENTRY_COLUMN_NAMES} FROM {entry_table_name}"
+        ),
+    )
+    .await?;
+    _execute(&mut txn, &format!("DROP TABLE {entry_table_name}")).await?;
+    _execute(
+        &mut txn,
+        &format!("ALTER TABLE {ENTRY_TEMP_TABLE_NAME} RENAME TO {entry_table_name}"),
+    )
+    .await?;
+
+    let mut title_index = Index::create()
+        .name(ENTRY_TITLE_INDEX_NAME)
+        .table(Entry::Table)
+        .col(Entry::Title)
+        .to_owned();
+    if has_trash {
+        title_index
+            .unique()
+            .and_where(Expr::col(Entry::DeletedAt).is_null());
+    }
+    _execute(&mut txn, &title_index.to_string(SqliteQueryBuilder)).await?;
+    let folder_id_index = Index::create()
+        .name(ENTRY_FOLDER_ID_INDEX_NAME)
+        .table(Entry::Table)
+        .col(Entry::FolderId)
+        .to_owned();
+    _execute(&mut txn, &folder_id_index.to_string(SqliteQueryBuilder)).await?;
+
+    for trigger in triggers {
+        _execute(&mut txn, &trigger).await?;
+    }
+
+    // the transaction is rolled back when it's dropped
+    let violation = sqlx::query("PRAGMA foreign_key_check")
+        .fetch_optional(&mut *txn)
+        .await
+        .map_err(_exec_err)?;
+    if violation.is_some() {
+        return Err(DbErr::Migration(
+            "Rebuilding the entry table violated a foreign key.".to_owned(),
+        ));
+    }
+
+    txn.commit().await.map_err(_exec_err)
+}
+
+async fn _execute(con: &mut SqliteConnection, sql: &str) -> Result<(), DbErr> {
+    sqlx::query(sql)
+        .execute(con)
+        .await
+        .map(|_| ())
+        .map_err(_exec_err)
+}
+
+fn _exec_err(e: sqlx::Error) -> DbErr {
+    DbErr::Exec(RuntimeErr::SqlxError(e))
+}
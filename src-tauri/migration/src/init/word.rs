@@ -46,7 +46,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum Word {
+pub enum Word {
     Table,
     Id,
     LanguageId,
@@ -54,4 +54,5 @@ enum Word {
     Spelling,
     Definition,
     Translations,
+    Revision,
 }
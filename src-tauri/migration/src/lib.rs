@@ -15,6 +15,34 @@ impl MigratorTrait for Migrator {
             Box::new(init::language::Migration),
             Box::new(init::word::Migration),
             Box::new(init::person::Migration),
+            Box::new(init::entry_search::Migration),
+            Box::new(init::entry_reference::Migration),
+            Box::new(init::entry_revision::Migration),
+            Box::new(init::calendar::Migration),
+            Box::new(init::entry_date::Migration),
+            Box::new(init::event::Migration),
+            Box::new(init::event_participant::Migration),
+            Box::new(init::inflected_word::Migration),
+            Box::new(init::language_grammar::Migration),
+            Box::new(init::place::Migration),
+            Box::new(init::map::Migration),
+            Box::new(init::map_pin::Migration),
+            Box::new(init::organization::Migration),
+            Box::new(init::membership::Migration),
+            Box::new(init::person_relationship::Migration),
+            Box::new(init::entry_alias::Migration),
+            Box::new(init::tag::Migration),
+            Box::new(init::entry_tag::Migration),
+            Box::new(init::folder_tag::Migration),
+            Box::new(init::custom_property::Migration),
+            Box::new(init::custom_property_value::Migration),
+            Box::new(init::entry_template::Migration),
+            Box::new(init::asset::Migration),
+            Box::new(init::asset_reference::Migration),
+            Box::new(init::journal::Migration),
+            Box::new(init::trash::Migration),
+            Box::new(init::revision_counter::Migration),
+            Box::new(init::index_version::Migration),
             // migrations after 1.0.0 go here
         ]
     }
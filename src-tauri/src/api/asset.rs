@@ -0,0 +1,65 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::{asset::AssetResponseSchema, entry::EntryInfoResponseSchema};
+use crate::services::{asset_service, project_service};
+
+#[tauri::command]
+pub async fn import_asset(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    source_path: String,
+) -> Result<AssetResponseSchema, Error> {
+    let state = state.lock().await;
+    let project = project_service::get_project(&state, project_id)?;
+    asset_service::import(
+        &project.database.connection,
+        &project.folder_path,
+        &source_path,
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn get_assets(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+) -> Result<Vec<AssetResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    asset_service::get_all(db).await
+}
+
+#[tauri::command]
+pub async fn rename_asset(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+    name: String,
+) -> Result<AssetResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    asset_service::rename(db, id, &name).await
+}
+
+#[tauri::command]
+pub async fn delete_asset(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let project = project_service::get_project(&state, project_id)?;
+    asset_service::delete(&project.database.connection, &project.folder_path, id).await
+}
+
+#[tauri::command]
+pub async fn get_asset_references(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    asset_service::get_referencing_entries(db, id).await
+}
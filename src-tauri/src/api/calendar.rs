@@ -0,0 +1,87 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::calendar::{
+    CalendarDateResponseSchema, CalendarDateSchema, EntryDateCreateSchema, EntryDateResponseSchema,
+    EntryDateUpdateSchema,
+};
+use crate::services::{calendar_service, entry_date_service, project_service};
+
+#[tauri::command]
+pub async fn describe_date(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    date: CalendarDateSchema,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    calendar_service::describe_date(db, date).await
+}
+
+#[tauri::command]
+pub async fn parse_date(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    calendar_id: Uuid,
+    text: &str,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    calendar_service::parse_date(db, calendar_id, text).await
+}
+
+#[tauri::command]
+pub async fn convert_date(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    date: CalendarDateSchema,
+    calendar_id: Uuid,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    calendar_service::convert_date(db, date, calendar_id).await
+}
+
+#[tauri::command]
+pub async fn create_entry_date(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    entry_date: EntryDateCreateSchema,
+) -> Result<EntryDateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_date_service::create(db, entry_date).await
+}
+
+#[tauri::command]
+pub async fn update_entry_date(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    entry_date: EntryDateUpdateSchema,
+) -> Result<EntryDateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_date_service::update(db, entry_date).await
+}
+
+#[tauri::command]
+pub async fn get_entry_dates(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    entry_id: Uuid,
+) -> Result<Vec<EntryDateResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_date_service::get_all_for_entry(db, entry_id).await
+}
+
+#[tauri::command]
+pub async fn delete_entry_date(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_date_service::delete(db, id).await
+}
@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::custom_property::{
+    CustomPropertyCreateSchema, CustomPropertyResponseSchema, CustomPropertyUpdateSchema,
+};
+use crate::services::{custom_property_service, project_service};
+use crate::types::entity::EntityType;
+
+#[tauri::command]
+pub async fn create_custom_property(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    property: CustomPropertyCreateSchema,
+) -> Result<CustomPropertyResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    custom_property_service::create(db, property).await
+}
+
+#[tauri::command]
+pub async fn update_custom_property(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    property: CustomPropertyUpdateSchema,
+) -> Result<CustomPropertyResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    custom_property_service::update(db, property).await
+}
+
+#[tauri::command]
+pub async fn delete_custom_property(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    custom_property_service::delete(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_custom_properties(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    entity_type: EntityType,
+) -> Result<Vec<CustomPropertyResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    custom_property_service::get_all(db, entity_type).await
+}
@@ -4,12 +4,17 @@ use crate::model::{errors::Error, state::State};
 use crate::schema::{
     common::DiagnosticResponseSchema,
     entry::{
-        EntryArticleResponseSchema, EntryCreateSchema, EntryInfoResponseSchema,
-        EntryPropertyResponseSchema, EntrySearchSchema, EntryUpdateResponseSchema,
-        EntryUpdateSchema,
+        EntryAliasResponseSchema, EntryArticleResponseSchema, EntryBacklinkResponseSchema,
+        EntryCreateSchema, EntryInfoResponseSchema, EntryMentionResponseSchema,
+        EntryMentionSearchSchema, EntryPropertyResponseSchema, EntryRevisionInfoResponseSchema,
+        EntryRevisionResponseSchema, EntrySearchSchema, EntryTextSearchResponseSchema,
+        EntryTextSearchSchema, EntryUpdateMode, EntryUpdateResponseSchema, EntryUpdateSchema,
     },
 };
-use crate::services::{entry_service, project_service};
+use crate::services::{
+    entry_alias_service, entry_reference_service, entry_revision_service, entry_search_service,
+    entry_service, project_service, trash_service,
+};
 
 #[tauri::command]
 pub async fn create_entry(
@@ -27,10 +32,15 @@ pub async fn update_entry(
     state: tauri::State<'_, State>,
     project_id: Uuid,
     entry: EntryUpdateSchema,
+    atomic: Option<bool>,
 ) -> Result<DiagnosticResponseSchema<EntryUpdateResponseSchema>, Error> {
     let state = state.lock().await;
     let db = project_service::get_database(&state, project_id)?;
-    Ok(entry_service::update(db, entry).await)
+    if atomic.unwrap_or(false) {
+        Ok(entry_service::update_atomically(db, entry).await)
+    } else {
+        Ok(entry_service::update(db, entry).await)
+    }
 }
 
 #[tauri::command]
@@ -38,10 +48,11 @@ pub async fn update_entries(
     state: tauri::State<'_, State>,
     project_id: Uuid,
     entries: Vec<EntryUpdateSchema>,
+    mode: Option<EntryUpdateMode>,
 ) -> Result<Vec<DiagnosticResponseSchema<EntryUpdateResponseSchema>>, Error> {
     let state = state.lock().await;
     let db = project_service::get_database(&state, project_id)?;
-    Ok(entry_service::bulk_update(db, entries).await)
+    Ok(entry_service::bulk_update(db, entries, mode.unwrap_or_default()).await)
 }
 
 #[tauri::command]
@@ -89,6 +100,50 @@ pub async fn get_entry_text(
     entry_service::get_text(db, id).await
 }
 
+#[tauri::command]
+pub async fn get_entry_backlinks(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<EntryBacklinkResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_reference_service::get_backlinks(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_entry_revisions(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<EntryRevisionInfoResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_revision_service::get_all(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_entry_revision(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<DiagnosticResponseSchema<EntryRevisionResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_revision_service::get(db, id).await
+}
+
+#[tauri::command]
+pub async fn restore_entry_revision(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<DiagnosticResponseSchema<EntryUpdateResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_revision_service::restore(db, id).await
+}
+
 #[tauri::command]
 pub async fn get_entries(
     state: tauri::State<'_, State>,
@@ -110,6 +165,62 @@ pub async fn search_entries(
     entry_service::search(db, query).await
 }
 
+#[tauri::command]
+pub async fn search_entry_text(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    query: EntryTextSearchSchema,
+) -> Result<Vec<EntryTextSearchResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_search_service::search(db, query).await
+}
+
+#[tauri::command]
+pub async fn search_entry_mentions(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    query: EntryMentionSearchSchema,
+) -> Result<Vec<EntryMentionResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_alias_service::search_mentions(db, query).await
+}
+
+#[tauri::command]
+pub async fn add_entry_alias(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+    alias: String,
+) -> Result<EntryAliasResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_alias_service::add(db, id, &alias).await
+}
+
+#[tauri::command]
+pub async fn remove_entry_alias(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_alias_service::remove(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_entry_aliases(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<EntryAliasResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_alias_service::get_all_for_entry(db, id).await
+}
+
 #[tauri::command]
 pub async fn delete_entry(
     state: tauri::State<'_, State>,
@@ -118,5 +229,5 @@ pub async fn delete_entry(
 ) -> Result<(), Error> {
     let state = state.lock().await;
     let db = project_service::get_database(&state, project_id)?;
-    entry_service::delete(db, id).await
+    trash_service::trash_entry(db, id).await
 }
@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::entry_template::{
+    EntryTemplateCreateSchema, EntryTemplateResponseSchema, EntryTemplateUpdateSchema,
+};
+use crate::services::{entry_template_service, project_service};
+use crate::types::entity::EntityType;
+
+#[tauri::command]
+pub async fn create_entry_template(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    template: EntryTemplateCreateSchema,
+) -> Result<EntryTemplateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_template_service::create(db, template).await
+}
+
+#[tauri::command]
+pub async fn update_entry_template(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    template: EntryTemplateUpdateSchema,
+) -> Result<EntryTemplateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_template_service::update(db, template).await
+}
+
+#[tauri::command]
+pub async fn delete_entry_template(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_template_service::delete(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_entry_template(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<EntryTemplateResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_template_service::get(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_entry_templates(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    entity_type: Option<EntityType>,
+) -> Result<Vec<EntryTemplateResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    entry_template_service::get_all(db, entity_type).await
+}
@@ -0,0 +1,16 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::event::{TimelineEventResponseSchema, TimelineQuerySchema};
+use crate::services::{event_service, project_service};
+
+#[tauri::command]
+pub async fn get_timeline(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    query: TimelineQuerySchema,
+) -> Result<Vec<TimelineEventResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    event_service::get_timeline(db, query).await
+}
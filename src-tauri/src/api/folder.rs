@@ -9,7 +9,7 @@ use crate::schema::{
         FolderValidationSchema,
     },
 };
-use crate::services::{folder_service, project_service};
+use crate::services::{folder_service, project_service, trash_service};
 
 #[tauri::command]
 pub async fn create_folder(
@@ -86,5 +86,5 @@ pub async fn delete_folder(
 ) -> Result<BulkFileResponseSchema, Error> {
     let state = state.lock().await;
     let db = project_service::get_database(&state, project_id)?;
-    folder_service::delete(db, id).await
+    trash_service::trash_folder(db, id).await
 }
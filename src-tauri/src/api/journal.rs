@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::journal::{JournalRecordResponseSchema, JournalResponseSchema};
+use crate::services::{journal_service, project_service};
+
+#[tauri::command]
+pub async fn get_journal(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+) -> Result<JournalResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    journal_service::get(db).await
+}
+
+#[tauri::command]
+pub async fn undo(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+) -> Result<Option<JournalRecordResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    journal_service::undo(db).await
+}
+
+#[tauri::command]
+pub async fn redo(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+) -> Result<Option<JournalRecordResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    journal_service::redo(db).await
+}
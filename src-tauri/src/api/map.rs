@@ -0,0 +1,91 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::map::{
+    MapPinCreateSchema, MapPinMoveSchema, MapPinResponseSchema, MapPinsOfEntryResponseSchema,
+    MapSchema,
+};
+use crate::services::{map_service, project_service};
+
+#[tauri::command]
+pub async fn set_map_image(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+    source_path: String,
+) -> Result<MapSchema, Error> {
+    let state = state.lock().await;
+    let project = project_service::get_project(&state, project_id)?;
+    map_service::set_image(
+        &project.database.connection,
+        &project.folder_path,
+        id,
+        &source_path,
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn add_map_pin(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    pin: MapPinCreateSchema,
+) -> Result<MapPinResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    map_service::add_pin(db, pin).await
+}
+
+#[tauri::command]
+pub async fn move_map_pin(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    pin: MapPinMoveSchema,
+) -> Result<MapPinResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    map_service::move_pin(db, pin).await
+}
+
+#[tauri::command]
+pub async fn remove_map_pin(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    map_service::remove_pin(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_map_pins(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    map_id: Uuid,
+) -> Result<Vec<MapPinResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    map_service::get_pins(db, map_id).await
+}
+
+#[tauri::command]
+pub async fn get_maps_of_entry(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    entry_id: Uuid,
+) -> Result<Vec<MapPinsOfEntryResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    map_service::get_maps_of_entry(db, entry_id).await
+}
+
+#[tauri::command]
+pub async fn get_dangling_map_pins(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+) -> Result<Vec<MapPinResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    map_service::get_dangling_pins(db).await
+}
@@ -1,4 +1,16 @@
+pub mod asset;
+pub mod calendar;
+pub mod custom_property;
 pub mod entry;
+pub mod entry_template;
+pub mod event;
 pub mod folder;
+pub mod journal;
+pub mod map;
+pub mod organization;
+pub mod person;
+pub mod place;
 pub mod project;
+pub mod tag;
+pub mod trash;
 pub mod word;
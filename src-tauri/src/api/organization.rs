@@ -0,0 +1,74 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::{
+    entry::EntryInfoResponseSchema,
+    organization::{MembershipCreateSchema, MembershipResponseSchema, MembershipUpdateSchema},
+};
+use crate::services::{organization_service, project_service};
+
+#[tauri::command]
+pub async fn get_sub_organizations(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    organization_service::get_sub_organizations(db, id).await
+}
+
+#[tauri::command]
+pub async fn add_membership(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    membership: MembershipCreateSchema,
+) -> Result<MembershipResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    organization_service::add_membership(db, membership).await
+}
+
+#[tauri::command]
+pub async fn update_membership(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    membership: MembershipUpdateSchema,
+) -> Result<MembershipResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    organization_service::update_membership(db, membership).await
+}
+
+#[tauri::command]
+pub async fn remove_membership(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    organization_service::remove_membership(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_organization_members(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<MembershipResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    organization_service::get_members(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_person_affiliations(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    person_id: Uuid,
+) -> Result<Vec<MembershipResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    organization_service::get_affiliations(db, person_id).await
+}
@@ -0,0 +1,100 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::person::{
+    FamilyTreeResponseSchema, KinshipResponseSchema, PersonRelationshipCreateSchema,
+    PersonRelationshipResponseSchema, PersonRelationshipUpdateSchema, RelativeResponseSchema,
+};
+use crate::services::{genealogy_service, project_service};
+
+#[tauri::command]
+pub async fn add_person_relationship(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    relationship: PersonRelationshipCreateSchema,
+) -> Result<PersonRelationshipResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::add_relationship(db, relationship).await
+}
+
+#[tauri::command]
+pub async fn update_person_relationship(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    relationship: PersonRelationshipUpdateSchema,
+) -> Result<PersonRelationshipResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::update_relationship(db, relationship).await
+}
+
+#[tauri::command]
+pub async fn remove_person_relationship(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::remove_relationship(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_person_relationships(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    person_id: Uuid,
+) -> Result<Vec<PersonRelationshipResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::get_relationships(db, person_id).await
+}
+
+#[tauri::command]
+pub async fn get_person_ancestors(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+    generations: u32,
+) -> Result<Vec<RelativeResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::get_ancestors(db, id, generations).await
+}
+
+#[tauri::command]
+pub async fn get_person_descendants(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+    generations: u32,
+) -> Result<Vec<RelativeResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::get_descendants(db, id, generations).await
+}
+
+#[tauri::command]
+pub async fn get_kinship(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    person_id: Uuid,
+    relative_id: Uuid,
+) -> Result<KinshipResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::get_kinship(db, person_id, relative_id).await
+}
+
+#[tauri::command]
+pub async fn get_family_tree(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+    generations: u32,
+) -> Result<FamilyTreeResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    genealogy_service::get_family_tree(db, id, generations).await
+}
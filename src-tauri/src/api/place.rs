@@ -0,0 +1,27 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::entry::EntryInfoResponseSchema;
+use crate::services::{place_service, project_service};
+
+#[tauri::command]
+pub async fn get_place_descendants(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    place_service::get_descendants(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_place_ancestors(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    place_service::get_ancestors(db, id).await
+}
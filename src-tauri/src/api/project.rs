@@ -1,8 +1,17 @@
 use uuid::Uuid;
 
 use crate::model::{errors::Error, state::State};
-use crate::schema::project::ProjectResponseSchema;
-use crate::services::project_service;
+use crate::schema::{
+    backup::{BackupConfigSchema, BackupResponseSchema},
+    common::DiagnosticResponseSchema,
+    export::{MarkdownExportResponseSchema, MarkdownExportSchema},
+    import::{MarkdownImportResponseSchema, MarkdownImportSchema},
+    integrity::IntegrityReportSchema,
+    project::ProjectResponseSchema,
+};
+use crate::services::{
+    backup_service, export_service, import_service, integrity_service, project_service,
+};
 
 #[tauri::command]
 pub async fn create_project(
@@ -43,3 +52,87 @@ pub async fn update_project(
     let response = project_service::update(&mut state, id, &name).await?;
     Ok(response)
 }
+
+#[tauri::command]
+pub async fn export_project(
+    state: tauri::State<'_, State>,
+    id: Uuid,
+    export: MarkdownExportSchema,
+) -> Result<DiagnosticResponseSchema<MarkdownExportResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, id)?;
+    let response = export_service::export_markdown(db, export).await?;
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn import_project(
+    state: tauri::State<'_, State>,
+    id: Uuid,
+    import: MarkdownImportSchema,
+) -> Result<DiagnosticResponseSchema<MarkdownImportResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, id)?;
+    let response = import_service::import_markdown(db, import).await?;
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn get_project_backup_config(
+    state: tauri::State<'_, State>,
+    id: Uuid,
+) -> Result<BackupConfigSchema, Error> {
+    let state = state.lock().await;
+    let response = project_service::get_backup_config(&state, id)?;
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn update_project_backup_config(
+    state: tauri::State<'_, State>,
+    id: Uuid,
+    backup_config: BackupConfigSchema,
+) -> Result<BackupConfigSchema, Error> {
+    let mut state = state.lock().await;
+    let response = project_service::update_backup_config(&mut state, id, backup_config).await?;
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn backup_project(
+    state: tauri::State<'_, State>,
+    id: Uuid,
+) -> Result<BackupResponseSchema, Error> {
+    let state = state.lock().await;
+    let response = backup_service::create(&state, id).await?;
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn get_project_backups(folder_path: String) -> Result<Vec<BackupResponseSchema>, Error> {
+    let response = backup_service::get_all(&folder_path)?;
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn restore_project(
+    state: tauri::State<'_, State>,
+    folder_path: String,
+    file_name: String,
+) -> Result<Option<BackupResponseSchema>, Error> {
+    let state = state.lock().await;
+    let response = backup_service::restore(&state, &folder_path, &file_name).await?;
+    Ok(response)
+}
+
+#[tauri::command]
+pub async fn check_project(
+    state: tauri::State<'_, State>,
+    id: Uuid,
+    repair: Option<bool>,
+) -> Result<DiagnosticResponseSchema<IntegrityReportSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, id)?;
+    let response = integrity_service::check(db, repair.unwrap_or(false)).await?;
+    Ok(response)
+}
@@ -0,0 +1,105 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::tag::{
+    TagAssignmentSchema, TagCreateSchema, TagMergeSchema, TagResponseSchema, TagUpdateSchema,
+};
+use crate::services::{project_service, tag_service};
+
+#[tauri::command]
+pub async fn create_tag(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    tag: TagCreateSchema,
+) -> Result<TagResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::create(db, tag).await
+}
+
+#[tauri::command]
+pub async fn update_tag(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    tag: TagUpdateSchema,
+) -> Result<TagResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::update(db, tag).await
+}
+
+#[tauri::command]
+pub async fn delete_tag(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::delete(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_tags(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+) -> Result<Vec<TagResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::get_all(db).await
+}
+
+#[tauri::command]
+pub async fn merge_tags(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    merge: TagMergeSchema,
+) -> Result<TagResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::merge(db, merge).await
+}
+
+#[tauri::command]
+pub async fn tag_items(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    assignment: TagAssignmentSchema,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::tag(db, assignment).await
+}
+
+#[tauri::command]
+pub async fn untag_items(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    assignment: TagAssignmentSchema,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::untag(db, assignment).await
+}
+
+#[tauri::command]
+pub async fn get_entry_tags(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<TagResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::get_for_entry(db, id).await
+}
+
+#[tauri::command]
+pub async fn get_folder_tags(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<Vec<TagResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    tag_service::get_for_folder(db, id).await
+}
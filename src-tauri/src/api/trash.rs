@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use crate::model::{errors::Error, state::State};
+use crate::schema::trash::{TrashResponseSchema, TrashRestoreResponseSchema};
+use crate::services::{project_service, trash_service};
+
+#[tauri::command]
+pub async fn get_trash(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+) -> Result<TrashResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    trash_service::get_all(db).await
+}
+
+#[tauri::command]
+pub async fn restore_entry(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<TrashRestoreResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    trash_service::restore_entry(db, id).await
+}
+
+#[tauri::command]
+pub async fn restore_folder(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<TrashRestoreResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    trash_service::restore_folder(db, id).await
+}
+
+#[tauri::command]
+pub async fn empty_trash(state: tauri::State<'_, State>, project_id: Uuid) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    trash_service::empty(db).await
+}
@@ -3,7 +3,10 @@ use uuid::Uuid;
 use crate::model::{errors::Error, state::State};
 use crate::schema::{
     common::DiagnosticResponseSchema,
-    word::{WordResponseSchema, WordUpsertResponseSchema, WordUpsertSchema},
+    word::{
+        InflectedWordCreateSchema, InflectedWordResponseSchema, InflectedWordUpdateSchema,
+        WordResponseSchema, WordUpsertResponseSchema, WordUpsertSchema,
+    },
 };
 use crate::services::{project_service, word_service};
 use crate::types::grammar::WordType;
@@ -53,3 +56,47 @@ pub async fn delete_word(
     let db = project_service::get_database(&state, project_id)?;
     word_service::delete(db, id).await
 }
+
+#[tauri::command]
+pub async fn create_inflected_word(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    inflected_word: InflectedWordCreateSchema,
+) -> Result<InflectedWordResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    word_service::create_inflected_word(db, inflected_word).await
+}
+
+#[tauri::command]
+pub async fn update_inflected_word(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    inflected_word: InflectedWordUpdateSchema,
+) -> Result<InflectedWordResponseSchema, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    word_service::update_inflected_word(db, inflected_word).await
+}
+
+#[tauri::command]
+pub async fn get_inflected_words(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    word_id: Uuid,
+) -> Result<Vec<InflectedWordResponseSchema>, Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    word_service::get_inflected_words(db, word_id).await
+}
+
+#[tauri::command]
+pub async fn delete_inflected_word(
+    state: tauri::State<'_, State>,
+    project_id: Uuid,
+    id: Uuid,
+) -> Result<(), Error> {
+    let state = state.lock().await;
+    let db = project_service::get_database(&state, project_id)?;
+    word_service::delete_inflected_word(db, id).await
+}
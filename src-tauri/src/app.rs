@@ -1,6 +1,8 @@
+use tauri::Manager;
+
 use crate::api;
 use crate::model::{config::AppConfig, errors::Error, state::State};
-use crate::services::project_service;
+use crate::services::{backup_service, project_service};
 
 pub async fn setup(config: AppConfig) -> Result<State, Error> {
     println!("Setting up backend");
@@ -23,6 +25,20 @@ pub async fn setup(config: AppConfig) -> Result<State, Error> {
     Ok(state)
 }
 
+pub fn attach_background_tasks<R>(builder: tauri::Builder<R>) -> tauri::Builder<R>
+where
+    R: tauri::Runtime,
+{
+    builder.setup(|app| {
+        let handle = app.handle().clone();
+        tauri::async_runtime::spawn(async move {
+            let state = handle.state::<State>();
+            backup_service::run_automatic_backups(&state).await;
+        });
+        Ok(())
+    })
+}
+
 pub fn attach_handlers<R>(builder: tauri::Builder<R>) -> tauri::Builder<R>
 where
     R: tauri::Runtime,
@@ -33,6 +49,14 @@ where
         api::project::load_project,
         api::project::close_project,
         api::project::update_project,
+        api::project::export_project,
+        api::project::import_project,
+        api::project::get_project_backup_config,
+        api::project::update_project_backup_config,
+        api::project::backup_project,
+        api::project::get_project_backups,
+        api::project::restore_project,
+        api::project::check_project,
         // entry API
         api::entry::create_entry,
         api::entry::update_entry,
@@ -42,8 +66,54 @@ where
         api::entry::get_entries,
         api::entry::get_entry_properties,
         api::entry::get_entry_text,
+        api::entry::get_entry_backlinks,
+        api::entry::get_entry_revisions,
+        api::entry::get_entry_revision,
+        api::entry::restore_entry_revision,
         api::entry::search_entries,
+        api::entry::search_entry_text,
+        api::entry::search_entry_mentions,
+        api::entry::add_entry_alias,
+        api::entry::remove_entry_alias,
+        api::entry::get_entry_aliases,
         api::entry::delete_entry,
+        // organization API
+        api::organization::get_sub_organizations,
+        api::organization::add_membership,
+        api::organization::update_membership,
+        api::organization::remove_membership,
+        api::organization::get_organization_members,
+        api::organization::get_person_affiliations,
+        // person API
+        api::person::add_person_relationship,
+        api::person::update_person_relationship,
+        api::person::remove_person_relationship,
+        api::person::get_person_relationships,
+        api::person::get_person_ancestors,
+        api::person::get_person_descendants,
+        api::person::get_kinship,
+        api::person::get_family_tree,
+        // tag API
+        api::tag::create_tag,
+        api::tag::update_tag,
+        api::tag::delete_tag,
+        api::tag::get_tags,
+        api::tag::merge_tags,
+        api::tag::tag_items,
+        api::tag::untag_items,
+        api::tag::get_entry_tags,
+        api::tag::get_folder_tags,
+        // entry template API
+        api::entry_template::create_entry_template,
+        api::entry_template::update_entry_template,
+        api::entry_template::delete_entry_template,
+        api::entry_template::get_entry_template,
+        api::entry_template::get_entry_templates,
+        // custom property API
+        api::custom_property::create_custom_property,
+        api::custom_property::update_custom_property,
+        api::custom_property::delete_custom_property,
+        api::custom_property::get_custom_properties,
         // folder API
         api::folder::create_folder,
         api::folder::update_folder,
@@ -52,10 +122,50 @@ where
         api::folder::get_folder,
         api::folder::get_folders,
         api::folder::delete_folder,
+        // trash API
+        api::trash::get_trash,
+        api::trash::restore_entry,
+        api::trash::restore_folder,
+        api::trash::empty_trash,
+        // journal API
+        api::journal::get_journal,
+        api::journal::undo,
+        api::journal::redo,
         // word API
         api::word::upsert_words,
         api::word::get_word,
         api::word::get_words,
         api::word::delete_word,
+        api::word::create_inflected_word,
+        api::word::update_inflected_word,
+        api::word::get_inflected_words,
+        api::word::delete_inflected_word,
+        // calendar API
+        api::calendar::describe_date,
+        api::calendar::parse_date,
+        api::calendar::convert_date,
+        api::calendar::create_entry_date,
+        api::calendar::update_entry_date,
+        api::calendar::get_entry_dates,
+        api::calendar::delete_entry_date,
+        // event API
+        api::event::get_timeline,
+        // place API
+        api::place::get_place_descendants,
+        api::place::get_place_ancestors,
+        // map API
+        api::map::set_map_image,
+        api::map::add_map_pin,
+        api::map::move_map_pin,
+        api::map::remove_map_pin,
+        api::map::get_map_pins,
+        api::map::get_maps_of_entry,
+        api::map::get_dangling_map_pins,
+        // asset API
+        api::asset::import_asset,
+        api::asset::get_assets,
+        api::asset::rename_asset,
+        api::asset::delete_asset,
+        api::asset::get_asset_references,
     ])
 }
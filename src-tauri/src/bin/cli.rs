@@ -0,0 +1,279 @@
+//! Command-line tool that works on a project folder without running the app,
+//! so that maintenance can be scripted against the same database that the app uses.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::process::ExitCode;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use hellebore::{
+    constants::{DEFAULT_DB_FILE_NAME, ROOT_FOLDER_ID},
+    model::errors::Error,
+    schema::{
+        entry::EntrySearchSchema,
+        export::{MarkdownExportSchema, MentionStyle},
+        import::MarkdownImportSchema,
+    },
+    services::{
+        backup_service, entry_service, export_service, import_service, integrity_service,
+        project_service,
+    },
+};
+
+const USAGE: &str = "\
+Usage: hellebore-cli <project-folder> <command> [options]
+
+Commands:
+  list                                  List the entries of the project.
+  search <keyword> [--tags <expression>] [--limit <count>]
+                                        Search the entries by title.
+  export <folder> [--wikilinks]         Export the project as a Markdown vault.
+  import <folder> [--folder-id <id>]    Import a Markdown vault into the project.
+  check [--repair]                      Check the project for inconsistencies.
+  backup                                Take a backup of the project.
+  stats                                 Count the contents of the project.
+
+Results are printed to the standard output as JSON, and errors to the standard error.";
+
+const DEFAULT_SEARCH_LIMIT: u64 = 50;
+
+enum Command {
+    List,
+    Search {
+        keyword: String,
+        tags: Option<String>,
+        limit: u64,
+    },
+    Export {
+        folder_path: String,
+        mention_style: MentionStyle,
+    },
+    Import {
+        folder_path: String,
+        folder_id: Uuid,
+    },
+    Check {
+        repair: bool,
+    },
+    Backup,
+    Stats,
+}
+
+/// Arguments of a command, split into positional arguments, options with a value, and flags.
+struct CommandArgs {
+    positionals: Vec<String>,
+    options: HashMap<String, String>,
+    flags: HashSet<String>,
+}
+
+impl CommandArgs {
+    fn parse(
+        args: impl Iterator<Item = String>,
+        positional_names: &[&str],
+        option_names: &[&str],
+        flag_names: &[&str],
+    ) -> Result<Self, String> {
+        let mut command_args = Self {
+            positionals: Vec::new(),
+            options: HashMap::new(),
+            flags: HashSet::new(),
+        };
+
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            if option_names.contains(&arg.as_str()) {
+                let value = args
+                    .next_if(|value| !value.starts_with("--"))
+                    .ok_or_else(|| format!("Missing value of option '{arg}'."))?;
+                command_args.options.insert(arg, value);
+            } else if flag_names.contains(&arg.as_str()) {
+                command_args.flags.insert(arg);
+            } else if arg.starts_with("--") {
+                return Err(format!("Unknown option '{arg}'."));
+            } else {
+                command_args.positionals.push(arg);
+            }
+        }
+
+        if command_args.positionals.len() != positional_names.len() {
+            let expected: Vec<String> = positional_names
+                .iter()
+                .map(|name| format!("<{name}>"))
+                .collect();
+            return Err(format!(
+                "Expected {} argument(s): {}.",
+                positional_names.len(),
+                expected.join(" ")
+            ));
+        }
+
+        Ok(command_args)
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    let (folder_path, command) = match parse_args(args) {
+        Ok(parsed) => parsed,
+        Err(msg) => {
+            eprintln!("{msg}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&folder_path, command).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", to_json(&e));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: Vec<String>) -> Result<(String, Command), String> {
+    let mut args = args.into_iter();
+    let folder_path = args.next().ok_or("Missing project folder.")?;
+    let command_name = args.next().ok_or("Missing command.")?;
+
+    let command = match command_name.as_str() {
+        "list" => {
+            CommandArgs::parse(args, &[], &[], &[])?;
+            Command::List
+        }
+        "search" => {
+            let mut args = CommandArgs::parse(args, &["keyword"], &["--tags", "--limit"], &[])?;
+            let limit = match args.options.remove("--limit") {
+                Some(limit) => limit
+                    .parse()
+                    .map_err(|_| format!("Invalid limit '{limit}'."))?,
+                None => DEFAULT_SEARCH_LIMIT,
+            };
+            Command::Search {
+                keyword: args.positionals.remove(0),
+                tags: args.options.remove("--tags"),
+                limit,
+            }
+        }
+        "export" => {
+            let mut args = CommandArgs::parse(args, &["folder"], &[], &["--wikilinks"])?;
+            let mention_style = if args.flags.contains("--wikilinks") {
+                MentionStyle::Wikilink
+            } else {
+                MentionStyle::RelativeLink
+            };
+            Command::Export {
+                folder_path: args.positionals.remove(0),
+                mention_style,
+            }
+        }
+        "import" => {
+            let mut args = CommandArgs::parse(args, &["folder"], &["--folder-id"], &[])?;
+            let folder_id = match args.options.remove("--folder-id") {
+                Some(id) => {
+                    Uuid::parse_str(&id).map_err(|_| format!("Invalid folder ID '{id}'."))?
+                }
+                None => ROOT_FOLDER_ID,
+            };
+            Command::Import {
+                folder_path: args.positionals.remove(0),
+                folder_id,
+            }
+        }
+        "check" => {
+            let args = CommandArgs::parse(args, &[], &[], &["--repair"])?;
+            Command::Check {
+                repair: args.flags.contains("--repair"),
+            }
+        }
+        "backup" => {
+            CommandArgs::parse(args, &[], &[], &[])?;
+            Command::Backup
+        }
+        "stats" => {
+            CommandArgs::parse(args, &[], &[], &[])?;
+            Command::Stats
+        }
+        _ => return Err(format!("Unknown command '{command_name}'.")),
+    };
+
+    Ok((folder_path, command))
+}
+
+async fn run(folder_path: &str, command: Command) -> Result<(), Error> {
+    // opening a folder without a database would create an empty project
+    let db_path = Path::new(folder_path).join(DEFAULT_DB_FILE_NAME);
+    if !db_path.is_file() {
+        return Err(Error::FileSystemOperationFailed {
+            msg: "Project not found.".to_owned(),
+            error: format!("'{}' does not exist.", db_path.display()),
+        });
+    }
+
+    // the project can still be worked on if its indexes fail to sync,
+    // so the errors are reported without failing the command
+    let response = project_service::open(folder_path).await?;
+    for e in response.errors.iter() {
+        eprintln!("{}", to_json(e));
+    }
+    let project = response.data;
+    let database = &project.database.connection;
+
+    let output = match command {
+        Command::List => to_json(&entry_service::get_all(database).await?),
+        Command::Search {
+            keyword,
+            tags,
+            limit,
+        } => {
+            let query = EntrySearchSchema {
+                keyword,
+                tags,
+                before: None,
+                after: None,
+                limit,
+            };
+            to_json(&entry_service::search(database, query).await?)
+        }
+        Command::Export {
+            folder_path,
+            mention_style,
+        } => {
+            let export = MarkdownExportSchema {
+                folder_path,
+                mention_style,
+            };
+            to_json(&export_service::export_markdown(database, export).await?)
+        }
+        Command::Import {
+            folder_path,
+            folder_id,
+        } => {
+            let import = MarkdownImportSchema {
+                folder_path,
+                folder_id,
+            };
+            to_json(&import_service::import_markdown(database, import).await?)
+        }
+        Command::Check { repair } => to_json(&integrity_service::check(database, repair).await?),
+        Command::Backup => to_json(&backup_service::create_for_project(&project).await?),
+        Command::Stats => to_json(&project_service::get_stats(database).await?),
+    };
+    println!("{output}");
+
+    Ok(())
+}
+
+fn to_json<T>(value: &T) -> String
+where
+    T: Serialize,
+{
+    serde_json::to_string_pretty(value).expect("Failed to serialize the output")
+}
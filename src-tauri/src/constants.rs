@@ -8,3 +8,6 @@ pub const APP_CONFIG_FILE_NAME: &str = "hellebore.config.json";
 
 pub const PROJECT_CONFIG_FILE_NAME: &str = "hellebore.project.json";
 pub const DEFAULT_DB_FILE_NAME: &str = "db.sqlite";
+pub const BACKUP_DIR_NAME: &str = "backups";
+pub const MAP_DIR_NAME: &str = "maps";
+pub const ASSET_DIR_NAME: &str = "assets";
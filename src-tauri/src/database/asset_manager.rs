@@ -0,0 +1,88 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{asset, asset::Entity as Asset};
+
+pub async fn insert<C>(
+    con: &C,
+    name: &str,
+    hash: &str,
+    mime_type: &str,
+    size: i64,
+    path: &str,
+) -> Result<asset::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let asset = asset::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        name: Set(name.to_owned()),
+        hash: Set(hash.to_owned()),
+        mime_type: Set(mime_type.to_owned()),
+        size: Set(size),
+        path: Set(path.to_owned()),
+    };
+    asset.insert(con).await
+}
+
+pub async fn update_name<C>(con: &C, id: Uuid, name: &str) -> Result<asset::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Asset not found.".to_owned()));
+    };
+    let mut entity: asset::ActiveModel = entity.into();
+    entity.name = Set(name.to_owned());
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<asset::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Asset::find_by_id(id).one(con).await
+}
+
+pub async fn get_by_hash<C>(con: &C, hash: &str) -> Result<Option<asset::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Asset::find()
+        .filter(asset::Column::Hash.eq(hash))
+        .one(con)
+        .await
+}
+
+pub async fn get_all<C>(con: &C) -> Result<Vec<asset::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Asset::find()
+        .order_by_asc(asset::Column::Name)
+        .all(con)
+        .await
+}
+
+pub async fn get_existing_ids<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<Uuid>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Asset::find()
+        .select_only()
+        .column(asset::Column::Id)
+        .filter(asset::Column::Id.is_in(ids))
+        .into_tuple::<Uuid>()
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Asset not found.".to_owned()));
+    };
+    entity.delete(con).await
+}
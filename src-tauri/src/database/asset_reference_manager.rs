@@ -0,0 +1,46 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{asset_reference, asset_reference::Entity as AssetReference};
+
+/// Replaces all of the assets referenced by the entry.
+pub async fn replace_for_entry<C>(
+    con: &C,
+    entry_id: Uuid,
+    asset_ids: Vec<Uuid>,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    AssetReference::delete_many()
+        .filter(asset_reference::Column::EntryId.eq(entry_id))
+        .exec(con)
+        .await?;
+
+    if asset_ids.is_empty() {
+        return Ok(());
+    }
+
+    let new_references = asset_ids
+        .into_iter()
+        .map(|asset_id| asset_reference::ActiveModel {
+            entry_id: Set(entry_id),
+            asset_id: Set(asset_id),
+        });
+    AssetReference::insert_many(new_references)
+        .on_conflict_do_nothing()
+        .exec_without_returning(con)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_for_asset<C>(con: &C, asset_id: Uuid) -> Result<Vec<asset_reference::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    AssetReference::find()
+        .filter(asset_reference::Column::AssetId.eq(asset_id))
+        .all(con)
+        .await
+}
@@ -0,0 +1,72 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{calendar, calendar::Entity as Calendar};
+
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    months: serde_json::Value,
+    weekdays: serde_json::Value,
+    leap_rules: serde_json::Value,
+    eras: serde_json::Value,
+    epoch_offset: i64,
+) -> Result<calendar::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let calendar = calendar::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        months: Set(months),
+        weekdays: Set(weekdays),
+        leap_rules: Set(leap_rules),
+        eras: Set(eras),
+        epoch_offset: Set(epoch_offset),
+    };
+    calendar.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    entry_id: Uuid,
+    months: serde_json::Value,
+    weekdays: serde_json::Value,
+    leap_rules: serde_json::Value,
+    eras: serde_json::Value,
+    epoch_offset: i64,
+) -> Result<calendar::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, entry_id).await? else {
+        return Err(DbErr::RecordNotFound("Calendar not found.".to_owned()));
+    };
+    let mut entity: calendar::ActiveModel = entity.into();
+    entity.months = Set(months);
+    entity.weekdays = Set(weekdays);
+    entity.leap_rules = Set(leap_rules);
+    entity.eras = Set(eras);
+    entity.epoch_offset = Set(epoch_offset);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, entry_id: Uuid) -> Result<Option<calendar::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Calendar::find()
+        .filter(calendar::Column::EntryId.eq(entry_id))
+        .one(con)
+        .await
+}
+
+pub async fn get_all<C>(con: &C) -> Result<Vec<calendar::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Calendar::find()
+        .order_by_asc(calendar::Column::Id)
+        .all(con)
+        .await
+}
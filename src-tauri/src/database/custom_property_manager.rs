@@ -0,0 +1,113 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{custom_property, custom_property::Entity as CustomProperty};
+
+use crate::types::{custom_property::CustomPropertyType, entity::EntityType};
+use crate::utils::CodedEnum;
+
+pub async fn insert<C>(
+    con: &C,
+    entity_type: EntityType,
+    name: &str,
+    property_type: CustomPropertyType,
+    item_type: Option<CustomPropertyType>,
+    options: serde_json::Value,
+) -> Result<custom_property::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    // new properties are listed after the existing ones
+    let position = CustomProperty::find()
+        .filter(custom_property::Column::EntityType.eq(entity_type.code()))
+        .count(con)
+        .await?;
+    let property = custom_property::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entity_type: Set(entity_type.code()),
+        name: Set(name.to_owned()),
+        property_type: Set(property_type.code()),
+        item_type: Set(item_type.map(|item_type| item_type.code())),
+        options: Set(options),
+        position: Set(position as i32),
+    };
+    property.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    name: &str,
+    options: serde_json::Value,
+) -> Result<custom_property::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound(
+            "Custom property not found.".to_owned(),
+        ));
+    };
+    let mut entity: custom_property::ActiveModel = entity.into();
+    entity.name = Set(name.to_owned());
+    entity.options = Set(options);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<custom_property::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    CustomProperty::find_by_id(id).one(con).await
+}
+
+pub async fn get_by_name<C>(
+    con: &C,
+    entity_type: EntityType,
+    name: &str,
+) -> Result<Option<custom_property::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    CustomProperty::find()
+        .filter(custom_property::Column::EntityType.eq(entity_type.code()))
+        .filter(custom_property::Column::Name.eq(name))
+        .one(con)
+        .await
+}
+
+pub async fn get_many<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<custom_property::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    CustomProperty::find()
+        .filter(custom_property::Column::Id.is_in(ids))
+        .all(con)
+        .await
+}
+
+pub async fn get_all_for_type<C>(
+    con: &C,
+    entity_type: EntityType,
+) -> Result<Vec<custom_property::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    CustomProperty::find()
+        .filter(custom_property::Column::EntityType.eq(entity_type.code()))
+        .order_by_asc(custom_property::Column::Position)
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound(
+            "Custom property not found.".to_owned(),
+        ));
+    };
+    entity.delete(con).await
+}
@@ -0,0 +1,71 @@
+use sea_orm::{sea_query::OnConflict, *};
+use uuid::Uuid;
+
+use ::entity::{custom_property_value, custom_property_value::Entity as CustomPropertyValue};
+
+/// Sets the value of the custom property of the entry, replacing its current value if any.
+pub async fn upsert<C>(
+    con: &C,
+    entry_id: Uuid,
+    property_id: Uuid,
+    value: serde_json::Value,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let property_value = custom_property_value::ActiveModel {
+        entry_id: Set(entry_id),
+        property_id: Set(property_id),
+        value: Set(value),
+    };
+    CustomPropertyValue::insert(property_value)
+        .on_conflict(
+            OnConflict::columns([
+                custom_property_value::Column::EntryId,
+                custom_property_value::Column::PropertyId,
+            ])
+            .update_column(custom_property_value::Column::Value)
+            .to_owned(),
+        )
+        .exec_without_returning(con)
+        .await?;
+    Ok(())
+}
+
+pub async fn get_all_for_entry<C>(
+    con: &C,
+    entry_id: Uuid,
+) -> Result<Vec<custom_property_value::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    CustomPropertyValue::find()
+        .filter(custom_property_value::Column::EntryId.eq(entry_id))
+        .all(con)
+        .await
+}
+
+pub async fn get_all_for_property<C>(
+    con: &C,
+    property_id: Uuid,
+) -> Result<Vec<custom_property_value::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    CustomPropertyValue::find()
+        .filter(custom_property_value::Column::PropertyId.eq(property_id))
+        .all(con)
+        .await
+}
+
+/// Clears the value of the custom property of the entry; clearing a missing value is a no-op.
+pub async fn delete<C>(con: &C, entry_id: Uuid, property_id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    CustomPropertyValue::delete_many()
+        .filter(custom_property_value::Column::EntryId.eq(entry_id))
+        .filter(custom_property_value::Column::PropertyId.eq(property_id))
+        .exec(con)
+        .await
+}
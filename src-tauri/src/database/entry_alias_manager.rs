@@ -0,0 +1,72 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{entry_alias, entry_alias::Entity as EntryAlias};
+
+use crate::database::entry_manager;
+
+pub async fn insert<C>(con: &C, entry_id: Uuid, alias: &str) -> Result<entry_alias::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let alias = entry_alias::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        alias: Set(alias.to_owned()),
+    };
+    alias.insert(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<entry_alias::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryAlias::find_by_id(id).one(con).await
+}
+
+/// Returns the alias, unless its entry is in the trash.
+pub async fn get_by_alias<C>(con: &C, alias: &str) -> Result<Option<entry_alias::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryAlias::find()
+        .filter(entry_alias::Column::Alias.eq(alias))
+        .filter(entry_alias::Column::EntryId.in_subquery(entry_manager::select_untrashed_ids()))
+        .one(con)
+        .await
+}
+
+pub async fn get_all_for_entry<C>(con: &C, entry_id: Uuid) -> Result<Vec<entry_alias::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryAlias::find()
+        .filter(entry_alias::Column::EntryId.eq(entry_id))
+        .order_by_asc(entry_alias::Column::Alias)
+        .all(con)
+        .await
+}
+
+/// Returns the aliases that contain the keyword, leaving out the ones of entries in the trash.
+pub async fn search<C>(con: &C, keyword: &str, limit: u64) -> Result<Vec<entry_alias::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryAlias::find()
+        .filter(entry_alias::Column::Alias.like(format!("%{}%", keyword)))
+        .filter(entry_alias::Column::EntryId.in_subquery(entry_manager::select_untrashed_ids()))
+        .order_by_asc(entry_alias::Column::Alias)
+        .limit(limit)
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Alias not found.".to_owned()));
+    };
+    existing_entity.delete(con).await
+}
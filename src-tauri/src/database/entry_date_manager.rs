@@ -0,0 +1,111 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{entry_date, entry_date::Entity as EntryDate};
+
+use crate::database::utils::set_optional_value;
+use crate::model::calendar::FictionalDate;
+
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    calendar_id: Uuid,
+    label: String,
+    date: FictionalDate,
+    day_number: i64,
+) -> Result<entry_date::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let entry_date = entry_date::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        calendar_id: Set(calendar_id),
+        label: Set(label),
+        year: Set(date.year),
+        month: Set(date.month as i32),
+        day: Set(date.day as i32),
+        day_number: Set(day_number),
+    };
+    entry_date.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    label: Option<String>,
+    calendar_id: Option<Uuid>,
+    date: Option<FictionalDate>,
+    day_number: Option<i64>,
+) -> Result<entry_date::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let updated_entry_date = entry_date::ActiveModel {
+        id: Unchanged(id),
+        entry_id: NotSet,
+        calendar_id: set_optional_value(calendar_id),
+        label: set_optional_value(label),
+        year: set_optional_value(date.map(|date| date.year)),
+        month: set_optional_value(date.map(|date| date.month as i32)),
+        day: set_optional_value(date.map(|date| date.day as i32)),
+        day_number: set_optional_value(day_number),
+    };
+    updated_entry_date.update(con).await
+}
+
+/// Updates the day numbers of several dates, e.g., after the definition of their calendar changed.
+pub async fn update_day_numbers<C>(con: &C, day_numbers: Vec<(Uuid, i64)>) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    for (id, day_number) in day_numbers.into_iter() {
+        let updated_entry_date = entry_date::ActiveModel {
+            id: Unchanged(id),
+            day_number: Set(day_number),
+            ..Default::default()
+        };
+        updated_entry_date.update(con).await?;
+    }
+    Ok(())
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<entry_date::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryDate::find_by_id(id).one(con).await
+}
+
+/// Returns the dates of the entry in chronological order.
+pub async fn get_all_for_entry<C>(con: &C, entry_id: Uuid) -> Result<Vec<entry_date::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryDate::find()
+        .filter(entry_date::Column::EntryId.eq(entry_id))
+        .order_by_asc(entry_date::Column::DayNumber)
+        .order_by_asc(entry_date::Column::Label)
+        .all(con)
+        .await
+}
+
+pub async fn get_all_for_calendar<C>(
+    con: &C,
+    calendar_id: Uuid,
+) -> Result<Vec<entry_date::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryDate::find()
+        .filter(entry_date::Column::CalendarId.eq(calendar_id))
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryDate::delete_by_id(id).exec(con).await
+}
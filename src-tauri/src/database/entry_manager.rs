@@ -1,9 +1,14 @@
 use ::entity::{entry, entry::Entity as EntryModel};
-use sea_orm::*;
+use sea_orm::{
+    prelude::DateTimeUtc,
+    sea_query::{Expr, Query, SelectStatement},
+    *,
+};
 use uuid::Uuid;
 
 use crate::{
-    database::{file_manager, utils},
+    database::{entry_alias_manager, entry_tag_manager, file_manager, utils},
+    model::tag::TagExpression,
     types::entity::EntityType,
     utils::CodedEnum,
 };
@@ -15,6 +20,7 @@ pub struct EntityInfo {
     pub folder_id: Option<Uuid>,
     pub entity_type: i8,
     pub title: String,
+    pub revision: i32,
 }
 
 pub async fn insert<C>(
@@ -33,6 +39,8 @@ where
         title: Set(title),
         entity_type: Set(entity_type.code()),
         text: Set(text),
+        revision: Set(0),
+        deleted_at: Set(None),
     };
     match new_entity.insert(con).await {
         Ok(created_entity) => created_entity.try_into_model(),
@@ -40,12 +48,16 @@ where
     }
 }
 
+/// Updates the entry and bumps its revision.
+/// If an expected revision is given, then the entry is only updated if it's still at that revision,
+/// or else `DbErr::RecordNotUpdated` is returned.
 pub async fn update<C>(
     con: &C,
     id: Uuid,
     folder_id: Option<Uuid>,
     title: Option<String>,
     text: Option<String>,
+    expected_revision: Option<i32>,
 ) -> Result<entry::Model, DbErr>
 where
     C: ConnectionTrait,
@@ -53,14 +65,22 @@ where
     let Some(existing_entity) = get_info(con, id).await? else {
         return Err(DbErr::RecordNotFound("Entity not found.".to_owned()));
     };
+    let revision = expected_revision.unwrap_or(existing_entity.revision);
     let updated_entity = entry::ActiveModel {
         id: Unchanged(existing_entity.id),
         folder_id: file_manager::set_optional_folder_id(folder_id),
         entity_type: NotSet,
         title: utils::set_optional_value(title),
         text: utils::set_optional_value(text),
+        revision: Set(revision + 1),
+        deleted_at: NotSet,
     };
-    updated_entity.update(con).await
+    let mut query = EntryModel::update(updated_entity);
+    if let Some(expected_revision) = expected_revision {
+        // the check and the write are a single statement, so that no write can come in between
+        query = query.filter(entry::Column::Revision.eq(expected_revision));
+    }
+    query.exec(con).await
 }
 
 pub async fn exists<C>(con: &C, id: Uuid) -> Result<bool, DbErr>
@@ -70,11 +90,12 @@ where
     return Ok(get_info(con, id).await?.is_some());
 }
 
+/// Checks whether the title is taken by the title or one of the aliases of any entry.
 pub async fn title_exists<C>(con: &C, title: &str) -> Result<bool, DbErr>
 where
     C: ConnectionTrait,
 {
-    return Ok(get_by_title(con, title).await?.is_some());
+    return Ok(!is_title_unique_for_id(con, None, title).await?);
 }
 
 pub async fn is_title_unique_for_id<C>(
@@ -85,12 +106,15 @@ pub async fn is_title_unique_for_id<C>(
 where
     C: ConnectionTrait,
 {
-    let entity = get_by_title(con, title).await?;
-    match entity {
-        Some(a) => match id {
-            Some(id) => Ok(a.id == id),
-            None => Ok(false),
-        },
+    // titles and aliases share a namespace, so that a mention can't be ambiguous
+    let entity_id = match get_by_title(con, title).await? {
+        Some(entity) => Some(entity.id),
+        None => entry_alias_manager::get_by_alias(con, title)
+            .await?
+            .map(|alias| alias.entry_id),
+    };
+    match entity_id {
+        Some(entity_id) => Ok(id == Some(entity_id)),
         None => Ok(true),
     }
 }
@@ -108,6 +132,7 @@ where
 {
     EntryModel::find()
         .filter(entry::Column::Title.eq(title))
+        .filter(entry::Column::DeletedAt.is_null())
         .into_partial_model::<EntityInfo>()
         .one(con)
         .await
@@ -123,20 +148,122 @@ where
         .await
 }
 
+/// Returns all of the entries that aren't in the trash.
 pub async fn get_all<C>(con: &C) -> Result<Vec<EntityInfo>, DbErr>
 where
     C: ConnectionTrait,
 {
     EntryModel::find()
+        .filter(entry::Column::DeletedAt.is_null())
         .order_by_asc(entry::Column::Title)
         .into_partial_model::<EntityInfo>()
         .all(con)
         .await
 }
 
+/// Returns all of the entries, including the ones in the trash.
+pub async fn get_all_models<C>(con: &C) -> Result<Vec<entry::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .order_by_asc(entry::Column::Title)
+        .all(con)
+        .await
+}
+
+/// Returns the given entries, leaving out the ones in the trash.
+pub async fn get_many<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<entry::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .filter(entry::Column::Id.is_in(ids))
+        .filter(entry::Column::DeletedAt.is_null())
+        .order_by_asc(entry::Column::Title)
+        .all(con)
+        .await
+}
+
+/// Returns the given entries that are in the trash.
+pub async fn get_many_trashed<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<entry::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .filter(entry::Column::Id.is_in(ids))
+        .filter(entry::Column::DeletedAt.is_not_null())
+        .order_by_asc(entry::Column::Title)
+        .all(con)
+        .await
+}
+
+/// Returns the subset of the given IDs that belong to existing entries.
+pub async fn get_existing_ids<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<Uuid>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .select_only()
+        .column(entry::Column::Id)
+        .filter(entry::Column::Id.is_in(ids))
+        .into_tuple::<Uuid>()
+        .all(con)
+        .await
+}
+
+/// Returns the subset of the given IDs that belong to entries outside of the trash.
+pub async fn get_untrashed_ids<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<Uuid>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .select_only()
+        .column(entry::Column::Id)
+        .filter(entry::Column::Id.is_in(ids))
+        .filter(entry::Column::DeletedAt.is_null())
+        .into_tuple::<Uuid>()
+        .all(con)
+        .await
+}
+
+/// Selects the IDs of the entries that aren't in the trash,
+/// so that the rows that refer to entries can leave out the ones in the trash.
+pub fn select_untrashed_ids() -> SelectStatement {
+    Query::select()
+        .column(entry::Column::Id)
+        .from(EntryModel)
+        .and_where(entry::Column::DeletedAt.is_null())
+        .to_owned()
+}
+
+/// Counts the entries of the given type that aren't in the trash.
+pub async fn count_of_type<C>(con: &C, entity_type: EntityType) -> Result<u64, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .filter(entry::Column::EntityType.eq(entity_type.code()))
+        .filter(entry::Column::DeletedAt.is_null())
+        .count(con)
+        .await
+}
+
+pub async fn count_trashed<C>(con: &C) -> Result<u64, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .filter(entry::Column::DeletedAt.is_not_null())
+        .count(con)
+        .await
+}
+
+/// Searches entries by title; if a tag expression is given, then only the entries that match it are returned.
 pub async fn search<C>(
     con: &C,
     keyword: String,
+    tags: Option<&TagExpression>,
     before: Option<String>,
     after: Option<String>,
     limit: u64,
@@ -144,9 +271,13 @@ pub async fn search<C>(
 where
     C: ConnectionTrait,
 {
-    let mut cursor = EntryModel::find()
+    let mut query = EntryModel::find()
         .filter(entry::Column::Title.like(format!("%{}%", keyword)))
-        .cursor_by(entry::Column::Title);
+        .filter(entry::Column::DeletedAt.is_null());
+    if let Some(tags) = tags {
+        query = query.filter(entry_tag_manager::build_condition(tags));
+    }
+    let mut cursor = query.cursor_by(entry::Column::Title);
 
     if let Some(before_value) = before {
         cursor.before(before_value);
@@ -159,6 +290,76 @@ where
     cursor.into_partial_model::<EntityInfo>().all(con).await
 }
 
+/// Returns the entries in the trash, most recently deleted first.
+pub async fn get_trashed<C>(con: &C) -> Result<Vec<entry::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::find()
+        .filter(entry::Column::DeletedAt.is_not_null())
+        .order_by_desc(entry::Column::DeletedAt)
+        .order_by_asc(entry::Column::Title)
+        .all(con)
+        .await
+}
+
+/// Moves the entries to the trash; entries that are already in the trash keep their deletion time.
+pub async fn trash_many<C>(
+    con: &C,
+    ids: Vec<Uuid>,
+    deleted_at: DateTimeUtc,
+) -> Result<UpdateResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::update_many()
+        .col_expr(entry::Column::DeletedAt, Expr::value(deleted_at))
+        .col_expr(
+            entry::Column::Revision,
+            Expr::col(entry::Column::Revision).add(1),
+        )
+        .filter(entry::Column::Id.is_in(ids))
+        .filter(entry::Column::DeletedAt.is_null())
+        .exec(con)
+        .await
+}
+
+/// Takes the entry out of the trash, placing it in the given folder under the given title.
+pub async fn restore<C>(
+    con: &C,
+    id: Uuid,
+    folder_id: Uuid,
+    title: String,
+) -> Result<entry::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get_info(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Entity not found.".to_owned()));
+    };
+    let updated_entity = entry::ActiveModel {
+        id: Unchanged(existing_entity.id),
+        folder_id: Set(file_manager::convert_root_folder_id_to_null(folder_id)),
+        entity_type: NotSet,
+        title: Set(title),
+        text: NotSet,
+        revision: Set(existing_entity.revision + 1),
+        deleted_at: Set(None),
+    };
+    updated_entity.update(con).await
+}
+
+/// Permanently deletes the entries in the trash.
+pub async fn delete_trashed<C>(con: &C) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryModel::delete_many()
+        .filter(entry::Column::DeletedAt.is_not_null())
+        .exec(con)
+        .await
+}
+
 pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
 where
     C: ConnectionTrait,
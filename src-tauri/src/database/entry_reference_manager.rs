@@ -0,0 +1,91 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{entry_reference, entry_reference::Entity as EntryReferenceModel};
+
+use crate::database::entry_manager;
+
+/// Replaces all of the references made by the source entry.
+pub async fn replace_for_source<C>(
+    con: &C,
+    source_id: Uuid,
+    references: Vec<(Uuid, i32)>,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    delete_for_source(con, source_id).await?;
+
+    if references.is_empty() {
+        return Ok(());
+    }
+
+    let new_references =
+        references
+            .into_iter()
+            .map(|(target_id, mention_count)| entry_reference::ActiveModel {
+                source_id: Set(source_id),
+                target_id: Set(target_id),
+                mention_count: Set(mention_count),
+            });
+    EntryReferenceModel::insert_many(new_references)
+        .exec(con)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_for_source<C>(
+    con: &C,
+    source_id: Uuid,
+) -> Result<Vec<entry_reference::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryReferenceModel::find()
+        .filter(entry_reference::Column::SourceId.eq(source_id))
+        .all(con)
+        .await
+}
+
+/// Returns the references to the target, leaving out the ones made by entries in the trash.
+pub async fn get_for_target<C>(
+    con: &C,
+    target_id: Uuid,
+) -> Result<Vec<entry_reference::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryReferenceModel::find()
+        .filter(entry_reference::Column::TargetId.eq(target_id))
+        .filter(
+            entry_reference::Column::SourceId.in_subquery(entry_manager::select_untrashed_ids()),
+        )
+        .order_by_desc(entry_reference::Column::MentionCount)
+        .all(con)
+        .await
+}
+
+pub async fn count<C>(con: &C) -> Result<u64, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryReferenceModel::find().count(con).await
+}
+
+pub async fn delete_for_source<C>(con: &C, source_id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryReferenceModel::delete_many()
+        .filter(entry_reference::Column::SourceId.eq(source_id))
+        .exec(con)
+        .await
+}
+
+pub async fn delete_all<C>(con: &C) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryReferenceModel::delete_many().exec(con).await
+}
@@ -0,0 +1,112 @@
+use sea_orm::{prelude::DateTimeUtc, *};
+use uuid::Uuid;
+
+use ::entity::{entry_revision, entry_revision::Entity as EntryRevisionModel};
+
+#[derive(DerivePartialModel, FromQueryResult)]
+#[sea_orm(entity = "EntryRevisionModel")]
+pub struct EntryRevisionInfo {
+    pub id: Uuid,
+    pub entry_id: Uuid,
+    pub created_at: DateTimeUtc,
+    pub title: String,
+}
+
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    created_at: DateTimeUtc,
+    title: String,
+    text: String,
+    properties: serde_json::Value,
+) -> Result<entry_revision::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let new_revision = entry_revision::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        created_at: Set(created_at),
+        title: Set(title),
+        text: Set(text),
+        properties: Set(properties),
+    };
+    new_revision.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    title: String,
+    text: String,
+    properties: serde_json::Value,
+) -> Result<entry_revision::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let updated_revision = entry_revision::ActiveModel {
+        id: Unchanged(id),
+        entry_id: NotSet,
+        created_at: NotSet,
+        title: Set(title),
+        text: Set(text),
+        properties: Set(properties),
+    };
+    updated_revision.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<entry_revision::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryRevisionModel::find_by_id(id).one(con).await
+}
+
+pub async fn get_latest<C>(con: &C, entry_id: Uuid) -> Result<Option<entry_revision::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryRevisionModel::find()
+        .filter(entry_revision::Column::EntryId.eq(entry_id))
+        .order_by_desc(entry_revision::Column::CreatedAt)
+        .one(con)
+        .await
+}
+
+/// Returns the revisions of the entry, from newest to oldest.
+pub async fn get_all_for_entry<C>(con: &C, entry_id: Uuid) -> Result<Vec<EntryRevisionInfo>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryRevisionModel::find()
+        .filter(entry_revision::Column::EntryId.eq(entry_id))
+        .order_by_desc(entry_revision::Column::CreatedAt)
+        .into_partial_model::<EntryRevisionInfo>()
+        .all(con)
+        .await
+}
+
+/// Deletes all but the `keep` most recent revisions of the entry.
+pub async fn delete_oldest<C>(con: &C, entry_id: Uuid, keep: u64) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    // SQLite doesn't accept an OFFSET clause without a LIMIT clause,
+    // so the revisions to keep are skipped after the IDs are fetched
+    let stale_ids: Vec<Uuid> = EntryRevisionModel::find()
+        .select_only()
+        .column(entry_revision::Column::Id)
+        .filter(entry_revision::Column::EntryId.eq(entry_id))
+        .order_by_desc(entry_revision::Column::CreatedAt)
+        .into_tuple::<Uuid>()
+        .all(con)
+        .await?
+        .into_iter()
+        .skip(keep as usize)
+        .collect();
+
+    EntryRevisionModel::delete_many()
+        .filter(entry_revision::Column::Id.is_in(stale_ids))
+        .exec(con)
+        .await
+}
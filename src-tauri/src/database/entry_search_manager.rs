@@ -0,0 +1,102 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+// The search index is an FTS5 virtual table, which sea-orm can't model as an entity;
+// all of the queries in this module are therefore written by hand.
+
+#[derive(Debug, FromQueryResult)]
+pub struct EntrySearchHit {
+    pub id: Uuid,
+    pub folder_id: Option<Uuid>,
+    pub entity_type: i8,
+    pub title: String,
+    pub revision: i32,
+    pub rank: f64,
+    pub highlighted_title: String,
+    pub snippet: String,
+}
+
+pub struct SearchHighlight<'a> {
+    pub open_tag: &'a str,
+    pub close_tag: &'a str,
+    pub ellipsis: &'a str,
+    pub snippet_tokens: i64,
+}
+
+pub async fn upsert<C>(con: &C, id: Uuid, title: &str, body: &str) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    delete(con, id).await?;
+
+    let stmt = Statement::from_sql_and_values(
+        con.get_database_backend(),
+        "INSERT INTO entry_search (entry_id, title, body) VALUES ($1, $2, $3)",
+        [id.into(), title.into(), body.into()],
+    );
+    con.execute(stmt).await?;
+
+    Ok(())
+}
+
+pub async fn search<C>(
+    con: &C,
+    query: &str,
+    highlight: &SearchHighlight<'_>,
+    limit: u64,
+    offset: u64,
+) -> Result<Vec<EntrySearchHit>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    // Column 1 is the title and column 2 is the body.
+    // FTS5's built-in `rank` column is the BM25 score of the hit; lower is better.
+    let stmt = Statement::from_sql_and_values(
+        con.get_database_backend(),
+        "SELECT \
+            entry.id AS id, \
+            entry.folder_id AS folder_id, \
+            entry.entity_type AS entity_type, \
+            entry.title AS title, \
+            entry.revision AS revision, \
+            entry_search.rank AS rank, \
+            highlight(entry_search, 1, $1, $2) AS highlighted_title, \
+            snippet(entry_search, 2, $1, $2, $3, $4) AS snippet \
+        FROM entry_search \
+        INNER JOIN entry ON entry.id = entry_search.entry_id \
+        WHERE entry_search MATCH $5 AND entry.deleted_at IS NULL \
+        ORDER BY entry_search.rank \
+        LIMIT $6 OFFSET $7",
+        [
+            highlight.open_tag.into(),
+            highlight.close_tag.into(),
+            highlight.ellipsis.into(),
+            highlight.snippet_tokens.into(),
+            query.into(),
+            (limit as i64).into(),
+            (offset as i64).into(),
+        ],
+    );
+    EntrySearchHit::find_by_statement(stmt).all(con).await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let stmt = Statement::from_sql_and_values(
+        con.get_database_backend(),
+        "DELETE FROM entry_search WHERE entry_id = $1",
+        [id.into()],
+    );
+    con.execute(stmt).await?;
+    Ok(())
+}
+
+pub async fn delete_all<C>(con: &C) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    con.execute_unprepared("DELETE FROM entry_search").await?;
+    Ok(())
+}
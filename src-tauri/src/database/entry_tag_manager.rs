@@ -0,0 +1,106 @@
+use sea_orm::{
+    sea_query::{Expr, SimpleExpr},
+    *,
+};
+use uuid::Uuid;
+
+use ::entity::{entry_tag, entry_tag::Entity as EntryTag};
+
+use crate::model::tag::TagExpression;
+
+/// Tags each of the entries with each of the tags; existing links are kept as they are.
+pub async fn insert_many<C>(con: &C, tag_ids: Vec<Uuid>, entry_ids: Vec<Uuid>) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let links = tag_ids.iter().flat_map(|tag_id| {
+        entry_ids.iter().map(|entry_id| entry_tag::ActiveModel {
+            tag_id: Set(*tag_id),
+            entry_id: Set(*entry_id),
+        })
+    });
+    EntryTag::insert_many(links)
+        .on_conflict_do_nothing()
+        .exec_without_returning(con)
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_many<C>(
+    con: &C,
+    tag_ids: Vec<Uuid>,
+    entry_ids: Vec<Uuid>,
+) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryTag::delete_many()
+        .filter(entry_tag::Column::TagId.is_in(tag_ids))
+        .filter(entry_tag::Column::EntryId.is_in(entry_ids))
+        .exec(con)
+        .await
+}
+
+pub async fn get_for_tag<C>(con: &C, tag_id: Uuid) -> Result<Vec<entry_tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryTag::find()
+        .filter(entry_tag::Column::TagId.eq(tag_id))
+        .all(con)
+        .await
+}
+
+pub async fn get_for_entries<C>(
+    con: &C,
+    entry_ids: Vec<Uuid>,
+) -> Result<Vec<entry_tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryTag::find()
+        .filter(entry_tag::Column::EntryId.is_in(entry_ids))
+        .all(con)
+        .await
+}
+
+/// Builds a condition on the entry table that holds for the entries matching the tag expression.
+/// Entries have the tags of their own, along with those of the folders that contain them at any depth.
+pub fn build_condition(expression: &TagExpression) -> Condition {
+    // conditions rather than plain expressions, so that nested operators are parenthesized
+    match expression {
+        TagExpression::Tag(name) => Condition::all().add(_build_tag_condition(name)),
+        TagExpression::Not(expression) => build_condition(expression).not(),
+        TagExpression::And(left, right) => Condition::all()
+            .add(build_condition(left))
+            .add(build_condition(right)),
+        TagExpression::Or(left, right) => Condition::any()
+            .add(build_condition(left))
+            .add(build_condition(right)),
+    }
+}
+
+fn _build_tag_condition(name: &str) -> SimpleExpr {
+    // SQLite placeholders aren't numbered, so the name is bound once per placeholder
+    Expr::cust_with_values(
+        "entry.id IN ( \
+            SELECT entry_tag.entry_id FROM entry_tag \
+            INNER JOIN tag ON tag.id = entry_tag.tag_id \
+            WHERE tag.name = ? OR substr(tag.name, 1, length(?) + 1) = ? || '/' \
+            UNION \
+            SELECT tagged_entry.id FROM entry AS tagged_entry \
+            WHERE tagged_entry.folder_id IN ( \
+                WITH RECURSIVE tagged_folder(id) AS ( \
+                    SELECT folder_tag.folder_id FROM folder_tag \
+                    INNER JOIN tag ON tag.id = folder_tag.tag_id \
+                    WHERE tag.name = ? OR substr(tag.name, 1, length(?) + 1) = ? || '/' \
+                    UNION \
+                    SELECT folder.id FROM folder \
+                    INNER JOIN tagged_folder ON folder.parent_id = tagged_folder.id \
+                ) \
+                SELECT id FROM tagged_folder \
+            ) \
+        )",
+        std::iter::repeat_n(name.to_owned(), 6),
+    )
+}
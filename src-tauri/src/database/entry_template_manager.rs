@@ -0,0 +1,106 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{entry_template, entry_template::Entity as EntryTemplate};
+
+use crate::types::entity::EntityType;
+use crate::utils::CodedEnum;
+
+pub async fn insert<C>(
+    con: &C,
+    entity_type: EntityType,
+    name: &str,
+    text: String,
+    properties: Option<serde_json::Value>,
+    custom_properties: serde_json::Value,
+) -> Result<entry_template::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let template = entry_template::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entity_type: Set(entity_type.code()),
+        name: Set(name.to_owned()),
+        text: Set(text),
+        properties: Set(properties),
+        custom_properties: Set(custom_properties),
+    };
+    template.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    name: &str,
+    text: String,
+    properties: Option<serde_json::Value>,
+    custom_properties: serde_json::Value,
+) -> Result<entry_template::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound(
+            "Entry template not found.".to_owned(),
+        ));
+    };
+    let mut entity: entry_template::ActiveModel = entity.into();
+    entity.name = Set(name.to_owned());
+    entity.text = Set(text);
+    entity.properties = Set(properties);
+    entity.custom_properties = Set(custom_properties);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<entry_template::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryTemplate::find_by_id(id).one(con).await
+}
+
+pub async fn get_by_name<C>(
+    con: &C,
+    entity_type: EntityType,
+    name: &str,
+) -> Result<Option<entry_template::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EntryTemplate::find()
+        .filter(entry_template::Column::EntityType.eq(entity_type.code()))
+        .filter(entry_template::Column::Name.eq(name))
+        .one(con)
+        .await
+}
+
+/// Returns the templates of the given entity type, or all of them, sorted by name.
+pub async fn get_all<C>(
+    con: &C,
+    entity_type: Option<EntityType>,
+) -> Result<Vec<entry_template::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut query = EntryTemplate::find();
+    if let Some(entity_type) = entity_type {
+        query = query.filter(entry_template::Column::EntityType.eq(entity_type.code()));
+    }
+    query
+        .order_by_asc(entry_template::Column::EntityType)
+        .order_by_asc(entry_template::Column::Name)
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound(
+            "Entry template not found.".to_owned(),
+        ));
+    };
+    entity.delete(con).await
+}
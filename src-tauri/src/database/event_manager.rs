@@ -0,0 +1,162 @@
+use sea_orm::{sea_query::Query, *};
+use uuid::Uuid;
+
+use ::entity::{event, event::Entity as Event, event_participant};
+
+use crate::database::entry_manager;
+use crate::model::calendar::FictionalDate;
+use crate::types::calendar::DatePrecision;
+use crate::utils::CodedEnum;
+
+/// A date of an event along with its day number.
+pub type EventDate = (FictionalDate, i64);
+
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    calendar_id: Uuid,
+    start: EventDate,
+    end: Option<EventDate>,
+    precision: DatePrecision,
+    location_id: Option<Uuid>,
+) -> Result<event::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let (start_date, start_day_number) = start;
+    let event = event::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        calendar_id: Set(calendar_id),
+        start_year: Set(start_date.year),
+        start_month: Set(start_date.month as i32),
+        start_day: Set(start_date.day as i32),
+        start_day_number: Set(start_day_number),
+        end_year: Set(end.map(|(date, _)| date.year)),
+        end_month: Set(end.map(|(date, _)| date.month as i32)),
+        end_day: Set(end.map(|(date, _)| date.day as i32)),
+        end_day_number: Set(end.map(|(_, day_number)| day_number)),
+        precision: Set(precision.code()),
+        location_id: Set(location_id),
+    };
+    event.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    entry_id: Uuid,
+    calendar_id: Uuid,
+    start: EventDate,
+    end: Option<EventDate>,
+    precision: DatePrecision,
+    location_id: Option<Uuid>,
+) -> Result<event::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, entry_id).await? else {
+        return Err(DbErr::RecordNotFound("Event not found.".to_owned()));
+    };
+    let (start_date, start_day_number) = start;
+    let mut entity: event::ActiveModel = entity.into();
+    entity.calendar_id = Set(calendar_id);
+    entity.start_year = Set(start_date.year);
+    entity.start_month = Set(start_date.month as i32);
+    entity.start_day = Set(start_date.day as i32);
+    entity.start_day_number = Set(start_day_number);
+    entity.end_year = Set(end.map(|(date, _)| date.year));
+    entity.end_month = Set(end.map(|(date, _)| date.month as i32));
+    entity.end_day = Set(end.map(|(date, _)| date.day as i32));
+    entity.end_day_number = Set(end.map(|(_, day_number)| day_number));
+    entity.precision = Set(precision.code());
+    entity.location_id = Set(location_id);
+    entity.update(con).await
+}
+
+/// Updates the day numbers of several events, e.g., after the definition of their calendar changed.
+pub async fn update_day_numbers<C>(
+    con: &C,
+    day_numbers: Vec<(Uuid, i64, Option<i64>)>,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    for (id, start_day_number, end_day_number) in day_numbers.into_iter() {
+        let updated_event = event::ActiveModel {
+            id: Unchanged(id),
+            start_day_number: Set(start_day_number),
+            end_day_number: Set(end_day_number),
+            ..Default::default()
+        };
+        updated_event.update(con).await?;
+    }
+    Ok(())
+}
+
+pub async fn get<C>(con: &C, entry_id: Uuid) -> Result<Option<event::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Event::find()
+        .filter(event::Column::EntryId.eq(entry_id))
+        .one(con)
+        .await
+}
+
+pub async fn get_all_for_calendar<C>(con: &C, calendar_id: Uuid) -> Result<Vec<event::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Event::find()
+        .filter(event::Column::CalendarId.eq(calendar_id))
+        .all(con)
+        .await
+}
+
+/// Returns the events that overlap with the range of day numbers, in chronological order.
+/// Events without an end date last a single day.
+pub async fn get_timeline<C>(
+    con: &C,
+    after: Option<i64>,
+    before: Option<i64>,
+    participant_id: Option<Uuid>,
+) -> Result<Vec<event::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    // events in the trash aren't part of the timeline
+    let mut query = Event::find()
+        .filter(event::Column::EntryId.in_subquery(entry_manager::select_untrashed_ids()));
+
+    if let Some(after) = after {
+        query = query.filter(
+            Condition::any()
+                .add(event::Column::EndDayNumber.gte(after))
+                .add(
+                    Condition::all()
+                        .add(event::Column::EndDayNumber.is_null())
+                        .add(event::Column::StartDayNumber.gte(after)),
+                ),
+        );
+    }
+    if let Some(before) = before {
+        query = query.filter(event::Column::StartDayNumber.lte(before));
+    }
+    if let Some(participant_id) = participant_id {
+        query = query.filter(
+            event::Column::EntryId.in_subquery(
+                Query::select()
+                    .column(event_participant::Column::EventId)
+                    .from(event_participant::Entity)
+                    .and_where(event_participant::Column::ParticipantId.eq(participant_id))
+                    .to_owned(),
+            ),
+        );
+    }
+
+    query
+        .order_by_asc(event::Column::StartDayNumber)
+        .order_by_asc(event::Column::EndDayNumber)
+        .all(con)
+        .await
+}
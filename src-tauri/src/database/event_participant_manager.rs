@@ -0,0 +1,50 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{event_participant, event_participant::Entity as EventParticipant};
+
+/// Replaces all of the participants of the event.
+pub async fn replace_for_event<C>(
+    con: &C,
+    event_id: Uuid,
+    participant_ids: Vec<Uuid>,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    EventParticipant::delete_many()
+        .filter(event_participant::Column::EventId.eq(event_id))
+        .exec(con)
+        .await?;
+
+    if participant_ids.is_empty() {
+        return Ok(());
+    }
+
+    let participants =
+        participant_ids
+            .into_iter()
+            .map(|participant_id| event_participant::ActiveModel {
+                event_id: Set(event_id),
+                participant_id: Set(participant_id),
+            });
+    EventParticipant::insert_many(participants)
+        .exec(con)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_for_events<C>(
+    con: &C,
+    event_ids: Vec<Uuid>,
+) -> Result<Vec<event_participant::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    EventParticipant::find()
+        .filter(event_participant::Column::EventId.is_in(event_ids))
+        .order_by_asc(event_participant::Column::ParticipantId)
+        .all(con)
+        .await
+}
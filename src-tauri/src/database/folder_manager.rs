@@ -1,4 +1,4 @@
-use sea_orm::*;
+use sea_orm::{prelude::DateTimeUtc, sea_query::Expr, *};
 use uuid::Uuid;
 
 use ::entity::{folder, folder::Entity as FolderModel};
@@ -13,6 +13,8 @@ where
         id: Set(Uuid::new_v4()),
         parent_id: Set(file_manager::convert_root_folder_id_to_null(parent_id)),
         name: Set(name.to_string()),
+        revision: Set(0),
+        deleted_at: Set(None),
     };
     match new_entity.insert(con).await {
         Ok(created_entity) => created_entity.try_into_model(),
@@ -20,11 +22,15 @@ where
     }
 }
 
+/// Updates the folder and bumps its revision.
+/// If an expected revision is given, then the folder is only updated if it's still at that revision,
+/// or else `DbErr::RecordNotUpdated` is returned.
 pub async fn update<C>(
     con: &C,
     id: Uuid,
     parent_id: Option<Uuid>,
     name: Option<String>,
+    expected_revision: Option<i32>,
 ) -> Result<folder::Model, DbErr>
 where
     C: ConnectionTrait,
@@ -32,12 +38,20 @@ where
     let Some(existing_entity) = get(con, id).await? else {
         return Err(DbErr::RecordNotFound("Folder not found.".to_owned()));
     };
+    let revision = expected_revision.unwrap_or(existing_entity.revision);
     let updated_entity = folder::ActiveModel {
         id: Unchanged(existing_entity.id),
         parent_id: file_manager::set_optional_folder_id(parent_id),
         name: utils::set_optional_value(name),
+        revision: Set(revision + 1),
+        deleted_at: NotSet,
     };
-    updated_entity.update(con).await
+    let mut query = FolderModel::update(updated_entity);
+    if let Some(expected_revision) = expected_revision {
+        // the check and the write are a single statement, so that no write can come in between
+        query = query.filter(folder::Column::Revision.eq(expected_revision));
+    }
+    query.exec(con).await
 }
 
 pub async fn exists<C>(con: &C, id: Uuid) -> Result<bool, DbErr>
@@ -55,7 +69,9 @@ pub async fn is_name_unique_at_location<C>(
 where
     C: ConnectionTrait,
 {
-    let mut query = FolderModel::find().filter(folder::Column::Name.eq(name));
+    let mut query = FolderModel::find()
+        .filter(folder::Column::Name.eq(name))
+        .filter(folder::Column::DeletedAt.is_null());
 
     let parent_id = file_manager::convert_root_folder_id_to_null(parent_id);
     if parent_id.is_none() {
@@ -88,8 +104,9 @@ where
     FolderModel::find_by_id(id).one(con).await
 }
 
+/// Builds a query for the folders that aren't in the trash.
 pub fn query(parent_id: Option<Uuid>, name: Option<String>) -> Select<FolderModel> {
-    let mut query = FolderModel::find();
+    let mut query = FolderModel::find().filter(folder::Column::DeletedAt.is_null());
 
     if let Some(parent_id_value) = parent_id {
         let nullable_parent_id = file_manager::convert_root_folder_id_to_null(parent_id_value);
@@ -106,11 +123,118 @@ pub fn query(parent_id: Option<Uuid>, name: Option<String>) -> Select<FolderMode
     query
 }
 
+/// Returns all of the folders that aren't in the trash.
 pub async fn get_all<C>(con: &C) -> Result<Vec<folder::Model>, DbErr>
 where
     C: ConnectionTrait,
 {
-    FolderModel::find().all(con).await
+    FolderModel::find()
+        .filter(folder::Column::DeletedAt.is_null())
+        .all(con)
+        .await
+}
+
+/// Returns all of the folders, including the ones in the trash.
+pub async fn get_all_models<C>(con: &C) -> Result<Vec<folder::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderModel::find()
+        .order_by_asc(folder::Column::Name)
+        .all(con)
+        .await
+}
+
+pub async fn get_many<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<folder::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderModel::find()
+        .filter(folder::Column::Id.is_in(ids))
+        .all(con)
+        .await
+}
+
+/// Returns the subset of the given IDs that belong to existing folders.
+pub async fn get_existing_ids<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<Uuid>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderModel::find()
+        .select_only()
+        .column(folder::Column::Id)
+        .filter(folder::Column::Id.is_in(ids))
+        .into_tuple::<Uuid>()
+        .all(con)
+        .await
+}
+
+/// Returns the folders in the trash, most recently deleted first.
+pub async fn get_trashed<C>(con: &C) -> Result<Vec<folder::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderModel::find()
+        .filter(folder::Column::DeletedAt.is_not_null())
+        .order_by_desc(folder::Column::DeletedAt)
+        .order_by_asc(folder::Column::Name)
+        .all(con)
+        .await
+}
+
+/// Moves the folders to the trash; folders that are already in the trash keep their deletion time.
+pub async fn trash_many<C>(
+    con: &C,
+    ids: Vec<Uuid>,
+    deleted_at: DateTimeUtc,
+) -> Result<UpdateResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderModel::update_many()
+        .col_expr(folder::Column::DeletedAt, Expr::value(deleted_at))
+        .col_expr(
+            folder::Column::Revision,
+            Expr::col(folder::Column::Revision).add(1),
+        )
+        .filter(folder::Column::Id.is_in(ids))
+        .filter(folder::Column::DeletedAt.is_null())
+        .exec(con)
+        .await
+}
+
+/// Takes the folder out of the trash, placing it in the given parent under the given name.
+pub async fn restore<C>(
+    con: &C,
+    id: Uuid,
+    parent_id: Uuid,
+    name: &str,
+) -> Result<folder::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Folder not found.".to_owned()));
+    };
+    let updated_entity = folder::ActiveModel {
+        id: Unchanged(existing_entity.id),
+        parent_id: Set(file_manager::convert_root_folder_id_to_null(parent_id)),
+        name: Set(name.to_owned()),
+        revision: Set(existing_entity.revision + 1),
+        deleted_at: Set(None),
+    };
+    updated_entity.update(con).await
+}
+
+/// Permanently deletes the folders in the trash, along with their contents.
+pub async fn delete_trashed<C>(con: &C) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderModel::delete_many()
+        .filter(folder::Column::DeletedAt.is_not_null())
+        .exec(con)
+        .await
 }
 
 pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
@@ -0,0 +1,60 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{folder_tag, folder_tag::Entity as FolderTag};
+
+/// Tags each of the folders with each of the tags; existing links are kept as they are.
+pub async fn insert_many<C>(con: &C, tag_ids: Vec<Uuid>, folder_ids: Vec<Uuid>) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let links = tag_ids.iter().flat_map(|tag_id| {
+        folder_ids.iter().map(|folder_id| folder_tag::ActiveModel {
+            tag_id: Set(*tag_id),
+            folder_id: Set(*folder_id),
+        })
+    });
+    FolderTag::insert_many(links)
+        .on_conflict_do_nothing()
+        .exec_without_returning(con)
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_many<C>(
+    con: &C,
+    tag_ids: Vec<Uuid>,
+    folder_ids: Vec<Uuid>,
+) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderTag::delete_many()
+        .filter(folder_tag::Column::TagId.is_in(tag_ids))
+        .filter(folder_tag::Column::FolderId.is_in(folder_ids))
+        .exec(con)
+        .await
+}
+
+pub async fn get_for_tag<C>(con: &C, tag_id: Uuid) -> Result<Vec<folder_tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderTag::find()
+        .filter(folder_tag::Column::TagId.eq(tag_id))
+        .all(con)
+        .await
+}
+
+pub async fn get_for_folders<C>(
+    con: &C,
+    folder_ids: Vec<Uuid>,
+) -> Result<Vec<folder_tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    FolderTag::find()
+        .filter(folder_tag::Column::FolderId.is_in(folder_ids))
+        .all(con)
+        .await
+}
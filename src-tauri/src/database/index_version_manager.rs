@@ -0,0 +1,34 @@
+use sea_orm::{sea_query::OnConflict, *};
+
+use ::entity::{index_version, index_version::Entity as IndexVersion};
+
+/// Returns the version with which the index was last built, if it has been built at all.
+pub async fn get<C>(con: &C, name: &str) -> Result<Option<i32>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Ok(IndexVersion::find_by_id(name.to_owned())
+        .one(con)
+        .await?
+        .map(|index| index.version))
+}
+
+/// Records the version with which the index has been built.
+pub async fn set<C>(con: &C, name: &str, version: i32) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let index = index_version::ActiveModel {
+        name: Set(name.to_owned()),
+        version: Set(version),
+    };
+    IndexVersion::insert(index)
+        .on_conflict(
+            OnConflict::column(index_version::Column::Name)
+                .update_column(index_version::Column::Version)
+                .to_owned(),
+        )
+        .exec_without_returning(con)
+        .await?;
+    Ok(())
+}
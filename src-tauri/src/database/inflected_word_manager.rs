@@ -0,0 +1,100 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{inflected_word, inflected_word::Entity as InflectedWord};
+
+use crate::database::utils::set_optional_value;
+use crate::model::grammar::Inflection;
+use crate::utils::CodedEnum;
+
+pub async fn insert<C>(
+    con: &C,
+    word_id: Uuid,
+    spelling: String,
+    inflection: Inflection,
+) -> Result<inflected_word::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut inflected_word = inflected_word::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        word_id: Set(word_id),
+        spelling: Set(spelling),
+        ..Default::default()
+    };
+    _set_inflection(&mut inflected_word, inflection);
+    inflected_word.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    spelling: Option<String>,
+    inflection: Option<Inflection>,
+) -> Result<inflected_word::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut inflected_word = inflected_word::ActiveModel {
+        id: Unchanged(id),
+        spelling: set_optional_value(spelling),
+        ..Default::default()
+    };
+    if let Some(inflection) = inflection {
+        _set_inflection(&mut inflected_word, inflection);
+    }
+    inflected_word.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<inflected_word::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    InflectedWord::find_by_id(id).one(con).await
+}
+
+/// Returns the inflected forms of the word, ordered by their grammatical categories.
+pub async fn get_all_for_word<C>(
+    con: &C,
+    word_id: Uuid,
+) -> Result<Vec<inflected_word::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    InflectedWord::find()
+        .filter(inflected_word::Column::WordId.eq(word_id))
+        .order_by_asc(inflected_word::Column::Number)
+        .order_by_asc(inflected_word::Column::Case)
+        .order_by_asc(inflected_word::Column::Person)
+        .order_by_asc(inflected_word::Column::Gender)
+        .order_by_asc(inflected_word::Column::Tense)
+        .order_by_asc(inflected_word::Column::Spelling)
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound(
+            "Inflected word not found.".to_owned(),
+        ));
+    };
+    existing_entity.delete(con).await
+}
+
+fn _set_inflection(inflected_word: &mut inflected_word::ActiveModel, inflection: Inflection) {
+    inflected_word.case = Set(inflection.case.code());
+    inflected_word.voice = Set(inflection.voice.code());
+    inflected_word.mood = Set(inflection.mood.code());
+    inflected_word.tense = Set(inflection.tense.code());
+    inflected_word.aspect = Set(inflection.aspect.code());
+    inflected_word.definiteness = Set(inflection.definiteness.code());
+    inflected_word.number = Set(inflection.number.code());
+    inflected_word.person = Set(inflection.person.code());
+    inflected_word.gender = Set(inflection.gender.code());
+    inflected_word.animacy = Set(inflection.animacy.code());
+    inflected_word.verb_form = Set(inflection.verb_form.code());
+}
@@ -0,0 +1,116 @@
+use sea_orm::{prelude::DateTimeUtc, *};
+use uuid::Uuid;
+
+use ::entity::{journal, journal::Entity as Journal};
+
+/// Appends a record to the end of the journal.
+pub async fn insert<C>(
+    con: &C,
+    created_at: DateTimeUtc,
+    label: &str,
+    undo: serde_json::Value,
+    redo: serde_json::Value,
+) -> Result<journal::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let last_position: Option<i64> = Journal::find()
+        .select_only()
+        .column(journal::Column::Position)
+        .order_by_desc(journal::Column::Position)
+        .into_tuple()
+        .one(con)
+        .await?;
+
+    let record = journal::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        position: Set(last_position.map_or(0, |position| position + 1)),
+        created_at: Set(created_at),
+        label: Set(label.to_owned()),
+        undo: Set(undo),
+        redo: Set(redo),
+        is_undone: Set(false),
+    };
+    record.insert(con).await
+}
+
+pub async fn set_undone<C>(con: &C, id: Uuid, is_undone: bool) -> Result<journal::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let record = journal::ActiveModel {
+        id: Unchanged(id),
+        position: NotSet,
+        created_at: NotSet,
+        label: NotSet,
+        undo: NotSet,
+        redo: NotSet,
+        is_undone: Set(is_undone),
+    };
+    record.update(con).await
+}
+
+/// Returns the most recent record that hasn't been undone, i.e., the next one to undo.
+pub async fn get_last_done<C>(con: &C) -> Result<Option<journal::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Journal::find()
+        .filter(journal::Column::IsUndone.eq(false))
+        .order_by_desc(journal::Column::Position)
+        .one(con)
+        .await
+}
+
+/// Returns the oldest record that has been undone, i.e., the next one to redo.
+pub async fn get_first_undone<C>(con: &C) -> Result<Option<journal::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Journal::find()
+        .filter(journal::Column::IsUndone.eq(true))
+        .order_by_asc(journal::Column::Position)
+        .one(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Journal::delete_by_id(id).exec(con).await
+}
+
+pub async fn delete_undone<C>(con: &C) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Journal::delete_many()
+        .filter(journal::Column::IsUndone.eq(true))
+        .exec(con)
+        .await
+}
+
+/// Deletes all but the `keep` most recent records.
+pub async fn delete_oldest<C>(con: &C, keep: u64) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    // SQLite doesn't accept an OFFSET clause without a LIMIT clause,
+    // so the records to keep are skipped after the IDs are fetched
+    let stale_ids: Vec<Uuid> = Journal::find()
+        .select_only()
+        .column(journal::Column::Id)
+        .order_by_desc(journal::Column::Position)
+        .into_tuple::<Uuid>()
+        .all(con)
+        .await?
+        .into_iter()
+        .skip(keep as usize)
+        .collect();
+
+    Journal::delete_many()
+        .filter(journal::Column::Id.is_in(stale_ids))
+        .exec(con)
+        .await
+}
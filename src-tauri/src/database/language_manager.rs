@@ -3,17 +3,38 @@ use uuid::Uuid;
 
 use ::entity::{language, language::Entity as Language};
 
-pub async fn insert<C>(con: &C, entry_id: Uuid) -> Result<language::Model, DbErr>
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    grammar: serde_json::Value,
+) -> Result<language::Model, DbErr>
 where
     C: ConnectionTrait,
 {
     let language = language::ActiveModel {
         id: Set(Uuid::new_v4()),
         entry_id: Set(entry_id),
+        grammar: Set(Some(grammar)),
     };
     language.insert(con).await
 }
 
+pub async fn update<C>(
+    con: &C,
+    entry_id: Uuid,
+    grammar: serde_json::Value,
+) -> Result<language::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, entry_id).await? else {
+        return Err(DbErr::RecordNotFound("Language not found.".to_owned()));
+    };
+    let mut entity: language::ActiveModel = entity.into();
+    entity.grammar = Set(Some(grammar));
+    entity.update(con).await
+}
+
 pub async fn get<C>(con: &C, entry_id: Uuid) -> Result<Option<language::Model>, DbErr>
 where
     C: ConnectionTrait,
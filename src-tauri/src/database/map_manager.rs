@@ -0,0 +1,67 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{map, map::Entity as Map};
+
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    width: f64,
+    height: f64,
+) -> Result<map::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let map = map::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        width: Set(width),
+        height: Set(height),
+        image_path: Set(None),
+    };
+    map.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    entry_id: Uuid,
+    width: f64,
+    height: f64,
+) -> Result<map::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, entry_id).await? else {
+        return Err(DbErr::RecordNotFound("Map not found.".to_owned()));
+    };
+    let mut entity: map::ActiveModel = entity.into();
+    entity.width = Set(width);
+    entity.height = Set(height);
+    entity.update(con).await
+}
+
+pub async fn update_image_path<C>(
+    con: &C,
+    entry_id: Uuid,
+    image_path: Option<String>,
+) -> Result<map::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, entry_id).await? else {
+        return Err(DbErr::RecordNotFound("Map not found.".to_owned()));
+    };
+    let mut entity: map::ActiveModel = entity.into();
+    entity.image_path = Set(image_path);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, entry_id: Uuid) -> Result<Option<map::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Map::find()
+        .filter(map::Column::EntryId.eq(entry_id))
+        .one(con)
+        .await
+}
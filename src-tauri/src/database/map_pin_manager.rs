@@ -0,0 +1,98 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{map_pin, map_pin::Entity as MapPin};
+
+use crate::database::entry_manager;
+
+use crate::types::map::PinShape;
+use crate::utils::CodedEnum;
+
+pub async fn insert<C>(
+    con: &C,
+    map_id: Uuid,
+    target_id: Uuid,
+    shape: PinShape,
+    points: serde_json::Value,
+) -> Result<map_pin::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let pin = map_pin::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        map_id: Set(map_id),
+        target_id: Set(target_id),
+        shape: Set(shape.code()),
+        points: Set(points),
+    };
+    pin.insert(con).await
+}
+
+pub async fn update_points<C>(
+    con: &C,
+    id: Uuid,
+    points: serde_json::Value,
+) -> Result<map_pin::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Map pin not found.".to_owned()));
+    };
+    let mut entity: map_pin::ActiveModel = entity.into();
+    entity.points = Set(points);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<map_pin::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    MapPin::find_by_id(id).one(con).await
+}
+
+pub async fn get_all_for_map<C>(con: &C, map_id: Uuid) -> Result<Vec<map_pin::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    MapPin::find()
+        .filter(map_pin::Column::MapId.eq(map_id))
+        .order_by_asc(map_pin::Column::Id)
+        .all(con)
+        .await
+}
+
+pub async fn get_all_for_target<C>(con: &C, target_id: Uuid) -> Result<Vec<map_pin::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    MapPin::find()
+        .filter(map_pin::Column::TargetId.eq(target_id))
+        .order_by_asc(map_pin::Column::MapId)
+        .order_by_asc(map_pin::Column::Id)
+        .all(con)
+        .await
+}
+
+/// Returns the pins whose target entries no longer exist or are in the trash.
+pub async fn get_dangling<C>(con: &C) -> Result<Vec<map_pin::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    MapPin::find()
+        .filter(map_pin::Column::TargetId.not_in_subquery(entry_manager::select_untrashed_ids()))
+        .order_by_asc(map_pin::Column::MapId)
+        .order_by_asc(map_pin::Column::Id)
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Map pin not found.".to_owned()));
+    };
+    existing_entity.delete(con).await
+}
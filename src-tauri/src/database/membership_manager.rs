@@ -0,0 +1,123 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{membership, membership::Entity as Membership};
+
+use crate::database::entry_manager;
+use crate::model::calendar::DatePeriod;
+
+pub async fn insert<C>(
+    con: &C,
+    organization_id: Uuid,
+    person_id: Uuid,
+    role: &str,
+    rank: Option<i32>,
+    dates: DatePeriod,
+) -> Result<membership::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut membership = membership::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        organization_id: Set(organization_id),
+        person_id: Set(person_id),
+        role: Set(role.to_owned()),
+        rank: Set(rank),
+        ..Default::default()
+    };
+    _set_dates(&mut membership, dates);
+    membership.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    role: &str,
+    rank: Option<i32>,
+    dates: DatePeriod,
+) -> Result<membership::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Membership not found.".to_owned()));
+    };
+    let mut entity: membership::ActiveModel = entity.into();
+    entity.role = Set(role.to_owned());
+    entity.rank = Set(rank);
+    _set_dates(&mut entity, dates);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<membership::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Membership::find_by_id(id).one(con).await
+}
+
+/// Returns the memberships of the organization, leaving out the members in the trash.
+pub async fn get_all_for_organization<C>(
+    con: &C,
+    organization_id: Uuid,
+) -> Result<Vec<membership::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Membership::find()
+        .filter(membership::Column::OrganizationId.eq(organization_id))
+        .filter(membership::Column::PersonId.in_subquery(entry_manager::select_untrashed_ids()))
+        .all(con)
+        .await
+}
+
+/// Returns the memberships of the person, leaving out the organizations in the trash.
+pub async fn get_all_for_person<C>(
+    con: &C,
+    person_id: Uuid,
+) -> Result<Vec<membership::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Membership::find()
+        .filter(membership::Column::PersonId.eq(person_id))
+        .filter(
+            membership::Column::OrganizationId.in_subquery(entry_manager::select_untrashed_ids()),
+        )
+        .all(con)
+        .await
+}
+
+pub async fn get_all_for_calendar<C>(
+    con: &C,
+    calendar_id: Uuid,
+) -> Result<Vec<membership::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Membership::find()
+        .filter(membership::Column::CalendarId.eq(calendar_id))
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Membership not found.".to_owned()));
+    };
+    existing_entity.delete(con).await
+}
+
+fn _set_dates(membership: &mut membership::ActiveModel, dates: DatePeriod) {
+    let (calendar_id, start, end) = dates;
+    membership.calendar_id = Set(calendar_id);
+    membership.start_year = Set(start.map(|date| date.year));
+    membership.start_month = Set(start.map(|date| date.month as i32));
+    membership.start_day = Set(start.map(|date| date.day as i32));
+    membership.end_year = Set(end.map(|date| date.year));
+    membership.end_month = Set(end.map(|date| date.month as i32));
+    membership.end_day = Set(end.map(|date| date.day as i32));
+}
@@ -3,11 +3,37 @@ pub mod utils;
 
 pub mod setup;
 
+pub mod asset_manager;
+pub mod asset_reference_manager;
+pub mod custom_property_manager;
+pub mod custom_property_value_manager;
+pub mod entry_alias_manager;
+pub mod entry_date_manager;
 pub mod entry_manager;
+pub mod entry_reference_manager;
+pub mod entry_revision_manager;
+pub mod entry_search_manager;
+pub mod entry_tag_manager;
+pub mod entry_template_manager;
 pub mod file_manager;
 pub mod folder_manager;
+pub mod folder_tag_manager;
+pub mod index_version_manager;
+pub mod tag_manager;
 
+pub mod inflected_word_manager;
+pub mod journal_manager;
 pub mod language_manager;
 pub mod word_manager;
 
+pub mod calendar_manager;
+
+pub mod event_manager;
+pub mod event_participant_manager;
+pub mod map_manager;
+pub mod map_pin_manager;
+pub mod membership_manager;
+pub mod organization_manager;
 pub mod person_manager;
+pub mod person_relationship_manager;
+pub mod place_manager;
@@ -0,0 +1,56 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{organization, organization::Entity as Organization};
+
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    parent_id: Option<Uuid>,
+) -> Result<organization::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let organization = organization::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        parent_id: Set(parent_id),
+    };
+    organization.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    entry_id: Uuid,
+    parent_id: Option<Uuid>,
+) -> Result<organization::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, entry_id).await? else {
+        return Err(DbErr::RecordNotFound("Organization not found.".to_owned()));
+    };
+    let mut entity: organization::ActiveModel = entity.into();
+    entity.parent_id = Set(parent_id);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, entry_id: Uuid) -> Result<Option<organization::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Organization::find()
+        .filter(organization::Column::EntryId.eq(entry_id))
+        .one(con)
+        .await
+}
+
+pub async fn get_children<C>(con: &C, parent_id: Uuid) -> Result<Vec<organization::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Organization::find()
+        .filter(organization::Column::ParentId.eq(parent_id))
+        .all(con)
+        .await
+}
@@ -0,0 +1,183 @@
+use sea_orm::*;
+use uuid::Uuid;
+
+use ::entity::{person_relationship, person_relationship::Entity as PersonRelationship};
+
+use crate::model::calendar::DatePeriod;
+use crate::types::person::PersonRelationshipType;
+use crate::utils::CodedEnum;
+
+pub async fn insert<C>(
+    con: &C,
+    person_id: Uuid,
+    relative_id: Uuid,
+    relationship_type: PersonRelationshipType,
+    is_adoptive: bool,
+    dates: DatePeriod,
+) -> Result<person_relationship::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let mut relationship = person_relationship::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        person_id: Set(person_id),
+        relative_id: Set(relative_id),
+        relationship_type: Set(relationship_type.code()),
+        is_adoptive: Set(is_adoptive),
+        ..Default::default()
+    };
+    _set_dates(&mut relationship, dates);
+    relationship.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    is_adoptive: bool,
+    dates: DatePeriod,
+) -> Result<person_relationship::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound(
+            "Person relationship not found.".to_owned(),
+        ));
+    };
+    let mut entity: person_relationship::ActiveModel = entity.into();
+    entity.is_adoptive = Set(is_adoptive);
+    _set_dates(&mut entity, dates);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<person_relationship::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    PersonRelationship::find_by_id(id).one(con).await
+}
+
+/// Returns the relationships in which the person takes part, on either side.
+pub async fn get_all_for_person<C>(
+    con: &C,
+    person_id: Uuid,
+) -> Result<Vec<person_relationship::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    PersonRelationship::find()
+        .filter(
+            Condition::any()
+                .add(person_relationship::Column::PersonId.eq(person_id))
+                .add(person_relationship::Column::RelativeId.eq(person_id)),
+        )
+        .all(con)
+        .await
+}
+
+/// Returns the relationships between the given people, in either direction.
+pub async fn get_all_between<C>(
+    con: &C,
+    ids: Vec<Uuid>,
+) -> Result<Vec<person_relationship::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    PersonRelationship::find()
+        .filter(person_relationship::Column::PersonId.is_in(ids.clone()))
+        .filter(person_relationship::Column::RelativeId.is_in(ids))
+        .all(con)
+        .await
+}
+
+/// Returns the parent relationships of the given children.
+pub async fn get_parents<C>(
+    con: &C,
+    child_ids: Vec<Uuid>,
+) -> Result<Vec<person_relationship::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    PersonRelationship::find()
+        .filter(
+            person_relationship::Column::RelationshipType.eq(PersonRelationshipType::Parent.code()),
+        )
+        .filter(person_relationship::Column::RelativeId.is_in(child_ids))
+        .all(con)
+        .await
+}
+
+/// Returns the parent relationships of the given parents.
+pub async fn get_children<C>(
+    con: &C,
+    parent_ids: Vec<Uuid>,
+) -> Result<Vec<person_relationship::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    PersonRelationship::find()
+        .filter(
+            person_relationship::Column::RelationshipType.eq(PersonRelationshipType::Parent.code()),
+        )
+        .filter(person_relationship::Column::PersonId.is_in(parent_ids))
+        .all(con)
+        .await
+}
+
+/// Returns the partner relationships of the given people, on either side.
+pub async fn get_partners<C>(
+    con: &C,
+    ids: Vec<Uuid>,
+) -> Result<Vec<person_relationship::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    PersonRelationship::find()
+        .filter(
+            person_relationship::Column::RelationshipType
+                .eq(PersonRelationshipType::Partner.code()),
+        )
+        .filter(
+            Condition::any()
+                .add(person_relationship::Column::PersonId.is_in(ids.clone()))
+                .add(person_relationship::Column::RelativeId.is_in(ids)),
+        )
+        .all(con)
+        .await
+}
+
+pub async fn get_all_for_calendar<C>(
+    con: &C,
+    calendar_id: Uuid,
+) -> Result<Vec<person_relationship::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    PersonRelationship::find()
+        .filter(person_relationship::Column::CalendarId.eq(calendar_id))
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound(
+            "Person relationship not found.".to_owned(),
+        ));
+    };
+    existing_entity.delete(con).await
+}
+
+fn _set_dates(relationship: &mut person_relationship::ActiveModel, dates: DatePeriod) {
+    let (calendar_id, start, end) = dates;
+    relationship.calendar_id = Set(calendar_id);
+    relationship.start_year = Set(start.map(|date| date.year));
+    relationship.start_month = Set(start.map(|date| date.month as i32));
+    relationship.start_day = Set(start.map(|date| date.day as i32));
+    relationship.end_year = Set(end.map(|date| date.year));
+    relationship.end_month = Set(end.map(|date| date.month as i32));
+    relationship.end_day = Set(end.map(|date| date.day as i32));
+}
@@ -0,0 +1,172 @@
+use sea_orm::{
+    sea_query::{
+        Alias, CommonTableExpression, Expr, JoinType, SelectStatement, UnionType, WithClause,
+    },
+    *,
+};
+use uuid::Uuid;
+
+use ::entity::{
+    place,
+    place::{Column as PlaceColumn, Entity as Place},
+};
+
+use crate::types::place::PlaceType;
+use crate::utils::CodedEnum;
+
+/// A place in the hierarchy of another place.
+/// The depth is the number of levels between the two places.
+#[derive(Debug, FromQueryResult)]
+pub struct PlaceNode {
+    pub entry_id: Uuid,
+    pub depth: i32,
+}
+
+pub async fn insert<C>(
+    con: &C,
+    entry_id: Uuid,
+    place_type: PlaceType,
+    parent_id: Option<Uuid>,
+) -> Result<place::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let place = place::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entry_id: Set(entry_id),
+        place_type: Set(place_type.code()),
+        parent_id: Set(parent_id),
+    };
+    place.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    entry_id: Uuid,
+    place_type: PlaceType,
+    parent_id: Option<Uuid>,
+) -> Result<place::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, entry_id).await? else {
+        return Err(DbErr::RecordNotFound("Place not found.".to_owned()));
+    };
+    let mut entity: place::ActiveModel = entity.into();
+    entity.place_type = Set(place_type.code());
+    entity.parent_id = Set(parent_id);
+    entity.update(con).await
+}
+
+pub async fn get<C>(con: &C, entry_id: Uuid) -> Result<Option<place::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Place::find()
+        .filter(place::Column::EntryId.eq(entry_id))
+        .one(con)
+        .await
+}
+
+/// Returns every place located in the place, nearest first.
+pub async fn get_descendants<C>(con: &C, entry_id: Uuid) -> Result<Vec<PlaceNode>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let descendant_table = Alias::new("descendants");
+
+    let base_query = SelectStatement::new()
+        .column((Place, PlaceColumn::EntryId))
+        .expr_as(Expr::val(1), Alias::new("depth"))
+        .from(Place)
+        .and_where(Expr::col((Place, PlaceColumn::ParentId)).eq(entry_id))
+        .to_owned();
+
+    let recursive_query = SelectStatement::new()
+        .column((Place, PlaceColumn::EntryId))
+        .expr(Expr::col((descendant_table.clone(), Alias::new("depth"))).add(1))
+        .from(Place)
+        .join(
+            JoinType::InnerJoin,
+            descendant_table.clone(),
+            Expr::col((Place, PlaceColumn::ParentId))
+                .equals((descendant_table.clone(), PlaceColumn::EntryId)),
+        )
+        .to_owned();
+
+    _get_hierarchy(con, descendant_table, base_query, recursive_query).await
+}
+
+/// Returns every place that the place is located in, nearest first.
+pub async fn get_ancestors<C>(con: &C, entry_id: Uuid) -> Result<Vec<PlaceNode>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let ancestor_table = Alias::new("ancestors");
+
+    let base_query = SelectStatement::new()
+        .expr_as(
+            Expr::col((Place, PlaceColumn::ParentId)),
+            Alias::new("entry_id"),
+        )
+        .expr_as(Expr::val(1), Alias::new("depth"))
+        .from(Place)
+        .and_where(Expr::col((Place, PlaceColumn::EntryId)).eq(entry_id))
+        .and_where(Expr::col((Place, PlaceColumn::ParentId)).is_not_null())
+        .to_owned();
+
+    let recursive_query = SelectStatement::new()
+        .column((Place, PlaceColumn::ParentId))
+        .expr(Expr::col((ancestor_table.clone(), Alias::new("depth"))).add(1))
+        .from(Place)
+        .join(
+            JoinType::InnerJoin,
+            ancestor_table.clone(),
+            Expr::col((Place, PlaceColumn::EntryId))
+                .equals((ancestor_table.clone(), PlaceColumn::EntryId)),
+        )
+        .and_where(Expr::col((Place, PlaceColumn::ParentId)).is_not_null())
+        .to_owned();
+
+    _get_hierarchy(con, ancestor_table, base_query, recursive_query).await
+}
+
+/// Runs a recursive query over the place hierarchy.
+/// The hierarchy must not contain cycles, or else the query never ends.
+/// Places in the trash are included, since a restored place would otherwise close a cycle.
+async fn _get_hierarchy<C>(
+    con: &C,
+    table: Alias,
+    base_query: SelectStatement,
+    recursive_query: SelectStatement,
+) -> Result<Vec<PlaceNode>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let cte = CommonTableExpression::new()
+        .query(
+            base_query
+                .clone()
+                .union(UnionType::All, recursive_query)
+                .to_owned(),
+        )
+        .column(PlaceColumn::EntryId)
+        .column(Alias::new("depth"))
+        .table_name(table.clone())
+        .to_owned();
+
+    let select = SelectStatement::new()
+        .column((table.clone(), PlaceColumn::EntryId))
+        .column((table.clone(), Alias::new("depth")))
+        .from(table.clone())
+        .order_by((table.clone(), Alias::new("depth")), Order::Asc)
+        .to_owned();
+
+    let with_clause = WithClause::new().recursive(true).cte(cte).to_owned();
+
+    let query = select.with(with_clause).to_owned();
+
+    let stmt = con.get_database_backend().build(&query);
+
+    PlaceNode::find_by_statement(stmt).all(con).await
+}
@@ -0,0 +1,122 @@
+use sea_orm::{
+    sea_query::{Expr, SimpleExpr},
+    *,
+};
+use uuid::Uuid;
+
+use ::entity::{tag, tag::Entity as Tag};
+
+pub async fn insert<C>(con: &C, name: &str, color: Option<String>) -> Result<tag::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let tag = tag::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        name: Set(name.to_owned()),
+        color: Set(color),
+    };
+    tag.insert(con).await
+}
+
+pub async fn update<C>(
+    con: &C,
+    id: Uuid,
+    name: &str,
+    color: Option<String>,
+) -> Result<tag::Model, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Tag not found.".to_owned()));
+    };
+    let mut entity: tag::ActiveModel = entity.into();
+    entity.name = Set(name.to_owned());
+    entity.color = Set(color);
+    entity.update(con).await
+}
+
+/// Renames the tags nested in the given tag, by replacing the prefix of their names.
+pub async fn rename_nested<C>(con: &C, name: &str, new_name: &str) -> Result<UpdateResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Tag::update_many()
+        .col_expr(
+            tag::Column::Name,
+            Expr::cust_with_values(
+                "? || substr(tag.name, length(?) + 1)",
+                [new_name.to_owned(), name.to_owned()],
+            ),
+        )
+        .filter(is_nested_in(name))
+        .exec(con)
+        .await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Tag::find_by_id(id).one(con).await
+}
+
+pub async fn get_by_name<C>(con: &C, name: &str) -> Result<Option<tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Tag::find()
+        .filter(tag::Column::Name.eq(name))
+        .one(con)
+        .await
+}
+
+pub async fn get_many<C>(con: &C, ids: Vec<Uuid>) -> Result<Vec<tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Tag::find()
+        .filter(tag::Column::Id.is_in(ids))
+        .order_by_asc(tag::Column::Name)
+        .all(con)
+        .await
+}
+
+pub async fn get_all<C>(con: &C) -> Result<Vec<tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Tag::find().order_by_asc(tag::Column::Name).all(con).await
+}
+
+/// Returns the tags nested in the given tag at any depth.
+pub async fn get_nested<C>(con: &C, name: &str) -> Result<Vec<tag::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Tag::find()
+        .filter(is_nested_in(name))
+        .order_by_asc(tag::Column::Name)
+        .all(con)
+        .await
+}
+
+pub async fn delete<C>(con: &C, id: Uuid) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Tag not found.".to_owned()));
+    };
+    existing_entity.delete(con).await
+}
+
+/// Matches the tags whose names start with the given name followed by a slash.
+/// The prefix is compared with `substr` rather than `LIKE`, so that wildcards in tag names don't need escaping.
+pub fn is_nested_in(name: &str) -> SimpleExpr {
+    // SQLite placeholders aren't numbered, so each placeholder takes its own value
+    Expr::cust_with_values(
+        "substr(tag.name, 1, length(?) + 1) = ? || '/'",
+        [name.to_owned(), name.to_owned()],
+    )
+}
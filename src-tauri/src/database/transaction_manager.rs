@@ -1,4 +1,4 @@
-use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use sea_orm::{DatabaseTransaction, TransactionTrait};
 
 use crate::model::errors::{Error, ErrorBuilder};
 
@@ -9,7 +9,11 @@ It's not clear why a closure is preferred to using the begin/commit syntax,
 but the latter is more intuitive to use.
 */
 
-pub async fn begin(db: &DatabaseConnection) -> Result<DatabaseTransaction, Error> {
+/// Begins a transaction, or a nested transaction if `db` is itself a transaction.
+pub async fn begin<C>(db: &C) -> Result<DatabaseTransaction, Error>
+where
+    C: TransactionTrait,
+{
     db.begin().await.map_err(|e| {
         ErrorBuilder::new()
             .msg("Failed to create a new DB transaction.")
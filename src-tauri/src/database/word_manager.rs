@@ -1,4 +1,7 @@
-use sea_orm::*;
+use sea_orm::{
+    sea_query::{Expr, OnConflict},
+    *,
+};
 use uuid::Uuid;
 
 use ::entity::{word, word::Entity as WordEntity};
@@ -30,10 +33,15 @@ where
         spelling: utils::set_value_or_default(spelling),
         definition: utils::set_value_or_default(definition),
         translations,
+        revision: Set(0),
     };
     return new_entity.insert(con).await;
 }
 
+/// Updates the word and bumps its revision.
+/// If an expected revision is given, then the word is only updated if it's still at that revision,
+/// or else `DbErr::RecordNotUpdated` is returned.
+#[allow(clippy::too_many_arguments)]
 pub async fn update<C>(
     con: &C,
     id: Uuid,
@@ -42,6 +50,7 @@ pub async fn update<C>(
     spelling: Option<String>,
     definition: Option<String>,
     translations: Option<serde_json::Value>,
+    expected_revision: Option<i32>,
 ) -> Result<word::Model, DbErr>
 where
     C: ConnectionTrait,
@@ -50,15 +59,25 @@ where
         Some(t) => Set(t),
         None => NotSet,
     };
+    let Some(existing_entity) = get(con, id).await? else {
+        return Err(DbErr::RecordNotFound("Word not found.".to_owned()));
+    };
+    let revision = expected_revision.unwrap_or(existing_entity.revision);
     let updated_entity = word::ActiveModel {
-        id: Unchanged(id),
+        id: Unchanged(existing_entity.id),
         language_id: utils::set_optional_value(language_id),
         word_type: utils::set_optional_type(word_type),
         spelling: utils::set_optional_value(spelling),
         definition: utils::set_optional_value(definition),
         translations,
+        revision: Set(revision + 1),
     };
-    updated_entity.update(con).await
+    let mut query = WordEntity::update(updated_entity);
+    if let Some(expected_revision) = expected_revision {
+        // the check and the write are a single statement, so that no write can come in between
+        query = query.filter(word::Column::Revision.eq(expected_revision));
+    }
+    query.exec(con).await
 }
 
 pub async fn get<C>(con: &C, id: Uuid) -> Result<Option<word::Model>, DbErr>
@@ -68,6 +87,23 @@ where
     WordEntity::find_by_id(id).one(con).await
 }
 
+pub async fn get_all<C>(con: &C) -> Result<Vec<word::Model>, DbErr>
+where
+    C: ConnectionTrait,
+{
+    WordEntity::find()
+        .order_by_asc(word::Column::Spelling)
+        .all(con)
+        .await
+}
+
+pub async fn count<C>(con: &C) -> Result<u64, DbErr>
+where
+    C: ConnectionTrait,
+{
+    WordEntity::find().count(con).await
+}
+
 pub async fn get_all_for_language<C>(
     con: &C,
     language_id: Uuid,
@@ -94,3 +130,46 @@ where
     };
     return existing_entity.delete(con).await;
 }
+
+/// Inserts the word with the given ID, or overwrites the word if it already exists.
+pub async fn upsert<C>(
+    con: &C,
+    id: Uuid,
+    language_id: Uuid,
+    word_type: WordType,
+    spelling: String,
+    definition: String,
+    translations: serde_json::Value,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let entity = word::ActiveModel {
+        id: Set(id),
+        language_id: Set(language_id),
+        word_type: Set(word_type.code()),
+        spelling: Set(spelling),
+        definition: Set(definition),
+        translations: Set(translations),
+        revision: Set(0),
+    };
+    WordEntity::insert(entity)
+        .on_conflict(
+            OnConflict::column(word::Column::Id)
+                .update_columns([
+                    word::Column::LanguageId,
+                    word::Column::WordType,
+                    word::Column::Spelling,
+                    word::Column::Definition,
+                    word::Column::Translations,
+                ])
+                .value(
+                    word::Column::Revision,
+                    Expr::col((WordEntity, word::Column::Revision)).add(1),
+                )
+                .to_owned(),
+        )
+        .exec_without_returning(con)
+        .await?;
+    Ok(())
+}
@@ -24,6 +24,9 @@ async fn main() {
     let state = app::setup(config).await.expect("Failed to set up app");
     builder = builder.manage(state);
 
+    println!("Attaching background tasks");
+    builder = app::attach_background_tasks(builder);
+
     println!("Attaching API handlers");
     builder = app::attach_handlers(builder);
 
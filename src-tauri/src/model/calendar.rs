@@ -0,0 +1,395 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::calendar::{CalendarSchema, EraSchema};
+
+/// Leap rules repeat after the least common multiple of their intervals;
+/// longer cycles are rejected because the years of a cycle are summed when converting dates.
+const MAX_LEAP_CYCLE_YEARS: i64 = 10_000;
+
+/// A date of a fictional calendar.
+/// The year is counted from the calendar's epoch; the month and the day start at 1.
+/// Dates of the same calendar are ordered chronologically.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FictionalDate {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl FictionalDate {
+    pub fn new(year: i64, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+}
+
+/// A period with optional start and end dates, along with the calendar of its dates.
+/// The calendar is only set if one of the dates is.
+pub type DatePeriod = (Option<Uuid>, Option<FictionalDate>, Option<FictionalDate>);
+
+/// A validated calendar definition that converts dates to and from day numbers.
+#[derive(Clone, Debug)]
+pub struct Calendar {
+    definition: CalendarSchema,
+    cycle_years: i64,
+    cycle_days: i64,
+}
+
+impl Calendar {
+    pub fn new(definition: CalendarSchema) -> Result<Self, String> {
+        _validate(&definition)?;
+
+        let mut calendar = Self {
+            definition,
+            cycle_years: 1,
+            cycle_days: 0,
+        };
+        calendar.cycle_years = calendar
+            .definition
+            .leap_rules
+            .iter()
+            .fold(1, |cycle, rule| _lcm(cycle, rule.interval as i64));
+        if calendar.cycle_years > MAX_LEAP_CYCLE_YEARS {
+            return Err(format!(
+                "The leap rules repeat every {} years, but at most {MAX_LEAP_CYCLE_YEARS} are supported.",
+                calendar.cycle_years
+            ));
+        }
+        calendar.cycle_days = (1..=calendar.cycle_years)
+            .map(|year| calendar.year_length(year))
+            .sum();
+
+        Ok(calendar)
+    }
+
+    pub fn definition(&self) -> &CalendarSchema {
+        &self.definition
+    }
+
+    pub fn is_leap_year(&self, year: i64) -> bool {
+        self.definition
+            .leap_rules
+            .iter()
+            .rev()
+            .find(|rule| (year - rule.offset as i64).rem_euclid(rule.interval as i64) == 0)
+            .is_some_and(|rule| rule.is_leap)
+    }
+
+    pub fn year_length(&self, year: i64) -> i64 {
+        (1..=self.definition.months.len() as u32)
+            .map(|month| self._month_length(year, month) as i64)
+            .sum()
+    }
+
+    /// Returns `None` if the calendar doesn't have the month.
+    pub fn month_length(&self, year: i64, month: u32) -> Option<u32> {
+        if month == 0 || month as usize > self.definition.months.len() {
+            return None;
+        }
+        Some(self._month_length(year, month))
+    }
+
+    pub fn validate_date(&self, date: &FictionalDate) -> Result<(), String> {
+        let Some(length) = self.month_length(date.year, date.month) else {
+            return Err(format!(
+                "The calendar doesn't have a month {}; it has {} months.",
+                date.month,
+                self.definition.months.len()
+            ));
+        };
+        if date.day == 0 || date.day > length {
+            return Err(format!(
+                "{} {} only has {length} days.",
+                self.definition.months[date.month as usize - 1].name,
+                date.year
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the number of days between the shared epoch of all calendars and the date.
+    pub fn to_day_number(&self, date: &FictionalDate) -> Result<i64, String> {
+        self.validate_date(date)?;
+
+        let days_before_month: i64 = (1..date.month)
+            .map(|month| self._month_length(date.year, month) as i64)
+            .sum();
+
+        Ok(self.definition.epoch_offset
+            + self._days_before_year(date.year)
+            + days_before_month
+            + (date.day as i64 - 1))
+    }
+
+    pub fn date_from_day_number(&self, day_number: i64) -> FictionalDate {
+        let days_since_epoch = day_number - self.definition.epoch_offset;
+        let cycles = days_since_epoch.div_euclid(self.cycle_days);
+        let mut remaining_days = days_since_epoch.rem_euclid(self.cycle_days);
+
+        let mut year = cycles * self.cycle_years + 1;
+        loop {
+            let length = self.year_length(year);
+            if remaining_days < length {
+                break;
+            }
+            remaining_days -= length;
+            year += 1;
+        }
+
+        let mut month = 1;
+        loop {
+            let length = self._month_length(year, month) as i64;
+            if remaining_days < length {
+                break;
+            }
+            remaining_days -= length;
+            month += 1;
+        }
+
+        FictionalDate::new(year, month, remaining_days as u32 + 1)
+    }
+
+    /// Returns `None` if the calendar doesn't have weeks.
+    pub fn weekday(&self, day_number: i64) -> Option<&str> {
+        let weekdays = &self.definition.weekdays;
+        if weekdays.is_empty() {
+            return None;
+        }
+        let index = (day_number - self.definition.epoch_offset).rem_euclid(weekdays.len() as i64);
+        Some(&weekdays[index as usize])
+    }
+
+    /// Formats the date as, e.g., "3rd of Frostmoon, 412 AE".
+    /// The era is left out if no era applies to the year.
+    pub fn format(&self, date: &FictionalDate) -> Result<String, String> {
+        self.validate_date(date)?;
+
+        let month = &self.definition.months[date.month as usize - 1].name;
+        let day = _format_ordinal(date.day);
+        Ok(format!("{day} of {month}, {}", self.format_year(date.year)))
+    }
+
+    /// Formats the year as, e.g., "412 AE".
+    pub fn format_year(&self, year: i64) -> String {
+        match self._get_era(year) {
+            Some(era) => format!("{} {}", _get_era_year(era, year), era.abbreviation),
+            None => year.to_string(),
+        }
+    }
+
+    /// Parses a date in the format produced by [`Calendar::format`].
+    /// Month and era names are matched case-insensitively, and eras may be given by name or abbreviation.
+    /// Without an era, the year is counted from the epoch.
+    pub fn parse(&self, text: &str) -> Result<FictionalDate, String> {
+        let invalid_format =
+            || format!("'{text}' isn't a date of the form \"3rd of Frostmoon, 412 AE\".");
+
+        let (day_and_month, year_and_era) = text.split_once(',').ok_or_else(invalid_format)?;
+
+        let (day, month) = day_and_month
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(invalid_format)?;
+        let day = _parse_ordinal(day).ok_or_else(invalid_format)?;
+        let month = month.trim();
+        let month = match month.split_once(char::is_whitespace) {
+            Some((of, name)) if of.eq_ignore_ascii_case("of") => name.trim(),
+            _ => month,
+        };
+        let month = self
+            .definition
+            .months
+            .iter()
+            .position(|m| _eq_ignore_case(&m.name, month))
+            .ok_or_else(|| format!("The calendar doesn't have a month named '{month}'."))?
+            as u32
+            + 1;
+
+        let year_and_era = year_and_era.trim();
+        let (year, era) = match year_and_era.split_once(char::is_whitespace) {
+            Some((year, era)) => (year, Some(era.trim())),
+            None => (year_and_era, None),
+        };
+        let year: i64 = year.parse().map_err(|_| invalid_format())?;
+
+        let year = match era {
+            Some(era_name) => {
+                let era = self
+                    .definition
+                    .eras
+                    .iter()
+                    .find(|era| {
+                        _eq_ignore_case(&era.abbreviation, era_name)
+                            || _eq_ignore_case(&era.name, era_name)
+                    })
+                    .ok_or_else(|| {
+                        format!("The calendar doesn't have an era named '{era_name}'.")
+                    })?;
+                if year < 1 {
+                    return Err(format!("Years of the era {} start at 1.", era.name));
+                }
+                let epoch_year = if era.is_reversed {
+                    era.start_year - year
+                } else {
+                    era.start_year + year - 1
+                };
+                if self._get_era(epoch_year) != Some(era) {
+                    return Err(format!(
+                        "The year {year} {} is outside of the era.",
+                        era.abbreviation
+                    ));
+                }
+                epoch_year
+            }
+            None => year,
+        };
+
+        let date = FictionalDate::new(year, month, day);
+        self.validate_date(&date)?;
+        Ok(date)
+    }
+
+    fn _month_length(&self, year: i64, month: u32) -> u32 {
+        let month = &self.definition.months[month as usize - 1];
+        if self.is_leap_year(year) {
+            month.days + month.leap_days
+        } else {
+            month.days
+        }
+    }
+
+    /// Returns the number of days between the first day of year 1 and the first day of the year.
+    fn _days_before_year(&self, year: i64) -> i64 {
+        // the leap rules repeat every cycle, so only the years of the last partial cycle are summed
+        let years = year - 1;
+        let cycles = years.div_euclid(self.cycle_years);
+        let remaining_years = years.rem_euclid(self.cycle_years);
+        let remaining_days: i64 = (1..=remaining_years)
+            .map(|year| self.year_length(year))
+            .sum();
+        cycles * self.cycle_days + remaining_days
+    }
+
+    fn _get_era(&self, year: i64) -> Option<&EraSchema> {
+        let eras = &self.definition.eras;
+        eras.iter()
+            .filter(|era| !era.is_reversed && era.start_year <= year)
+            .max_by_key(|era| era.start_year)
+            .or_else(|| {
+                eras.iter()
+                    .filter(|era| era.is_reversed && era.start_year > year)
+                    .min_by_key(|era| era.start_year)
+            })
+    }
+}
+
+fn _validate(definition: &CalendarSchema) -> Result<(), String> {
+    if definition.months.is_empty() {
+        return Err("A calendar must have at least one month.".to_owned());
+    }
+
+    let mut month_names: HashSet<String> = HashSet::new();
+    for month in definition.months.iter() {
+        if month.name.trim().is_empty() {
+            return Err("Month names must not be empty.".to_owned());
+        }
+        // dates are parsed by month name, so the names must be distinct
+        if !month_names.insert(month.name.to_lowercase()) {
+            return Err(format!("The month name '{}' is used twice.", month.name));
+        }
+        if month.days == 0 {
+            return Err(format!(
+                "The month {} must have at least one day.",
+                month.name
+            ));
+        }
+    }
+
+    if definition
+        .weekdays
+        .iter()
+        .any(|name| name.trim().is_empty())
+    {
+        return Err("Weekday names must not be empty.".to_owned());
+    }
+
+    if definition.leap_rules.iter().any(|rule| rule.interval == 0) {
+        return Err("Leap rule intervals must be at least one year.".to_owned());
+    }
+
+    let mut era_names: HashSet<String> = HashSet::new();
+    for era in definition.eras.iter() {
+        if era.name.trim().is_empty() || era.abbreviation.trim().is_empty() {
+            return Err("Era names and abbreviations must not be empty.".to_owned());
+        }
+        if era.abbreviation.contains(char::is_whitespace) {
+            return Err(format!(
+                "The era abbreviation '{}' must not contain spaces.",
+                era.abbreviation
+            ));
+        }
+        // dates are parsed by era name or abbreviation, so neither may refer to another era
+        let names = HashSet::from([era.name.to_lowercase(), era.abbreviation.to_lowercase()]);
+        if names.iter().any(|name| !era_names.insert(name.to_owned())) {
+            return Err(format!("The era '{}' isn't uniquely named.", era.name));
+        }
+    }
+
+    let mut start_years: HashSet<(i64, bool)> = HashSet::new();
+    for era in definition.eras.iter() {
+        if !start_years.insert((era.start_year, era.is_reversed)) {
+            return Err(format!(
+                "Two eras start in the year {}; each year must belong to one era.",
+                era.start_year
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn _lcm(a: i64, b: i64) -> i64 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    // saturates so that overly long cycles are rejected rather than overflowing
+    (a / x).saturating_mul(b)
+}
+
+fn _get_era_year(era: &EraSchema, year: i64) -> i64 {
+    if era.is_reversed {
+        era.start_year - year
+    } else {
+        year - era.start_year + 1
+    }
+}
+
+fn _format_ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+/// Parses a number with an optional ordinal suffix, e.g., "3" or "3rd".
+fn _parse_ordinal(text: &str) -> Option<u32> {
+    let digits = text.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let suffix = &text[digits.len()..];
+    let n: u32 = digits.parse().ok()?;
+    if suffix.is_empty() || _format_ordinal(n) == format!("{n}{}", suffix.to_lowercase()) {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+fn _eq_ignore_case(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::{
     model::errors::{
         builders::{
@@ -84,4 +86,22 @@ impl EntityErrorBuilder {
             id: self.id.clone(),
         }
     }
+
+    /// Reports a stale write, along with the state of the entity at its current revision.
+    pub fn revision_conflict(
+        &self,
+        expected_revision: i32,
+        revision: i32,
+        current: &impl Serialize,
+    ) -> Error {
+        Error::RevisionConflict {
+            msg: self.message.clone(),
+            error: self.error.clone(),
+            entity_type: self.entity_type,
+            id: self.id.clone(),
+            expected_revision,
+            revision,
+            current: serde_json::to_value(current).unwrap_or_default(),
+        }
+    }
 }
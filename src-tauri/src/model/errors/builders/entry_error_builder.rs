@@ -59,6 +59,14 @@ impl EntryErrorBuilder {
         }
     }
 
+    pub fn bad_asset_id(&self, id: &impl ToString) -> Error {
+        Error::BadEntryAssetId {
+            msg: self.message.clone(),
+            id: self.id.clone(),
+            asset_id: id.to_string(),
+        }
+    }
+
     pub fn bad_text_value_type(&self, key: &str, value: &str, expected_type: &str) -> Error {
         Error::BadEntryTextValueType {
             msg: self.message.clone(),
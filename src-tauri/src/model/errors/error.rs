@@ -42,6 +42,17 @@ pub enum Error {
         entity_type: EntityType,
         id: String,
     },
+    /// The entity has been written since the revision that the update was based on;
+    /// `current` holds the state of the entity as it is now.
+    RevisionConflict {
+        msg: String,
+        error: Option<String>,
+        entity_type: EntityType,
+        id: String,
+        expected_revision: i32,
+        revision: i32,
+        current: serde_json::Value,
+    },
 
     // ATTRIBUTE ERRORS
     AttributeNotUpdated {
@@ -89,6 +100,11 @@ pub enum Error {
         id: String,
         reference_id: String,
     },
+    BadEntryAssetId {
+        msg: String,
+        id: String,
+        asset_id: String,
+    },
     BadEntryTextValueType {
         msg: String,
         id: String,
@@ -197,6 +213,21 @@ impl std::fmt::Display for Error {
                 error,
             ),
 
+            Error::RevisionConflict {
+                msg,
+                error,
+                entity_type,
+                id,
+                expected_revision,
+                revision,
+                ..
+            } => create_formatted_error_string(
+                "REVISION_CONFLICT",
+                &format!("{} {} {} {}", entity_type, id, expected_revision, revision),
+                msg,
+                error,
+            ),
+
             Error::AttributeNotUpdated {
                 msg,
                 error,
@@ -271,6 +302,13 @@ impl std::fmt::Display for Error {
                 &None,
             ),
 
+            Error::BadEntryAssetId { msg, id, asset_id } => create_formatted_error_string(
+                "BAD_ASSET_ID",
+                &format!("{} {}", id, asset_id),
+                msg,
+                &None,
+            ),
+
             Error::BadEntryTextValueType {
                 msg,
                 id,
@@ -0,0 +1,83 @@
+/// The distances from two people to one of their closest common ancestors.
+/// A distance of 0 means that the person is the common ancestor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Kinship {
+    pub person_generations: u32,
+    pub relative_generations: u32,
+    /// Whether the people are siblings that share only one of their parents.
+    pub is_half: bool,
+}
+
+impl Kinship {
+    /// Describes what the relative is to the person, e.g., "first cousin twice removed".
+    pub fn describe(&self) -> String {
+        let up = self.person_generations;
+        let down = self.relative_generations;
+        match (up, down) {
+            (0, 0) => "self".to_owned(),
+            (_, 0) => _with_greats(up, "parent"),
+            (0, _) => _with_greats(down, "child"),
+            (1, 1) if self.is_half => "half-sibling".to_owned(),
+            (1, 1) => "sibling".to_owned(),
+            (1, _) => format!(
+                "{} or {}",
+                _with_greats(down, "niece"),
+                _with_greats(down, "nephew")
+            ),
+            (_, 1) => format!(
+                "{} or {}",
+                _with_greats(up, "aunt"),
+                _with_greats(up, "uncle")
+            ),
+            _ => {
+                let cousin = format!("{} cousin", _ordinal(up.min(down) - 1));
+                match up.abs_diff(down) {
+                    0 => cousin,
+                    removed => format!("{} {} removed", cousin, _times(removed)),
+                }
+            }
+        }
+    }
+}
+
+/// Prefixes the relation with "grand" and "great-" to span the number of generations.
+/// Aunts, uncles, nieces and nephews start two generations away and only use "great-".
+fn _with_greats(generations: u32, relation: &str) -> String {
+    let (grand, greats) = match relation {
+        "parent" | "child" => (generations >= 2, generations.saturating_sub(2)),
+        _ => (false, generations.saturating_sub(2)),
+    };
+    format!(
+        "{}{}{}",
+        "great-".repeat(greats as usize),
+        if grand { "grand" } else { "" },
+        relation
+    )
+}
+
+fn _ordinal(n: u32) -> String {
+    const ORDINALS: [&str; 10] = [
+        "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+        "tenth",
+    ];
+    if let Some(ordinal) = ORDINALS.get(n as usize - 1) {
+        return (*ordinal).to_owned();
+    }
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+fn _times(n: u32) -> String {
+    match n {
+        1 => "once".to_owned(),
+        2 => "twice".to_owned(),
+        3 => "thrice".to_owned(),
+        _ => format!("{} times", n),
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schema::language::GrammarSchema;
+use crate::types::grammar::{
+    GrammaticalAnimacy, GrammaticalAspect, GrammaticalCase, GrammaticalDefiniteness,
+    GrammaticalGender, GrammaticalMood, GrammaticalNumber, GrammaticalPerson, GrammaticalVoice,
+    VerbForm, VerbTense, WordType,
+};
+
+/// The grammatical categories that an inflected form of a word is marked for.
+/// Categories that don't apply to the form are `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Inflection {
+    pub case: GrammaticalCase,
+    pub voice: GrammaticalVoice,
+    pub mood: GrammaticalMood,
+    pub tense: VerbTense,
+    pub aspect: GrammaticalAspect,
+    pub definiteness: GrammaticalDefiniteness,
+    pub number: GrammaticalNumber,
+    pub person: GrammaticalPerson,
+    pub gender: GrammaticalGender,
+    pub animacy: GrammaticalAnimacy,
+    pub verb_form: VerbForm,
+}
+
+impl Inflection {
+    /// Checks that the language uses each of the grammatical categories that the form is marked for.
+    pub fn validate(&self, grammar: &GrammarSchema) -> Result<(), String> {
+        _validate_value("case", &grammar.cases, self.case)?;
+        _validate_value("voice", &grammar.voices, self.voice)?;
+        _validate_value("mood", &grammar.moods, self.mood)?;
+        _validate_value("tense", &grammar.tenses, self.tense)?;
+        _validate_value("aspect", &grammar.aspects, self.aspect)?;
+        _validate_value("definiteness", &grammar.definiteness, self.definiteness)?;
+        _validate_value("number", &grammar.numbers, self.number)?;
+        _validate_value("person", &grammar.persons, self.person)?;
+        _validate_value("gender", &grammar.genders, self.gender)?;
+        _validate_value("animacy", &grammar.animacies, self.animacy)?;
+        _validate_value("verb form", &grammar.verb_forms, self.verb_form)
+    }
+}
+
+pub fn validate_word_type(grammar: &GrammarSchema, word_type: WordType) -> Result<(), String> {
+    _validate_value("word type", &grammar.word_types, word_type)
+}
+
+/// Values are valid if the category is undeclared, if they're declared, or if they're `None`.
+fn _validate_value<T>(
+    category: &str,
+    declared_values: &Option<Vec<T>>,
+    value: T,
+) -> Result<(), String>
+where
+    T: Debug + Default + PartialEq,
+{
+    match declared_values {
+        Some(values) if value != T::default() && !values.contains(&value) => Err(format!(
+            "The language doesn't use the {category} {value:?}."
+        )),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{
+    entry::EntryUpdateSchema, folder::FolderUpdateSchema, word::WordResponseSchema,
+};
+
+/// An operation that the journal replays to undo or redo a change.
+/// Operations are stored in the project database, so their format must stay readable by later versions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum JournalOperation {
+    UpdateEntry {
+        // boxed, since the update schema is much larger than the other operations
+        entry: Box<EntryUpdateSchema>,
+    },
+    TrashEntry {
+        id: Uuid,
+    },
+    RestoreEntry {
+        id: Uuid,
+    },
+    UpdateFolder {
+        folder: FolderUpdateSchema,
+    },
+    TrashFolder {
+        id: Uuid,
+    },
+    RestoreFolder {
+        id: Uuid,
+    },
+    /// Creates the word in the given state, or overwrites it if it exists.
+    PutWord {
+        word: WordResponseSchema,
+    },
+    DeleteWord {
+        id: Uuid,
+    },
+}
+
+/// A change to the project, as the operations that revert it and the operations that make it again.
+#[derive(Clone, Debug, Default)]
+pub struct JournalChange {
+    /// Applied in reverse order, so that the latest operation is reverted first.
+    pub undo: Vec<JournalOperation>,
+    pub redo: Vec<JournalOperation>,
+}
+
+impl JournalChange {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, undo: JournalOperation, redo: JournalOperation) {
+        self.undo.push(undo);
+        self.redo.push(redo);
+    }
+
+    pub fn extend(&mut self, change: JournalChange) {
+        self.undo.extend(change.undo);
+        self.redo.extend(change.redo);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.undo.is_empty() && self.redo.is_empty()
+    }
+}
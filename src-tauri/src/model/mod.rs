@@ -1,5 +1,10 @@
+pub mod calendar;
 pub mod config;
 pub mod errors;
+pub mod genealogy;
+pub mod grammar;
+pub mod journal;
 pub mod project;
 pub mod state;
+pub mod tag;
 pub mod text;
@@ -2,17 +2,22 @@ use sea_orm::DatabaseConnection;
 use uuid::Uuid;
 
 use crate::constants::DEFAULT_DB_FILE_NAME;
+use crate::schema::backup::BackupConfigSchema;
 
+#[derive(Clone)]
 pub struct DatabaseConfig {
     pub in_memory: bool,
     pub connection: DatabaseConnection,
 }
 
+/// Clones share the connection pool of the original.
+#[derive(Clone)]
 pub struct Project {
     pub id: Uuid,
     pub name: String,
     pub folder_path: String,
     pub database: DatabaseConfig,
+    pub backup_config: BackupConfigSchema,
 }
 
 impl Project {
@@ -22,6 +27,7 @@ impl Project {
             name,
             folder_path,
             database,
+            backup_config: BackupConfigSchema::default(),
         }
     }
 
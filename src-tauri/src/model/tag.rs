@@ -0,0 +1,144 @@
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+/// A boolean query over the tags of entries, such as `faction/north AND NOT (deceased OR exiled)`.
+/// `NOT` binds tighter than `AND`, which binds tighter than `OR`;
+/// terms that follow each other without an operator are combined with `AND`.
+/// Tag names containing spaces or parentheses are written in double quotes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TagExpression {
+    /// Matches items with the tag or one of its nested tags.
+    Tag(String),
+    Not(Box<TagExpression>),
+    And(Box<TagExpression>, Box<TagExpression>),
+    Or(Box<TagExpression>, Box<TagExpression>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Name(String),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl TagExpression {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut tokens = _tokenize(text)?.into_iter().peekable();
+        if tokens.peek().is_none() {
+            return Err("The tag expression is empty.".to_owned());
+        }
+        let expression = _parse_or(&mut tokens)?;
+        match tokens.next() {
+            None => Ok(expression),
+            Some(token) => Err(format!("Unexpected {} in the tag expression.", token)),
+        }
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Name(name) => write!(f, "tag \"{}\"", name),
+            Token::And => write!(f, "AND"),
+            Token::Or => write!(f, "OR"),
+            Token::Not => write!(f, "NOT"),
+            Token::Open => write!(f, "\"(\""),
+            Token::Close => write!(f, "\")\""),
+        }
+    }
+}
+
+fn _tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '"' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(c) => name.push(c),
+                        None => return Err("Unterminated quote in the tag expression.".to_owned()),
+                    }
+                }
+                tokens.push(Token::Name(name));
+            }
+            c => {
+                let mut word = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                // only unquoted keywords are operators, so that tags can still be called "and"
+                tokens.push(match word.to_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Name(word),
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn _parse_or(tokens: &mut Peekable<IntoIter<Token>>) -> Result<TagExpression, String> {
+    let mut expression = _parse_and(tokens)?;
+    while tokens.next_if_eq(&Token::Or).is_some() {
+        let right = _parse_and(tokens)?;
+        expression = TagExpression::Or(Box::new(expression), Box::new(right));
+    }
+    Ok(expression)
+}
+
+fn _parse_and(tokens: &mut Peekable<IntoIter<Token>>) -> Result<TagExpression, String> {
+    let mut expression = _parse_not(tokens)?;
+    loop {
+        let is_and = match tokens.peek() {
+            Some(Token::And) => {
+                tokens.next();
+                true
+            }
+            Some(Token::Name(_) | Token::Not | Token::Open) => true,
+            _ => false,
+        };
+        if !is_and {
+            return Ok(expression);
+        }
+        let right = _parse_not(tokens)?;
+        expression = TagExpression::And(Box::new(expression), Box::new(right));
+    }
+}
+
+fn _parse_not(tokens: &mut Peekable<IntoIter<Token>>) -> Result<TagExpression, String> {
+    match tokens.next() {
+        Some(Token::Not) => Ok(TagExpression::Not(Box::new(_parse_not(tokens)?))),
+        Some(Token::Name(name)) => Ok(TagExpression::Tag(name)),
+        Some(Token::Open) => {
+            let expression = _parse_or(tokens)?;
+            match tokens.next() {
+                Some(Token::Close) => Ok(expression),
+                Some(token) => Err(format!(
+                    "Expected \")\" but found {} in the tag expression.",
+                    token
+                )),
+                None => Err("Unclosed \"(\" in the tag expression.".to_owned()),
+            }
+        }
+        Some(token) => Err(format!(
+            "Expected a tag but found {} in the tag expression.",
+            token
+        )),
+        None => Err("The tag expression ends unexpectedly.".to_owned()),
+    }
+}
@@ -55,7 +55,7 @@ impl TextNode {
     }
 
     pub fn new_text(text: String) -> Self {
-        let mut node = TextNode::new("paragraph".to_owned());
+        let mut node = TextNode::new("text".to_owned());
         node.text = Some(text.to_owned());
         node
     }
@@ -70,6 +70,15 @@ impl TextNode {
         node
     }
 
+    pub fn new_image(asset_id: Uuid) -> Self {
+        let mut node = TextNode::new("image".to_owned());
+        node.attrs = Some(HashMap::from([(
+            "assetId".to_owned(),
+            asset_id.to_string().into(),
+        )]));
+        node
+    }
+
     pub fn is_type(&self, node_type: &str) -> bool {
         self.node_type.is_some() && self.node_type.as_ref().unwrap() == node_type
     }
@@ -109,4 +118,47 @@ impl TextNode {
         self.add_child(child);
         self
     }
+
+    /// Returns `true` if the node is rendered inline with its siblings.
+    pub fn is_inline(&self) -> bool {
+        match &self.node_type {
+            Some(node_type) => INLINE_NODE_TYPES.contains(&node_type.as_str()),
+            None => true,
+        }
+    }
+
+    /// Flattens the node into plain text.
+    /// Block nodes are separated by line breaks, and mentions are replaced by their labels.
+    pub fn to_plain_text(&self) -> String {
+        let mut buffer = String::new();
+        self.write_plain_text(&mut buffer);
+        buffer.trim().to_owned()
+    }
+
+    /// Appends the plain text of the node to the buffer without any trimming.
+    pub fn write_plain_text(&self, buffer: &mut String) {
+        if let Some(text) = &self.text {
+            buffer.push_str(text);
+        }
+
+        if self.is_type("mention") {
+            if let Some(label) = self.get_attr("label").and_then(|v| v.as_str()) {
+                buffer.push_str(label);
+            }
+        } else if self.is_type("hardBreak") {
+            buffer.push('\n');
+        }
+
+        if let Some(content) = &self.content {
+            for child in content.iter() {
+                child.write_plain_text(buffer);
+            }
+        }
+
+        if !self.is_inline() && !buffer.is_empty() && !buffer.ends_with('\n') {
+            buffer.push('\n');
+        }
+    }
 }
+
+const INLINE_NODE_TYPES: [&str; 4] = ["text", "mention", "hardBreak", "image"];
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetResponseSchema {
+    pub id: Uuid,
+    pub name: String,
+    /// The SHA-256 of the content, in hexadecimal.
+    pub hash: String,
+    pub mime_type: String,
+    /// In bytes.
+    pub size: i64,
+    /// Relative to the project folder.
+    pub path: String,
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BackupConfigSchema {
+    /// Whether backups are taken automatically while the project is open.
+    pub is_automatic: bool,
+    pub interval_minutes: u64,
+    /// Number of the most recent automatic backups that are kept.
+    pub keep_last: usize,
+    /// Number of days for which the most recent automatic backup of the day is kept.
+    pub keep_daily: usize,
+    /// Number of weeks for which the most recent automatic backup of the week is kept.
+    pub keep_weekly: usize,
+}
+
+impl Default for BackupConfigSchema {
+    fn default() -> Self {
+        Self {
+            is_automatic: false,
+            interval_minutes: 60,
+            keep_last: 10,
+            keep_daily: 7,
+            keep_weekly: 4,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BackupKind {
+    /// Taken on request; never deleted automatically.
+    Manual,
+    /// Taken on a schedule; deleted according to the retention settings.
+    Automatic,
+    /// Copy of the database taken before a backup was restored over it.
+    PreRestore,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupResponseSchema {
+    pub file_name: String,
+    pub kind: BackupKind,
+    pub created_at: DateTime<Utc>,
+    pub size: u64,
+}
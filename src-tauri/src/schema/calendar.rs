@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarSchema {
+    pub months: Vec<MonthSchema>,
+    /// Names of the days of the week. The first day of the calendar falls on the first weekday.
+    #[serde(default)]
+    pub weekdays: Vec<String>,
+    #[serde(default)]
+    pub leap_rules: Vec<LeapRuleSchema>,
+    #[serde(default)]
+    pub eras: Vec<EraSchema>,
+    /// Day number of the first day of year 1.
+    /// Calendars are converted into one another through their day numbers.
+    #[serde(default)]
+    pub epoch_offset: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthSchema {
+    pub name: String,
+    pub days: u32,
+    /// Number of days added to the month in leap years.
+    #[serde(default)]
+    pub leap_days: u32,
+}
+
+/// A year is a leap year if the last rule that matches it says so.
+/// The Gregorian calendar uses the rules "every 4 years", "not every 100 years"
+/// and "every 400 years", in that order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeapRuleSchema {
+    pub interval: u32,
+    /// The rule matches the years whose difference to the offset is divisible by the interval.
+    #[serde(default)]
+    pub offset: u32,
+    pub is_leap: bool,
+}
+
+/// An era starts at a year of the calendar and lasts until the next era starts.
+/// Reversed eras count backwards from their start year instead,
+/// like BC, and only apply to the years before the first era that isn't reversed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraSchema {
+    pub name: String,
+    pub abbreviation: String,
+    pub start_year: i64,
+    #[serde(default)]
+    pub is_reversed: bool,
+}
+
+/// A date of a calendar, with its year counted from the calendar's epoch rather than its era.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarDateSchema {
+    pub calendar_id: Uuid,
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarDateResponseSchema {
+    pub calendar_id: Uuid,
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    /// Number of days since the shared epoch of all calendars; used to compare dates across calendars.
+    pub day_number: i64,
+    pub weekday: Option<String>,
+    pub formatted: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryDateCreateSchema {
+    pub entry_id: Uuid,
+    pub label: String,
+    pub date: CalendarDateSchema,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryDateUpdateSchema {
+    pub id: Uuid,
+    pub label: Option<String>,
+    pub date: Option<CalendarDateSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryDateResponseSchema {
+    pub id: Uuid,
+    pub entry_id: Uuid,
+    pub label: String,
+    pub date: CalendarDateResponseSchema,
+}
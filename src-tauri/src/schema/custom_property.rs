@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::types::{custom_property::CustomPropertyType, entity::EntityType};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPropertyCreateSchema {
+    /// The type of the entries that have the property.
+    pub entity_type: EntityType,
+    pub name: String,
+    pub property_type: CustomPropertyType,
+    /// The type of the items of a list property.
+    #[serde(default)]
+    pub item_type: Option<CustomPropertyType>,
+    /// The choices of an enum property, or of a list of enums.
+    #[serde(default)]
+    pub options: Vec<String>,
+}
+
+/// The type of a property can't be changed once it is created, since its values would no longer be valid.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPropertyUpdateSchema {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub options: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPropertyResponseSchema {
+    pub id: Uuid,
+    pub entity_type: EntityType,
+    pub name: String,
+    pub property_type: CustomPropertyType,
+    pub item_type: Option<CustomPropertyType>,
+    pub options: Vec<String>,
+}
+
+/// The value of a custom property of an entry.
+/// Values are plain JSON: dates are written like `{ "calendarId": ..., "year": 412, "month": 1, "day": 3 }`,
+/// entry references are entry IDs, and `null` clears the value.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPropertyValueSchema {
+    pub property_id: Uuid,
+    pub value: serde_json::Value,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPropertyValueResponseSchema {
+    pub property_id: Uuid,
+    pub name: String,
+    pub property_type: CustomPropertyType,
+    /// `null` if the entry doesn't have a value for the property.
+    pub value: serde_json::Value,
+}
@@ -1,12 +1,19 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
     model::text::TextNode,
     schema::{
+        calendar::CalendarSchema,
         common::UpdateResponseSchema,
+        custom_property::{CustomPropertyValueResponseSchema, CustomPropertyValueSchema},
+        event::EventSchema,
         language::LanguageSchema,
+        map::MapSchema,
+        organization::OrganizationSchema,
         person::PersonSchema,
+        place::PlaceSchema,
         word::{WordUpsertResponseSchema, WordUpsertSchema},
     },
     types::entity::EntityType,
@@ -15,10 +22,21 @@ use crate::{
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all_fields = "camelCase")]
 pub enum EntryProperties {
+    Entry(GenericEntrySchema),
     Language(LanguageSchema),
+    Calendar(CalendarSchema),
     Person(PersonSchema),
+    Event(EventSchema),
+    Place(PlaceSchema),
+    Map(MapSchema),
+    Organization(OrganizationSchema),
 }
 
+/// Generic entries don't have any properties besides their title and text.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenericEntrySchema {}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EntryCreateSchema {
@@ -26,6 +44,9 @@ pub struct EntryCreateSchema {
     pub entity_type: EntityType,
     pub title: String,
     pub properties: EntryProperties,
+    /// The template that the text, the properties and the custom properties of the entry are taken from.
+    #[serde(default)]
+    pub template_id: Option<Uuid>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -37,6 +58,12 @@ pub struct EntryUpdateSchema {
     pub properties: Option<EntryProperties>,
     pub text: Option<String>,
     pub words: Option<Vec<WordUpsertSchema>>,
+    /// The values of the custom properties to set; the other custom properties are left as they are.
+    #[serde(default)]
+    pub custom_properties: Option<Vec<CustomPropertyValueSchema>>,
+    /// The revision that the update is based on; the update is rejected if the entry has been written since.
+    #[serde(default)]
+    pub expected_revision: Option<i32>,
 }
 
 impl EntryUpdateSchema {
@@ -46,9 +73,23 @@ impl EntryUpdateSchema {
             || self.properties.is_some()
             || self.text.is_some()
             || self.words.is_some()
+            || self.custom_properties.is_some()
     }
 }
 
+/// How much of a bulk update is kept when some of its changes fail.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EntryUpdateMode {
+    /// The changes that succeed are kept, even if other changes to the same entry fail.
+    #[default]
+    Partial,
+    /// Each entry is updated in a transaction, so that it is either fully updated or not at all.
+    Entry,
+    /// The whole batch is updated in a single transaction: if any change fails, then no entry is updated.
+    Batch,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EntryTitleUpdateResponseSchema {
@@ -65,6 +106,7 @@ pub struct EntryUpdateResponseSchema {
     pub properties: UpdateResponseSchema,
     pub text: UpdateResponseSchema,
     pub words: Vec<WordUpsertResponseSchema>,
+    pub custom_properties: UpdateResponseSchema,
 }
 
 impl EntryUpdateResponseSchema {
@@ -85,6 +127,9 @@ impl EntryUpdateResponseSchema {
                 updated: entry.text.is_some(),
             },
             words: Vec::new(),
+            custom_properties: UpdateResponseSchema {
+                updated: entry.custom_properties.is_some(),
+            },
         }
     }
 
@@ -93,6 +138,7 @@ impl EntryUpdateResponseSchema {
         self.title.updated = updated;
         self.properties.updated = updated;
         self.text.updated = updated;
+        self.custom_properties.updated = updated;
     }
 }
 
@@ -103,6 +149,7 @@ pub struct EntryInfoResponseSchema {
     pub folder_id: Uuid,
     pub entity_type: EntityType,
     pub title: String,
+    pub revision: i32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -110,6 +157,7 @@ pub struct EntryInfoResponseSchema {
 pub struct EntryPropertyResponseSchema {
     pub info: EntryInfoResponseSchema,
     pub properties: EntryProperties,
+    pub custom_properties: Vec<CustomPropertyValueResponseSchema>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -123,7 +171,75 @@ pub struct EntryArticleResponseSchema {
 #[serde(rename_all = "camelCase")]
 pub struct EntrySearchSchema {
     pub keyword: String,
+    /// A tag expression such as `faction/north AND NOT deceased`.
+    #[serde(default)]
+    pub tags: Option<String>,
     pub before: Option<String>,
     pub after: Option<String>,
     pub limit: u64,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryTextSearchSchema {
+    pub keyword: String,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryTextSearchResponseSchema {
+    pub info: EntryInfoResponseSchema,
+    pub rank: f64,
+    pub highlighted_title: String,
+    pub snippet: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryBacklinkResponseSchema {
+    pub info: EntryInfoResponseSchema,
+    pub mention_count: i32,
+    pub snippets: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryRevisionInfoResponseSchema {
+    pub id: Uuid,
+    pub entry_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryRevisionResponseSchema {
+    pub info: EntryRevisionInfoResponseSchema,
+    pub properties: Option<EntryProperties>,
+    pub text: TextNode,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryAliasResponseSchema {
+    pub id: Uuid,
+    pub entry_id: Uuid,
+    pub alias: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryMentionSearchSchema {
+    pub keyword: String,
+    pub limit: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryMentionResponseSchema {
+    pub info: EntryInfoResponseSchema,
+    /// The alias that matched the keyword; if it's `None`, then the title matched.
+    pub alias: Option<String>,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    model::text::TextNode,
+    schema::{custom_property::CustomPropertyValueSchema, entry::EntryProperties},
+    types::entity::EntityType,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryTemplateCreateSchema {
+    /// The type of the entries created from the template.
+    pub entity_type: EntityType,
+    pub name: String,
+    pub text: TextNode,
+    /// The properties of new entries; if they're not set, then the properties given on creation are used.
+    #[serde(default)]
+    pub properties: Option<EntryProperties>,
+    #[serde(default)]
+    pub custom_properties: Vec<CustomPropertyValueSchema>,
+}
+
+/// Replaces the contents of the template; its entity type can't be changed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryTemplateUpdateSchema {
+    pub id: Uuid,
+    pub name: String,
+    pub text: TextNode,
+    #[serde(default)]
+    pub properties: Option<EntryProperties>,
+    #[serde(default)]
+    pub custom_properties: Vec<CustomPropertyValueSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryTemplateResponseSchema {
+    pub id: Uuid,
+    pub entity_type: EntityType,
+    pub name: String,
+    pub text: TextNode,
+    pub properties: Option<EntryProperties>,
+    pub custom_properties: Vec<CustomPropertyValueSchema>,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{
+    calendar::{CalendarDateResponseSchema, CalendarDateSchema},
+    entry::EntryInfoResponseSchema,
+};
+use crate::types::calendar::DatePrecision;
+
+/// The end date must belong to the same calendar as the start date.
+/// Dates with a precision of a month or a year are stored as the first day of that period;
+/// end dates are stored as its last day.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventSchema {
+    pub start: CalendarDateSchema,
+    #[serde(default)]
+    pub end: Option<CalendarDateSchema>,
+    #[serde(default)]
+    pub precision: DatePrecision,
+    #[serde(default)]
+    pub participant_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub location_id: Option<Uuid>,
+}
+
+/// Filters of the timeline; events are included if they overlap with the date range.
+/// If `calendar_id` is set, then the dates of all events are converted into that calendar.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TimelineQuerySchema {
+    pub calendar_id: Option<Uuid>,
+    pub start: Option<CalendarDateSchema>,
+    pub end: Option<CalendarDateSchema>,
+    pub folder_id: Option<Uuid>,
+    pub participant_id: Option<Uuid>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEventResponseSchema {
+    pub info: EntryInfoResponseSchema,
+    pub start: CalendarDateResponseSchema,
+    pub end: Option<CalendarDateResponseSchema>,
+    pub precision: DatePrecision,
+    pub participant_ids: Vec<Uuid>,
+    pub location_id: Option<Uuid>,
+}
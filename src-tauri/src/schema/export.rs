@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MentionStyle {
+    /// `[Label](../relative/path/to/Entry.md)`
+    #[default]
+    RelativeLink,
+    /// `[[Entry|Label]]`
+    Wikilink,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownExportSchema {
+    pub folder_path: String,
+    pub mention_style: MentionStyle,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownExportResponseSchema {
+    pub folder_path: String,
+    pub folder_count: usize,
+    pub entry_count: usize,
+}
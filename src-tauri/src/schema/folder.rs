@@ -14,6 +14,9 @@ pub struct FolderUpdateSchema {
     pub id: Uuid,
     pub parent_id: Option<Uuid>,
     pub name: Option<String>,
+    /// The revision that the update is based on; the update is rejected if the folder has been written since.
+    #[serde(default)]
+    pub expected_revision: Option<i32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -56,4 +59,5 @@ pub struct FolderResponseSchema {
     pub id: Uuid,
     pub parent_id: Uuid,
     pub name: String,
+    pub revision: i32,
 }
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownImportSchema {
+    pub folder_path: String,
+    /// ID of the folder that the imported files are placed in.
+    pub folder_id: Uuid,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownImportResponseSchema {
+    pub folder_count: usize,
+    pub entry_count: usize,
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::types::entity::EntityType;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IntegrityIssueType {
+    /// The type code of the entry doesn't match any entity type.
+    UnknownEntityType,
+    /// The text of the entry can't be parsed.
+    InvalidText,
+    /// The text of the entry mentions an entry that doesn't exist.
+    MissingMentionTarget,
+    /// The entry has no row in the property table of its type.
+    MissingProperties,
+    /// The language of the word doesn't exist.
+    OrphanedWord,
+    /// The folder is one of its own ancestors.
+    FolderCycle,
+    /// The folder has the same name as one of its siblings.
+    DuplicateFolderName,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityIssueSchema {
+    pub issue_type: IntegrityIssueType,
+    pub entity_type: EntityType,
+    pub id: Uuid,
+    /// The other entity involved in the issue, e.g., the missing target of a mention.
+    pub related_id: Option<Uuid>,
+    pub msg: String,
+    pub repaired: bool,
+}
+
+impl IntegrityIssueSchema {
+    pub fn new(
+        issue_type: IntegrityIssueType,
+        entity_type: EntityType,
+        id: Uuid,
+        related_id: Option<Uuid>,
+        msg: &str,
+    ) -> Self {
+        Self {
+            issue_type,
+            entity_type,
+            id,
+            related_id,
+            msg: msg.to_owned(),
+            repaired: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReportSchema {
+    pub issues: Vec<IntegrityIssueSchema>,
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A change to the project that can be undone or redone.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalRecordResponseSchema {
+    pub id: Uuid,
+    /// A short description of the change, e.g., to label the undo button with.
+    pub label: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JournalResponseSchema {
+    /// The change that is reverted by the next undo.
+    pub undo: Option<JournalRecordResponseSchema>,
+    /// The change that is made again by the next redo.
+    pub redo: Option<JournalRecordResponseSchema>,
+}
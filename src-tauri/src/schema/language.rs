@@ -1,5 +1,34 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+use crate::types::grammar::{
+    GrammaticalAnimacy, GrammaticalAspect, GrammaticalCase, GrammaticalDefiniteness,
+    GrammaticalGender, GrammaticalMood, GrammaticalNumber, GrammaticalPerson, GrammaticalVoice,
+    VerbForm, VerbTense, WordType,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LanguageSchema {}
+pub struct LanguageSchema {
+    #[serde(default)]
+    pub grammar: GrammarSchema,
+}
+
+/// The word types and grammatical categories that a language uses.
+/// Words and inflections are only validated against the categories that are declared;
+/// an empty list declares that the language doesn't use the category at all.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GrammarSchema {
+    pub word_types: Option<Vec<WordType>>,
+    pub cases: Option<Vec<GrammaticalCase>>,
+    pub voices: Option<Vec<GrammaticalVoice>>,
+    pub moods: Option<Vec<GrammaticalMood>>,
+    pub tenses: Option<Vec<VerbTense>>,
+    pub aspects: Option<Vec<GrammaticalAspect>>,
+    pub definiteness: Option<Vec<GrammaticalDefiniteness>>,
+    pub numbers: Option<Vec<GrammaticalNumber>>,
+    pub persons: Option<Vec<GrammaticalPerson>>,
+    pub genders: Option<Vec<GrammaticalGender>>,
+    pub animacies: Option<Vec<GrammaticalAnimacy>>,
+    pub verb_forms: Option<Vec<VerbForm>>,
+}
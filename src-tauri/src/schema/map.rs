@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::entry::EntryInfoResponseSchema;
+use crate::types::map::PinShape;
+
+/// Map coordinates range from the top-left corner (0, 0) to the bottom-right corner (width, height).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapSchema {
+    pub width: f64,
+    pub height: f64,
+    /// Path of the map image, relative to the project folder.
+    /// The image is set with its own command; this field is ignored when the map is created or updated.
+    #[serde(default)]
+    pub image_path: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapPointSchema {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapPinCreateSchema {
+    pub map_id: Uuid,
+    pub target_id: Uuid,
+    #[serde(default)]
+    pub shape: PinShape,
+    pub points: Vec<MapPointSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapPinMoveSchema {
+    pub id: Uuid,
+    pub points: Vec<MapPointSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapPinResponseSchema {
+    pub id: Uuid,
+    pub map_id: Uuid,
+    pub target_id: Uuid,
+    pub shape: PinShape,
+    pub points: Vec<MapPointSchema>,
+    /// Whether the target entry of the pin has been deleted.
+    pub is_dangling: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapPinsOfEntryResponseSchema {
+    pub map: EntryInfoResponseSchema,
+    pub pins: Vec<MapPinResponseSchema>,
+}
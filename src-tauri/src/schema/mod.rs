@@ -1,9 +1,24 @@
+pub mod asset;
+pub mod backup;
+pub mod calendar;
 pub mod common;
 pub mod config;
+pub mod custom_property;
 pub mod entry;
+pub mod entry_template;
+pub mod event;
+pub mod export;
 pub mod file;
 pub mod folder;
+pub mod import;
+pub mod integrity;
+pub mod journal;
 pub mod language;
+pub mod map;
+pub mod organization;
 pub mod person;
+pub mod place;
 pub mod project;
+pub mod tag;
+pub mod trash;
 pub mod word;
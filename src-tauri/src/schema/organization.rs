@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{calendar::CalendarDateSchema, entry::EntryInfoResponseSchema};
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationSchema {
+    /// The organization that this organization is part of.
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipCreateSchema {
+    pub organization_id: Uuid,
+    pub person_id: Uuid,
+    pub role: String,
+    /// Members with lower ranks are listed first, e.g., rank 1 for the leader.
+    #[serde(default)]
+    pub rank: Option<i32>,
+    #[serde(default)]
+    pub start: Option<CalendarDateSchema>,
+    #[serde(default)]
+    pub end: Option<CalendarDateSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipUpdateSchema {
+    pub id: Uuid,
+    pub role: String,
+    #[serde(default)]
+    pub rank: Option<i32>,
+    #[serde(default)]
+    pub start: Option<CalendarDateSchema>,
+    #[serde(default)]
+    pub end: Option<CalendarDateSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipResponseSchema {
+    pub id: Uuid,
+    pub organization: EntryInfoResponseSchema,
+    pub person: EntryInfoResponseSchema,
+    pub role: String,
+    pub rank: Option<i32>,
+    pub start: Option<CalendarDateSchema>,
+    pub end: Option<CalendarDateSchema>,
+}
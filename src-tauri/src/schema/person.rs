@@ -1,7 +1,95 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::{calendar::CalendarDateSchema, entry::EntryInfoResponseSchema};
+use crate::types::person::PersonRelationshipType;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PersonSchema {
     pub name: String,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonRelationshipCreateSchema {
+    /// For parent relationships, the person is the parent of the relative.
+    pub person_id: Uuid,
+    pub relative_id: Uuid,
+    pub relationship_type: PersonRelationshipType,
+    #[serde(default)]
+    pub is_adoptive: bool,
+    #[serde(default)]
+    pub start: Option<CalendarDateSchema>,
+    #[serde(default)]
+    pub end: Option<CalendarDateSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonRelationshipUpdateSchema {
+    pub id: Uuid,
+    #[serde(default)]
+    pub is_adoptive: bool,
+    #[serde(default)]
+    pub start: Option<CalendarDateSchema>,
+    #[serde(default)]
+    pub end: Option<CalendarDateSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonRelationshipResponseSchema {
+    pub id: Uuid,
+    pub person: EntryInfoResponseSchema,
+    pub relative: EntryInfoResponseSchema,
+    pub relationship_type: PersonRelationshipType,
+    pub is_adoptive: bool,
+    pub start: Option<CalendarDateSchema>,
+    pub end: Option<CalendarDateSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelativeResponseSchema {
+    pub person: EntryInfoResponseSchema,
+    /// The number of generations between the person and the relative, e.g., 2 for grandparents.
+    pub generation: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KinshipResponseSchema {
+    /// What the relative is to the person, e.g., "second cousin once removed".
+    /// Unrelated people have no kinship.
+    pub description: Option<String>,
+    /// The closest ancestors that both people descend from.
+    pub common_ancestors: Vec<EntryInfoResponseSchema>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FamilyTreePersonSchema {
+    pub person: EntryInfoResponseSchema,
+    /// The generation relative to the root of the tree.
+    /// Ancestors have negative generations and descendants positive ones.
+    pub generation: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FamilyTreeRelationshipSchema {
+    pub id: Uuid,
+    pub person_id: Uuid,
+    pub relative_id: Uuid,
+    pub relationship_type: PersonRelationshipType,
+    pub is_adoptive: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FamilyTreeResponseSchema {
+    pub root_id: Uuid,
+    pub people: Vec<FamilyTreePersonSchema>,
+    pub relationships: Vec<FamilyTreeRelationshipSchema>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::types::place::PlaceType;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceSchema {
+    #[serde(default)]
+    pub place_type: PlaceType,
+    /// The place that this place is located in.
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::schema::backup::BackupConfigSchema;
+use crate::types::entity::EntityType;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectResponseSchema {
@@ -12,4 +15,29 @@ pub struct ProjectResponseSchema {
 pub struct ProjectConfigFileSchema {
     #[serde(default)]
     pub name: String,
+    #[serde(default)]
+    pub backup: BackupConfigSchema,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryTypeCountSchema {
+    pub entity_type: EntityType,
+    pub count: u64,
+}
+
+/// Sizes of the project's contents, excluding the contents of the trash unless stated otherwise.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStatsSchema {
+    pub entry_count: u64,
+    pub entry_type_counts: Vec<EntryTypeCountSchema>,
+    pub folder_count: u64,
+    pub word_count: u64,
+    pub tag_count: u64,
+    pub asset_count: u64,
+    /// Number of pairs of entries where the first mentions the second.
+    pub reference_count: u64,
+    pub trashed_entry_count: u64,
+    pub trashed_folder_count: u64,
 }
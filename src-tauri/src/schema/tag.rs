@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCreateSchema {
+    /// The full name of the tag; slashes nest it in other tags, e.g., "faction/north".
+    pub name: String,
+    /// A hex colour such as "#a0c4ff".
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagUpdateSchema {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagResponseSchema {
+    pub id: Uuid,
+    pub name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagAssignmentSchema {
+    pub tag_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub entry_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub folder_ids: Vec<Uuid>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagMergeSchema {
+    /// The tags to merge; they are deleted once their entries and folders are moved to the target.
+    pub source_ids: Vec<Uuid>,
+    pub target_id: Uuid,
+}
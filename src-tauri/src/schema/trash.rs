@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{entry::EntryInfoResponseSchema, folder::FolderResponseSchema};
+
+/// An entry that was deleted on its own, rather than along with its folder.
+/// The folder of the entry is where it will be restored to, if that folder still exists.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedEntryResponseSchema {
+    pub info: EntryInfoResponseSchema,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// A folder that was deleted along with its contents.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedFolderResponseSchema {
+    pub folder: FolderResponseSchema,
+    pub deleted_at: DateTime<Utc>,
+    /// Number of entries that were deleted along with the folder.
+    pub entry_count: usize,
+}
+
+/// Lists the items that were deleted, most recently deleted first.
+/// Items that were deleted along with a folder are only restored with that folder.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashResponseSchema {
+    pub entries: Vec<TrashedEntryResponseSchema>,
+    pub folders: Vec<TrashedFolderResponseSchema>,
+}
+
+/// The items that were put back into the file tree,
+/// with the titles and names that they were given to avoid collisions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashRestoreResponseSchema {
+    pub entries: Vec<EntryInfoResponseSchema>,
+    pub folders: Vec<FolderResponseSchema>,
+}
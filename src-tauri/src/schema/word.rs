@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{schema::common::UpsertResponseSchema, types::grammar::WordType};
+use crate::{
+    model::grammar::Inflection, schema::common::UpsertResponseSchema, types::grammar::WordType,
+};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,6 +14,9 @@ pub struct WordUpsertSchema {
     pub spelling: Option<String>,
     pub definition: Option<String>,
     pub translations: Option<Vec<String>>,
+    /// The revision that the update is based on; the update is rejected if the word has been written since.
+    #[serde(default)]
+    pub expected_revision: Option<i32>,
 }
 
 impl WordUpsertSchema {
@@ -23,6 +28,7 @@ impl WordUpsertSchema {
             spelling: self.spelling.clone().unwrap_or_default(),
             definition: self.definition.clone().unwrap_or_default(),
             translations: self.translations.clone().unwrap_or_default(),
+            revision: 0,
         }
     }
 }
@@ -55,4 +61,31 @@ pub struct WordResponseSchema {
     pub spelling: String,
     pub definition: String,
     pub translations: Vec<String>,
+    pub revision: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InflectedWordCreateSchema {
+    pub word_id: Uuid,
+    pub spelling: String,
+    #[serde(default)]
+    pub inflection: Inflection,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InflectedWordUpdateSchema {
+    pub id: Uuid,
+    pub spelling: Option<String>,
+    pub inflection: Option<Inflection>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InflectedWordResponseSchema {
+    pub id: Uuid,
+    pub word_id: Uuid,
+    pub spelling: String,
+    pub inflection: Inflection,
 }
@@ -0,0 +1,311 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use ::entity::asset::Model as AssetModel;
+
+use crate::constants::ASSET_DIR_NAME;
+use crate::database::{asset_manager, asset_reference_manager, entry_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    text::TextNode,
+};
+use crate::schema::{asset::AssetResponseSchema, entry::EntryInfoResponseSchema};
+use crate::services::entry_service;
+use crate::types::entity::{ASSET, ENTRY};
+
+const DEFAULT_MIME_TYPE: &str = "application/octet-stream";
+
+/// MIME types of the file extensions that the app knows how to display.
+const MIME_TYPES: [(&str, &str); 13] = [
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("svg", "image/svg+xml"),
+    ("bmp", "image/bmp"),
+    ("avif", "image/avif"),
+    ("pdf", "application/pdf"),
+    ("txt", "text/plain"),
+    ("md", "text/markdown"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+];
+
+/// Copies the file into the asset directory of the project.
+/// Files are stored under their content hash, so importing the same file twice
+/// returns the asset that was created the first time.
+pub async fn import(
+    database: &DatabaseConnection,
+    folder_path: &str,
+    source_path: &str,
+) -> Result<AssetResponseSchema, Error> {
+    let source_path = Path::new(source_path);
+    let bytes = fs::read(source_path).map_err(|e| Error::FileSystemOperationFailed {
+        msg: format!("Failed to read file '{}'.", source_path.display()),
+        error: e.to_string(),
+    })?;
+    let hash = format!("{:x}", Sha256::digest(&bytes));
+
+    let existing = asset_manager::get_by_hash(database, &hash)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the asset table while fetching an asset by hash.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if let Some(existing) = existing {
+        return Ok(generate_response(&existing));
+    }
+
+    let name = source_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    if name.is_empty() {
+        return Err(ErrorBuilder::new()
+            .msg("Only files can be imported as assets.")
+            .entity(ASSET)
+            .attribute("sourcePath")
+            .with_value(&source_path.display())
+            .invalid());
+    }
+    let extension = source_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase());
+    let mime_type = extension
+        .as_deref()
+        .and_then(|extension| {
+            MIME_TYPES
+                .iter()
+                .find(|(known_extension, _)| *known_extension == extension)
+        })
+        .map(|(_, mime_type)| *mime_type)
+        .unwrap_or(DEFAULT_MIME_TYPE);
+
+    fs::create_dir_all(Path::new(folder_path).join(ASSET_DIR_NAME)).map_err(|e| {
+        Error::FileSystemOperationFailed {
+            msg: "Failed to create the asset directory.".to_owned(),
+            error: e.to_string(),
+        }
+    })?;
+    let asset_path = match &extension {
+        Some(extension) => format!("{ASSET_DIR_NAME}/{hash}.{extension}"),
+        None => format!("{ASSET_DIR_NAME}/{hash}"),
+    };
+    let full_path = Path::new(folder_path).join(&asset_path);
+    fs::write(&full_path, &bytes).map_err(|e| Error::FileSystemOperationFailed {
+        msg: "Failed to copy an asset into the project folder.".to_owned(),
+        error: e.to_string(),
+    })?;
+
+    let asset = asset_manager::insert(
+        database,
+        name,
+        &hash,
+        mime_type,
+        bytes.len() as i64,
+        &asset_path,
+    )
+    .await;
+    match asset {
+        Ok(asset) => Ok(generate_response(&asset)),
+        Err(e) => {
+            let _ = fs::remove_file(&full_path);
+            Err(ErrorBuilder::new()
+                .msg("Asset not created.")
+                .from_err(e)
+                .entity(ASSET)
+                .not_created())
+        }
+    }
+}
+
+pub async fn get_all(database: &DatabaseConnection) -> Result<Vec<AssetResponseSchema>, Error> {
+    let assets = asset_manager::get_all(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the asset table while fetching assets.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    Ok(assets.iter().map(generate_response).collect())
+}
+
+/// Changes the name that the asset is shown under; the file itself keeps its name.
+pub async fn rename(
+    database: &DatabaseConnection,
+    id: Uuid,
+    name: &str,
+) -> Result<AssetResponseSchema, Error> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ErrorBuilder::new()
+            .msg("Asset names can't be empty.")
+            .entity(ASSET)
+            .attribute("name")
+            .with_id(Some(&id))
+            .with_value(&name)
+            .invalid());
+    }
+
+    let asset = asset_manager::update_name(database, id, name)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Asset not updated.")
+                .from_err(e)
+                .entity(ASSET)
+                .with_id(&id)
+                .not_updated()
+        })?;
+    Ok(generate_response(&asset))
+}
+
+/// Deletes the asset and its file.
+/// Images that show the asset are left in the text of entries, where they are reported as broken.
+pub async fn delete(
+    database: &DatabaseConnection,
+    folder_path: &str,
+    id: Uuid,
+) -> Result<(), Error> {
+    let asset = _get(database, id).await?;
+
+    asset_manager::delete(database, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Asset not deleted.")
+            .from_err(e)
+            .entity(ASSET)
+            .with_id(&id)
+            .not_deleted()
+    })?;
+
+    // the database no longer refers to the file, so a leftover file is harmless
+    let _ = fs::remove_file(Path::new(folder_path).join(asset.path));
+
+    Ok(())
+}
+
+/// Returns the entries whose text shows the asset, sorted by title.
+pub async fn get_referencing_entries(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    // raises an error if the asset doesn't exist
+    _get(database, id).await?;
+
+    let references = asset_reference_manager::get_for_asset(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the asset_reference table while fetching references.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let entry_ids = references.iter().map(|r| r.entry_id).collect();
+    let entries = entry_manager::get_many(database, entry_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while fetching the entries of an asset.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    Ok(entries
+        .iter()
+        .map(entry_service::generate_insert_response)
+        .collect())
+}
+
+/// Replaces the assets referenced by the entry with the images found in its text.
+/// Images of assets that don't exist are ignored.
+pub async fn index<C>(con: &C, entry_id: Uuid, text: &TextNode) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let mut asset_ids: HashSet<Uuid> = HashSet::new();
+    _collect_asset_ids(text, &mut asset_ids);
+
+    let existing_ids = if asset_ids.is_empty() {
+        Vec::new()
+    } else {
+        asset_manager::get_existing_ids(con, asset_ids.into_iter().collect())
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the asset table while indexing asset references.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?
+    };
+
+    asset_reference_manager::replace_for_entry(con, entry_id, existing_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to update the asset references of an entry.")
+                .from_err(e)
+                .entity(ENTRY)
+                .with_id(&entry_id)
+                .not_updated()
+        })
+}
+
+async fn _get<C>(con: &C, id: Uuid) -> Result<AssetModel, Error>
+where
+    C: ConnectionTrait,
+{
+    let asset = asset_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the asset table while fetching an asset by id.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    asset.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Asset not found.")
+            .entity(ASSET)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+fn _collect_asset_ids(node: &TextNode, asset_ids: &mut HashSet<Uuid>) {
+    if node.is_type("image")
+        && let Some(asset_id) = node
+            .get_attr("assetId")
+            .and_then(|value| value.as_str())
+            .and_then(|value| Uuid::parse_str(value).ok())
+    {
+        asset_ids.insert(asset_id);
+    }
+
+    if let Some(content) = &node.content {
+        for child_node in content.iter() {
+            _collect_asset_ids(child_node, asset_ids);
+        }
+    }
+}
+
+fn generate_response(asset: &AssetModel) -> AssetResponseSchema {
+    AssetResponseSchema {
+        id: asset.id,
+        name: asset.name.to_owned(),
+        hash: asset.hash.to_owned(),
+        mime_type: asset.mime_type.to_owned(),
+        size: asset.size,
+        path: asset.path.to_owned(),
+    }
+}
@@ -0,0 +1,464 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::ffi::{CStr, CString, OsStr};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, NaiveDateTime, TimeDelta, Utc};
+use libsqlite3_sys::{
+    SQLITE_DONE, SQLITE_OK, sqlite3, sqlite3_backup_finish, sqlite3_backup_init,
+    sqlite3_backup_step, sqlite3_close, sqlite3_errmsg, sqlite3_open,
+};
+use sea_orm::{Database, DatabaseConnection};
+use tokio::sync::MutexGuard;
+use uuid::Uuid;
+
+use crate::constants::{BACKUP_DIR_NAME, DEFAULT_DB_FILE_NAME};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    project::Project,
+    state::{State, StateData},
+};
+use crate::schema::{
+    backup::{BackupConfigSchema, BackupKind, BackupResponseSchema},
+    common::DiagnosticResponseSchema,
+};
+use crate::types::entity::PROJECT;
+
+const BACKUP_FILE_EXTENSION: &str = "sqlite";
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%3fZ";
+/// How often the open projects are checked for due automatic backups.
+const AUTOMATIC_BACKUP_CHECK_INTERVAL_SECONDS: u64 = 60;
+
+/// Takes a consistent snapshot of the project's database and stores it in the backup directory.
+pub async fn create(
+    state: &MutexGuard<'_, StateData>,
+    id: Uuid,
+) -> Result<BackupResponseSchema, Error> {
+    let project = _get_project(state, id)?;
+    create_for_project(project).await
+}
+
+/// Takes a manual backup of a project that isn't necessarily open in the app, e.g., for tools.
+pub async fn create_for_project(project: &Project) -> Result<BackupResponseSchema, Error> {
+    _create(
+        &project.database.connection,
+        &project.folder_path,
+        BackupKind::Manual,
+        Utc::now(),
+    )
+    .await
+}
+
+/// Returns the backups of the project at the given path, newest first.
+pub fn get_all(folder_path: &str) -> Result<Vec<BackupResponseSchema>, Error> {
+    let backup_dir_path = _get_backup_dir_path(folder_path);
+    if !backup_dir_path.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let contents =
+        fs::read_dir(&backup_dir_path).map_err(|e| Error::FileSystemOperationFailed {
+            msg: "Failed to read the backup directory.".to_owned(),
+            error: e.to_string(),
+        })?;
+
+    let mut backups: Vec<BackupResponseSchema> = contents
+        .filter_map(|dir_entry| dir_entry.ok())
+        .filter_map(|dir_entry| _generate_response(&dir_entry.path()))
+        .collect();
+    backups.sort_by_key(|backup| Reverse(backup.created_at));
+
+    Ok(backups)
+}
+
+/// Replaces the database of the project at the given path with one of its backups.
+/// The project must be closed. A snapshot of the current database is taken into the backup directory first,
+/// and the snapshot is returned if there was a database to take it of.
+pub async fn restore(
+    state: &MutexGuard<'_, StateData>,
+    folder_path: &str,
+    file_name: &str,
+) -> Result<Option<BackupResponseSchema>, Error> {
+    if let Some(id) = state.get_project_id_of_path(folder_path) {
+        return Err(ErrorBuilder::new()
+            .msg("The project must be closed before a backup can be restored.")
+            .entity(PROJECT)
+            .with_id(&id)
+            .not_updated());
+    }
+
+    let backup_path = _get_backup_dir_path(folder_path).join(file_name);
+    // the file name must refer to a backup, not to an arbitrary path
+    let is_file_name = Path::new(file_name).file_name() == Some(OsStr::new(file_name));
+    if !is_file_name || _generate_response(&backup_path).is_none() || !backup_path.is_file() {
+        return Err(Error::FileSystemOperationFailed {
+            msg: "Backup not found.".to_owned(),
+            error: format!("'{}' is not a backup.", backup_path.display()),
+        });
+    }
+
+    let db_path = Path::new(folder_path).join(DEFAULT_DB_FILE_NAME);
+    let safety_copy = if db_path.is_file() {
+        // copying the file alone would miss the changes that are still in its journal files
+        let connection_string = Project::generate_connection_string(folder_path, false);
+        let database = Database::connect(&connection_string).await.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to connect to the DB before restoring a backup.")
+                .from_err(e)
+                .db()
+                .connection_failed()
+        })?;
+        let safety_copy = _create(&database, folder_path, BackupKind::PreRestore, Utc::now()).await;
+        let _ = database.close().await;
+        Some(safety_copy?)
+    } else {
+        None
+    };
+
+    // the backup is copied next to the database first so that the swap itself is atomic
+    let temp_path = _get_temp_path(&db_path);
+    fs::copy(&backup_path, &temp_path).map_err(|e| Error::FileSystemOperationFailed {
+        msg: "Failed to copy the backup into the project directory.".to_owned(),
+        error: e.to_string(),
+    })?;
+    // journal files left behind by the replaced database would corrupt the restored one;
+    // their changes are already part of the snapshot taken above
+    for suffix in ["-wal", "-shm", "-journal"] {
+        let _ = fs::remove_file(format!("{}{suffix}", db_path.display()));
+    }
+    fs::rename(&temp_path, &db_path).map_err(|e| Error::FileSystemOperationFailed {
+        msg: "Failed to replace the database with the backup.".to_owned(),
+        error: e.to_string(),
+    })?;
+
+    Ok(safety_copy)
+}
+
+/// Deletes the automatic backups that aren't retained by the backup config.
+/// Manual backups and copies taken before a restore are never deleted.
+/// Returns the deleted backups.
+pub fn prune(
+    folder_path: &str,
+    config: &BackupConfigSchema,
+) -> Result<Vec<BackupResponseSchema>, Error> {
+    let backups: Vec<BackupResponseSchema> = get_all(folder_path)?
+        .into_iter()
+        .filter(|backup| backup.kind == BackupKind::Automatic)
+        .collect();
+    let kept = _get_retained_backups(&backups, config);
+
+    let mut deleted: Vec<BackupResponseSchema> = Vec::new();
+    for (i, backup) in backups.into_iter().enumerate() {
+        if kept.contains(&i) {
+            continue;
+        }
+        let path = _get_backup_dir_path(folder_path).join(&backup.file_name);
+        fs::remove_file(&path).map_err(|e| Error::FileSystemOperationFailed {
+            msg: format!("Failed to delete backup '{}'.", backup.file_name),
+            error: e.to_string(),
+        })?;
+        deleted.push(backup);
+    }
+
+    Ok(deleted)
+}
+
+/// Takes an automatic backup of each project that is due for one and prunes its old backups.
+/// Returns the backups that were taken.
+pub async fn create_due_backups<'a>(
+    projects: impl IntoIterator<Item = &'a Project>,
+) -> DiagnosticResponseSchema<Vec<BackupResponseSchema>> {
+    let now = Utc::now();
+    let mut backups: Vec<BackupResponseSchema> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
+
+    for project in projects {
+        let config = &project.backup_config;
+        if !config.is_automatic {
+            continue;
+        }
+
+        let result = _create_due_backup(project, now).await;
+        match result {
+            Ok(Some(backup)) => backups.push(backup),
+            Ok(None) => {}
+            Err(e) => errors.push(e),
+        }
+    }
+
+    DiagnosticResponseSchema {
+        data: backups,
+        errors,
+    }
+}
+
+/// Periodically takes the automatic backups of the open projects. Never returns.
+pub async fn run_automatic_backups(state: &State) {
+    let mut interval =
+        tokio::time::interval(Duration::from_secs(AUTOMATIC_BACKUP_CHECK_INTERVAL_SECONDS));
+
+    loop {
+        interval.tick().await;
+
+        // backups can take a while, so they're taken without holding the lock on the state
+        let projects: Vec<Project> = state
+            .lock()
+            .await
+            .projects
+            .values()
+            .filter(|project| project.backup_config.is_automatic)
+            .cloned()
+            .collect();
+        let response = create_due_backups(&projects).await;
+        for e in response.errors.iter() {
+            println!("Failed to take an automatic backup: {e}");
+        }
+    }
+}
+
+async fn _create_due_backup(
+    project: &Project,
+    now: DateTime<Utc>,
+) -> Result<Option<BackupResponseSchema>, Error> {
+    let config = &project.backup_config;
+
+    let latest_backup = get_all(&project.folder_path)?
+        .into_iter()
+        .find(|backup| backup.kind == BackupKind::Automatic);
+    let interval = TimeDelta::minutes(config.interval_minutes as i64);
+    if let Some(backup) = latest_backup
+        && now - backup.created_at < interval
+    {
+        return Ok(None);
+    }
+
+    let backup = _create(
+        &project.database.connection,
+        &project.folder_path,
+        BackupKind::Automatic,
+        now,
+    )
+    .await?;
+    prune(&project.folder_path, config)?;
+
+    Ok(Some(backup))
+}
+
+async fn _create(
+    database: &DatabaseConnection,
+    folder_path: &str,
+    kind: BackupKind,
+    created_at: DateTime<Utc>,
+) -> Result<BackupResponseSchema, Error> {
+    _create_backup_dir(folder_path)?;
+
+    let path = _generate_backup_path(folder_path, kind, created_at);
+    // the snapshot is written under a temporary name so that partial backups are never listed
+    let temp_path = _get_temp_path(&path);
+    _write_snapshot(database, &temp_path).await?;
+    fs::rename(&temp_path, &path).map_err(|e| Error::FileSystemOperationFailed {
+        msg: "Failed to move the backup into place.".to_owned(),
+        error: e.to_string(),
+    })?;
+
+    _generate_response(&path).ok_or_else(|| Error::FileSystemOperationFailed {
+        msg: "Failed to read the backup.".to_owned(),
+        error: format!("'{}' is not a backup.", path.display()),
+    })
+}
+
+/// Copies the database into a new file with SQLite's online backup API,
+/// which produces a consistent snapshot even while the database is in use.
+async fn _write_snapshot(database: &DatabaseConnection, path: &Path) -> Result<(), Error> {
+    let mut connection = database
+        .get_sqlite_connection_pool()
+        .acquire()
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to acquire a database connection for the backup.")
+                .from_err(e)
+                .db()
+                .connection_failed()
+        })?;
+    let mut handle = connection.lock_handle().await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to lock the database connection for the backup.")
+            .from_err(e)
+            .db()
+            .connection_failed()
+    })?;
+
+    let path = CString::new(path.to_string_lossy().as_bytes()).map_err(|e| {
+        Error::FileSystemOperationFailed {
+            msg: "The backup path is invalid.".to_owned(),
+            error: e.to_string(),
+        }
+    })?;
+
+    _copy_database(handle.as_raw_handle(), &path).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to back up the project database.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })
+}
+
+fn _copy_database(source: NonNull<sqlite3>, path: &CStr) -> Result<(), String> {
+    let database_name = c"main";
+    let mut destination: *mut sqlite3 = ptr::null_mut();
+
+    // SAFETY: the source handle stays valid and locked for the duration of the call,
+    // and the destination handle is closed before returning.
+    unsafe {
+        if sqlite3_open(path.as_ptr(), &mut destination) != SQLITE_OK {
+            let error = _get_error_message(destination);
+            sqlite3_close(destination);
+            return Err(error);
+        }
+
+        let backup = sqlite3_backup_init(
+            destination,
+            database_name.as_ptr(),
+            source.as_ptr(),
+            database_name.as_ptr(),
+        );
+        if backup.is_null() {
+            let error = _get_error_message(destination);
+            sqlite3_close(destination);
+            return Err(error);
+        }
+
+        // copying every page in a single step keeps the source locked against writes
+        // until the copy is complete
+        let step_code = sqlite3_backup_step(backup, -1);
+        let finish_code = sqlite3_backup_finish(backup);
+        let result = if step_code == SQLITE_DONE && finish_code == SQLITE_OK {
+            Ok(())
+        } else {
+            Err(_get_error_message(destination))
+        };
+
+        sqlite3_close(destination);
+        result
+    }
+}
+
+/// # Safety
+/// The handle must be null or a valid SQLite connection.
+unsafe fn _get_error_message(handle: *mut sqlite3) -> String {
+    // SAFETY: SQLite returns a valid string even for null handles
+    unsafe { CStr::from_ptr(sqlite3_errmsg(handle)) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns the indices of the backups that are kept.
+/// The backups must be sorted from newest to oldest.
+/// The daily and weekly retention count the days and weeks that have backups,
+/// keeping the newest backup of each.
+fn _get_retained_backups(
+    backups: &[BackupResponseSchema],
+    config: &BackupConfigSchema,
+) -> HashSet<usize> {
+    let mut kept: HashSet<usize> = (0..backups.len().min(config.keep_last)).collect();
+
+    let mut days = HashSet::new();
+    let mut weeks = HashSet::new();
+    for (i, backup) in backups.iter().enumerate() {
+        let day = backup.created_at.date_naive();
+        if days.len() < config.keep_daily && days.insert(day) {
+            kept.insert(i);
+        }
+
+        let week = backup.created_at.iso_week();
+        if weeks.len() < config.keep_weekly && weeks.insert((week.year(), week.week())) {
+            kept.insert(i);
+        }
+    }
+
+    kept
+}
+
+fn _get_project<'a>(state: &'a MutexGuard<'_, StateData>, id: Uuid) -> Result<&'a Project, Error> {
+    state.get_project(id).ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Project not found.")
+            .entity(PROJECT)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+fn _create_backup_dir(folder_path: &str) -> Result<(), Error> {
+    fs::create_dir_all(_get_backup_dir_path(folder_path)).map_err(|e| {
+        Error::FileSystemOperationFailed {
+            msg: "Failed to create the backup directory.".to_owned(),
+            error: e.to_string(),
+        }
+    })
+}
+
+fn _get_backup_dir_path(folder_path: &str) -> PathBuf {
+    Path::new(folder_path).join(BACKUP_DIR_NAME)
+}
+
+fn _get_temp_path(path: &Path) -> PathBuf {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".partial");
+    PathBuf::from(temp_path)
+}
+
+fn _generate_backup_path(
+    folder_path: &str,
+    kind: BackupKind,
+    created_at: DateTime<Utc>,
+) -> PathBuf {
+    let file_name = format!(
+        "{}-{}.{BACKUP_FILE_EXTENSION}",
+        _get_file_name_prefix(kind),
+        created_at.format(BACKUP_TIMESTAMP_FORMAT)
+    );
+    _get_backup_dir_path(folder_path).join(file_name)
+}
+
+fn _get_file_name_prefix(kind: BackupKind) -> &'static str {
+    match kind {
+        BackupKind::Manual => "manual",
+        BackupKind::Automatic => "auto",
+        BackupKind::PreRestore => "pre-restore",
+    }
+}
+
+/// Reads the kind and the creation time of a backup from its file name.
+/// Returns `None` if the file isn't a backup.
+fn _generate_response(path: &Path) -> Option<BackupResponseSchema> {
+    let file_name = path.file_name()?.to_str()?;
+    let stem = file_name.strip_suffix(&format!(".{BACKUP_FILE_EXTENSION}"))?;
+    let (prefix, timestamp) = stem.rsplit_once('-')?;
+
+    let kind = [
+        BackupKind::Manual,
+        BackupKind::Automatic,
+        BackupKind::PreRestore,
+    ]
+    .into_iter()
+    .find(|kind| _get_file_name_prefix(*kind) == prefix)?;
+    let created_at = NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT)
+        .ok()?
+        .and_utc();
+    let size = fs::metadata(path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+
+    Some(BackupResponseSchema {
+        file_name: file_name.to_owned(),
+        kind,
+        created_at,
+        size,
+    })
+}
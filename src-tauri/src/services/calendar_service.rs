@@ -0,0 +1,485 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+use ::entity::calendar::Model as CalendarModel;
+
+use crate::database::{
+    calendar_manager, entry_date_manager, event_manager, membership_manager,
+    person_relationship_manager,
+};
+use crate::model::{
+    calendar::{Calendar, DatePeriod, FictionalDate},
+    errors::{Error, ErrorBuilder},
+};
+use crate::schema::calendar::{CalendarDateResponseSchema, CalendarDateSchema, CalendarSchema};
+use crate::types::entity::{CALENDAR, EntityType};
+
+pub async fn create<C>(con: &C, entry_id: Uuid, properties: &CalendarSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    _build_calendar(properties.clone(), None)?;
+
+    let (months, weekdays, leap_rules, eras) = _serialize_definition(properties)?;
+    calendar_manager::insert(
+        con,
+        entry_id,
+        months,
+        weekdays,
+        leap_rules,
+        eras,
+        properties.epoch_offset,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Calendar not created.")
+            .from_err(e)
+            .entity(CALENDAR)
+            .not_created()
+    })?;
+    Ok(())
+}
+
+/// Updates the definition of the calendar.
+/// The dates and events stored in the calendar keep their year, month and day, so their day numbers are recomputed;
+/// the update is rejected if any of the dates doesn't exist in the new definition.
+pub async fn update<C>(con: &C, id: Uuid, properties: &CalendarSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let calendar = _build_calendar(properties.clone(), Some(id))?;
+
+    let dates = entry_date_manager::get_all_for_calendar(con, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_date table while fetching the dates of a calendar.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut day_numbers: Vec<(Uuid, i64)> = Vec::new();
+    for date in dates.iter() {
+        let fictional_date = FictionalDate::new(date.year, date.month as u32, date.day as u32);
+        let day_number = calendar.to_day_number(&fictional_date).map_err(|e| {
+            ErrorBuilder::new()
+                .msg("The calendar would no longer contain one of its dates.")
+                .from_err(e)
+                .entity(CALENDAR)
+                .attribute("months")
+                .with_id(Some(&id))
+                .with_value(&date.id)
+                .invalid()
+        })?;
+        if day_number != date.day_number {
+            day_numbers.push((date.id, day_number));
+        }
+    }
+
+    let events = event_manager::get_all_for_calendar(con, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the event table while fetching the events of a calendar.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut event_day_numbers: Vec<(Uuid, i64, Option<i64>)> = Vec::new();
+    for event in events.iter() {
+        let to_day_number = |date: FictionalDate| {
+            calendar.to_day_number(&date).map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("The calendar would no longer contain the date of one of its events.")
+                    .from_err(e)
+                    .entity(CALENDAR)
+                    .attribute("months")
+                    .with_id(Some(&id))
+                    .with_value(&event.entry_id)
+                    .invalid()
+            })
+        };
+        let start_day_number = to_day_number(FictionalDate::new(
+            event.start_year,
+            event.start_month as u32,
+            event.start_day as u32,
+        ))?;
+        let end_day_number = match (event.end_year, event.end_month, event.end_day) {
+            (Some(year), Some(month), Some(day)) => Some(to_day_number(FictionalDate::new(
+                year,
+                month as u32,
+                day as u32,
+            ))?),
+            _ => None,
+        };
+        if start_day_number != event.start_day_number || end_day_number != event.end_day_number {
+            event_day_numbers.push((event.id, start_day_number, end_day_number));
+        }
+    }
+
+    // memberships and family relationships don't store day numbers, but their dates must remain valid
+    let memberships = membership_manager::get_all_for_calendar(con, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the membership table while fetching the memberships of a calendar.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    for membership in memberships.iter() {
+        let dates = [
+            (
+                membership.start_year,
+                membership.start_month,
+                membership.start_day,
+            ),
+            (
+                membership.end_year,
+                membership.end_month,
+                membership.end_day,
+            ),
+        ];
+        _validate_stored_dates(&calendar, id, membership.id, dates)?;
+    }
+
+    let relationships = person_relationship_manager::get_all_for_calendar(con, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the person_relationship table while fetching the relationships of a calendar.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    for relationship in relationships.iter() {
+        let dates = [
+            (
+                relationship.start_year,
+                relationship.start_month,
+                relationship.start_day,
+            ),
+            (
+                relationship.end_year,
+                relationship.end_month,
+                relationship.end_day,
+            ),
+        ];
+        _validate_stored_dates(&calendar, id, relationship.id, dates)?;
+    }
+
+    let (months, weekdays, leap_rules, eras) = _serialize_definition(properties)?;
+    calendar_manager::update(
+        con,
+        id,
+        months,
+        weekdays,
+        leap_rules,
+        eras,
+        properties.epoch_offset,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Calendar not updated.")
+            .from_err(e)
+            .entity(CALENDAR)
+            .with_id(&id)
+            .not_updated()
+    })?;
+
+    entry_date_manager::update_day_numbers(con, day_numbers)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to update the day numbers of the calendar's dates.")
+                .from_err(e)
+                .entity(CALENDAR)
+                .with_id(&id)
+                .not_updated()
+        })?;
+
+    event_manager::update_day_numbers(con, event_day_numbers)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to update the day numbers of the calendar's events.")
+                .from_err(e)
+                .entity(CALENDAR)
+                .with_id(&id)
+                .not_updated()
+        })
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<CalendarSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    let calendar = _get(con, id).await?;
+    generate_response(calendar)
+}
+
+/// Loads the calendar of the entry with the given ID.
+pub async fn get_calendar<C>(con: &C, id: Uuid) -> Result<Calendar, Error>
+where
+    C: ConnectionTrait,
+{
+    let calendar = _get(con, id).await?;
+    _build_calendar(generate_response(calendar)?, Some(id))
+}
+
+/// Checks that the optional start and end dates of a period exist in the same calendar,
+/// and that the period doesn't end before it starts.
+pub async fn get_period<C>(
+    con: &C,
+    entity_type: EntityType,
+    id: Option<Uuid>,
+    start: &Option<CalendarDateSchema>,
+    end: &Option<CalendarDateSchema>,
+) -> Result<DatePeriod, Error>
+where
+    C: ConnectionTrait,
+{
+    let Some(calendar_id) = start.or(*end).map(|date| date.calendar_id) else {
+        return Ok((None, None, None));
+    };
+    if let (Some(start), Some(end)) = (start, end)
+        && start.calendar_id != end.calendar_id
+    {
+        return Err(ErrorBuilder::new()
+            .msg("The start and end dates must belong to the same calendar.")
+            .entity(entity_type)
+            .attribute("end")
+            .with_id(id.as_ref())
+            .with_value(&end.calendar_id)
+            .invalid());
+    }
+
+    let calendar = get_calendar(con, calendar_id).await?;
+    let start_day_number = start
+        .map(|date| get_day_number(&calendar, &date))
+        .transpose()?;
+    let end_day_number = end
+        .map(|date| get_day_number(&calendar, &date))
+        .transpose()?;
+    if let (Some(start_day_number), Some(end_day_number)) = (start_day_number, end_day_number)
+        && end_day_number < start_day_number
+    {
+        return Err(ErrorBuilder::new()
+            .msg("A period can't end before it starts.")
+            .entity(entity_type)
+            .attribute("end")
+            .with_id(id.as_ref())
+            .with_value(&end_day_number)
+            .invalid());
+    }
+
+    let to_fictional_date =
+        |date: CalendarDateSchema| FictionalDate::new(date.year, date.month, date.day);
+    Ok((
+        Some(calendar_id),
+        start.map(to_fictional_date),
+        end.map(to_fictional_date),
+    ))
+}
+
+/// Returns the day number, weekday and formatted form of a date.
+pub async fn describe_date(
+    database: &DatabaseConnection,
+    date: CalendarDateSchema,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let calendar = get_calendar(database, date.calendar_id).await?;
+    let fictional_date = FictionalDate::new(date.year, date.month, date.day);
+    generate_date_response(date.calendar_id, &calendar, fictional_date)
+}
+
+/// Parses a date such as "3rd of Frostmoon, 412 AE".
+pub async fn parse_date(
+    database: &DatabaseConnection,
+    calendar_id: Uuid,
+    text: &str,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let calendar = get_calendar(database, calendar_id).await?;
+    let date = calendar.parse(text).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to parse the date.")
+            .from_err(e)
+            .entity(CALENDAR)
+            .attribute("date")
+            .with_id(Some(&calendar_id))
+            .with_value(&text)
+            .invalid()
+    })?;
+    generate_date_response(calendar_id, &calendar, date)
+}
+
+/// Converts a date into the calendar with the given ID.
+pub async fn convert_date(
+    database: &DatabaseConnection,
+    date: CalendarDateSchema,
+    calendar_id: Uuid,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let source_calendar = get_calendar(database, date.calendar_id).await?;
+    let day_number = get_day_number(&source_calendar, &date)?;
+
+    let target_calendar = get_calendar(database, calendar_id).await?;
+    let converted_date = target_calendar.date_from_day_number(day_number);
+    generate_date_response(calendar_id, &target_calendar, converted_date)
+}
+
+pub fn get_day_number(calendar: &Calendar, date: &CalendarDateSchema) -> Result<i64, Error> {
+    let fictional_date = FictionalDate::new(date.year, date.month, date.day);
+    calendar
+        .to_day_number(&fictional_date)
+        .map_err(|e| _generate_invalid_date_error(date.calendar_id, &fictional_date, e))
+}
+
+pub fn generate_date_response(
+    calendar_id: Uuid,
+    calendar: &Calendar,
+    date: FictionalDate,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let day_number = calendar
+        .to_day_number(&date)
+        .map_err(|e| _generate_invalid_date_error(calendar_id, &date, e))?;
+    let formatted = calendar
+        .format(&date)
+        .map_err(|e| _generate_invalid_date_error(calendar_id, &date, e))?;
+
+    Ok(CalendarDateResponseSchema {
+        calendar_id,
+        year: date.year,
+        month: date.month,
+        day: date.day,
+        day_number,
+        weekday: calendar.weekday(day_number).map(str::to_owned),
+        formatted,
+    })
+}
+
+async fn _get<C>(con: &C, id: Uuid) -> Result<CalendarModel, Error>
+where
+    C: ConnectionTrait,
+{
+    let calendar = calendar_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the calendar table while fetching a calendar by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    match calendar {
+        Some(calendar) => Ok(calendar),
+        None => Err(ErrorBuilder::new()
+            .msg("Calendar not found.")
+            .entity(CALENDAR)
+            .with_id(&id)
+            .not_found()),
+    }
+}
+
+fn _build_calendar(definition: CalendarSchema, id: Option<Uuid>) -> Result<Calendar, Error> {
+    Calendar::new(definition).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Invalid calendar definition.")
+            .from_err(e)
+            .entity(CALENDAR)
+            .attribute("properties")
+            .with_id(id.as_ref())
+            .invalid()
+    })
+}
+
+type SerializedDefinition = (
+    serde_json::Value,
+    serde_json::Value,
+    serde_json::Value,
+    serde_json::Value,
+);
+
+/// Checks that the calendar still contains the stored dates of a period.
+/// Periods don't store day numbers, so only the dates that are set need to exist.
+fn _validate_stored_dates(
+    calendar: &Calendar,
+    id: Uuid,
+    owner_id: Uuid,
+    dates: [(Option<i64>, Option<i32>, Option<i32>); 2],
+) -> Result<(), Error> {
+    for (year, month, day) in dates.into_iter() {
+        let (Some(year), Some(month), Some(day)) = (year, month, day) else {
+            continue;
+        };
+        let date = FictionalDate::new(year, month as u32, day as u32);
+        calendar.to_day_number(&date).map_err(|e| {
+            ErrorBuilder::new()
+                .msg("The calendar would no longer contain one of the dates that use it.")
+                .from_err(e)
+                .entity(CALENDAR)
+                .attribute("months")
+                .with_id(Some(&id))
+                .with_value(&owner_id)
+                .invalid()
+        })?;
+    }
+    Ok(())
+}
+
+fn _serialize_definition(properties: &CalendarSchema) -> Result<SerializedDefinition, Error> {
+    let serialize = |value: serde_json::Result<serde_json::Value>| {
+        value.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to serialize the calendar definition.")
+                .from_err(e)
+                .entity(CALENDAR)
+                .not_created()
+        })
+    };
+    Ok((
+        serialize(serde_json::to_value(&properties.months))?,
+        serialize(serde_json::to_value(&properties.weekdays))?,
+        serialize(serde_json::to_value(&properties.leap_rules))?,
+        serialize(serde_json::to_value(&properties.eras))?,
+    ))
+}
+
+fn _deserialize_column<T: DeserializeOwned>(
+    calendar: &CalendarModel,
+    key: &str,
+    value: &serde_json::Value,
+) -> Result<T, Error> {
+    serde_json::from_value(value.clone()).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to deserialize the calendar definition.")
+            .from_err(e)
+            .entity(CALENDAR)
+            .attribute(key)
+            .with_id(Some(&calendar.entry_id))
+            .with_value(value)
+            .invalid()
+    })
+}
+
+fn _generate_invalid_date_error(calendar_id: Uuid, date: &FictionalDate, error: String) -> Error {
+    ErrorBuilder::new()
+        .msg("The date doesn't exist in the calendar.")
+        .from_err(error)
+        .entity(CALENDAR)
+        .attribute("date")
+        .with_id(Some(&calendar_id))
+        .with_value(&format!("{}-{}-{}", date.year, date.month, date.day))
+        .invalid()
+}
+
+fn generate_response(calendar: CalendarModel) -> Result<CalendarSchema, Error> {
+    Ok(CalendarSchema {
+        months: _deserialize_column(&calendar, "months", &calendar.months)?,
+        weekdays: _deserialize_column(&calendar, "weekdays", &calendar.weekdays)?,
+        leap_rules: _deserialize_column(&calendar, "leapRules", &calendar.leap_rules)?,
+        eras: _deserialize_column(&calendar, "eras", &calendar.eras)?,
+        epoch_offset: calendar.epoch_offset,
+    })
+}
@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+use uuid::Uuid;
+
+use ::entity::custom_property::Model as CustomPropertyModel;
+
+use crate::database::{
+    custom_property_manager, custom_property_value_manager, entry_manager, transaction_manager,
+};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::{
+    calendar::CalendarDateSchema,
+    custom_property::{
+        CustomPropertyCreateSchema, CustomPropertyResponseSchema, CustomPropertyUpdateSchema,
+        CustomPropertyValueResponseSchema, CustomPropertyValueSchema,
+    },
+};
+use crate::services::calendar_service;
+use crate::types::{
+    custom_property::CustomPropertyType,
+    entity::{CUSTOM_PROPERTY, ENTRY, ENTRY_TYPES, EntityType},
+};
+use crate::utils::CodedEnum;
+
+pub async fn create(
+    database: &DatabaseConnection,
+    property: CustomPropertyCreateSchema,
+) -> Result<CustomPropertyResponseSchema, Error> {
+    if !ENTRY_TYPES.contains(&property.entity_type) {
+        return Err(ErrorBuilder::new()
+            .msg("Only entries can have custom properties.")
+            .entity(CUSTOM_PROPERTY)
+            .attribute("entityType")
+            .with_value(&property.entity_type)
+            .invalid());
+    }
+    let name = _normalize_name(None, &property.name)?;
+    _validate_item_type(property.property_type, property.item_type)?;
+    let has_options = _has_options(property.property_type, property.item_type);
+    let options = _normalize_options(None, has_options, &property.options)?;
+    _validate_name_is_free(database, property.entity_type, None, &name).await?;
+
+    let property = custom_property_manager::insert(
+        database,
+        property.entity_type,
+        &name,
+        property.property_type,
+        property.item_type,
+        serde_json::Value::from(options),
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Custom property not created.")
+            .from_err(e)
+            .entity(CUSTOM_PROPERTY)
+            .not_created()
+    })?;
+    Ok(generate_response(&property))
+}
+
+/// Renames the property and replaces its options.
+/// Options can only be removed once no entry uses them.
+pub async fn update(
+    database: &DatabaseConnection,
+    property: CustomPropertyUpdateSchema,
+) -> Result<CustomPropertyResponseSchema, Error> {
+    let id = property.id;
+    let existing = _get_property(database, id).await?;
+    let entity_type = EntityType::from(existing.entity_type);
+    let name = _normalize_name(Some(id), &property.name)?;
+    if name != existing.name {
+        _validate_name_is_free(database, entity_type, Some(id), &name).await?;
+    }
+
+    let has_options = _has_options(
+        existing.property_type.into(),
+        existing.item_type.map(CustomPropertyType::from),
+    );
+    let options = _normalize_options(Some(id), has_options, &property.options)?;
+    let removed_options: Vec<String> = _get_options(&existing)
+        .into_iter()
+        .filter(|option| !options.contains(option))
+        .collect();
+    if !removed_options.is_empty() {
+        _validate_options_are_unused(database, id, &removed_options).await?;
+    }
+
+    let property =
+        custom_property_manager::update(database, id, &name, serde_json::Value::from(options))
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Custom property not updated.")
+                    .from_err(e)
+                    .entity(CUSTOM_PROPERTY)
+                    .with_id(&id)
+                    .not_updated()
+            })?;
+    Ok(generate_response(&property))
+}
+
+/// Deletes the property along with its values.
+pub async fn delete(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    custom_property_manager::delete(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Custom property not deleted.")
+                .from_err(e)
+                .entity(CUSTOM_PROPERTY)
+                .with_id(&id)
+                .not_deleted()
+        })?;
+    Ok(())
+}
+
+/// Returns the properties of the entries of the given type, in the order in which they were created.
+pub async fn get_all(
+    database: &DatabaseConnection,
+    entity_type: EntityType,
+) -> Result<Vec<CustomPropertyResponseSchema>, Error> {
+    let properties = _get_all_for_type(database, entity_type).await?;
+    Ok(properties.iter().map(generate_response).collect())
+}
+
+/// Returns the values of all of the properties of the entry's type, including those it doesn't have a value for.
+pub async fn get_values<C>(
+    con: &C,
+    entry_id: Uuid,
+    entity_type: EntityType,
+) -> Result<Vec<CustomPropertyValueResponseSchema>, Error>
+where
+    C: ConnectionTrait,
+{
+    let properties = _get_all_for_type(con, entity_type).await?;
+    if properties.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let values = custom_property_value_manager::get_all_for_entry(con, entry_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the custom_property_value table while fetching the values of an entry.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let mut values: HashMap<Uuid, serde_json::Value> = values
+        .into_iter()
+        .map(|value| (value.property_id, value.value))
+        .collect();
+
+    Ok(properties
+        .into_iter()
+        .map(|property| CustomPropertyValueResponseSchema {
+            property_id: property.id,
+            value: values
+                .remove(&property.id)
+                .unwrap_or(serde_json::Value::Null),
+            name: property.name,
+            property_type: property.property_type.into(),
+        })
+        .collect())
+}
+
+/// Sets the values of the custom properties of the entry; `null` values are cleared.
+/// Either all of the values are set, or none of them is.
+pub async fn set_values<C>(
+    con: &C,
+    entry_id: Uuid,
+    values: Vec<CustomPropertyValueSchema>,
+) -> Result<(), Error>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let entry = entry_manager::get_info(con, entry_id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while fetching an entry by id.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let Some(entry) = entry else {
+        return Err(ErrorBuilder::new()
+            .msg("Entry not found.")
+            .entity(ENTRY)
+            .with_id(&entry_id)
+            .not_found());
+    };
+
+    let txn = transaction_manager::begin(con).await?;
+    _set_values(&txn, entry_id, entry.entity_type.into(), values).await?;
+    transaction_manager::end(txn).await
+}
+
+/// Validates and sets the values of the custom properties of an entry of the given type.
+pub async fn _set_values<C>(
+    con: &C,
+    entry_id: Uuid,
+    entity_type: EntityType,
+    values: Vec<CustomPropertyValueSchema>,
+) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    validate_values(con, entity_type, &values).await?;
+
+    for value in values.into_iter() {
+        let property_id = value.property_id;
+        let result = if value.value.is_null() {
+            custom_property_value_manager::delete(con, entry_id, property_id)
+                .await
+                .map(|_| ())
+        } else {
+            custom_property_value_manager::upsert(con, entry_id, property_id, value.value).await
+        };
+        result.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to set the value of a custom property.")
+                .from_err(e)
+                .entity(CUSTOM_PROPERTY)
+                .with_id(&property_id)
+                .not_updated()
+        })?;
+    }
+    Ok(())
+}
+
+/// Checks that the properties of the values exist and apply to entries of the given type,
+/// and that the values that aren't `null` are valid for their property.
+pub async fn validate_values<C>(
+    con: &C,
+    entity_type: EntityType,
+    values: &[CustomPropertyValueSchema],
+) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let property_ids = values.iter().map(|value| value.property_id).collect();
+    let properties = custom_property_manager::get_many(con, property_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the custom_property table while fetching properties.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    for value in values.iter() {
+        let Some(property) = properties
+            .iter()
+            .find(|property| property.id == value.property_id)
+        else {
+            return Err(ErrorBuilder::new()
+                .msg("Custom property not found.")
+                .entity(CUSTOM_PROPERTY)
+                .with_id(&value.property_id)
+                .not_found());
+        };
+        if property.entity_type != entity_type.code() {
+            return Err(ErrorBuilder::new()
+                .msg("The custom property belongs to another type of entry.")
+                .entity(CUSTOM_PROPERTY)
+                .attribute("entityType")
+                .with_id(Some(&property.id))
+                .with_value(&entity_type)
+                .invalid());
+        }
+        if !value.value.is_null() {
+            _validate_value(con, property, &value.value).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn _get_property(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<CustomPropertyModel, Error> {
+    let property = custom_property_manager::get(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the custom_property table while fetching a property by id.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    property.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Custom property not found.")
+            .entity(CUSTOM_PROPERTY)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+async fn _get_all_for_type<C>(
+    con: &C,
+    entity_type: EntityType,
+) -> Result<Vec<CustomPropertyModel>, Error>
+where
+    C: ConnectionTrait,
+{
+    custom_property_manager::get_all_for_type(con, entity_type)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the custom_property table while fetching the properties of an entry type.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })
+}
+
+async fn _validate_value<C>(
+    con: &C,
+    property: &CustomPropertyModel,
+    value: &serde_json::Value,
+) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let options = _get_options(property);
+    match CustomPropertyType::from(property.property_type) {
+        CustomPropertyType::List => {
+            let Some(items) = value.as_array() else {
+                return Err(_generate_invalid_value_error(
+                    property,
+                    value,
+                    "Expected a list.",
+                ));
+            };
+            let item_type = property
+                .item_type
+                .map(CustomPropertyType::from)
+                .unwrap_or(CustomPropertyType::Text);
+            for item in items.iter() {
+                _validate_item(con, property, item_type, &options, item).await?;
+            }
+            Ok(())
+        }
+        property_type => _validate_item(con, property, property_type, &options, value).await,
+    }
+}
+
+async fn _validate_item<C>(
+    con: &C,
+    property: &CustomPropertyModel,
+    item_type: CustomPropertyType,
+    options: &[String],
+    value: &serde_json::Value,
+) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let reason = match item_type {
+        CustomPropertyType::Text if !value.is_string() => Some("Expected text."),
+        CustomPropertyType::Number if !value.is_number() => Some("Expected a number."),
+        CustomPropertyType::Boolean if !value.is_boolean() => Some("Expected true or false."),
+        CustomPropertyType::Enum
+            if !value
+                .as_str()
+                .is_some_and(|value| options.iter().any(|option| option == value)) =>
+        {
+            Some("Expected one of the options of the property.")
+        }
+        CustomPropertyType::List => Some("Lists can't be nested."),
+        CustomPropertyType::Date => {
+            match serde_json::from_value::<CalendarDateSchema>(value.clone()) {
+                Ok(date) => {
+                    let calendar = calendar_service::get_calendar(con, date.calendar_id).await?;
+                    calendar_service::get_day_number(&calendar, &date)?;
+                    None
+                }
+                Err(_) => Some("Expected a date."),
+            }
+        }
+        CustomPropertyType::Entry => match serde_json::from_value::<Uuid>(value.clone()) {
+            Ok(id) => {
+                let entry = entry_manager::get_info(con, id).await.map_err(|e| {
+                    ErrorBuilder::new()
+                        .msg("Failed to query the entry table while fetching an entry by id.")
+                        .from_err(e)
+                        .db()
+                        .query_failed()
+                })?;
+                if entry.is_none() {
+                    return Err(ErrorBuilder::new()
+                        .msg("The entry referenced by a custom property doesn't exist.")
+                        .entity(ENTRY)
+                        .with_id(&id)
+                        .not_found());
+                }
+                None
+            }
+            Err(_) => Some("Expected the ID of an entry."),
+        },
+        _ => None,
+    };
+    match reason {
+        Some(reason) => Err(_generate_invalid_value_error(property, value, reason)),
+        None => Ok(()),
+    }
+}
+
+async fn _validate_options_are_unused(
+    database: &DatabaseConnection,
+    id: Uuid,
+    removed_options: &[String],
+) -> Result<(), Error> {
+    let values = custom_property_value_manager::get_all_for_property(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the custom_property_value table while fetching the values of a property.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let is_used = |option: &String| {
+        values.iter().any(|value| match &value.value {
+            serde_json::Value::Array(items) => items.iter().any(|item| item == option.as_str()),
+            value => value == option.as_str(),
+        })
+    };
+    match removed_options.iter().find(|option| is_used(option)) {
+        Some(option) => Err(ErrorBuilder::new()
+            .msg("An option can't be removed while entries use it.")
+            .entity(CUSTOM_PROPERTY)
+            .attribute("options")
+            .with_id(Some(&id))
+            .with_value(option)
+            .invalid()),
+        None => Ok(()),
+    }
+}
+
+async fn _validate_name_is_free(
+    database: &DatabaseConnection,
+    entity_type: EntityType,
+    id: Option<Uuid>,
+    name: &str,
+) -> Result<(), Error> {
+    let existing = custom_property_manager::get_by_name(database, entity_type, name)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the custom_property table while checking whether a name is taken.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if existing.is_some_and(|existing| Some(existing.id) != id) {
+        return Err(ErrorBuilder::new()
+            .msg("Custom property names must be unique for each type of entry.")
+            .entity(CUSTOM_PROPERTY)
+            .attribute("name")
+            .with_id(id.as_ref())
+            .with_value(&name)
+            .not_unique());
+    }
+    Ok(())
+}
+
+fn _validate_item_type(
+    property_type: CustomPropertyType,
+    item_type: Option<CustomPropertyType>,
+) -> Result<(), Error> {
+    let is_valid = match property_type {
+        CustomPropertyType::List => {
+            item_type.is_some_and(|item_type| item_type != CustomPropertyType::List)
+        }
+        _ => item_type.is_none(),
+    };
+    if !is_valid {
+        return Err(ErrorBuilder::new()
+            .msg("List properties need an item type that isn't a list; other properties can't have one.")
+            .entity(CUSTOM_PROPERTY)
+            .attribute("itemType")
+            .with_value(&format!("{:?}", item_type))
+            .invalid());
+    }
+    Ok(())
+}
+
+fn _has_options(property_type: CustomPropertyType, item_type: Option<CustomPropertyType>) -> bool {
+    property_type == CustomPropertyType::Enum || item_type == Some(CustomPropertyType::Enum)
+}
+
+fn _normalize_name(id: Option<Uuid>, name: &str) -> Result<String, Error> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ErrorBuilder::new()
+            .msg("Custom property names can't be empty.")
+            .entity(CUSTOM_PROPERTY)
+            .attribute("name")
+            .with_id(id.as_ref())
+            .with_value(&name)
+            .invalid());
+    }
+    Ok(name.to_owned())
+}
+
+/// Trims the options; enums need at least one option and can't repeat any, while other types have none.
+fn _normalize_options(
+    id: Option<Uuid>,
+    has_options: bool,
+    options: &[String],
+) -> Result<Vec<String>, Error> {
+    let options: Vec<String> = options
+        .iter()
+        .map(|option| option.trim().to_owned())
+        .collect();
+    let is_valid = if has_options {
+        !options.is_empty()
+            && options
+                .iter()
+                .enumerate()
+                .all(|(index, option)| !option.is_empty() && !options[..index].contains(option))
+    } else {
+        options.is_empty()
+    };
+    if !is_valid {
+        return Err(ErrorBuilder::new()
+            .msg("Enum properties need distinct, non-empty options; other properties can't have any.")
+            .entity(CUSTOM_PROPERTY)
+            .attribute("options")
+            .with_id(id.as_ref())
+            .with_value(&options.join(", "))
+            .invalid());
+    }
+    Ok(options)
+}
+
+fn _get_options(property: &CustomPropertyModel) -> Vec<String> {
+    match property.options.as_array() {
+        Some(options) => options
+            .iter()
+            .filter_map(|option| option.as_str().map(str::to_owned))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+fn _generate_invalid_value_error(
+    property: &CustomPropertyModel,
+    value: &serde_json::Value,
+    reason: &str,
+) -> Error {
+    ErrorBuilder::new()
+        .msg("Invalid value for a custom property.")
+        .from_err(reason)
+        .entity(CUSTOM_PROPERTY)
+        .attribute("value")
+        .with_id(Some(&property.id))
+        .with_value(value)
+        .invalid()
+}
+
+pub fn generate_response(property: &CustomPropertyModel) -> CustomPropertyResponseSchema {
+    CustomPropertyResponseSchema {
+        id: property.id,
+        entity_type: property.entity_type.into(),
+        name: property.name.to_owned(),
+        property_type: property.property_type.into(),
+        item_type: property.item_type.map(CustomPropertyType::from),
+        options: _get_options(property),
+    }
+}
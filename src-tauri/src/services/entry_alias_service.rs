@@ -0,0 +1,193 @@
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use ::entity::entry_alias::Model as EntryAliasModel;
+
+use crate::database::{entry_alias_manager, entry_manager};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::entry::{
+    EntryAliasResponseSchema, EntryMentionResponseSchema, EntryMentionSearchSchema,
+};
+use crate::services::entry_service;
+use crate::types::entity::{ALIAS, ENTRY};
+
+/// Adds an alternative name to the entry.
+/// Aliases must be unique among both the titles and the aliases of all entries.
+pub async fn add(
+    database: &DatabaseConnection,
+    entry_id: Uuid,
+    alias: &str,
+) -> Result<EntryAliasResponseSchema, Error> {
+    let alias = alias.trim();
+    if alias.is_empty() {
+        return Err(ErrorBuilder::new()
+            .msg("An alias can't be empty.")
+            .entity(ALIAS)
+            .attribute("alias")
+            .with_value(&alias)
+            .invalid());
+    }
+
+    let exists = entry_manager::exists(database, entry_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while checking the entry of an alias.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if !exists {
+        return Err(ErrorBuilder::new()
+            .msg("Entry not found.")
+            .entity(ENTRY)
+            .with_id(&entry_id)
+            .not_found());
+    }
+
+    let is_unique = entry_manager::is_title_unique_for_id(database, None, alias)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while checking whether the alias is unique.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if !is_unique {
+        return Err(ErrorBuilder::new()
+            .msg("Aliases must be unique among the titles and aliases of all entries.")
+            .entity(ALIAS)
+            .attribute("alias")
+            .with_value(&alias)
+            .not_unique());
+    }
+
+    let alias = entry_alias_manager::insert(database, entry_id, alias)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Alias not created.")
+                .from_err(e)
+                .entity(ALIAS)
+                .not_created()
+        })?;
+    Ok(generate_response(&alias))
+}
+
+pub async fn remove(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    entry_alias_manager::delete(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Alias not deleted.")
+                .from_err(e)
+                .entity(ALIAS)
+                .with_id(&id)
+                .not_deleted()
+        })?;
+    Ok(())
+}
+
+/// Returns the aliases of the entry in alphabetical order.
+pub async fn get_all_for_entry(
+    database: &DatabaseConnection,
+    entry_id: Uuid,
+) -> Result<Vec<EntryAliasResponseSchema>, Error> {
+    let aliases = entry_alias_manager::get_all_for_entry(database, entry_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg(
+                    "Failed to query the entry_alias table while fetching the aliases of an entry.",
+                )
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    Ok(aliases.iter().map(generate_response).collect())
+}
+
+/// Suggests entries to mention whose title or one of whose aliases contains the keyword.
+/// Suggestions are sorted by the name that matched, and an entry is suggested once per matching name.
+pub async fn search_mentions(
+    database: &DatabaseConnection,
+    query: EntryMentionSearchSchema,
+) -> Result<Vec<EntryMentionResponseSchema>, Error> {
+    let entries = entry_manager::search(
+        database,
+        query.keyword.clone(),
+        None,
+        None,
+        None,
+        query.limit,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while searching for mentions.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let aliases = entry_alias_manager::search(database, &query.keyword, query.limit)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_alias table while searching for mentions.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let alias_entries = entry_manager::get_many(
+        database,
+        aliases.iter().map(|alias| alias.entry_id).collect(),
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while fetching the entries of aliases.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    let mut suggestions: Vec<EntryMentionResponseSchema> = entries
+        .iter()
+        .map(|entry| EntryMentionResponseSchema {
+            info: entry_service::generate_info_response(entry),
+            alias: None,
+        })
+        .collect();
+    for alias in aliases.into_iter() {
+        let Some(entry) = alias_entries
+            .iter()
+            .find(|entry| entry.id == alias.entry_id)
+        else {
+            continue;
+        };
+        suggestions.push(EntryMentionResponseSchema {
+            info: entry_service::generate_insert_response(entry),
+            alias: Some(alias.alias),
+        });
+    }
+
+    let label = |suggestion: &EntryMentionResponseSchema| {
+        suggestion
+            .alias
+            .clone()
+            .unwrap_or_else(|| suggestion.info.title.clone())
+    };
+    suggestions.sort_by_key(label);
+    suggestions.truncate(query.limit as usize);
+    Ok(suggestions)
+}
+
+fn generate_response(alias: &EntryAliasModel) -> EntryAliasResponseSchema {
+    EntryAliasResponseSchema {
+        id: alias.id,
+        entry_id: alias.entry_id,
+        alias: alias.alias.clone(),
+    }
+}
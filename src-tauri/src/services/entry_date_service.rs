@@ -0,0 +1,175 @@
+use std::collections::{HashMap, hash_map::Entry};
+
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use ::entity::entry_date::Model as EntryDateModel;
+
+use crate::database::entry_date_manager;
+use crate::model::{
+    calendar::{Calendar, FictionalDate},
+    errors::{Error, ErrorBuilder},
+};
+use crate::schema::calendar::{
+    EntryDateCreateSchema, EntryDateResponseSchema, EntryDateUpdateSchema,
+};
+use crate::services::{calendar_service, entry_service};
+use crate::types::entity::DATE;
+
+pub async fn create(
+    database: &DatabaseConnection,
+    entry_date: EntryDateCreateSchema,
+) -> Result<EntryDateResponseSchema, Error> {
+    // raises an error if the entry doesn't exist
+    entry_service::get_info(database, entry_date.entry_id).await?;
+
+    let calendar = calendar_service::get_calendar(database, entry_date.date.calendar_id).await?;
+    let day_number = calendar_service::get_day_number(&calendar, &entry_date.date)?;
+
+    let date = entry_date_manager::insert(
+        database,
+        entry_date.entry_id,
+        entry_date.date.calendar_id,
+        entry_date.label,
+        FictionalDate::new(
+            entry_date.date.year,
+            entry_date.date.month,
+            entry_date.date.day,
+        ),
+        day_number,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Entry date not created.")
+            .from_err(e)
+            .entity(DATE)
+            .not_created()
+    })?;
+
+    generate_response(&date, &calendar)
+}
+
+pub async fn update(
+    database: &DatabaseConnection,
+    entry_date: EntryDateUpdateSchema,
+) -> Result<EntryDateResponseSchema, Error> {
+    // raises an error if the date doesn't exist
+    _get(database, entry_date.id).await?;
+
+    let (calendar_id, date, day_number) = match entry_date.date {
+        Some(date) => {
+            let calendar = calendar_service::get_calendar(database, date.calendar_id).await?;
+            let day_number = calendar_service::get_day_number(&calendar, &date)?;
+            (
+                Some(date.calendar_id),
+                Some(FictionalDate::new(date.year, date.month, date.day)),
+                Some(day_number),
+            )
+        }
+        None => (None, None, None),
+    };
+
+    let updated_date = entry_date_manager::update(
+        database,
+        entry_date.id,
+        entry_date.label,
+        calendar_id,
+        date,
+        day_number,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Entry date not updated.")
+            .from_err(e)
+            .entity(DATE)
+            .with_id(&entry_date.id)
+            .not_updated()
+    })?;
+
+    let calendar = calendar_service::get_calendar(database, updated_date.calendar_id).await?;
+    generate_response(&updated_date, &calendar)
+}
+
+pub async fn delete(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    entry_date_manager::delete(database, id)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Entry date not deleted.")
+                .from_err(e)
+                .entity(DATE)
+                .with_id(&id)
+                .not_deleted()
+        })
+}
+
+/// Returns the dates of the entry in chronological order.
+pub async fn get_all_for_entry(
+    database: &DatabaseConnection,
+    entry_id: Uuid,
+) -> Result<Vec<EntryDateResponseSchema>, Error> {
+    // raises an error if the entry doesn't exist
+    entry_service::get_info(database, entry_id).await?;
+
+    let dates = entry_date_manager::get_all_for_entry(database, entry_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_date table while fetching the dates of an entry.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut calendars: HashMap<Uuid, Calendar> = HashMap::new();
+    for date in dates.iter() {
+        if let Entry::Vacant(vacant) = calendars.entry(date.calendar_id) {
+            vacant.insert(calendar_service::get_calendar(database, date.calendar_id).await?);
+        }
+    }
+
+    let responses = dates
+        .iter()
+        .map(|date| generate_response(date, &calendars[&date.calendar_id]))
+        .collect::<Result<Vec<EntryDateResponseSchema>, Error>>()?;
+
+    Ok(responses)
+}
+
+async fn _get(database: &DatabaseConnection, id: Uuid) -> Result<EntryDateModel, Error> {
+    let entry_date = entry_date_manager::get(database, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry_date table while fetching a date by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    entry_date.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Entry date not found.")
+            .entity(DATE)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+fn generate_response(
+    entry_date: &EntryDateModel,
+    calendar: &Calendar,
+) -> Result<EntryDateResponseSchema, Error> {
+    let date = FictionalDate::new(
+        entry_date.year,
+        entry_date.month as u32,
+        entry_date.day as u32,
+    );
+    Ok(EntryDateResponseSchema {
+        id: entry_date.id,
+        entry_id: entry_date.entry_id,
+        label: entry_date.label.to_owned(),
+        date: calendar_service::generate_date_response(entry_date.calendar_id, calendar, date)?,
+    })
+}
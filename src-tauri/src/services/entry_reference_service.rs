@@ -0,0 +1,264 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use crate::database::{
+    entry_manager, entry_reference_manager, index_version_manager, transaction_manager,
+};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    text::TextNode,
+};
+use crate::schema::entry::EntryBacklinkResponseSchema;
+use crate::services::{entry_service, entry_text_service};
+use crate::types::entity::ENTRY;
+
+/// Maximum number of characters of context on either side of a mention in a backlink snippet.
+const SNIPPET_CONTEXT_LENGTH: usize = 80;
+const SNIPPET_ELLIPSIS: &str = "…";
+const INDEX_NAME: &str = "entry_reference";
+/// The version of the way references are indexed; raising it rebuilds the references of every project.
+const INDEX_VERSION: i32 = 1;
+
+/// Replaces the references of the source entry with the mentions found in its text.
+/// Mentions of entries that don't exist and mentions of the source entry itself are ignored.
+pub async fn index<C>(con: &C, source_id: Uuid, text: &TextNode) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let mut mention_counts: HashMap<Uuid, i32> = HashMap::new();
+    _count_mentions(text, &mut mention_counts);
+    mention_counts.remove(&source_id);
+
+    let existing_ids = if mention_counts.is_empty() {
+        Vec::new()
+    } else {
+        entry_manager::get_existing_ids(con, mention_counts.keys().cloned().collect())
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the entry table while indexing references.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?
+    };
+
+    let references = existing_ids
+        .into_iter()
+        .map(|target_id| (target_id, mention_counts[&target_id]))
+        .collect();
+
+    entry_reference_manager::replace_for_source(con, source_id, references)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to update the references of an entry.")
+                .from_err(e)
+                .entity(ENTRY)
+                .with_id(&source_id)
+                .not_updated()
+        })
+}
+
+pub async fn get_backlinks(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<Vec<EntryBacklinkResponseSchema>, Error> {
+    // raises an error if the target doesn't exist
+    entry_service::get_info(database, id).await?;
+
+    let references = entry_reference_manager::get_for_target(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_reference table while fetching backlinks.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let source_ids = references.iter().map(|r| r.source_id).collect();
+    let sources = entry_manager::get_many(database, source_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while fetching backlinks.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let mention_counts: HashMap<Uuid, i32> = references
+        .iter()
+        .map(|r| (r.source_id, r.mention_count))
+        .collect();
+
+    let mut backlinks: Vec<EntryBacklinkResponseSchema> = sources
+        .into_iter()
+        .map(|source| {
+            let mut snippets = Vec::new();
+            // the text of the source was parsed successfully when it was indexed
+            if let Ok(text) = entry_text_service::_parse_text(source.id, &source.text) {
+                _collect_snippets(&text, id, &mut snippets);
+            }
+
+            EntryBacklinkResponseSchema {
+                mention_count: mention_counts[&source.id],
+                info: entry_service::generate_insert_response(&source),
+                snippets,
+            }
+        })
+        .collect();
+
+    // the sort is stable, so sources with the same mention count remain sorted by title
+    backlinks.sort_by_key(|backlink| Reverse(backlink.mention_count));
+
+    Ok(backlinks)
+}
+
+/// Rebuilds the reference table unless it has been built with the current version of the index,
+/// e.g., because the project was created before the table existed.
+pub async fn sync(database: &DatabaseConnection) -> Result<(), Error> {
+    let version = index_version_manager::get(database, INDEX_NAME)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the index_version table while syncing the references.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    if version == Some(INDEX_VERSION) {
+        return Ok(());
+    }
+
+    rebuild(database).await
+}
+
+pub async fn rebuild(database: &DatabaseConnection) -> Result<(), Error> {
+    let txn = transaction_manager::begin(database).await?;
+
+    entry_reference_manager::delete_all(&txn)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to clear the entry_reference table.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let entries = entry_manager::get_all_models(&txn).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while rebuilding the references.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    for entry in entries.iter() {
+        // unparsable text shouldn't prevent the rest of the project from being indexed
+        if let Ok(text) = entry_text_service::_parse_text(entry.id, &entry.text) {
+            index(&txn, entry.id, &text).await?;
+        }
+    }
+
+    // a project without any mentions has no references, so the table can't tell whether it's built
+    index_version_manager::set(&txn, INDEX_NAME, INDEX_VERSION)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to update the index_version table while rebuilding the references.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    transaction_manager::end(txn).await
+}
+
+fn _count_mentions(node: &TextNode, mention_counts: &mut HashMap<Uuid, i32>) {
+    if let Some(target_id) = _get_mention_id(node) {
+        *mention_counts.entry(target_id).or_insert(0) += 1;
+    }
+
+    if let Some(content) = &node.content {
+        for child in content.iter() {
+            _count_mentions(child, mention_counts);
+        }
+    }
+}
+
+/// Finds each block containing a mention of the target
+/// and extracts the text surrounding the mention.
+fn _collect_snippets(node: &TextNode, target_id: Uuid, snippets: &mut Vec<String>) {
+    let Some(content) = &node.content else {
+        return;
+    };
+
+    let is_parent_of_mention = content
+        .iter()
+        .any(|child| _get_mention_id(child) == Some(target_id));
+
+    if !is_parent_of_mention {
+        for child in content.iter() {
+            _collect_snippets(child, target_id, snippets);
+        }
+        return;
+    }
+
+    let mut text = String::new();
+    let mut mention_spans: Vec<(usize, usize)> = Vec::new();
+    for child in content.iter() {
+        let start = text.len();
+        child.write_plain_text(&mut text);
+        if _get_mention_id(child) == Some(target_id) {
+            mention_spans.push((start, text.len()));
+        }
+    }
+
+    for (start, end) in mention_spans {
+        snippets.push(_extract_snippet(&text, start, end));
+    }
+}
+
+fn _extract_snippet(text: &str, start: usize, end: usize) -> String {
+    let before = &text[..start];
+    let after = &text[end..];
+
+    let before_length = before.chars().count();
+    let before = if before_length > SNIPPET_CONTEXT_LENGTH {
+        let truncated: String = before
+            .chars()
+            .skip(before_length - SNIPPET_CONTEXT_LENGTH)
+            .collect();
+        format!("{SNIPPET_ELLIPSIS}{truncated}")
+    } else {
+        before.to_owned()
+    };
+
+    let after = if after.chars().count() > SNIPPET_CONTEXT_LENGTH {
+        let truncated: String = after.chars().take(SNIPPET_CONTEXT_LENGTH).collect();
+        format!("{truncated}{SNIPPET_ELLIPSIS}")
+    } else {
+        after.to_owned()
+    };
+
+    // line breaks and runs of whitespace are collapsed so that the snippet fits on one line
+    format!("{before}{}{after}", &text[start..end])
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn _get_mention_id(node: &TextNode) -> Option<Uuid> {
+    if !node.is_type("mention") {
+        return None;
+    }
+    // malformed mentions are reported when the text is fetched, so they're skipped here
+    let id = node.get_attr("id")?.as_str()?;
+    Uuid::parse_str(id).ok()
+}
@@ -0,0 +1,317 @@
+use chrono::{TimeDelta, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use ::entity::entry_revision::Model as EntryRevisionModel;
+
+use crate::database::{entry_manager, entry_revision_manager};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::{
+    common::DiagnosticResponseSchema,
+    entry::{
+        EntryProperties, EntryRevisionInfoResponseSchema, EntryRevisionResponseSchema,
+        EntryUpdateResponseSchema, EntryUpdateSchema,
+    },
+};
+use crate::services::{entry_service, entry_text_service};
+use crate::types::entity::{ENTRY, REVISION};
+
+/// Maximum number of revisions kept per entry; the oldest revisions are deleted first.
+const MAX_REVISIONS_PER_ENTRY: u64 = 50;
+/// Saves made within this many seconds of the creation of the latest revision are merged into it,
+/// which prevents autosaves from flooding the history.
+/// The window doesn't slide, so a long editing session is still split into several revisions.
+const REVISION_MERGE_WINDOW_SECONDS: i64 = 5 * 60;
+
+struct EntrySnapshot {
+    title: String,
+    text: String,
+    properties: serde_json::Value,
+}
+
+impl EntrySnapshot {
+    fn matches(&self, revision: &EntryRevisionModel) -> bool {
+        self.title == revision.title
+            && self.text == revision.text
+            && self.properties == revision.properties
+    }
+}
+
+/// Records the current state of the entry if it doesn't have any revisions yet,
+/// e.g., because it was created before revisions were tracked.
+/// Returns `true` if a revision was recorded.
+pub async fn record_baseline<C>(con: &C, id: Uuid) -> Result<bool, Error>
+where
+    C: ConnectionTrait,
+{
+    let latest_revision = _get_latest(con, id).await?;
+    if latest_revision.is_some() {
+        return Ok(false);
+    }
+
+    let Some(snapshot) = _take_snapshot(con, id).await? else {
+        return Ok(false);
+    };
+    _insert(con, id, snapshot).await?;
+
+    Ok(true)
+}
+
+/// Records the current state of the entry as a revision.
+/// Unless `is_new_revision` is set, the state is merged into the latest revision
+/// if the latter was recorded within the merge window.
+pub async fn record<C>(con: &C, id: Uuid, is_new_revision: bool) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let Some(snapshot) = _take_snapshot(con, id).await? else {
+        return Ok(());
+    };
+
+    let latest_revision = _get_latest(con, id).await?;
+
+    match latest_revision {
+        Some(revision) if snapshot.matches(&revision) => Ok(()),
+        Some(revision)
+            if !is_new_revision
+                && Utc::now() - revision.created_at
+                    < TimeDelta::seconds(REVISION_MERGE_WINDOW_SECONDS) =>
+        {
+            entry_revision_manager::update(
+                con,
+                revision.id,
+                snapshot.title,
+                snapshot.text,
+                snapshot.properties,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to update the latest revision of an entry.")
+                    .from_err(e)
+                    .entity(REVISION)
+                    .with_id(&revision.id)
+                    .not_updated()
+            })
+        }
+        _ => {
+            _insert(con, id, snapshot).await?;
+            _prune(con, id).await
+        }
+    }
+}
+
+pub async fn get_all(
+    database: &DatabaseConnection,
+    entry_id: Uuid,
+) -> Result<Vec<EntryRevisionInfoResponseSchema>, Error> {
+    // raises an error if the entry doesn't exist
+    entry_service::get_info(database, entry_id).await?;
+
+    let revisions = entry_revision_manager::get_all_for_entry(database, entry_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_revision table while fetching the revisions of an entry.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    Ok(revisions.iter().map(generate_info_response).collect())
+}
+
+pub async fn get(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<DiagnosticResponseSchema<EntryRevisionResponseSchema>, Error> {
+    let revision = _get(database, id).await?;
+
+    let mut errors: Vec<Error> = Vec::new();
+    let text =
+        entry_text_service::sync_text(database, revision.entry_id, &revision.text, &mut errors)
+            .await;
+    let properties = _deserialize_properties(&revision)?;
+
+    Ok(DiagnosticResponseSchema {
+        data: EntryRevisionResponseSchema {
+            info: EntryRevisionInfoResponseSchema {
+                id: revision.id,
+                entry_id: revision.entry_id,
+                created_at: revision.created_at,
+                title: revision.title,
+            },
+            properties,
+            text,
+        },
+        errors,
+    })
+}
+
+/// Makes the revision the current version of its entry.
+/// The restored state is recorded as a new revision so that the state it replaces can itself be restored.
+pub async fn restore(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<DiagnosticResponseSchema<EntryUpdateResponseSchema>, Error> {
+    let revision = _get(database, id).await?;
+    let properties = _deserialize_properties(&revision)?;
+
+    let entry = EntryUpdateSchema {
+        id: revision.entry_id,
+        folder_id: None,
+        title: Some(revision.title),
+        properties,
+        text: Some(revision.text),
+        words: None,
+        custom_properties: None,
+        expected_revision: None,
+    };
+
+    Ok(entry_service::_update_with_revision(database, entry, true).await)
+}
+
+async fn _get(database: &DatabaseConnection, id: Uuid) -> Result<EntryRevisionModel, Error> {
+    let revision = entry_revision_manager::get(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_revision table while fetching a revision by id.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    match revision {
+        Some(revision) => Ok(revision),
+        None => Err(ErrorBuilder::new()
+            .msg("Revision not found.")
+            .entity(REVISION)
+            .with_id(&id)
+            .not_found()),
+    }
+}
+
+async fn _get_latest<C>(con: &C, entry_id: Uuid) -> Result<Option<EntryRevisionModel>, Error>
+where
+    C: ConnectionTrait,
+{
+    entry_revision_manager::get_latest(con, entry_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_revision table while fetching the latest revision.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })
+}
+
+async fn _take_snapshot<C>(con: &C, id: Uuid) -> Result<Option<EntrySnapshot>, Error>
+where
+    C: ConnectionTrait,
+{
+    let entry = entry_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query entry table while fetching an entry by id.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    let Some(entry) = entry else {
+        return Ok(None);
+    };
+
+    let properties = match entry_service::_get_properties(con, id, entry.entity_type.into()).await {
+        Ok(properties) => serde_json::to_value(properties).map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to serialize the properties of an entry.")
+                .from_err(e)
+                .entity(REVISION)
+                .not_created()
+        })?,
+        // entries without properties only have their title and text tracked
+        Err(Error::UnsupportedEntryType { .. }) => serde_json::Value::Null,
+        Err(e) => return Err(e),
+    };
+
+    Ok(Some(EntrySnapshot {
+        title: entry.title,
+        text: entry.text,
+        properties,
+    }))
+}
+
+async fn _insert<C>(con: &C, entry_id: Uuid, snapshot: EntrySnapshot) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    entry_revision_manager::insert(
+        con,
+        entry_id,
+        Utc::now(),
+        snapshot.title,
+        snapshot.text,
+        snapshot.properties,
+    )
+    .await
+    .map(|_| ())
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Revision record not created.")
+            .from_err(e)
+            .entity(REVISION)
+            .not_created()
+    })
+}
+
+async fn _prune<C>(con: &C, entry_id: Uuid) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    entry_revision_manager::delete_oldest(con, entry_id, MAX_REVISIONS_PER_ENTRY)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to delete the oldest revisions of an entry.")
+                .from_err(e)
+                .entity(ENTRY)
+                .with_id(&entry_id)
+                .not_updated()
+        })
+}
+
+fn _deserialize_properties(
+    revision: &EntryRevisionModel,
+) -> Result<Option<EntryProperties>, Error> {
+    if revision.properties.is_null() {
+        return Ok(None);
+    }
+
+    serde_json::from_value(revision.properties.clone())
+        .map(Some)
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to deserialize the properties of a revision.")
+                .from_err(e)
+                .entity(REVISION)
+                .attribute("properties")
+                .with_id(Some(&revision.id))
+                .with_value(&revision.properties)
+                .invalid()
+        })
+}
+
+pub fn generate_info_response(
+    revision: &entry_revision_manager::EntryRevisionInfo,
+) -> EntryRevisionInfoResponseSchema {
+    EntryRevisionInfoResponseSchema {
+        id: revision.id,
+        entry_id: revision.entry_id,
+        created_at: revision.created_at,
+        title: revision.title.to_owned(),
+    }
+}
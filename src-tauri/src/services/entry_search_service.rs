@@ -0,0 +1,165 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use crate::database::{
+    entry_manager, entry_search_manager, file_manager, index_version_manager, transaction_manager,
+};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::entry::{
+    EntryInfoResponseSchema, EntryTextSearchResponseSchema, EntryTextSearchSchema,
+};
+use crate::services::entry_text_service;
+use crate::types::entity::EntityType;
+
+const INDEX_NAME: &str = "entry_search";
+/// The version of the way entries are indexed; raising it rebuilds the search index of every project.
+const INDEX_VERSION: i32 = 1;
+
+const HIGHLIGHT: entry_search_manager::SearchHighlight<'static> =
+    entry_search_manager::SearchHighlight {
+        open_tag: "<mark>",
+        close_tag: "</mark>",
+        ellipsis: "…",
+        snippet_tokens: 16,
+    };
+
+/// Adds the entry to the full-text search index, replacing any stale copy of it.
+/// If the text can't be parsed as a document, e.g., because it is plain text, then it is indexed as is.
+pub async fn index<C>(con: &C, id: Uuid, title: &str, text: &str) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let body = match entry_text_service::_parse_text(id, text) {
+        Ok(node) => node.to_plain_text(),
+        Err(_) => text.to_owned(),
+    };
+
+    entry_search_manager::upsert(con, id, title, &body)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to add an entry to the search index.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })
+}
+
+pub async fn search(
+    database: &DatabaseConnection,
+    query: EntryTextSearchSchema,
+) -> Result<Vec<EntryTextSearchResponseSchema>, Error> {
+    let match_query = _build_match_query(&query.keyword);
+    if match_query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let hits = entry_search_manager::search(
+        database,
+        &match_query,
+        &HIGHLIGHT,
+        query.limit,
+        query.offset,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the search index.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    Ok(hits.into_iter().map(generate_search_response).collect())
+}
+
+/// Rebuilds the search index unless it has been built with the current version of the index,
+/// e.g., because the project was created before the index existed.
+pub async fn sync(database: &DatabaseConnection) -> Result<(), Error> {
+    let version = index_version_manager::get(database, INDEX_NAME)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the index_version table while syncing the search index.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    if version == Some(INDEX_VERSION) {
+        return Ok(());
+    }
+
+    rebuild(database).await
+}
+
+pub async fn rebuild(database: &DatabaseConnection) -> Result<(), Error> {
+    let txn = transaction_manager::begin(database).await?;
+
+    entry_search_manager::delete_all(&txn).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to clear the search index.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    let entries = entry_manager::get_all_models(&txn).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while rebuilding the search index.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    for entry in entries.iter() {
+        index(&txn, entry.id, &entry.title, &entry.text).await?;
+    }
+
+    index_version_manager::set(&txn, INDEX_NAME, INDEX_VERSION)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to update the index_version table while rebuilding the search index.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    transaction_manager::end(txn).await
+}
+
+/// Converts a free-form keyword into an FTS5 query.
+/// Each word is quoted so that FTS5 operators in the input are matched literally,
+/// and the last word is matched as a prefix so that results update while typing.
+fn _build_match_query(keyword: &str) -> String {
+    let terms: Vec<String> = keyword
+        .split_whitespace()
+        .map(|term| term.replace('"', ""))
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("\"{term}\""))
+        .collect();
+
+    match terms.split_last() {
+        Some((last, [])) => format!("{last}*"),
+        Some((last, rest)) => format!("{} {last}*", rest.join(" ")),
+        None => String::new(),
+    }
+}
+
+fn generate_search_response(
+    hit: entry_search_manager::EntrySearchHit,
+) -> EntryTextSearchResponseSchema {
+    EntryTextSearchResponseSchema {
+        info: EntryInfoResponseSchema {
+            id: hit.id,
+            folder_id: file_manager::convert_null_folder_id_to_root(hit.folder_id),
+            entity_type: EntityType::from(hit.entity_type),
+            title: hit.title,
+            revision: hit.revision,
+        },
+        rank: hit.rank,
+        highlighted_title: hit.highlighted_title,
+        snippet: hit.snippet,
+    }
+}
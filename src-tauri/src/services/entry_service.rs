@@ -1,5 +1,6 @@
-use futures::future;
-use sea_orm::{ConnectionTrait, DatabaseConnection};
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
 use uuid::Uuid;
 
 use ::entity::entry::Model as EntryModel;
@@ -7,44 +8,109 @@ use ::entity::entry::Model as EntryModel;
 use crate::database::{entry_manager, file_manager, transaction_manager};
 use crate::model::{
     errors::{Error, ErrorBuilder},
+    journal::{JournalChange, JournalOperation},
+    tag::TagExpression,
     text::TextNode,
 };
 use crate::schema::entry::EntrySearchSchema;
 use crate::schema::{
     common::DiagnosticResponseSchema,
+    custom_property::{CustomPropertyValueResponseSchema, CustomPropertyValueSchema},
     entry::{
         EntryArticleResponseSchema, EntryCreateSchema, EntryInfoResponseSchema, EntryProperties,
-        EntryPropertyResponseSchema, EntryUpdateResponseSchema, EntryUpdateSchema,
+        EntryPropertyResponseSchema, EntryUpdateMode, EntryUpdateResponseSchema, EntryUpdateSchema,
+        GenericEntrySchema,
     },
+    word::WordResponseSchema,
+};
+use crate::services::{
+    asset_service, calendar_service, custom_property_service, entry_reference_service,
+    entry_revision_service, entry_search_service, entry_template_service, entry_text_service,
+    event_service, journal_service, language_service, map_service, organization_service,
+    person_service, place_service, word_service,
 };
-use crate::services::{entry_text_service, language_service, person_service, word_service};
-use crate::types::entity::{ENTRY, EntityType};
+use crate::types::entity::{ENTRY, EntityType, TAG};
 
 pub async fn create(
     database: &DatabaseConnection,
     entry: EntryCreateSchema,
 ) -> Result<EntryInfoResponseSchema, Error> {
+    let txn = transaction_manager::begin(database).await?;
+    let entry = _create_in_txn(&txn, entry).await?;
+
+    // the change is recorded along with the entry, so that a created entry can always be undone
+    let mut change = JournalChange::new();
+    change.push(
+        JournalOperation::TrashEntry { id: entry.id },
+        JournalOperation::RestoreEntry { id: entry.id },
+    );
+    journal_service::record(&txn, "Create entry", change).await?;
+
+    transaction_manager::end(txn).await?;
+
+    Ok(generate_insert_response(&entry))
+}
+
+/// Creates the entry along with its properties and indexes, without recording the change in the journal.
+pub async fn _create_in_txn<C>(con: &C, entry: EntryCreateSchema) -> Result<EntryModel, Error>
+where
+    C: ConnectionTrait,
+{
     let entity_type = entry.entity_type;
     let folder_id = entry.folder_id;
     let title = entry.title;
-    let properties = entry.properties;
+    let template_id = entry.template_id;
+    let mut properties = entry.properties;
 
-    let txn = transaction_manager::begin(database).await?;
+    // the DB only enforces the uniqueness of titles, not of titles and aliases together
+    let is_unique = entry_manager::is_title_unique_for_id(con, None, &title)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while checking whether the title is unique.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if !is_unique {
+        return Err(ErrorBuilder::new()
+            .msg("Entry title must be globally unique.")
+            .entity(ENTRY)
+            .attribute("title")
+            .with_value(&title)
+            .not_unique());
+    }
 
-    let entry = _create(
-        &txn,
-        entity_type,
-        folder_id,
-        title.to_owned(),
-        "".to_owned(),
-    )
-    .await?;
+    let (text, custom_properties) = match template_id {
+        Some(template_id) => {
+            let (text, template_properties, custom_properties) =
+                entry_template_service::get_defaults(con, template_id, entity_type).await?;
+            if let Some(template_properties) = template_properties {
+                properties = template_properties;
+            }
+            (text, custom_properties)
+        }
+        None => ("".to_owned(), Vec::new()),
+    };
 
-    _create_properties(&txn, entry.id, &properties).await?;
+    let entry = _create(con, entity_type, folder_id, title.to_owned(), text).await?;
 
-    transaction_manager::end(txn).await?;
+    _create_properties(con, entry.id, &properties).await?;
 
-    Ok(generate_insert_response(&entry))
+    if !custom_properties.is_empty() {
+        custom_property_service::_set_values(con, entry.id, entity_type, custom_properties).await?;
+    }
+
+    entry_search_service::index(con, entry.id, &entry.title, &entry.text).await?;
+
+    // the text of a template may mention other entries and show assets
+    if !entry.text.is_empty() {
+        let text = entry_text_service::_parse_text(entry.id, &entry.text)?;
+        entry_reference_service::index(con, entry.id, &text).await?;
+        asset_service::index(con, entry.id, &text).await?;
+    }
+
+    Ok(entry)
 }
 
 pub async fn _create<C>(
@@ -76,20 +142,289 @@ where
     Ok(entry)
 }
 
-async fn _create_properties<C>(con: &C, id: Uuid, properties: &EntryProperties) -> Result<(), Error>
+pub async fn _create_properties<C>(
+    con: &C,
+    id: Uuid,
+    properties: &EntryProperties,
+) -> Result<(), Error>
 where
     C: ConnectionTrait,
 {
     match properties {
-        EntryProperties::Language(_) => Ok(language_service::create(con, id).await?),
+        EntryProperties::Entry(_) => Ok(()),
+        EntryProperties::Language(props) => Ok(language_service::create(con, id, props).await?),
+        EntryProperties::Calendar(props) => Ok(calendar_service::create(con, id, props).await?),
         EntryProperties::Person(props) => Ok(person_service::create(con, id, props).await?),
+        EntryProperties::Event(props) => Ok(event_service::create(con, id, props).await?),
+        EntryProperties::Place(props) => Ok(place_service::create(con, id, props).await?),
+        EntryProperties::Map(props) => Ok(map_service::create(con, id, props).await?),
+        EntryProperties::Organization(props) => {
+            Ok(organization_service::create(con, id, props).await?)
+        }
     }
 }
 
 pub async fn update(
     database: &DatabaseConnection,
-    mut entry: EntryUpdateSchema,
+    entry: EntryUpdateSchema,
+) -> DiagnosticResponseSchema<EntryUpdateResponseSchema> {
+    let txn = match transaction_manager::begin(database).await {
+        Ok(txn) => txn,
+        Err(e) => return _generate_failed_response(&entry, e),
+    };
+    let (mut response, change) = _update_with_change(&txn, entry).await;
+    // the update is rolled back if its change can't be recorded, so that it can always be undone
+    let result = async {
+        journal_service::record(&txn, "Update entry", change).await?;
+        transaction_manager::end(txn).await
+    }
+    .await;
+    if let Err(e) = result {
+        _set_rolled_back(&mut response.data);
+        response.errors.push(e);
+    }
+    response
+}
+
+/// Updates the entry in a single transaction, so that it is either fully updated or not at all.
+pub async fn update_atomically(
+    database: &DatabaseConnection,
+    entry: EntryUpdateSchema,
 ) -> DiagnosticResponseSchema<EntryUpdateResponseSchema> {
+    let txn = match transaction_manager::begin(database).await {
+        Ok(txn) => txn,
+        Err(e) => return _generate_failed_response(&entry, e),
+    };
+    let (mut responses, change) = _update_atomically_with_change(&txn, vec![entry]).await;
+    let mut response = responses.remove(0);
+    let result = async {
+        journal_service::record(&txn, "Update entry", change).await?;
+        transaction_manager::end(txn).await
+    }
+    .await;
+    if let Err(e) = result {
+        _set_rolled_back(&mut response.data);
+        response.errors.push(e);
+    }
+    response
+}
+
+/// Reports that nothing was updated, because of the error.
+fn _generate_failed_response(
+    entry: &EntryUpdateSchema,
+    e: Error,
+) -> DiagnosticResponseSchema<EntryUpdateResponseSchema> {
+    let mut data = EntryUpdateResponseSchema::new(entry);
+    data.set_updated(false);
+    DiagnosticResponseSchema {
+        data,
+        errors: vec![e],
+    }
+}
+
+/// The state that an update is about to overwrite.
+struct PreviousState {
+    entry: Result<Option<EntryUpdateSchema>, Error>,
+    words: Result<HashMap<Uuid, WordResponseSchema>, Error>,
+}
+
+/// Updates the entry and returns the change that was made, so that it can be journaled.
+async fn _update_with_change<C>(
+    con: &C,
+    entry: EntryUpdateSchema,
+) -> (
+    DiagnosticResponseSchema<EntryUpdateResponseSchema>,
+    JournalChange,
+)
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let previous_state = _get_previous_state(con, &entry).await;
+    let mut response = _update_with_revision(con, entry.clone(), false).await;
+    let change = _get_change(con, &entry, previous_state, &mut response).await;
+    (response, change)
+}
+
+/// Updates the entries in a single transaction and returns the change that was made.
+async fn _update_atomically_with_change<C>(
+    con: &C,
+    entries: Vec<EntryUpdateSchema>,
+) -> (
+    Vec<DiagnosticResponseSchema<EntryUpdateResponseSchema>>,
+    JournalChange,
+)
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let mut previous_states = Vec::new();
+    for entry in entries.iter() {
+        previous_states.push(_get_previous_state(con, entry).await);
+    }
+
+    let mut responses = _update_atomically(con, entries.clone()).await;
+
+    let mut change = JournalChange::new();
+    for ((entry, previous_state), response) in entries
+        .iter()
+        .zip(previous_states)
+        .zip(responses.iter_mut())
+    {
+        change.extend(_get_change(con, entry, previous_state, response).await);
+    }
+    (responses, change)
+}
+
+async fn _get_previous_state<C>(con: &C, entry: &EntryUpdateSchema) -> PreviousState
+where
+    C: ConnectionTrait,
+{
+    PreviousState {
+        entry: _get_journal_state(con, entry).await,
+        words: match &entry.words {
+            Some(words) => word_service::_get_journal_states(con, words).await,
+            None => Ok(HashMap::new()),
+        },
+    }
+}
+
+/// Returns the change made by the update, given the state that it overwrote.
+async fn _get_change<C>(
+    con: &C,
+    entry: &EntryUpdateSchema,
+    previous_state: PreviousState,
+    response: &mut DiagnosticResponseSchema<EntryUpdateResponseSchema>,
+) -> JournalChange
+where
+    C: ConnectionTrait,
+{
+    let mut change = JournalChange::new();
+
+    match (previous_state.entry, _get_journal_state(con, entry).await) {
+        (Ok(Some(previous_entry)), Ok(Some(updated_entry))) => {
+            let undo = _filter_updated_fields(previous_entry, &response.data);
+            let redo = _filter_updated_fields(updated_entry, &response.data);
+            if undo.has_update() {
+                change.push(
+                    JournalOperation::UpdateEntry {
+                        entry: Box::new(undo),
+                    },
+                    JournalOperation::UpdateEntry {
+                        entry: Box::new(redo),
+                    },
+                );
+            }
+        }
+        // updating a nonexistent entry fails, so there is nothing to undo
+        (Ok(_), Ok(_)) => {}
+        (Err(e), _) | (_, Err(e)) => response.errors.push(e),
+    }
+
+    let word_change = match previous_state.words {
+        Ok(previous_words) => {
+            word_service::_get_upsert_change(con, &previous_words, &response.data.words).await
+        }
+        Err(e) => Err(e),
+    };
+    match word_change {
+        Ok(word_change) => change.extend(word_change),
+        Err(e) => response.errors.push(e),
+    }
+
+    change
+}
+
+/// Returns the current values of the fields that the update sets, so that the update can be undone.
+/// Returns nothing if the entry doesn't exist.
+async fn _get_journal_state<C>(
+    con: &C,
+    entry: &EntryUpdateSchema,
+) -> Result<Option<EntryUpdateSchema>, Error>
+where
+    C: ConnectionTrait,
+{
+    let current_entry = entry_manager::get(con, entry.id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query entry table while fetching an entry by id.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let Some(current_entry) = current_entry else {
+        return Ok(None);
+    };
+    let entity_type = EntityType::from(current_entry.entity_type);
+
+    let properties = match (&entry.properties, entity_type) {
+        (None, _) => None,
+        (Some(_), EntityType::Entry) => Some(EntryProperties::Entry(GenericEntrySchema {})),
+        (Some(_), _) => Some(_get_properties(con, entry.id, entity_type).await?),
+    };
+
+    let custom_properties = match &entry.custom_properties {
+        Some(values) => {
+            let current_values =
+                custom_property_service::get_values(con, entry.id, entity_type).await?;
+            Some(
+                values
+                    .iter()
+                    .map(|value| CustomPropertyValueSchema {
+                        property_id: value.property_id,
+                        // properties without a value are cleared again when the update is undone
+                        value: current_values
+                            .iter()
+                            .find(|current| current.property_id == value.property_id)
+                            .map(|current| current.value.clone())
+                            .unwrap_or(serde_json::Value::Null),
+                    })
+                    .collect(),
+            )
+        }
+        None => None,
+    };
+
+    Ok(Some(EntryUpdateSchema {
+        id: entry.id,
+        folder_id: entry
+            .folder_id
+            .map(|_| file_manager::convert_null_folder_id_to_root(current_entry.folder_id)),
+        title: entry.title.as_ref().map(|_| current_entry.title.clone()),
+        properties,
+        text: entry.text.as_ref().map(|_| current_entry.text.clone()),
+        words: None,
+        custom_properties,
+        expected_revision: None,
+    }))
+}
+
+/// Keeps the fields of `entry` that the update succeeded in setting.
+fn _filter_updated_fields(
+    entry: EntryUpdateSchema,
+    response: &EntryUpdateResponseSchema,
+) -> EntryUpdateSchema {
+    EntryUpdateSchema {
+        id: entry.id,
+        folder_id: entry.folder_id.filter(|_| response.folder_id.updated),
+        title: entry.title.filter(|_| response.title.updated),
+        properties: entry.properties.filter(|_| response.properties.updated),
+        text: entry.text.filter(|_| response.text.updated),
+        words: None,
+        custom_properties: entry
+            .custom_properties
+            .filter(|_| response.custom_properties.updated),
+        expected_revision: None,
+    }
+}
+
+/// Updates the entry and records its new state in its revision history.
+/// If `is_new_revision` is set, then the new state is never merged into the latest revision.
+pub async fn _update_with_revision<C>(
+    con: &C,
+    mut entry: EntryUpdateSchema,
+    mut is_new_revision: bool,
+) -> DiagnosticResponseSchema<EntryUpdateResponseSchema>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
     let mut response = EntryUpdateResponseSchema::new(&entry);
     let mut errors: Vec<Error> = Vec::new();
 
@@ -103,10 +438,119 @@ pub async fn update(
         };
     }
 
-    // Check whether the updated title is unique; if not, then don't update the title
+    if let Err(e) = _check_revision(con, &entry).await {
+        response.set_updated(false);
+        errors.push(e);
+        return DiagnosticResponseSchema {
+            data: response,
+            errors,
+        };
+    }
+
+    _validate_title(con, &mut entry, &mut response, &mut errors).await;
+
+    let id = entry.id;
+    let is_revised = entry.title.is_some() || entry.text.is_some() || entry.properties.is_some();
+
+    if is_revised {
+        // the state preceding the update is lost unless it has already been recorded
+        match entry_revision_service::record_baseline(con, id).await {
+            Ok(is_recorded) => is_new_revision |= is_recorded,
+            Err(e) => errors.push(e),
+        }
+    }
+
+    _update(con, entry, &mut response, &mut errors).await;
+
+    if is_revised {
+        let record_result = entry_revision_service::record(con, id, is_new_revision).await;
+        if let Err(e) = record_result {
+            errors.push(e);
+        }
+    }
+
+    DiagnosticResponseSchema {
+        data: response,
+        errors,
+    }
+}
+
+/// Rejects the update early if the entry has been written since the revision that it is based on.
+/// The update itself only applies to that revision, so a write that comes in between is rejected too.
+async fn _check_revision<C>(con: &C, entry: &EntryUpdateSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let Some(expected_revision) = entry.expected_revision else {
+        return Ok(());
+    };
+    match _get_current_entry(con, entry.id).await? {
+        Some(current_entry) if current_entry.revision != expected_revision => Err(
+            _generate_revision_conflict(current_entry, expected_revision),
+        ),
+        // updating a nonexistent entry fails later on
+        _ => Ok(()),
+    }
+}
+
+/// Explains why an update that was based on the expected revision didn't apply.
+async fn _get_revision_conflict<C>(con: &C, id: Uuid, expected_revision: i32) -> Error
+where
+    C: ConnectionTrait,
+{
+    match _get_current_entry(con, id).await {
+        Ok(Some(current_entry)) => _generate_revision_conflict(current_entry, expected_revision),
+        Ok(None) => ErrorBuilder::new()
+            .msg("Entry not found.")
+            .entity(ENTRY)
+            .with_id(&id)
+            .not_found(),
+        Err(e) => e,
+    }
+}
+
+async fn _get_current_entry<C>(con: &C, id: Uuid) -> Result<Option<EntryModel>, Error>
+where
+    C: ConnectionTrait,
+{
+    entry_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query entry table while fetching an entry by id.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })
+}
+
+fn _generate_revision_conflict(current_entry: EntryModel, expected_revision: i32) -> Error {
+    let id = current_entry.id;
+    let revision = current_entry.revision;
+    // the conflict is still reported if the current text isn't a document
+    let text = entry_text_service::_parse_text(id, &current_entry.text)
+        .unwrap_or_else(|_| TextNode::new_doc());
+    ErrorBuilder::new()
+        .msg("The entry has been changed since it was last read.")
+        .entity(ENTRY)
+        .with_id(&id)
+        .revision_conflict(
+            expected_revision,
+            revision,
+            &generate_text_response(current_entry, text),
+        )
+}
+
+/// Checks whether the updated title is unique; if not, then the title isn't updated.
+async fn _validate_title<C>(
+    con: &C,
+    entry: &mut EntryUpdateSchema,
+    response: &mut EntryUpdateResponseSchema,
+    errors: &mut Vec<Error>,
+) where
+    C: ConnectionTrait,
+{
     if let Some(title_value) = entry.title.clone() {
         let is_unique_result =
-            entry_manager::is_title_unique_for_id(database, Some(entry.id), &title_value)
+            entry_manager::is_title_unique_for_id(con, Some(entry.id), &title_value)
                 .await
                 .map_err(|e| {
                     ErrorBuilder::new()
@@ -151,43 +595,85 @@ pub async fn update(
             );
         }
     }
-
-    _update(database, entry, &mut response, &mut errors).await;
-
-    DiagnosticResponseSchema {
-        data: response,
-        errors,
-    }
 }
 
-async fn _update(
-    database: &DatabaseConnection,
+async fn _update<C>(
+    con: &C,
     entry: EntryUpdateSchema,
     response: &mut EntryUpdateResponseSchema,
     errors: &mut Vec<Error>,
-) {
-    let update_result =
-        entry_manager::update(database, entry.id, entry.folder_id, entry.title, entry.text)
+) where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let is_indexed_content_updated = entry.title.is_some() || entry.text.is_some();
+    let is_text_updated = entry.text.is_some();
+
+    let update_result = entry_manager::update(
+        con,
+        entry.id,
+        entry.folder_id,
+        entry.title,
+        entry.text,
+        entry.expected_revision,
+    )
+    .await;
+    let update_result = match (update_result, entry.expected_revision) {
+        (Err(DbErr::RecordNotUpdated), Some(expected_revision)) => {
+            // the entry has been written since it was checked, so nothing else is updated either
+            response.set_updated(false);
+            errors.push(_get_revision_conflict(con, entry.id, expected_revision).await);
+            return;
+        }
+        (update_result, _) => update_result.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Entry record not updated.")
+                .from_err(e)
+                .entity(ENTRY)
+                .with_id(&entry.id)
+                .not_updated()
+        }),
+    };
+
+    match update_result {
+        Ok(updated_entry) if is_indexed_content_updated => {
+            if let Err(e) = entry_search_service::index(
+                con,
+                updated_entry.id,
+                &updated_entry.title,
+                &updated_entry.text,
+            )
             .await
-            .map(|_| ())
-            .map_err(|e| {
-                ErrorBuilder::new()
-                    .msg("Entry record not updated.")
-                    .from_err(e)
-                    .entity(ENTRY)
-                    .with_id(&entry.id)
-                    .not_updated()
-            });
-
-    if let Err(e) = update_result {
-        response.folder_id.updated = false;
-        response.title.updated = false;
-        response.text.updated = false;
-        errors.push(e);
+            {
+                errors.push(e);
+            }
+
+            if is_text_updated {
+                // text that isn't a document has no mentions or images to index
+                if let Ok(text) =
+                    entry_text_service::_parse_text(updated_entry.id, &updated_entry.text)
+                {
+                    if let Err(e) =
+                        entry_reference_service::index(con, updated_entry.id, &text).await
+                    {
+                        errors.push(e);
+                    }
+                    if let Err(e) = asset_service::index(con, updated_entry.id, &text).await {
+                        errors.push(e);
+                    }
+                }
+            }
+        }
+        Ok(_) => {}
+        Err(e) => {
+            response.folder_id.updated = false;
+            response.title.updated = false;
+            response.text.updated = false;
+            errors.push(e);
+        }
     }
 
     if let Some(property_values) = entry.properties {
-        let update_prop_result = _update_properties(database, entry.id, &property_values).await;
+        let update_prop_result = _update_properties(con, entry.id, &property_values).await;
 
         if let Err(e) = update_prop_result {
             response.properties.updated = false;
@@ -195,8 +681,18 @@ async fn _update(
         }
     }
 
+    if let Some(custom_property_values) = entry.custom_properties {
+        let set_values_result =
+            custom_property_service::set_values(con, entry.id, custom_property_values).await;
+
+        if let Err(e) = set_values_result {
+            response.custom_properties.updated = false;
+            errors.push(e);
+        }
+    }
+
     if let Some(word_values) = entry.words {
-        let upsert_word_results = word_service::bulk_upsert(database, word_values).await;
+        let upsert_word_results = word_service::_bulk_upsert(con, word_values).await;
 
         match upsert_word_results {
             Ok(upsert_word_responses) => {
@@ -217,23 +713,194 @@ where
     C: ConnectionTrait,
 {
     match properties {
-        EntryProperties::Language(_) => Ok(()),
+        EntryProperties::Entry(_) => Ok(()),
+        EntryProperties::Language(props) => Ok(language_service::update(con, id, props).await?),
+        EntryProperties::Calendar(props) => Ok(calendar_service::update(con, id, props).await?),
         EntryProperties::Person(props) => Ok(person_service::update(con, id, props).await?),
+        EntryProperties::Event(props) => Ok(event_service::update(con, id, props).await?),
+        EntryProperties::Place(props) => Ok(place_service::update(con, id, props).await?),
+        EntryProperties::Map(props) => Ok(map_service::update(con, id, props).await?),
+        EntryProperties::Organization(props) => {
+            Ok(organization_service::update(con, id, props).await?)
+        }
+    }
+}
+
+/// Updates the entries in a single transaction: either all of them are updated, or none of them is.
+async fn _update_atomically<C>(
+    con: &C,
+    entries: Vec<EntryUpdateSchema>,
+) -> Vec<DiagnosticResponseSchema<EntryUpdateResponseSchema>>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let mut responses: Vec<DiagnosticResponseSchema<EntryUpdateResponseSchema>> = entries
+        .iter()
+        .map(|entry| DiagnosticResponseSchema {
+            data: EntryUpdateResponseSchema::new(entry),
+            errors: Vec::new(),
+        })
+        .collect();
+
+    let mut is_committed = false;
+    match transaction_manager::begin(con).await {
+        Ok(txn) => {
+            for (mut entry, response) in entries.into_iter().zip(responses.iter_mut()) {
+                if !entry.has_update() {
+                    response.data.set_updated(false);
+                    continue;
+                }
+                if let Err(e) = _check_revision(&txn, &entry).await {
+                    response.data.set_updated(false);
+                    response.errors.push(e);
+                    continue;
+                }
+                _validate_title(&txn, &mut entry, &mut response.data, &mut response.errors).await;
+
+                let id = entry.id;
+                let is_revised =
+                    entry.title.is_some() || entry.text.is_some() || entry.properties.is_some();
+                let mut is_new_revision = false;
+                if is_revised {
+                    match entry_revision_service::record_baseline(&txn, id).await {
+                        Ok(is_recorded) => is_new_revision = is_recorded,
+                        Err(e) => response.errors.push(e),
+                    }
+                }
+                _update(&txn, entry, &mut response.data, &mut response.errors).await;
+                if is_revised
+                    && let Err(e) = entry_revision_service::record(&txn, id, is_new_revision).await
+                {
+                    response.errors.push(e);
+                }
+            }
+
+            // the transaction is rolled back when it is dropped
+            if responses.iter().all(|response| response.errors.is_empty()) {
+                match transaction_manager::end(txn).await {
+                    Ok(()) => is_committed = true,
+                    Err(e) => responses
+                        .iter_mut()
+                        .for_each(|response| response.errors.push(e.clone())),
+                }
+            }
+        }
+        Err(e) => responses
+            .iter_mut()
+            .for_each(|response| response.errors.push(e.clone())),
+    }
+
+    if !is_committed {
+        for response in responses.iter_mut() {
+            _set_rolled_back(&mut response.data);
+        }
+    }
+    responses
+}
+
+/// Marks nothing as updated, since the updates were rolled back.
+fn _set_rolled_back(response: &mut EntryUpdateResponseSchema) {
+    response.set_updated(false);
+    for word in response.words.iter_mut() {
+        if word.status.created {
+            word.id = None;
+        }
+        word.status.created = false;
+        word.status.updated = false;
     }
 }
 
+/// Updates several entries at once; `mode` decides how much of the batch is kept when an update fails.
+/// An entry can only be updated once per batch, since the updates don't apply in a defined order:
+/// the updates that follow the first one of an entry are rejected with a `NOT_UNIQUE` error.
+/// In [`EntryUpdateMode::Batch`] mode, such a rejection leaves every entry of the batch as it is.
 pub async fn bulk_update(
     database: &DatabaseConnection,
     entries: Vec<EntryUpdateSchema>,
+    mode: EntryUpdateMode,
 ) -> Vec<DiagnosticResponseSchema<EntryUpdateResponseSchema>> {
-    // FIXME: `entries` may contain multiple conflicting changes for the same entry;
-    // we need to decide how to handle such conflicts
-    future::join_all(
-        entries
-            .into_iter()
-            .map(async |entry| update(database, entry).await),
-    )
-    .await
+    let mut ids: HashSet<Uuid> = HashSet::new();
+    let is_duplicate: Vec<bool> = entries.iter().map(|entry| !ids.insert(entry.id)).collect();
+    let is_rejected = mode == EntryUpdateMode::Batch && is_duplicate.contains(&true);
+
+    let mut responses: Vec<Option<DiagnosticResponseSchema<EntryUpdateResponseSchema>>> =
+        vec![None; entries.len()];
+    let mut updates: Vec<(usize, EntryUpdateSchema)> = Vec::new();
+    for ((index, entry), is_duplicate) in entries.into_iter().enumerate().zip(is_duplicate) {
+        if is_duplicate || is_rejected {
+            let mut data = EntryUpdateResponseSchema::new(&entry);
+            data.set_updated(false);
+            let mut errors: Vec<Error> = Vec::new();
+            if is_duplicate {
+                errors.push(
+                    ErrorBuilder::new()
+                        .msg("An entry can only be updated once per batch.")
+                        .entity(ENTRY)
+                        .attribute("id")
+                        .with_id(Some(&entry.id))
+                        .not_unique(),
+                );
+            }
+            responses[index] = Some(DiagnosticResponseSchema { data, errors });
+        } else {
+            updates.push((index, entry));
+        }
+    }
+
+    let (indexes, entries): (Vec<usize>, Vec<EntryUpdateSchema>) = updates.into_iter().unzip();
+    let txn = match transaction_manager::begin(database).await {
+        Ok(txn) => txn,
+        Err(e) => {
+            for (index, entry) in indexes.into_iter().zip(entries) {
+                responses[index] = Some(_generate_failed_response(&entry, e.clone()));
+            }
+            return responses.into_iter().flatten().collect();
+        }
+    };
+    let (mut updated, change) = match mode {
+        // the updates share the transaction, so they are made one after another
+        EntryUpdateMode::Partial => {
+            let mut change = JournalChange::new();
+            let mut updated = Vec::new();
+            for entry in entries.into_iter() {
+                let (response, entry_change) = _update_with_change(&txn, entry).await;
+                updated.push(response);
+                change.extend(entry_change);
+            }
+            (updated, change)
+        }
+        EntryUpdateMode::Entry => {
+            let mut change = JournalChange::new();
+            let mut updated = Vec::new();
+            for entry in entries.into_iter() {
+                let (entry_responses, entry_change) =
+                    _update_atomically_with_change(&txn, vec![entry]).await;
+                updated.extend(entry_responses);
+                change.extend(entry_change);
+            }
+            (updated, change)
+        }
+        EntryUpdateMode::Batch if entries.is_empty() => (Vec::new(), JournalChange::new()),
+        EntryUpdateMode::Batch => _update_atomically_with_change(&txn, entries).await,
+    };
+
+    // the entries are updated together, so they are also undone together
+    let result = async {
+        journal_service::record(&txn, "Update entries", change).await?;
+        transaction_manager::end(txn).await
+    }
+    .await;
+    if let Err(e) = result {
+        for response in updated.iter_mut() {
+            _set_rolled_back(&mut response.data);
+            response.errors.push(e.clone());
+        }
+    }
+
+    for (index, response) in indexes.into_iter().zip(updated) {
+        responses[index] = Some(response);
+    }
+    responses.into_iter().flatten().collect()
 }
 
 pub async fn validate_title(
@@ -313,20 +980,42 @@ pub async fn get_properties(
     }
     let info = info.unwrap();
 
-    let properties = _get_properties(database, id, info.entity_type.into()).await?;
+    let entity_type = EntityType::from(info.entity_type);
+    let properties = match entity_type {
+        // generic entries only have custom properties
+        EntityType::Entry => EntryProperties::Entry(GenericEntrySchema {}),
+        _ => _get_properties(database, id, entity_type).await?,
+    };
+    let custom_properties = custom_property_service::get_values(database, id, entity_type).await?;
 
-    Ok(generate_property_response(&info, properties))
+    Ok(generate_property_response(
+        &info,
+        properties,
+        custom_properties,
+    ))
 }
 
-async fn _get_properties(
-    database: &DatabaseConnection,
+pub async fn _get_properties<C>(
+    con: &C,
     id: Uuid,
     entity_type: EntityType,
-) -> Result<EntryProperties, Error> {
+) -> Result<EntryProperties, Error>
+where
+    C: ConnectionTrait,
+{
     match entity_type {
-        EntityType::Language => Ok(EntryProperties::Language(language_service::get().await?)),
-        EntityType::Person => Ok(EntryProperties::Person(
-            person_service::get(database, id).await?,
+        EntityType::Language => Ok(EntryProperties::Language(
+            language_service::get(con, id).await?,
+        )),
+        EntityType::Calendar => Ok(EntryProperties::Calendar(
+            calendar_service::get(con, id).await?,
+        )),
+        EntityType::Person => Ok(EntryProperties::Person(person_service::get(con, id).await?)),
+        EntityType::Event => Ok(EntryProperties::Event(event_service::get(con, id).await?)),
+        EntityType::Place => Ok(EntryProperties::Place(place_service::get(con, id).await?)),
+        EntityType::Map => Ok(EntryProperties::Map(map_service::get(con, id).await?)),
+        EntityType::Organization => Ok(EntryProperties::Organization(
+            organization_service::get(con, id).await?,
         )),
         _ => Err(ErrorBuilder::new()
             .msg(&format!(
@@ -391,9 +1080,23 @@ pub async fn search(
     database: &DatabaseConnection,
     query: EntrySearchSchema,
 ) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    let tags = match query.tags.as_deref().map(str::trim) {
+        Some(tags) if !tags.is_empty() => Some(TagExpression::parse(tags).map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Invalid tag expression.")
+                .from_err(e)
+                .entity(TAG)
+                .attribute("tags")
+                .with_value(&tags)
+                .invalid()
+        })?),
+        _ => None,
+    };
+
     let entries = entry_manager::search(
         database,
         query.keyword,
+        tags.as_ref(),
         query.before,
         query.after,
         query.limit,
@@ -412,6 +1115,8 @@ pub async fn search(
 }
 
 pub async fn delete(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    // NOTE: the entry is purged from the search index by a DB trigger,
+    // and its references are removed by cascading deletes
     entry_manager::delete(database, id).await.map_err(|e| {
         ErrorBuilder::new()
             .msg("Failed to delete an entry record.")
@@ -442,6 +1147,7 @@ pub fn generate_insert_response(info: &EntryModel) -> EntryInfoResponseSchema {
         folder_id: file_manager::convert_null_folder_id_to_root(info.folder_id),
         title: info.title.to_string(),
         entity_type: EntityType::from(info.entity_type),
+        revision: info.revision,
     }
 }
 
@@ -451,16 +1157,19 @@ pub fn generate_info_response(info: &entry_manager::EntityInfo) -> EntryInfoResp
         folder_id: file_manager::convert_null_folder_id_to_root(info.folder_id),
         title: info.title.to_owned(),
         entity_type: EntityType::from(info.entity_type),
+        revision: info.revision,
     }
 }
 
 pub fn generate_property_response(
     info: &entry_manager::EntityInfo,
     properties: EntryProperties,
+    custom_properties: Vec<CustomPropertyValueResponseSchema>,
 ) -> EntryPropertyResponseSchema {
     EntryPropertyResponseSchema {
         info: generate_info_response(info),
         properties,
+        custom_properties,
     }
 }
 
@@ -471,6 +1180,7 @@ pub fn generate_text_response(entry: EntryModel, text: TextNode) -> EntryArticle
             folder_id: file_manager::convert_null_folder_id_to_root(entry.folder_id),
             title: entry.title.to_owned(),
             entity_type: EntityType::from(entry.entity_type),
+            revision: entry.revision,
         },
         text,
     }
@@ -0,0 +1,364 @@
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+use ::entity::entry_template::Model as EntryTemplateModel;
+
+use crate::database::{custom_property_manager, entry_template_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    text::TextNode,
+};
+use crate::schema::{
+    custom_property::CustomPropertyValueSchema,
+    entry::EntryProperties,
+    entry_template::{
+        EntryTemplateCreateSchema, EntryTemplateResponseSchema, EntryTemplateUpdateSchema,
+    },
+};
+use crate::services::custom_property_service;
+use crate::types::entity::{
+    CALENDAR, ENTRY, ENTRY_TYPES, EVENT, EntityType, LANGUAGE, MAP, ORGANIZATION, PERSON, PLACE,
+    TEMPLATE,
+};
+
+/// The text, properties and custom property values that a template gives to new entries.
+pub type EntryTemplateDefaults = (
+    String,
+    Option<EntryProperties>,
+    Vec<CustomPropertyValueSchema>,
+);
+
+type SerializedTemplate = (String, Option<serde_json::Value>, serde_json::Value);
+
+pub async fn create(
+    database: &DatabaseConnection,
+    template: EntryTemplateCreateSchema,
+) -> Result<EntryTemplateResponseSchema, Error> {
+    let entity_type = template.entity_type;
+    if !ENTRY_TYPES.contains(&entity_type) {
+        return Err(ErrorBuilder::new()
+            .msg("Templates can only be used to create entries.")
+            .entity(TEMPLATE)
+            .attribute("entityType")
+            .with_value(&entity_type)
+            .invalid());
+    }
+    let name = _normalize_name(None, &template.name)?;
+    _validate_properties(None, entity_type, &template.properties)?;
+    custom_property_service::validate_values(database, entity_type, &template.custom_properties)
+        .await?;
+    _validate_name_is_free(database, entity_type, None, &name).await?;
+
+    let (text, properties, custom_properties) = _serialize(
+        &template.text,
+        &template.properties,
+        &template.custom_properties,
+    )?;
+    let template = entry_template_manager::insert(
+        database,
+        entity_type,
+        &name,
+        text,
+        properties,
+        custom_properties,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Entry template not created.")
+            .from_err(e)
+            .entity(TEMPLATE)
+            .not_created()
+    })?;
+    generate_response(&template)
+}
+
+pub async fn update(
+    database: &DatabaseConnection,
+    template: EntryTemplateUpdateSchema,
+) -> Result<EntryTemplateResponseSchema, Error> {
+    let id = template.id;
+    let existing = _get(database, id).await?;
+    let entity_type = EntityType::from(existing.entity_type);
+    let name = _normalize_name(Some(id), &template.name)?;
+    _validate_properties(Some(id), entity_type, &template.properties)?;
+    custom_property_service::validate_values(database, entity_type, &template.custom_properties)
+        .await?;
+    if name != existing.name {
+        _validate_name_is_free(database, entity_type, Some(id), &name).await?;
+    }
+
+    let (text, properties, custom_properties) = _serialize(
+        &template.text,
+        &template.properties,
+        &template.custom_properties,
+    )?;
+    let template =
+        entry_template_manager::update(database, id, &name, text, properties, custom_properties)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Entry template not updated.")
+                    .from_err(e)
+                    .entity(TEMPLATE)
+                    .with_id(&id)
+                    .not_updated()
+            })?;
+    generate_response(&template)
+}
+
+/// Deletes the template; the entries created from it are left as they are.
+pub async fn delete(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    entry_template_manager::delete(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Entry template not deleted.")
+                .from_err(e)
+                .entity(TEMPLATE)
+                .with_id(&id)
+                .not_deleted()
+        })?;
+    Ok(())
+}
+
+pub async fn get(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<EntryTemplateResponseSchema, Error> {
+    let template = _get(database, id).await?;
+    generate_response(&template)
+}
+
+/// Returns the templates of the given entity type, or all of the templates if it isn't set.
+pub async fn get_all(
+    database: &DatabaseConnection,
+    entity_type: Option<EntityType>,
+) -> Result<Vec<EntryTemplateResponseSchema>, Error> {
+    let templates = entry_template_manager::get_all(database, entity_type)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_template table while fetching templates.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    templates.iter().map(generate_response).collect()
+}
+
+/// Returns what the template gives to a new entry of the given type.
+/// Values of custom properties that were deleted since the template was saved are left out.
+pub async fn get_defaults<C>(
+    con: &C,
+    id: Uuid,
+    entity_type: EntityType,
+) -> Result<EntryTemplateDefaults, Error>
+where
+    C: ConnectionTrait,
+{
+    let template = _get(con, id).await?;
+    if EntityType::from(template.entity_type) != entity_type {
+        return Err(ErrorBuilder::new()
+            .msg("The template is meant for another type of entry.")
+            .entity(TEMPLATE)
+            .attribute("entityType")
+            .with_id(Some(&id))
+            .with_value(&entity_type)
+            .invalid());
+    }
+
+    let properties = match &template.properties {
+        Some(properties) => Some(_deserialize_column(&template, "properties", properties)?),
+        None => None,
+    };
+    let custom_properties: Vec<CustomPropertyValueSchema> =
+        _deserialize_column(&template, "customProperties", &template.custom_properties)?;
+
+    let property_ids = custom_properties
+        .iter()
+        .map(|value| value.property_id)
+        .collect();
+    let existing_properties = custom_property_manager::get_many(con, property_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the custom_property table while fetching properties.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let custom_properties = custom_properties
+        .into_iter()
+        .filter(|value| {
+            existing_properties
+                .iter()
+                .any(|property| property.id == value.property_id)
+        })
+        .collect();
+
+    Ok((template.text, properties, custom_properties))
+}
+
+async fn _get<C>(con: &C, id: Uuid) -> Result<EntryTemplateModel, Error>
+where
+    C: ConnectionTrait,
+{
+    let template = entry_template_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry_template table while fetching a template by id.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    template.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Entry template not found.")
+            .entity(TEMPLATE)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+async fn _validate_name_is_free(
+    database: &DatabaseConnection,
+    entity_type: EntityType,
+    id: Option<Uuid>,
+    name: &str,
+) -> Result<(), Error> {
+    let existing = entry_template_manager::get_by_name(database, entity_type, name)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_template table while checking whether a name is taken.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if existing.is_some_and(|existing| Some(existing.id) != id) {
+        return Err(ErrorBuilder::new()
+            .msg("Template names must be unique for each type of entry.")
+            .entity(TEMPLATE)
+            .attribute("name")
+            .with_id(id.as_ref())
+            .with_value(&name)
+            .not_unique());
+    }
+    Ok(())
+}
+
+fn _validate_properties(
+    id: Option<Uuid>,
+    entity_type: EntityType,
+    properties: &Option<EntryProperties>,
+) -> Result<(), Error> {
+    let Some(properties) = properties else {
+        return Ok(());
+    };
+    let properties_type = match properties {
+        EntryProperties::Entry(_) => ENTRY,
+        EntryProperties::Language(_) => LANGUAGE,
+        EntryProperties::Calendar(_) => CALENDAR,
+        EntryProperties::Person(_) => PERSON,
+        EntryProperties::Event(_) => EVENT,
+        EntryProperties::Place(_) => PLACE,
+        EntryProperties::Map(_) => MAP,
+        EntryProperties::Organization(_) => ORGANIZATION,
+    };
+    if properties_type != entity_type {
+        return Err(ErrorBuilder::new()
+            .msg("The properties of the template don't match its entity type.")
+            .entity(TEMPLATE)
+            .attribute("properties")
+            .with_id(id.as_ref())
+            .with_value(&properties_type)
+            .invalid());
+    }
+    Ok(())
+}
+
+fn _normalize_name(id: Option<Uuid>, name: &str) -> Result<String, Error> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ErrorBuilder::new()
+            .msg("Template names can't be empty.")
+            .entity(TEMPLATE)
+            .attribute("name")
+            .with_id(id.as_ref())
+            .with_value(&name)
+            .invalid());
+    }
+    Ok(name.to_owned())
+}
+
+fn _serialize(
+    text: &TextNode,
+    properties: &Option<EntryProperties>,
+    custom_properties: &[CustomPropertyValueSchema],
+) -> Result<SerializedTemplate, Error> {
+    let serialize_failed = |e: serde_json::Error| {
+        ErrorBuilder::new()
+            .msg("Failed to serialize the entry template.")
+            .from_err(e)
+            .entity(TEMPLATE)
+            .not_created()
+    };
+    let properties = match properties {
+        Some(properties) => Some(serde_json::to_value(properties).map_err(serialize_failed)?),
+        None => None,
+    };
+    Ok((
+        serde_json::to_string(text).map_err(serialize_failed)?,
+        properties,
+        serde_json::to_value(custom_properties).map_err(serialize_failed)?,
+    ))
+}
+
+fn _deserialize_column<T: DeserializeOwned>(
+    template: &EntryTemplateModel,
+    key: &str,
+    value: &serde_json::Value,
+) -> Result<T, Error> {
+    serde_json::from_value(value.clone()).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to deserialize the entry template.")
+            .from_err(e)
+            .entity(TEMPLATE)
+            .attribute(key)
+            .with_id(Some(&template.id))
+            .with_value(value)
+            .invalid()
+    })
+}
+
+pub fn generate_response(
+    template: &EntryTemplateModel,
+) -> Result<EntryTemplateResponseSchema, Error> {
+    let text: TextNode = serde_json::from_str(&template.text).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to deserialize the text of the entry template.")
+            .from_err(e)
+            .entity(TEMPLATE)
+            .attribute("text")
+            .with_id(Some(&template.id))
+            .with_value(&template.text)
+            .invalid()
+    })?;
+    let properties = match &template.properties {
+        Some(properties) => Some(_deserialize_column(template, "properties", properties)?),
+        None => None,
+    };
+    Ok(EntryTemplateResponseSchema {
+        id: template.id,
+        entity_type: template.entity_type.into(),
+        name: template.name.to_owned(),
+        text,
+        properties,
+        custom_properties: _deserialize_column(
+            template,
+            "customProperties",
+            &template.custom_properties,
+        )?,
+    })
+}
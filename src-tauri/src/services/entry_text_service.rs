@@ -1,7 +1,7 @@
 use sea_orm::DatabaseConnection;
 use uuid::Uuid;
 
-use crate::database::entry_manager;
+use crate::database::{asset_manager, entry_alias_manager, entry_manager};
 use crate::model::{
     errors::{Error, ErrorBuilder},
     text::TextNode,
@@ -27,7 +27,7 @@ pub async fn sync_text(
     text
 }
 
-fn _parse_text(id: Uuid, text: &str) -> Result<TextNode, Error> {
+pub fn _parse_text(id: Uuid, text: &str) -> Result<TextNode, Error> {
     if text.is_empty() {
         return Ok(TextNode::new_doc());
     }
@@ -53,6 +53,9 @@ async fn _sync_text_node(
 ) {
     if node.is_type("mention") {
         _sync_reference_label(database, id, node, errors).await;
+    } else if node.is_type("image") && node.get_attr("assetId").is_some() {
+        // images without an asset ID point at external sources and are left as they are
+        _sync_asset_source(database, id, node, errors).await;
     }
 
     if let Some(content) = &mut node.content {
@@ -132,7 +135,11 @@ async fn _sync_reference_label(
     };
 
     if let Some(entry) = optional_entry {
-        node.set_attr("label", serde_json::Value::String(entry.title));
+        let label = _get_alias_label(database, entry.id, node, errors).await;
+        node.set_attr(
+            "label",
+            serde_json::Value::String(label.unwrap_or(entry.title)),
+        );
     } else {
         node.set_attr(
             "label",
@@ -147,3 +154,99 @@ async fn _sync_reference_label(
         );
     }
 }
+
+/// Points the image at the file of the asset that it shows.
+async fn _sync_asset_source(
+    database: &DatabaseConnection,
+    id: Uuid,
+    node: &mut TextNode,
+    errors: &mut Vec<Error>,
+) {
+    let Some(asset_id_value) = node.get_attr("assetId").cloned() else {
+        return;
+    };
+
+    let asset_id_string = match asset_id_value.as_str() {
+        Some(asset_id) => asset_id,
+        None => {
+            errors.push(
+                ErrorBuilder::new()
+                    .msg("Asset ID of Image node is not a string.")
+                    .entry(ENTRY)
+                    .with_id(&id)
+                    .bad_text_value_type("assetId", &asset_id_value.to_string(), "Uuid"),
+            );
+            return;
+        }
+    };
+
+    let asset_id = match Uuid::parse_str(asset_id_string) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            errors.push(
+                ErrorBuilder::new()
+                    .msg("Asset ID of Image node is not a valid UUID.")
+                    .entry(ENTRY)
+                    .with_id(&id)
+                    .bad_asset_id(&asset_id_string),
+            );
+            return;
+        }
+    };
+
+    let optional_asset = match asset_manager::get(database, asset_id).await {
+        Ok(optional_asset) => optional_asset,
+        Err(e) => {
+            errors.push(
+                ErrorBuilder::new()
+                    .msg("Failed to query asset table while fetching referenced asset.")
+                    .from_err(e)
+                    .db()
+                    .query_failed(),
+            );
+            return;
+        }
+    };
+
+    if let Some(asset) = optional_asset {
+        node.set_attr("src", serde_json::Value::String(asset.path));
+    } else {
+        node.set_attr("src", serde_json::Value::Null);
+        errors.push(
+            ErrorBuilder::new()
+                .msg("Referenced asset does not exist.")
+                .entry(ENTRY)
+                .with_id(&id)
+                .bad_asset_id(&asset_id),
+        );
+    }
+}
+
+/// Returns the alias that the mention uses as its label, if it's still an alias of the referenced entry.
+/// Stale aliases are cleared, so that the mention falls back to the title.
+async fn _get_alias_label(
+    database: &DatabaseConnection,
+    ref_id: Uuid,
+    node: &mut TextNode,
+    errors: &mut Vec<Error>,
+) -> Option<String> {
+    let alias = node.get_attr("alias")?.as_str()?.to_owned();
+
+    match entry_alias_manager::get_by_alias(database, &alias).await {
+        Ok(Some(entry_alias)) if entry_alias.entry_id == ref_id => Some(alias),
+        Ok(_) => {
+            node.set_attr("alias", serde_json::Value::Null);
+            None
+        }
+        Err(e) => {
+            errors.push(
+                ErrorBuilder::new()
+                    .msg("Failed to query entry_alias table while fetching the alias of a mention.")
+                    .from_err(e)
+                    .db()
+                    .query_failed(),
+            );
+            None
+        }
+    }
+}
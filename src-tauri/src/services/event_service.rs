@@ -0,0 +1,468 @@
+use std::collections::{HashMap, HashSet, hash_map::Entry};
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use ::entity::event::Model as EventModel;
+
+use crate::constants::ROOT_FOLDER_ID;
+use crate::database::{
+    entry_manager, event_manager, event_manager::EventDate, event_participant_manager, file_manager,
+};
+use crate::model::{
+    calendar::{Calendar, FictionalDate},
+    errors::{Error, ErrorBuilder},
+};
+use crate::schema::{
+    calendar::{CalendarDateResponseSchema, CalendarDateSchema},
+    event::{EventSchema, TimelineEventResponseSchema, TimelineQuerySchema},
+};
+use crate::services::{calendar_service, entry_service};
+use crate::types::{calendar::DatePrecision, entity::EVENT};
+
+pub async fn create<C>(con: &C, entry_id: Uuid, properties: &EventSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let (start, end) = _get_dates(con, entry_id, properties).await?;
+    let participant_ids = _validate_references(con, entry_id, properties).await?;
+
+    event_manager::insert(
+        con,
+        entry_id,
+        properties.start.calendar_id,
+        start,
+        end,
+        properties.precision,
+        properties.location_id,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Event not created.")
+            .from_err(e)
+            .entity(EVENT)
+            .not_created()
+    })?;
+
+    _replace_participants(con, entry_id, participant_ids).await
+}
+
+pub async fn update<C>(con: &C, id: Uuid, properties: &EventSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let (start, end) = _get_dates(con, id, properties).await?;
+    let participant_ids = _validate_references(con, id, properties).await?;
+
+    event_manager::update(
+        con,
+        id,
+        properties.start.calendar_id,
+        start,
+        end,
+        properties.precision,
+        properties.location_id,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Event not updated.")
+            .from_err(e)
+            .entity(EVENT)
+            .with_id(&id)
+            .not_updated()
+    })?;
+
+    _replace_participants(con, id, participant_ids).await
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<EventSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    let event = event_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the event table while fetching an event by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let Some(event) = event else {
+        return Err(ErrorBuilder::new()
+            .msg("Event not found.")
+            .entity(EVENT)
+            .with_id(&id)
+            .not_found());
+    };
+
+    let mut participants = _get_participants(con, vec![id]).await?;
+    let participant_ids = participants.remove(&id).unwrap_or_default();
+
+    Ok(generate_response(&event, participant_ids))
+}
+
+/// Returns the events that match the query, in chronological order.
+pub async fn get_timeline(
+    database: &DatabaseConnection,
+    query: TimelineQuerySchema,
+) -> Result<Vec<TimelineEventResponseSchema>, Error> {
+    let after = match query.start {
+        Some(date) => Some(_get_day_number(database, &date).await?),
+        None => None,
+    };
+    let before = match query.end {
+        Some(date) => Some(_get_day_number(database, &date).await?),
+        None => None,
+    };
+
+    let events = event_manager::get_timeline(database, after, before, query.participant_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the event table while fetching the timeline.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let events = match query.folder_id {
+        Some(folder_id) if folder_id != ROOT_FOLDER_ID => {
+            let entry_ids = _get_entries_in_folder(database, folder_id).await?;
+            events
+                .into_iter()
+                .filter(|event| entry_ids.contains(&event.entry_id))
+                .collect()
+        }
+        _ => events,
+    };
+
+    let event_ids: Vec<Uuid> = events.iter().map(|event| event.entry_id).collect();
+    let mut participants = _get_participants(database, event_ids.clone()).await?;
+    let entries = entry_manager::get_many(database, event_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while fetching the entries of events.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let entries: HashMap<Uuid, _> = entries.into_iter().map(|entry| (entry.id, entry)).collect();
+
+    let mut calendars: HashMap<Uuid, Calendar> = HashMap::new();
+    let calendar_ids = events
+        .iter()
+        .map(|event| query.calendar_id.unwrap_or(event.calendar_id));
+    for calendar_id in calendar_ids {
+        if let Entry::Vacant(vacant) = calendars.entry(calendar_id) {
+            vacant.insert(calendar_service::get_calendar(database, calendar_id).await?);
+        }
+    }
+
+    let mut responses: Vec<TimelineEventResponseSchema> = Vec::new();
+    for event in events.iter() {
+        let calendar_id = query.calendar_id.unwrap_or(event.calendar_id);
+        let calendar = &calendars[&calendar_id];
+        let precision = DatePrecision::from(event.precision);
+
+        let start = match query.calendar_id {
+            Some(_) => calendar.date_from_day_number(event.start_day_number),
+            None => _get_start_date(event),
+        };
+        let end = match (query.calendar_id, event.end_day_number) {
+            (Some(_), Some(day_number)) => Some(calendar.date_from_day_number(day_number)),
+            _ => _get_end_date(event),
+        };
+
+        let Some(entry) = entries.get(&event.entry_id) else {
+            continue;
+        };
+        responses.push(TimelineEventResponseSchema {
+            info: entry_service::generate_insert_response(entry),
+            start: _generate_date_response(calendar_id, calendar, start, precision)?,
+            end: end
+                .map(|date| _generate_date_response(calendar_id, calendar, date, precision))
+                .transpose()?,
+            precision,
+            participant_ids: participants.remove(&event.entry_id).unwrap_or_default(),
+            location_id: event.location_id,
+        });
+    }
+
+    // events that start and end on the same days are listed by title
+    responses.sort_by(|a, b| {
+        let end_a = a.end.as_ref().unwrap_or(&a.start).day_number;
+        let end_b = b.end.as_ref().unwrap_or(&b.start).day_number;
+        (a.start.day_number, end_a, &a.info.title).cmp(&(b.start.day_number, end_b, &b.info.title))
+    });
+
+    Ok(responses)
+}
+
+/// Returns the dates of the event along with their day numbers.
+/// Dates are widened to the period given by the precision.
+async fn _get_dates<C>(
+    con: &C,
+    id: Uuid,
+    properties: &EventSchema,
+) -> Result<(EventDate, Option<EventDate>), Error>
+where
+    C: ConnectionTrait,
+{
+    let calendar_id = properties.start.calendar_id;
+    let calendar = calendar_service::get_calendar(con, calendar_id).await?;
+
+    let start = _widen_date(&calendar, &properties.start, properties.precision, false);
+    let start_day_number = calendar_service::get_day_number(&calendar, &start)?;
+
+    let Some(end) = properties.end else {
+        return Ok(((_to_fictional_date(&start), start_day_number), None));
+    };
+
+    if end.calendar_id != calendar_id {
+        return Err(ErrorBuilder::new()
+            .msg("The start and end dates of an event must belong to the same calendar.")
+            .entity(EVENT)
+            .attribute("end")
+            .with_id(Some(&id))
+            .with_value(&end.calendar_id)
+            .invalid());
+    }
+
+    let end = _widen_date(&calendar, &end, properties.precision, true);
+    let end_day_number = calendar_service::get_day_number(&calendar, &end)?;
+    if end_day_number < start_day_number {
+        return Err(ErrorBuilder::new()
+            .msg("An event can't end before it starts.")
+            .entity(EVENT)
+            .attribute("end")
+            .with_id(Some(&id))
+            .with_value(&end_day_number)
+            .invalid());
+    }
+
+    Ok((
+        (_to_fictional_date(&start), start_day_number),
+        Some((_to_fictional_date(&end), end_day_number)),
+    ))
+}
+
+/// Moves the date to the start or the end of the month or year if the day or month is unknown.
+/// Dates that don't exist in the calendar are left as they are, so that they're reported as invalid.
+fn _widen_date(
+    calendar: &Calendar,
+    date: &CalendarDateSchema,
+    precision: DatePrecision,
+    is_end: bool,
+) -> CalendarDateSchema {
+    let mut date = *date;
+    let month_count = calendar.definition().months.len() as u32;
+    match precision {
+        DatePrecision::YearOnly => date.month = if is_end { month_count } else { 1 },
+        DatePrecision::MonthOnly => {}
+        DatePrecision::Exact | DatePrecision::Circa => return date,
+    }
+    date.day = if is_end {
+        calendar
+            .month_length(date.year, date.month)
+            .unwrap_or(date.day)
+    } else {
+        1
+    };
+    date
+}
+
+/// Checks that the location and participants of the event exist,
+/// and returns the IDs of the participants without duplicates.
+async fn _validate_references<C>(
+    con: &C,
+    id: Uuid,
+    properties: &EventSchema,
+) -> Result<Vec<Uuid>, Error>
+where
+    C: ConnectionTrait,
+{
+    if let Some(location_id) = properties.location_id {
+        let exists = entry_manager::exists(con, location_id).await.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while checking the location of an event.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+        if !exists {
+            return Err(ErrorBuilder::new()
+                .msg("The location of an event must be an existing entry.")
+                .entity(EVENT)
+                .attribute("locationId")
+                .with_id(Some(&id))
+                .with_value(&location_id)
+                .invalid());
+        }
+    }
+
+    let mut seen: HashSet<Uuid> = HashSet::new();
+    let participant_ids: Vec<Uuid> = properties
+        .participant_ids
+        .iter()
+        .filter(|participant_id| seen.insert(**participant_id))
+        .copied()
+        .collect();
+
+    let existing_ids = entry_manager::get_existing_ids(con, participant_ids.clone())
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while checking the participants of an event.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if let Some(missing_id) = participant_ids
+        .iter()
+        .find(|participant_id| !existing_ids.contains(participant_id))
+    {
+        return Err(ErrorBuilder::new()
+            .msg("The participants of an event must be existing entries.")
+            .entity(EVENT)
+            .attribute("participantIds")
+            .with_id(Some(&id))
+            .with_value(missing_id)
+            .invalid());
+    }
+
+    Ok(participant_ids)
+}
+
+async fn _replace_participants<C>(
+    con: &C,
+    id: Uuid,
+    participant_ids: Vec<Uuid>,
+) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    event_participant_manager::replace_for_event(con, id, participant_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to update the participants of an event.")
+                .from_err(e)
+                .entity(EVENT)
+                .with_id(&id)
+                .not_updated()
+        })
+}
+
+async fn _get_participants<C>(
+    con: &C,
+    event_ids: Vec<Uuid>,
+) -> Result<HashMap<Uuid, Vec<Uuid>>, Error>
+where
+    C: ConnectionTrait,
+{
+    let participants = event_participant_manager::get_for_events(con, event_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the event_participant table while fetching participants.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut participant_ids: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for participant in participants.into_iter() {
+        participant_ids
+            .entry(participant.event_id)
+            .or_default()
+            .push(participant.participant_id);
+    }
+    Ok(participant_ids)
+}
+
+async fn _get_day_number(
+    database: &DatabaseConnection,
+    date: &CalendarDateSchema,
+) -> Result<i64, Error> {
+    let calendar = calendar_service::get_calendar(database, date.calendar_id).await?;
+    calendar_service::get_day_number(&calendar, date)
+}
+
+async fn _get_entries_in_folder(
+    database: &DatabaseConnection,
+    folder_id: Uuid,
+) -> Result<HashSet<Uuid>, Error> {
+    let nodes = file_manager::get_folder_contents(database, folder_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the contents of a folder.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    Ok(nodes
+        .into_iter()
+        .filter(|node| node.node_type == "entry")
+        .map(|node| node.id)
+        .collect())
+}
+
+/// Formats the date according to its precision, e.g., "c. 3rd of Frostmoon, 412 AE" or "412 AE".
+fn _generate_date_response(
+    calendar_id: Uuid,
+    calendar: &Calendar,
+    date: FictionalDate,
+    precision: DatePrecision,
+) -> Result<CalendarDateResponseSchema, Error> {
+    let mut response = calendar_service::generate_date_response(calendar_id, calendar, date)?;
+    response.formatted = match precision {
+        DatePrecision::Exact => response.formatted,
+        DatePrecision::Circa => format!("c. {}", response.formatted),
+        DatePrecision::MonthOnly => format!(
+            "{}, {}",
+            calendar.definition().months[date.month as usize - 1].name,
+            calendar.format_year(date.year)
+        ),
+        DatePrecision::YearOnly => calendar.format_year(date.year),
+    };
+    Ok(response)
+}
+
+fn _to_fictional_date(date: &CalendarDateSchema) -> FictionalDate {
+    FictionalDate::new(date.year, date.month, date.day)
+}
+
+fn _get_start_date(event: &EventModel) -> FictionalDate {
+    FictionalDate::new(
+        event.start_year,
+        event.start_month as u32,
+        event.start_day as u32,
+    )
+}
+
+fn _get_end_date(event: &EventModel) -> Option<FictionalDate> {
+    Some(FictionalDate::new(
+        event.end_year?,
+        event.end_month? as u32,
+        event.end_day? as u32,
+    ))
+}
+
+fn generate_response(event: &EventModel, participant_ids: Vec<Uuid>) -> EventSchema {
+    let to_schema = |date: FictionalDate| CalendarDateSchema {
+        calendar_id: event.calendar_id,
+        year: date.year,
+        month: date.month,
+        day: date.day,
+    };
+    EventSchema {
+        start: to_schema(_get_start_date(event)),
+        end: _get_end_date(event).map(to_schema),
+        precision: DatePrecision::from(event.precision),
+        participant_ids,
+        location_id: event.location_id,
+    }
+}
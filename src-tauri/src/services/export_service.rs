@@ -0,0 +1,421 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use ::entity::{entry::Model as EntryModel, folder::Model as FolderModel};
+
+use crate::database::{entry_manager, folder_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    text::TextNode,
+};
+use crate::schema::{
+    common::DiagnosticResponseSchema,
+    entry::EntryProperties,
+    export::{MarkdownExportResponseSchema, MarkdownExportSchema, MentionStyle},
+};
+use crate::services::{
+    calendar_service, entry_alias_service, entry_service, entry_text_service, markdown_service,
+    tag_service, word_service,
+};
+use crate::types::entity::EntityType;
+
+const MARKDOWN_FILE_EXTENSION: &str = "md";
+const UNTITLED_FILE_NAME: &str = "Untitled";
+/// Characters that aren't allowed in file names on at least one platform,
+/// along with those that would break wikilinks.
+const RESERVED_FILE_NAME_CHARS: [char; 13] = [
+    '/', '\\', ':', '*', '?', '"', '<', '>', '|', '#', '^', '[', ']',
+];
+
+/// Path of a file relative to the root of the export, one component per directory.
+type RelativePath = Vec<String>;
+
+/// Writes the project to a directory as a tree of Markdown files.
+/// Folders become directories and entries become Markdown files with YAML front matter.
+pub async fn export_markdown(
+    database: &DatabaseConnection,
+    export: MarkdownExportSchema,
+) -> Result<DiagnosticResponseSchema<MarkdownExportResponseSchema>, Error> {
+    let root_path = PathBuf::from(&export.folder_path);
+    _prepare_export_dir(&root_path)?;
+
+    let folders = folder_manager::get_all(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the folder table while exporting the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let entries = entry_manager::get_all_models(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while exporting the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    // the trash isn't part of the vault
+    let entries: Vec<EntryModel> = entries
+        .into_iter()
+        .filter(|entry| entry.deleted_at.is_none())
+        .collect();
+
+    let folder_paths = _generate_folder_paths(&folders);
+    let entry_paths = _generate_entry_paths(&entries, &folder_paths);
+    let titles: HashMap<Uuid, &str> = entries
+        .iter()
+        .map(|entry| (entry.id, entry.title.as_str()))
+        .collect();
+
+    for folder_path in folder_paths.values() {
+        let path = _join_path(&root_path, folder_path);
+        fs::create_dir_all(&path).map_err(|e| Error::FileSystemOperationFailed {
+            msg: format!("Failed to create directory '{}'.", path.display()),
+            error: e.to_string(),
+        })?;
+    }
+
+    let mut errors: Vec<Error> = Vec::new();
+
+    for entry in entries.iter() {
+        let entry_path = &entry_paths[&entry.id];
+
+        // an article that can't be parsed is exported without its body
+        let text = match entry_text_service::_parse_text(entry.id, &entry.text) {
+            Ok(text) => text,
+            Err(e) => {
+                errors.push(e);
+                TextNode::new_doc()
+            }
+        };
+
+        let render_mention = |id: Option<Uuid>, label: &str| {
+            _render_mention(
+                id,
+                label,
+                entry_path,
+                &entry_paths,
+                &titles,
+                export.mention_style,
+            )
+        };
+
+        let front_matter = _generate_front_matter(database, entry, &mut errors).await;
+        let body = markdown_service::render(&text, &render_mention);
+        let markdown = if body.is_empty() {
+            front_matter
+        } else {
+            format!("{front_matter}\n{body}")
+        };
+
+        let path = _join_path(&root_path, entry_path);
+        fs::write(&path, markdown).map_err(|e| Error::FileSystemOperationFailed {
+            msg: format!("Failed to write file '{}'.", path.display()),
+            error: e.to_string(),
+        })?;
+    }
+
+    Ok(DiagnosticResponseSchema {
+        data: MarkdownExportResponseSchema {
+            folder_path: export.folder_path,
+            folder_count: folders.len(),
+            entry_count: entries.len(),
+        },
+        errors,
+    })
+}
+
+/// Creates the export directory, which must be empty if it already exists
+/// so that the export doesn't overwrite any files.
+fn _prepare_export_dir(path: &Path) -> Result<(), Error> {
+    if let Ok(mut contents) = fs::read_dir(path) {
+        return match contents.next() {
+            None => Ok(()),
+            Some(_) => Err(Error::FileSystemOperationFailed {
+                msg: "The export directory must be empty.".to_owned(),
+                error: format!("'{}' is not empty.", path.display()),
+            }),
+        };
+    }
+
+    fs::create_dir_all(path).map_err(|e| Error::FileSystemOperationFailed {
+        msg: "Failed to create the export directory.".to_owned(),
+        error: e.to_string(),
+    })
+}
+
+fn _generate_folder_paths(folders: &[FolderModel]) -> HashMap<Uuid, RelativePath> {
+    let mut children: HashMap<Option<Uuid>, Vec<&FolderModel>> = HashMap::new();
+    for folder in folders.iter() {
+        children.entry(folder.parent_id).or_default().push(folder);
+    }
+
+    let mut paths: HashMap<Uuid, RelativePath> = HashMap::new();
+    let mut stack: Vec<(Option<Uuid>, RelativePath)> = vec![(None, Vec::new())];
+
+    while let Some((parent_id, parent_path)) = stack.pop() {
+        let Some(subfolders) = children.get_mut(&parent_id) else {
+            continue;
+        };
+        subfolders.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // folder names are unique within their parent, but sanitizing them can create collisions
+        let mut taken_names: HashSet<String> = HashSet::new();
+        for folder in subfolders.iter() {
+            let name = _claim_unique_name(&_sanitize_file_name(&folder.name), &mut taken_names);
+            let mut path = parent_path.clone();
+            path.push(name);
+
+            paths.insert(folder.id, path.clone());
+            stack.push((Some(folder.id), path));
+        }
+    }
+
+    paths
+}
+
+fn _generate_entry_paths(
+    entries: &[EntryModel],
+    folder_paths: &HashMap<Uuid, RelativePath>,
+) -> HashMap<Uuid, RelativePath> {
+    // File names are unique across the whole export, not just within their directory,
+    // so that wikilinks can refer to entries by name alone.
+    let mut taken_names: HashSet<String> = HashSet::new();
+
+    entries
+        .iter()
+        .map(|entry| {
+            let name = _claim_unique_name(&_sanitize_file_name(&entry.title), &mut taken_names);
+            let mut path = match entry.folder_id {
+                Some(folder_id) => folder_paths.get(&folder_id).cloned().unwrap_or_default(),
+                None => Vec::new(),
+            };
+            path.push(format!("{name}.{MARKDOWN_FILE_EXTENSION}"));
+            (entry.id, path)
+        })
+        .collect()
+}
+
+async fn _generate_front_matter(
+    database: &DatabaseConnection,
+    entry: &EntryModel,
+    errors: &mut Vec<Error>,
+) -> String {
+    let entity_type = EntityType::from(entry.entity_type);
+
+    let mut lines: Vec<String> = vec![
+        "---".to_owned(),
+        format!("id: {}", entry.id),
+        format!("title: {}", _format_yaml_string(&entry.title)),
+        format!("type: {entity_type}"),
+    ];
+
+    match entry_alias_service::get_all_for_entry(database, entry.id).await {
+        Ok(aliases) if aliases.is_empty() => {}
+        Ok(aliases) => {
+            let aliases: Vec<String> = aliases
+                .iter()
+                .map(|alias| _format_yaml_string(&alias.alias))
+                .collect();
+            lines.push(format!("aliases: [{}]", aliases.join(", ")));
+        }
+        Err(e) => errors.push(e),
+    }
+
+    match tag_service::get_for_entry(database, entry.id).await {
+        Ok(tags) if tags.is_empty() => {}
+        Ok(tags) => {
+            let tags: Vec<String> = tags
+                .iter()
+                .map(|tag| _format_yaml_string(&tag.name))
+                .collect();
+            lines.push(format!("tags: [{}]", tags.join(", ")));
+        }
+        Err(e) => errors.push(e),
+    }
+
+    match entry_service::_get_properties(database, entry.id, entity_type).await {
+        Ok(EntryProperties::Person(person)) => {
+            lines.push(format!("name: {}", _format_yaml_string(&person.name)));
+        }
+        Ok(EntryProperties::Language(_)) => {
+            match word_service::get_all_for_language(database, entry.id, None).await {
+                Ok(words) if words.is_empty() => lines.push("words: []".to_owned()),
+                Ok(words) => {
+                    lines.push("words:".to_owned());
+                    for word in words.iter() {
+                        lines.push(format!(
+                            "  - spelling: {}",
+                            _format_yaml_string(&word.spelling)
+                        ));
+                        lines.push(format!("    type: {}", word.word_type));
+                        lines.push(format!(
+                            "    definition: {}",
+                            _format_yaml_string(&word.definition)
+                        ));
+                        let translations: Vec<String> = word
+                            .translations
+                            .iter()
+                            .map(|t| _format_yaml_string(t))
+                            .collect();
+                        lines.push(format!("    translations: [{}]", translations.join(", ")));
+                    }
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+        Ok(EntryProperties::Calendar(calendar)) => {
+            let months: Vec<String> = calendar
+                .months
+                .iter()
+                .map(|month| _format_yaml_string(&month.name))
+                .collect();
+            lines.push(format!("months: [{}]", months.join(", ")));
+        }
+        Ok(EntryProperties::Event(event)) => {
+            for (key, date) in [("start", Some(event.start)), ("end", event.end)] {
+                let Some(date) = date else {
+                    continue;
+                };
+                match calendar_service::describe_date(database, date).await {
+                    Ok(date) => {
+                        lines.push(format!("{key}: {}", _format_yaml_string(&date.formatted)))
+                    }
+                    Err(e) => errors.push(e),
+                }
+            }
+            lines.push(format!("precision: {}", event.precision));
+        }
+        Ok(EntryProperties::Place(place)) => {
+            lines.push(format!("place: {}", place.place_type));
+            if let Some(parent_id) = place.parent_id {
+                lines.push(format!("parent: {parent_id}"));
+            }
+        }
+        Ok(EntryProperties::Organization(organization)) => {
+            if let Some(parent_id) = organization.parent_id {
+                lines.push(format!("parent: {parent_id}"));
+            }
+        }
+        Ok(EntryProperties::Map(map)) => {
+            if let Some(image_path) = map.image_path {
+                lines.push(format!("image: {}", _format_yaml_string(&image_path)));
+            }
+        }
+        // entries without properties only have their title exported
+        Ok(EntryProperties::Entry(_)) | Err(Error::UnsupportedEntryType { .. }) => {}
+        Err(e) => errors.push(e),
+    }
+
+    lines.push("---".to_owned());
+    lines.join("\n") + "\n"
+}
+
+fn _render_mention(
+    id: Option<Uuid>,
+    label: &str,
+    source_path: &RelativePath,
+    entry_paths: &HashMap<Uuid, RelativePath>,
+    titles: &HashMap<Uuid, &str>,
+    mention_style: MentionStyle,
+) -> String {
+    let target = id.and_then(|id| Some((entry_paths.get(&id)?, *titles.get(&id)?)));
+
+    // dangling mentions are exported as plain text
+    let Some((target_path, title)) = target else {
+        return markdown_service::escape_text(label);
+    };
+
+    match mention_style {
+        MentionStyle::RelativeLink => {
+            let source_dir = &source_path[..source_path.len() - 1];
+            format!(
+                "[{}]({})",
+                markdown_service::escape_text(title),
+                markdown_service::format_link_destination(&_generate_relative_path(
+                    source_dir,
+                    target_path
+                ))
+            )
+        }
+        MentionStyle::Wikilink => {
+            let file_name = target_path
+                .last()
+                .map(|name| name.as_str())
+                .unwrap_or_default();
+            let name = file_name
+                .strip_suffix(&format!(".{MARKDOWN_FILE_EXTENSION}"))
+                .unwrap_or(file_name);
+            let label: String = title
+                .chars()
+                .filter(|c| !['[', ']', '|'].contains(c))
+                .collect();
+            if name == label {
+                format!("[[{name}]]")
+            } else {
+                format!("[[{name}|{label}]]")
+            }
+        }
+    }
+}
+
+fn _generate_relative_path(source_dir: &[String], target_path: &[String]) -> String {
+    let target_dir = &target_path[..target_path.len() - 1];
+    let common_length = source_dir
+        .iter()
+        .zip(target_dir.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut components: Vec<&str> = vec![".."; source_dir.len() - common_length];
+    components.extend(target_path[common_length..].iter().map(|c| c.as_str()));
+    components.join("/")
+}
+
+fn _sanitize_file_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_control() || RESERVED_FILE_NAME_CHARS.contains(&c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    // leading dots would hide the file, and trailing dots and spaces are stripped by Windows
+    let sanitized = sanitized.trim().trim_matches('.').trim();
+
+    if sanitized.is_empty() {
+        UNTITLED_FILE_NAME.to_owned()
+    } else {
+        sanitized.to_owned()
+    }
+}
+
+/// Appends a counter to the name until it doesn't collide with any of the taken names.
+/// The comparison is case-insensitive since not all file systems are case-sensitive.
+fn _claim_unique_name(name: &str, taken_names: &mut HashSet<String>) -> String {
+    let mut unique_name = name.to_owned();
+    let mut counter = 2;
+    while taken_names.contains(&unique_name.to_lowercase()) {
+        unique_name = format!("{name} ({counter})");
+        counter += 1;
+    }
+    taken_names.insert(unique_name.to_lowercase());
+    unique_name
+}
+
+fn _join_path(root_path: &Path, relative_path: &RelativePath) -> PathBuf {
+    let mut path = root_path.to_path_buf();
+    path.extend(relative_path);
+    path
+}
+
+/// JSON strings are valid double-quoted YAML scalars.
+fn _format_yaml_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
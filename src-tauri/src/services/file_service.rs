@@ -1,15 +1,18 @@
-use sea_orm::DatabaseConnection;
+use sea_orm::ConnectionTrait;
 use uuid::Uuid;
 
 use crate::database::file_manager;
 use crate::model::errors::{Error, ErrorBuilder};
 use crate::schema::file::BulkFileResponseSchema;
 
-pub async fn get_folder_contents(
-    database: &DatabaseConnection,
+pub async fn get_folder_contents<C>(
+    con: &C,
     folder_id: Uuid,
-) -> Result<BulkFileResponseSchema, Error> {
-    let contents = file_manager::get_folder_contents(database, folder_id)
+) -> Result<BulkFileResponseSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    let contents = file_manager::get_folder_contents(con, folder_id)
         .await
         .map_err(|e| {
             ErrorBuilder::new()
@@ -1,11 +1,13 @@
-use futures::future;
-use sea_orm::DatabaseConnection;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr};
 use uuid::Uuid;
 
 use ::entity::folder::Model as Folder;
 
-use crate::database::{file_manager, folder_manager};
-use crate::model::errors::{Error, ErrorBuilder};
+use crate::database::{file_manager, folder_manager, transaction_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    journal::{JournalChange, JournalOperation},
+};
 use crate::schema::{
     common::DiagnosticResponseSchema,
     file::BulkFileResponseSchema,
@@ -14,40 +16,163 @@ use crate::schema::{
         FolderUpdateResponseSchema, FolderUpdateSchema, FolderValidationSchema,
     },
 };
-use crate::services::file_service;
+use crate::services::{file_service, journal_service};
 use crate::types::entity::FOLDER;
 
 pub async fn create(
     database: &DatabaseConnection,
     folder: FolderCreateSchema,
 ) -> Result<FolderResponseSchema, Error> {
-    return match folder_manager::insert(database, folder.parent_id, &folder.name).await {
-        Ok(entity) => Ok(generate_response(&entity)),
-        Err(e) => Err(ErrorBuilder::new()
-            .msg("Folder not created.")
-            .from_err(e)
-            .entity(FOLDER)
-            .not_created()),
-    };
+    let txn = transaction_manager::begin(database).await?;
+    let folder = folder_manager::insert(&txn, folder.parent_id, &folder.name)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Folder not created.")
+                .from_err(e)
+                .entity(FOLDER)
+                .not_created()
+        })?;
+
+    let mut change = JournalChange::new();
+    change.push(
+        JournalOperation::TrashFolder { id: folder.id },
+        JournalOperation::RestoreFolder { id: folder.id },
+    );
+    journal_service::record(&txn, "Create folder", change).await?;
+    transaction_manager::end(txn).await?;
+
+    Ok(generate_response(&folder))
 }
 
 pub async fn update(
     database: &DatabaseConnection,
     folder: FolderUpdateSchema,
 ) -> DiagnosticResponseSchema<FolderUpdateResponseSchema> {
+    let txn = match transaction_manager::begin(database).await {
+        Ok(txn) => txn,
+        Err(e) => return _generate_failed_response(&folder, e),
+    };
+    let (mut response, change) = _update_with_change(&txn, folder).await;
+    // the update is rolled back if its change can't be recorded, so that it can always be undone
+    let result = async {
+        journal_service::record(&txn, "Update folder", change).await?;
+        transaction_manager::end(txn).await
+    }
+    .await;
+    if let Err(e) = result {
+        _set_rolled_back(&mut response, e);
+    }
+    response
+}
+
+/// Marks nothing as updated, since the update was rolled back because of the error.
+fn _set_rolled_back(response: &mut DiagnosticResponseSchema<FolderUpdateResponseSchema>, e: Error) {
+    response.data.parent_changed = false;
+    response.data.name_changed = false;
+    response.errors.push(e);
+}
+
+/// Reports that nothing was updated, because of the error.
+fn _generate_failed_response(
+    folder: &FolderUpdateSchema,
+    e: Error,
+) -> DiagnosticResponseSchema<FolderUpdateResponseSchema> {
+    let mut data = FolderUpdateResponseSchema::new(folder);
+    data.parent_changed = false;
+    data.name_changed = false;
+    DiagnosticResponseSchema {
+        data,
+        errors: vec![e],
+    }
+}
+
+/// Updates the folder and returns the change that was made, so that it can be journaled.
+async fn _update_with_change<C>(
+    con: &C,
+    folder: FolderUpdateSchema,
+) -> (
+    DiagnosticResponseSchema<FolderUpdateResponseSchema>,
+    JournalChange,
+)
+where
+    C: ConnectionTrait,
+{
+    let mut change = JournalChange::new();
+    let previous_folder = folder_manager::get(con, folder.id).await;
+    let mut response = _update(con, folder.clone()).await;
+
+    match previous_folder {
+        Ok(Some(previous_folder)) => {
+            let parent_id = folder.parent_id.filter(|_| response.data.parent_changed);
+            let name = folder.name.filter(|_| response.data.name_changed);
+            if parent_id.is_some() || name.is_some() {
+                let undo = FolderUpdateSchema {
+                    id: folder.id,
+                    parent_id: parent_id.map(|_| {
+                        file_manager::convert_null_folder_id_to_root(previous_folder.parent_id)
+                    }),
+                    name: name.as_ref().map(|_| previous_folder.name),
+                    expected_revision: None,
+                };
+                let redo = FolderUpdateSchema {
+                    id: folder.id,
+                    parent_id,
+                    name,
+                    expected_revision: None,
+                };
+                change.push(
+                    JournalOperation::UpdateFolder { folder: undo },
+                    JournalOperation::UpdateFolder { folder: redo },
+                );
+            }
+        }
+        // updating a nonexistent folder fails, so there is nothing to undo
+        Ok(None) => {}
+        Err(e) => response.errors.push(
+            ErrorBuilder::new()
+                .msg("Failed to query the folder table while fetching a folder by ID.")
+                .from_err(e)
+                .db()
+                .query_failed(),
+        ),
+    }
+
+    (response, change)
+}
+
+/// Updates the folder without recording the change in the journal.
+pub async fn _update<C>(
+    con: &C,
+    folder: FolderUpdateSchema,
+) -> DiagnosticResponseSchema<FolderUpdateResponseSchema>
+where
+    C: ConnectionTrait,
+{
     let mut response = FolderUpdateResponseSchema::new(&folder);
     let mut errors: Vec<Error> = Vec::new();
 
-    if let Err(e) = folder_manager::update(database, folder.id, folder.parent_id, folder.name)
-        .await
-        .map_err(|e| {
+    let result = folder_manager::update(
+        con,
+        folder.id,
+        folder.parent_id,
+        folder.name,
+        folder.expected_revision,
+    )
+    .await;
+    let result = match (result, folder.expected_revision) {
+        (Err(DbErr::RecordNotUpdated), Some(expected_revision)) => {
+            Err(_get_revision_conflict(con, folder.id, expected_revision).await)
+        }
+        (result, _) => result.map(|_| ()).map_err(|e| {
             ErrorBuilder::new()
                 .msg("Folder not updated.")
                 .from_err(e)
                 .entity(FOLDER)
                 .not_updated()
-        })
-    {
+        }),
+    };
+    if let Err(e) = result {
         response.parent_changed = false;
         response.name_changed = false;
         errors.push(e);
@@ -59,16 +184,70 @@ pub async fn update(
     }
 }
 
+/// Explains why an update that was based on the expected revision didn't apply,
+/// i.e., because the folder has been written since.
+async fn _get_revision_conflict<C>(con: &C, id: Uuid, expected_revision: i32) -> Error
+where
+    C: ConnectionTrait,
+{
+    match folder_manager::get(con, id).await {
+        Ok(Some(current_folder)) => ErrorBuilder::new()
+            .msg("The folder has been changed since it was last read.")
+            .entity(FOLDER)
+            .with_id(&id)
+            .revision_conflict(
+                expected_revision,
+                current_folder.revision,
+                &generate_response(&current_folder),
+            ),
+        Ok(None) => ErrorBuilder::new()
+            .msg("Folder not found.")
+            .entity(FOLDER)
+            .with_id(&id)
+            .not_found(),
+        Err(e) => ErrorBuilder::new()
+            .msg("Failed to query the folder table while fetching a folder by ID.")
+            .from_err(e)
+            .db()
+            .query_failed(),
+    }
+}
+
 pub async fn bulk_update(
     database: &DatabaseConnection,
     folders: Vec<FolderUpdateSchema>,
 ) -> Vec<DiagnosticResponseSchema<FolderUpdateResponseSchema>> {
-    future::join_all(
-        folders
-            .into_iter()
-            .map(async |folder| update(database, folder).await),
-    )
-    .await
+    let txn = match transaction_manager::begin(database).await {
+        Ok(txn) => txn,
+        Err(e) => {
+            return folders
+                .iter()
+                .map(|folder| _generate_failed_response(folder, e.clone()))
+                .collect();
+        }
+    };
+
+    // the updates share the transaction, so they are made one after another
+    let mut change = JournalChange::new();
+    let mut responses: Vec<DiagnosticResponseSchema<FolderUpdateResponseSchema>> = Vec::new();
+    for folder in folders.into_iter() {
+        let (response, folder_change) = _update_with_change(&txn, folder).await;
+        change.extend(folder_change);
+        responses.push(response);
+    }
+
+    // the folders are updated together, so they are also undone together
+    let result = async {
+        journal_service::record(&txn, "Update folders", change).await?;
+        transaction_manager::end(txn).await
+    }
+    .await;
+    if let Err(e) = result {
+        for response in responses.iter_mut() {
+            _set_rolled_back(response, e.clone());
+        }
+    }
+    responses
 }
 
 pub async fn validate_name(
@@ -197,10 +376,11 @@ pub async fn delete_many(database: &DatabaseConnection, ids: Vec<Uuid>) -> Resul
         })
 }
 
-fn generate_response(folder: &Folder) -> FolderResponseSchema {
+pub fn generate_response(folder: &Folder) -> FolderResponseSchema {
     FolderResponseSchema {
         id: folder.id,
         parent_id: file_manager::convert_null_folder_id_to_root(folder.parent_id),
         name: folder.name.to_string(),
+        revision: folder.revision,
     }
 }
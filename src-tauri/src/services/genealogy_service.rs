@@ -0,0 +1,635 @@
+use std::collections::{HashMap, HashSet, hash_map::Entry};
+
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use ::entity::{entry::Model as EntryModel, person_relationship::Model as RelationshipModel};
+
+use crate::database::{entry_manager, person_manager, person_relationship_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    genealogy::Kinship,
+};
+use crate::schema::{
+    calendar::CalendarDateSchema,
+    entry::EntryInfoResponseSchema,
+    person::{
+        FamilyTreePersonSchema, FamilyTreeRelationshipSchema, FamilyTreeResponseSchema,
+        KinshipResponseSchema, PersonRelationshipCreateSchema, PersonRelationshipResponseSchema,
+        PersonRelationshipUpdateSchema, RelativeResponseSchema,
+    },
+};
+use crate::services::{calendar_service, entry_service};
+use crate::types::{
+    entity::{PERSON, PERSON_RELATIONSHIP},
+    person::PersonRelationshipType,
+};
+
+/// The number of biological parents that a person can have.
+const MAX_BIOLOGICAL_PARENTS: usize = 2;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Lineage {
+    Ancestors,
+    Descendants,
+}
+
+pub async fn add_relationship(
+    database: &DatabaseConnection,
+    relationship: PersonRelationshipCreateSchema,
+) -> Result<PersonRelationshipResponseSchema, Error> {
+    _validate_person(database, relationship.person_id, "personId").await?;
+    _validate_person(database, relationship.relative_id, "relativeId").await?;
+    _validate_new_relationship(database, &relationship).await?;
+    let dates = calendar_service::get_period(
+        database,
+        PERSON_RELATIONSHIP,
+        None,
+        &relationship.start,
+        &relationship.end,
+    )
+    .await?;
+
+    let relationship = person_relationship_manager::insert(
+        database,
+        relationship.person_id,
+        relationship.relative_id,
+        relationship.relationship_type,
+        relationship.is_adoptive,
+        dates,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Person relationship not created.")
+            .from_err(e)
+            .entity(PERSON_RELATIONSHIP)
+            .not_created()
+    })?;
+
+    Ok(
+        _generate_relationship_responses(database, vec![relationship])
+            .await?
+            .remove(0),
+    )
+}
+
+pub async fn update_relationship(
+    database: &DatabaseConnection,
+    relationship: PersonRelationshipUpdateSchema,
+) -> Result<PersonRelationshipResponseSchema, Error> {
+    let id = relationship.id;
+    let existing = _get_relationship(database, id).await?;
+    if existing.is_adoptive && !relationship.is_adoptive {
+        _validate_biological_parents(database, &existing).await?;
+    }
+    let dates = calendar_service::get_period(
+        database,
+        PERSON_RELATIONSHIP,
+        Some(id),
+        &relationship.start,
+        &relationship.end,
+    )
+    .await?;
+
+    let relationship =
+        person_relationship_manager::update(database, id, relationship.is_adoptive, dates)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Person relationship not updated.")
+                    .from_err(e)
+                    .entity(PERSON_RELATIONSHIP)
+                    .with_id(&id)
+                    .not_updated()
+            })?;
+
+    Ok(
+        _generate_relationship_responses(database, vec![relationship])
+            .await?
+            .remove(0),
+    )
+}
+
+pub async fn remove_relationship(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    person_relationship_manager::delete(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Person relationship not deleted.")
+                .from_err(e)
+                .entity(PERSON_RELATIONSHIP)
+                .with_id(&id)
+                .not_deleted()
+        })?;
+    Ok(())
+}
+
+/// Returns the parent and partner relationships in which the person takes part,
+/// sorted by type, then by the names of the people.
+pub async fn get_relationships(
+    database: &DatabaseConnection,
+    person_id: Uuid,
+) -> Result<Vec<PersonRelationshipResponseSchema>, Error> {
+    _validate_person(database, person_id, "personId").await?;
+    let relationships = person_relationship_manager::get_all_for_person(database, person_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the person_relationship table while fetching the relationships of a person.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut responses = _generate_relationship_responses(database, relationships).await?;
+    responses.sort_by(|a, b| {
+        (
+            a.relationship_type as i8,
+            &a.person.title,
+            &a.relative.title,
+        )
+            .cmp(&(
+                b.relationship_type as i8,
+                &b.person.title,
+                &b.relative.title,
+            ))
+    });
+    Ok(responses)
+}
+
+/// Returns the ancestors of the person up to the given number of generations,
+/// sorted by generation, then by title.
+pub async fn get_ancestors(
+    database: &DatabaseConnection,
+    id: Uuid,
+    generations: u32,
+) -> Result<Vec<RelativeResponseSchema>, Error> {
+    _validate_person(database, id, "personId").await?;
+    let lineage = _get_lineage(database, id, Lineage::Ancestors, Some(generations)).await?;
+    _generate_relative_responses(database, lineage).await
+}
+
+/// Returns the descendants of the person up to the given number of generations,
+/// sorted by generation, then by title.
+pub async fn get_descendants(
+    database: &DatabaseConnection,
+    id: Uuid,
+    generations: u32,
+) -> Result<Vec<RelativeResponseSchema>, Error> {
+    _validate_person(database, id, "personId").await?;
+    let lineage = _get_lineage(database, id, Lineage::Descendants, Some(generations)).await?;
+    _generate_relative_responses(database, lineage).await
+}
+
+/// Describes what the relative is to the person, based on their closest common ancestors.
+/// People that aren't related by blood or adoption are only described if they are partners.
+pub async fn get_kinship(
+    database: &DatabaseConnection,
+    person_id: Uuid,
+    relative_id: Uuid,
+) -> Result<KinshipResponseSchema, Error> {
+    _validate_person(database, person_id, "personId").await?;
+    _validate_person(database, relative_id, "relativeId").await?;
+
+    let person_ancestors = _get_lineage(database, person_id, Lineage::Ancestors, None).await?;
+    let relative_ancestors = _get_lineage(database, relative_id, Lineage::Ancestors, None).await?;
+
+    // the closest common ancestors minimize the total number of generations between the people
+    let closest = person_ancestors
+        .iter()
+        .filter_map(|(id, up)| Some((*up, *relative_ancestors.get(id)?)))
+        .min_by_key(|(up, down)| (up + down, *up));
+
+    let Some((up, down)) = closest else {
+        let relationships =
+            person_relationship_manager::get_all_between(database, vec![person_id, relative_id])
+                .await
+                .map_err(|e| {
+                    ErrorBuilder::new()
+                .msg("Failed to query the person_relationship table while checking for partners.")
+                .from_err(e)
+                .db()
+                .query_failed()
+                })?;
+        let is_partner = relationships.iter().any(|relationship| {
+            PersonRelationshipType::from(relationship.relationship_type)
+                == PersonRelationshipType::Partner
+        });
+        return Ok(KinshipResponseSchema {
+            description: is_partner.then(|| "partner".to_owned()),
+            common_ancestors: Vec::new(),
+        });
+    };
+
+    let common_ancestor_ids: Vec<Uuid> = person_ancestors
+        .iter()
+        .filter(|(id, generations)| {
+            **generations == up && relative_ancestors.get(id) == Some(&down)
+        })
+        .map(|(id, _)| *id)
+        .collect();
+
+    // siblings that share one parent are half-siblings if either of them has another parent
+    let count_parents = |ancestors: &HashMap<Uuid, u32>| {
+        ancestors
+            .values()
+            .filter(|generations| **generations == 1)
+            .count()
+    };
+    let is_half = (up, down) == (1, 1)
+        && common_ancestor_ids.len() == 1
+        && (count_parents(&person_ancestors) > 1 || count_parents(&relative_ancestors) > 1);
+
+    let kinship = Kinship {
+        person_generations: up,
+        relative_generations: down,
+        is_half,
+    };
+    let mut common_ancestors: Vec<EntryInfoResponseSchema> =
+        _get_entries(database, common_ancestor_ids)
+            .await?
+            .iter()
+            .map(entry_service::generate_insert_response)
+            .collect();
+    common_ancestors.sort_by(|a, b| a.title.cmp(&b.title));
+
+    Ok(KinshipResponseSchema {
+        description: Some(kinship.describe()),
+        common_ancestors,
+    })
+}
+
+/// Returns the ancestors and descendants of the person up to the given number of generations,
+/// along with their partners and the relationships between all of them.
+pub async fn get_family_tree(
+    database: &DatabaseConnection,
+    id: Uuid,
+    generations: u32,
+) -> Result<FamilyTreeResponseSchema, Error> {
+    _validate_person(database, id, "personId").await?;
+
+    let mut people: HashMap<Uuid, i32> = HashMap::new();
+    for (ancestor_id, up) in
+        _get_lineage(database, id, Lineage::Ancestors, Some(generations)).await?
+    {
+        people.insert(ancestor_id, -(up as i32));
+    }
+    for (descendant_id, down) in
+        _get_lineage(database, id, Lineage::Descendants, Some(generations)).await?
+    {
+        people.insert(descendant_id, down as i32);
+    }
+
+    // partners are drawn next to the people of the tree, but their own families aren't included
+    let partners =
+        person_relationship_manager::get_partners(database, people.keys().copied().collect())
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the person_relationship table while fetching partners.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?;
+    for partner in partners.iter() {
+        for (member_id, partner_id) in [
+            (partner.person_id, partner.relative_id),
+            (partner.relative_id, partner.person_id),
+        ] {
+            if let Some(generation) = people.get(&member_id).copied() {
+                people.entry(partner_id).or_insert(generation);
+            }
+        }
+    }
+
+    let relationships =
+        person_relationship_manager::get_all_between(database, people.keys().copied().collect())
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+            .msg("Failed to query the person_relationship table while fetching a family tree.")
+            .from_err(e)
+            .db()
+            .query_failed()
+            })?;
+
+    let mut tree_people: Vec<FamilyTreePersonSchema> =
+        _get_entries(database, people.keys().copied().collect())
+            .await?
+            .iter()
+            .map(|entry| FamilyTreePersonSchema {
+                person: entry_service::generate_insert_response(entry),
+                generation: people[&entry.id],
+            })
+            .collect();
+    tree_people
+        .sort_by(|a, b| (a.generation, &a.person.title).cmp(&(b.generation, &b.person.title)));
+
+    // people in the trash are left out of the tree, and so are their relationships
+    let tree_ids: HashSet<Uuid> = tree_people.iter().map(|member| member.person.id).collect();
+    Ok(FamilyTreeResponseSchema {
+        root_id: id,
+        people: tree_people,
+        relationships: relationships
+            .iter()
+            .filter(|relationship| {
+                tree_ids.contains(&relationship.person_id)
+                    && tree_ids.contains(&relationship.relative_id)
+            })
+            .map(|relationship| FamilyTreeRelationshipSchema {
+                id: relationship.id,
+                person_id: relationship.person_id,
+                relative_id: relationship.relative_id,
+                relationship_type: relationship.relationship_type.into(),
+                is_adoptive: relationship.is_adoptive,
+            })
+            .collect(),
+    })
+}
+
+/// Walks the family one generation at a time and returns the number of generations to each person,
+/// including the person themselves at generation 0.
+async fn _get_lineage(
+    database: &DatabaseConnection,
+    id: Uuid,
+    lineage: Lineage,
+    max_generations: Option<u32>,
+) -> Result<HashMap<Uuid, u32>, Error> {
+    let mut generations = HashMap::from([(id, 0)]);
+    let mut current: Vec<Uuid> = vec![id];
+    let mut generation = 0;
+    while !current.is_empty() && max_generations.is_none_or(|max| generation < max) {
+        generation += 1;
+        let relationships = match lineage {
+            Lineage::Ancestors => person_relationship_manager::get_parents(database, current).await,
+            Lineage::Descendants => {
+                person_relationship_manager::get_children(database, current).await
+            }
+        }
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the person_relationship table while walking a family tree.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+        let mut next: HashSet<Uuid> = HashSet::new();
+        for relationship in relationships.iter() {
+            let next_id = match lineage {
+                Lineage::Ancestors => relationship.person_id,
+                Lineage::Descendants => relationship.relative_id,
+            };
+            if let Entry::Vacant(vacant) = generations.entry(next_id) {
+                vacant.insert(generation);
+                next.insert(next_id);
+            }
+        }
+        current = next.into_iter().collect();
+    }
+    Ok(generations)
+}
+
+async fn _validate_new_relationship(
+    database: &DatabaseConnection,
+    relationship: &PersonRelationshipCreateSchema,
+) -> Result<(), Error> {
+    let invalid = |msg: &str| {
+        ErrorBuilder::new()
+            .msg(msg)
+            .entity(PERSON_RELATIONSHIP)
+            .attribute("relativeId")
+            .with_value(&relationship.relative_id)
+            .invalid()
+    };
+    if relationship.person_id == relationship.relative_id {
+        return Err(invalid("A person can't be related to themselves."));
+    }
+
+    let existing = person_relationship_manager::get_all_between(
+        database,
+        vec![relationship.person_id, relationship.relative_id],
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the person_relationship table while checking for existing relationships.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let is_duplicate = existing.iter().any(|existing| {
+        PersonRelationshipType::from(existing.relationship_type) == relationship.relationship_type
+    });
+    if is_duplicate {
+        return Err(ErrorBuilder::new()
+            .msg("The people are already related this way.")
+            .entity(PERSON_RELATIONSHIP)
+            .attribute("relativeId")
+            .with_value(&relationship.relative_id)
+            .not_unique());
+    }
+
+    if relationship.relationship_type == PersonRelationshipType::Parent {
+        let ancestors =
+            _get_lineage(database, relationship.person_id, Lineage::Ancestors, None).await?;
+        if ancestors.contains_key(&relationship.relative_id) {
+            return Err(invalid(
+                "A person can't be the parent of one of their ancestors.",
+            ));
+        }
+        if !relationship.is_adoptive {
+            let parents = _get_biological_parents(database, relationship.relative_id).await?;
+            if parents.len() >= MAX_BIOLOGICAL_PARENTS {
+                return Err(invalid(
+                    "A person can't have more than two biological parents.",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that an adoptive parent relationship can become biological.
+async fn _validate_biological_parents(
+    database: &DatabaseConnection,
+    relationship: &RelationshipModel,
+) -> Result<(), Error> {
+    if PersonRelationshipType::from(relationship.relationship_type)
+        != PersonRelationshipType::Parent
+    {
+        return Ok(());
+    }
+    let parents = _get_biological_parents(database, relationship.relative_id).await?;
+    if parents.len() >= MAX_BIOLOGICAL_PARENTS {
+        return Err(ErrorBuilder::new()
+            .msg("A person can't have more than two biological parents.")
+            .entity(PERSON_RELATIONSHIP)
+            .attribute("isAdoptive")
+            .with_id(Some(&relationship.id))
+            .with_value(&false)
+            .invalid());
+    }
+    Ok(())
+}
+
+async fn _get_biological_parents(
+    database: &DatabaseConnection,
+    child_id: Uuid,
+) -> Result<Vec<RelationshipModel>, Error> {
+    let parents = person_relationship_manager::get_parents(database, vec![child_id])
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the person_relationship table while fetching the parents of a person.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    Ok(parents
+        .into_iter()
+        .filter(|parent| !parent.is_adoptive)
+        .collect())
+}
+
+async fn _get_relationship(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<RelationshipModel, Error> {
+    let relationship = person_relationship_manager::get(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the person_relationship table while fetching a relationship by ID.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    relationship.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Person relationship not found.")
+            .entity(PERSON_RELATIONSHIP)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+async fn _validate_person(
+    database: &DatabaseConnection,
+    person_id: Uuid,
+    attribute: &str,
+) -> Result<(), Error> {
+    let person = person_manager::get(database, person_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the person table while checking a relative.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if person.is_none() {
+        return Err(ErrorBuilder::new()
+            .msg("Family relationships can only be between existing people.")
+            .entity(PERSON)
+            .attribute(attribute)
+            .with_value(&person_id)
+            .invalid());
+    }
+    Ok(())
+}
+
+async fn _get_entries(
+    database: &DatabaseConnection,
+    ids: Vec<Uuid>,
+) -> Result<Vec<EntryModel>, Error> {
+    entry_manager::get_many(database, ids).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while fetching the entries of a family.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })
+}
+
+async fn _generate_relative_responses(
+    database: &DatabaseConnection,
+    lineage: HashMap<Uuid, u32>,
+) -> Result<Vec<RelativeResponseSchema>, Error> {
+    let ids = lineage
+        .iter()
+        .filter(|(_, generation)| **generation > 0)
+        .map(|(id, _)| *id)
+        .collect();
+    let mut responses: Vec<RelativeResponseSchema> = _get_entries(database, ids)
+        .await?
+        .iter()
+        .map(|entry| RelativeResponseSchema {
+            person: entry_service::generate_insert_response(entry),
+            generation: lineage[&entry.id],
+        })
+        .collect();
+    responses.sort_by(|a, b| (a.generation, &a.person.title).cmp(&(b.generation, &b.person.title)));
+    Ok(responses)
+}
+
+async fn _generate_relationship_responses(
+    database: &DatabaseConnection,
+    relationships: Vec<RelationshipModel>,
+) -> Result<Vec<PersonRelationshipResponseSchema>, Error> {
+    let ids = relationships
+        .iter()
+        .flat_map(|relationship| [relationship.person_id, relationship.relative_id])
+        .collect();
+    let entries: HashMap<Uuid, EntryModel> = _get_entries(database, ids)
+        .await?
+        .into_iter()
+        .map(|entry| (entry.id, entry))
+        .collect();
+
+    Ok(relationships
+        .iter()
+        .filter_map(|relationship| {
+            let person = entries.get(&relationship.person_id)?;
+            let relative = entries.get(&relationship.relative_id)?;
+            Some(generate_relationship_response(
+                relationship,
+                person,
+                relative,
+            ))
+        })
+        .collect())
+}
+
+fn generate_relationship_response(
+    relationship: &RelationshipModel,
+    person: &EntryModel,
+    relative: &EntryModel,
+) -> PersonRelationshipResponseSchema {
+    let to_date = |year: Option<i64>, month: Option<i32>, day: Option<i32>| {
+        Some(CalendarDateSchema {
+            calendar_id: relationship.calendar_id?,
+            year: year?,
+            month: month? as u32,
+            day: day? as u32,
+        })
+    };
+    PersonRelationshipResponseSchema {
+        id: relationship.id,
+        person: entry_service::generate_insert_response(person),
+        relative: entry_service::generate_insert_response(relative),
+        relationship_type: relationship.relationship_type.into(),
+        is_adoptive: relationship.is_adoptive,
+        start: to_date(
+            relationship.start_year,
+            relationship.start_month,
+            relationship.start_day,
+        ),
+        end: to_date(
+            relationship.end_year,
+            relationship.end_month,
+            relationship.end_day,
+        ),
+    }
+}
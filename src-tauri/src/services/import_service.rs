@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use crate::constants::ROOT_FOLDER_ID;
+use crate::database::{entry_manager, folder_manager, transaction_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    journal::{JournalChange, JournalOperation},
+};
+use crate::schema::{
+    common::DiagnosticResponseSchema,
+    entry::{EntryCreateSchema, EntryProperties, EntryUpdateSchema, GenericEntrySchema},
+    import::{MarkdownImportResponseSchema, MarkdownImportSchema},
+};
+use crate::services::{entry_service, journal_service, markdown_service};
+use crate::types::entity::{ENTRY, EntityType, FOLDER};
+
+const MARKDOWN_FILE_EXTENSION: &str = "md";
+
+struct ImportedFile {
+    id: Uuid,
+    path: PathBuf,
+}
+
+/// Imports a directory of Markdown files, such as an Obsidian vault.
+/// Directories become folders and Markdown files become entries titled after the file.
+/// Wikilinks between the files are converted into mentions.
+///
+/// Files whose title is already taken are skipped and reported as errors.
+/// Directories are merged into existing folders of the same name, and symbolic links are skipped.
+/// The whole import is made in a single transaction and recorded in the journal as a single change,
+/// so that an import that fails midway leaves nothing behind.
+pub async fn import_markdown(
+    database: &DatabaseConnection,
+    import: MarkdownImportSchema,
+) -> Result<DiagnosticResponseSchema<MarkdownImportResponseSchema>, Error> {
+    let root_path = PathBuf::from(&import.folder_path);
+    if !root_path.is_dir() {
+        return Err(Error::FileSystemOperationFailed {
+            msg: "The import directory does not exist.".to_owned(),
+            error: format!("'{}' is not a directory.", root_path.display()),
+        });
+    }
+    let txn = transaction_manager::begin(database).await?;
+    _validate_folder(&txn, import.folder_id).await?;
+
+    let mut errors: Vec<Error> = Vec::new();
+    let mut created_folder_ids: HashSet<Uuid> = HashSet::new();
+    let mut files: Vec<ImportedFile> = Vec::new();
+    // undoing the import moves the created folders and entries to the trash;
+    // folders and entries inside a created folder go along with it
+    let mut change = JournalChange::new();
+
+    // the directories are walked breadth-first so that, if several files share a title,
+    // the one that's closest to the root of the vault is imported
+    let mut queue: VecDeque<(PathBuf, Uuid)> = VecDeque::from([(root_path, import.folder_id)]);
+
+    while let Some((dir_path, folder_id)) = queue.pop_front() {
+        let paths = match _read_dir(&dir_path) {
+            Ok(paths) => paths,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+
+        for path in paths {
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            // hidden directories hold the configuration of the vault rather than notes
+            if name.starts_with('.') {
+                continue;
+            }
+            // links aren't followed, since a link back into the vault would be walked forever
+            let Ok(metadata) = fs::symlink_metadata(&path) else {
+                continue;
+            };
+            if metadata.is_symlink() {
+                continue;
+            }
+
+            if metadata.is_dir() {
+                let (id, is_created) = _get_or_create_folder(&txn, folder_id, name).await?;
+                if is_created {
+                    if !created_folder_ids.contains(&folder_id) {
+                        change.push(
+                            JournalOperation::TrashFolder { id },
+                            JournalOperation::RestoreFolder { id },
+                        );
+                    }
+                    created_folder_ids.insert(id);
+                }
+                queue.push_back((path, id));
+            } else if _is_markdown_file(&path) {
+                let Some(title) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                    continue;
+                };
+                if let Some(id) = _create_entry(&txn, folder_id, title, &mut errors).await? {
+                    if !created_folder_ids.contains(&folder_id) {
+                        change.push(
+                            JournalOperation::TrashEntry { id },
+                            JournalOperation::RestoreEntry { id },
+                        );
+                    }
+                    files.push(ImportedFile { id, path });
+                }
+            }
+        }
+    }
+
+    let titles = _get_title_index(&txn, &files).await?;
+    let resolve_mention = |target: &str| titles.get(&_normalize_link_target(target)).copied();
+
+    for file in files.iter() {
+        let markdown = match fs::read_to_string(&file.path) {
+            Ok(markdown) => markdown,
+            Err(e) => {
+                errors.push(Error::FileSystemOperationFailed {
+                    msg: format!("Failed to read file '{}'.", file.path.display()),
+                    error: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let text = markdown_service::parse(&markdown, &resolve_mention);
+        let text = serde_json::to_string(&text).map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to serialize the text of an imported entry.")
+                .from_err(e)
+                .entity(ENTRY)
+                .with_id(&file.id)
+                .not_updated()
+        })?;
+
+        let entry = EntryUpdateSchema {
+            id: file.id,
+            folder_id: None,
+            title: None,
+            properties: None,
+            text: Some(text),
+            words: None,
+            custom_properties: None,
+            expected_revision: None,
+        };
+        let response = entry_service::_update_with_revision(&txn, entry, false).await;
+        errors.extend(response.errors);
+    }
+
+    // the text of the imported entries is left out of the change, since it goes to the trash with them
+    journal_service::record(&txn, "Import", change).await?;
+    transaction_manager::end(txn).await?;
+
+    Ok(DiagnosticResponseSchema {
+        data: MarkdownImportResponseSchema {
+            folder_count: created_folder_ids.len(),
+            entry_count: files.len(),
+        },
+        errors,
+    })
+}
+
+async fn _validate_folder<C>(con: &C, folder_id: Uuid) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    if folder_id == ROOT_FOLDER_ID {
+        return Ok(());
+    }
+
+    let exists = folder_manager::exists(con, folder_id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the folder table while checking whether a folder exists.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    if exists {
+        Ok(())
+    } else {
+        Err(ErrorBuilder::new()
+            .msg("Folder not found.")
+            .entity(FOLDER)
+            .with_id(&folder_id)
+            .not_found())
+    }
+}
+
+/// Returns the paths of the directory's children in alphabetical order.
+fn _read_dir(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let contents = fs::read_dir(path).map_err(|e| Error::FileSystemOperationFailed {
+        msg: format!("Failed to read directory '{}'.", path.display()),
+        error: e.to_string(),
+    })?;
+
+    let mut paths: Vec<PathBuf> = contents
+        .filter_map(|dir_entry| dir_entry.ok())
+        .map(|dir_entry| dir_entry.path())
+        .collect();
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Returns the ID of the folder with the given name at the given location, creating it if needed.
+/// Also returns `true` if the folder was created.
+async fn _get_or_create_folder<C>(
+    con: &C,
+    parent_id: Uuid,
+    name: &str,
+) -> Result<(Uuid, bool), Error>
+where
+    C: ConnectionTrait,
+{
+    let folder = folder_manager::query(Some(parent_id), Some(name.to_owned()))
+        .one(con)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the folder table while fetching a folder by name.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    if let Some(folder) = folder {
+        return Ok((folder.id, false));
+    }
+
+    let folder = folder_manager::insert(con, parent_id, name)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Folder not created.")
+                .from_err(e)
+                .entity(FOLDER)
+                .not_created()
+        })?;
+
+    Ok((folder.id, true))
+}
+
+/// Creates an empty generic entry for a Markdown file.
+/// Returns `None` if the title is already taken, in which case the collision is reported.
+async fn _create_entry<C>(
+    con: &C,
+    folder_id: Uuid,
+    title: &str,
+    errors: &mut Vec<Error>,
+) -> Result<Option<Uuid>, Error>
+where
+    C: ConnectionTrait,
+{
+    let entry = EntryCreateSchema {
+        folder_id,
+        entity_type: EntityType::Entry,
+        title: title.to_owned(),
+        properties: EntryProperties::Entry(GenericEntrySchema {}),
+        template_id: None,
+    };
+    match entry_service::_create_in_txn(con, entry).await {
+        Ok(entry) => Ok(Some(entry.id)),
+        Err(e @ Error::AttributeNotUnique { .. }) => {
+            errors.push(e);
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Maps the normalized titles of all entries to their IDs.
+/// Imported entries take precedence over existing entries whose titles only differ in case.
+async fn _get_title_index<C>(
+    con: &C,
+    files: &[ImportedFile],
+) -> Result<HashMap<String, Uuid>, Error>
+where
+    C: ConnectionTrait,
+{
+    let entries = entry_manager::get_all(con).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while fetching all entries.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    let imported_ids: HashSet<Uuid> = files.iter().map(|file| file.id).collect();
+
+    let mut titles: HashMap<String, Uuid> = HashMap::new();
+    let mut imported_titles: Vec<(String, Uuid)> = Vec::new();
+    for entry in entries.into_iter() {
+        let title = entry.title.to_lowercase();
+        if imported_ids.contains(&entry.id) {
+            imported_titles.push((title, entry.id));
+        } else {
+            titles.insert(title, entry.id);
+        }
+    }
+    titles.extend(imported_titles);
+
+    Ok(titles)
+}
+
+/// Reduces a link target to the title of the entry that it refers to.
+/// Obsidian resolves links case-insensitively and allows them to point at
+/// a path, a heading (`Page#Heading`) or a block (`Page#^block`).
+fn _normalize_link_target(target: &str) -> String {
+    let target = _decode_percent_encoding(target);
+    let path = target.split('#').next().unwrap_or_default();
+    let name = path.rsplit('/').next().unwrap_or_default();
+    let name = match name.rsplit_once('.') {
+        Some((stem, extension)) if extension.eq_ignore_ascii_case(MARKDOWN_FILE_EXTENSION) => stem,
+        _ => name,
+    };
+    name.trim().to_lowercase()
+}
+
+/// Markdown links to files with spaces in their names are often percent-encoded.
+fn _decode_percent_encoding(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn _is_markdown_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case(MARKDOWN_FILE_EXTENSION))
+}
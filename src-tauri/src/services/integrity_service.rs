@@ -0,0 +1,431 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use sea_orm::DatabaseConnection;
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use ::entity::entry::Model as EntryModel;
+
+use crate::constants::ROOT_FOLDER_ID;
+use crate::database::{
+    entry_manager, file_manager, folder_manager, language_manager, word_manager,
+};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    text::TextNode,
+};
+use crate::schema::{
+    common::DiagnosticResponseSchema,
+    entry::{EntryProperties, EntryUpdateSchema},
+    integrity::{IntegrityIssueSchema, IntegrityIssueType, IntegrityReportSchema},
+    language::LanguageSchema,
+    organization::OrganizationSchema,
+    person::PersonSchema,
+    place::PlaceSchema,
+};
+use crate::services::{entry_service, entry_text_service, trash_service};
+use crate::types::entity::{ENTRY, EntityType, FOLDER, WORD};
+use crate::utils::CodedEnum;
+
+/// Scans the project for inconsistencies, e.g., ones left behind by editing the database by hand.
+/// If `repair` is set, then the issues that can be fixed without the user's input are fixed;
+/// the others are only reported. Errors raised by the repairs are returned along with the report.
+pub async fn check(
+    database: &DatabaseConnection,
+    repair: bool,
+) -> Result<DiagnosticResponseSchema<IntegrityReportSchema>, Error> {
+    let mut issues: Vec<IntegrityIssueSchema> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
+
+    _check_entries(database, repair, &mut issues, &mut errors).await?;
+    _check_words(database, repair, &mut issues, &mut errors).await?;
+    // cycles are broken first, since doing so moves folders to the root folder
+    _check_folder_cycles(database, repair, &mut issues, &mut errors).await?;
+    _check_folder_names(database, repair, &mut issues, &mut errors).await?;
+
+    Ok(DiagnosticResponseSchema {
+        data: IntegrityReportSchema { issues },
+        errors,
+    })
+}
+
+async fn _check_entries(
+    database: &DatabaseConnection,
+    repair: bool,
+    issues: &mut Vec<IntegrityIssueSchema>,
+    errors: &mut Vec<Error>,
+) -> Result<(), Error> {
+    let entries = entry_manager::get_all_models(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while checking the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let entry_ids: HashSet<Uuid> = entries.iter().map(|entry| entry.id).collect();
+
+    for entry in entries.iter() {
+        // the rest of the entry can't be checked without knowing which type it is
+        let Some(entity_type) = EntityType::iter().find(|value| value.code() == entry.entity_type)
+        else {
+            issues.push(IntegrityIssueSchema::new(
+                IntegrityIssueType::UnknownEntityType,
+                ENTRY,
+                entry.id,
+                None,
+                "The entry has a type that doesn't exist.",
+            ));
+            continue;
+        };
+
+        match entry_text_service::_parse_text(entry.id, &entry.text) {
+            Ok(mut text) => {
+                let mut missing_ids: Vec<Uuid> = Vec::new();
+                _remove_missing_mentions(&mut text, &entry_ids, &mut missing_ids);
+                if !missing_ids.is_empty() {
+                    let repaired = repair && _update_text(database, entry.id, &text, errors).await;
+                    for missing_id in missing_ids {
+                        let mut issue = IntegrityIssueSchema::new(
+                            IntegrityIssueType::MissingMentionTarget,
+                            entity_type,
+                            entry.id,
+                            Some(missing_id),
+                            "The entry mentions an entry that doesn't exist.",
+                        );
+                        issue.repaired = repaired;
+                        issues.push(issue);
+                    }
+                }
+            }
+            // the text can't be repaired without losing what the user wrote
+            Err(_) => issues.push(IntegrityIssueSchema::new(
+                IntegrityIssueType::InvalidText,
+                entity_type,
+                entry.id,
+                None,
+                "The text of the entry can't be parsed.",
+            )),
+        }
+
+        // generic entries only have custom properties
+        if matches!(entity_type, EntityType::Entry) {
+            continue;
+        }
+        match entry_service::_get_properties(database, entry.id, entity_type).await {
+            Ok(_) => {}
+            Err(Error::NotFound { .. }) => {
+                let mut issue = IntegrityIssueSchema::new(
+                    IntegrityIssueType::MissingProperties,
+                    entity_type,
+                    entry.id,
+                    None,
+                    "The entry has no properties.",
+                );
+                let properties = _get_default_properties(entry, entity_type).filter(|_| repair);
+                if let Some(properties) = properties {
+                    match entry_service::_create_properties(database, entry.id, &properties).await {
+                        Ok(()) => issue.repaired = true,
+                        Err(e) => errors.push(e),
+                    }
+                }
+                issues.push(issue);
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces the mentions of entries that don't exist with their labels,
+/// and collects the IDs of the missing entries.
+fn _remove_missing_mentions(
+    node: &mut TextNode,
+    entry_ids: &HashSet<Uuid>,
+    missing_ids: &mut Vec<Uuid>,
+) {
+    let Some(content) = &mut node.content else {
+        return;
+    };
+
+    for child in content.iter_mut() {
+        // malformed mentions are reported when the text is fetched, so they're skipped here
+        let mention_id = child
+            .get_attr("id")
+            .and_then(|id| id.as_str())
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .filter(|_| child.is_type("mention"));
+        match mention_id {
+            Some(mention_id) if !entry_ids.contains(&mention_id) => {
+                missing_ids.push(mention_id);
+                let label = child
+                    .get_attr("label")
+                    .and_then(|label| label.as_str())
+                    .unwrap_or_default()
+                    .to_owned();
+                *child = TextNode::new_text(label);
+            }
+            _ => _remove_missing_mentions(child, entry_ids, missing_ids),
+        }
+    }
+    // text nodes can't be empty, so mentions without a label are dropped
+    content.retain(|child| !child.is_type("text") || child.text.as_deref() != Some(""));
+}
+
+/// Returns the properties that an entry is given if its own are missing,
+/// or nothing if the type of the entry has no sensible defaults.
+fn _get_default_properties(entry: &EntryModel, entity_type: EntityType) -> Option<EntryProperties> {
+    match entity_type {
+        EntityType::Language => Some(EntryProperties::Language(LanguageSchema::default())),
+        EntityType::Person => Some(EntryProperties::Person(PersonSchema {
+            name: entry.title.to_owned(),
+        })),
+        EntityType::Place => Some(EntryProperties::Place(PlaceSchema::default())),
+        EntityType::Organization => {
+            Some(EntryProperties::Organization(OrganizationSchema::default()))
+        }
+        // calendars, events and maps can't be defined without the user's input
+        _ => None,
+    }
+}
+
+async fn _update_text(
+    database: &DatabaseConnection,
+    id: Uuid,
+    text: &TextNode,
+    errors: &mut Vec<Error>,
+) -> bool {
+    let text = match serde_json::to_string(text) {
+        Ok(text) => text,
+        Err(e) => {
+            errors.push(
+                ErrorBuilder::new()
+                    .msg("Failed to serialize the text of a repaired entry.")
+                    .from_err(e)
+                    .entity(ENTRY)
+                    .with_id(&id)
+                    .not_updated(),
+            );
+            return false;
+        }
+    };
+
+    let entry = EntryUpdateSchema {
+        id,
+        folder_id: None,
+        title: None,
+        properties: None,
+        text: Some(text),
+        words: None,
+        custom_properties: None,
+        expected_revision: None,
+    };
+    let response = entry_service::_update_with_revision(database, entry, false).await;
+    errors.extend(response.errors);
+    response.data.text.updated
+}
+
+async fn _check_words(
+    database: &DatabaseConnection,
+    repair: bool,
+    issues: &mut Vec<IntegrityIssueSchema>,
+    errors: &mut Vec<Error>,
+) -> Result<(), Error> {
+    let words = word_manager::get_all(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the word table while checking the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let languages = language_manager::get_all(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the language table while checking the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    // the language row of an entry is left behind if the entry is deleted by hand
+    let language_ids: HashSet<Uuid> = entry_manager::get_existing_ids(
+        database,
+        languages.iter().map(|language| language.entry_id).collect(),
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while checking the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?
+    .into_iter()
+    .collect();
+
+    for word in words
+        .iter()
+        .filter(|word| !language_ids.contains(&word.language_id))
+    {
+        let mut issue = IntegrityIssueSchema::new(
+            IntegrityIssueType::OrphanedWord,
+            WORD,
+            word.id,
+            Some(word.language_id),
+            "The language of the word doesn't exist.",
+        );
+        if repair {
+            match word_manager::delete(database, word.id).await {
+                Ok(_) => issue.repaired = true,
+                Err(e) => errors.push(
+                    ErrorBuilder::new()
+                        .msg("Word not deleted.")
+                        .from_err(e)
+                        .entity(WORD)
+                        .with_id(&word.id)
+                        .not_deleted(),
+                ),
+            }
+        }
+        issues.push(issue);
+    }
+
+    Ok(())
+}
+
+/// Reports one folder of each cycle; repairing the cycle moves that folder to the root folder.
+async fn _check_folder_cycles(
+    database: &DatabaseConnection,
+    repair: bool,
+    issues: &mut Vec<IntegrityIssueSchema>,
+    errors: &mut Vec<Error>,
+) -> Result<(), Error> {
+    let folders = folder_manager::get_all_models(database)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the folder table while checking the project.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let parent_ids: HashMap<Uuid, Option<Uuid>> = folders
+        .iter()
+        .map(|folder| (folder.id, folder.parent_id))
+        .collect();
+
+    let ids: Vec<Uuid> = folders.iter().map(|folder| folder.id).collect();
+    for cycle in _find_folder_cycles(&ids, &parent_ids) {
+        let id = *cycle.iter().min().unwrap();
+        let folder = folders.iter().find(|folder| folder.id == id).unwrap();
+
+        let mut issue = IntegrityIssueSchema::new(
+            IntegrityIssueType::FolderCycle,
+            FOLDER,
+            id,
+            folder.parent_id,
+            "The folder is one of its own ancestors.",
+        );
+        if repair {
+            match _move_folder(database, id, ROOT_FOLDER_ID, &folder.name).await {
+                Ok(()) => issue.repaired = true,
+                Err(e) => errors.push(e),
+            }
+        }
+        issues.push(issue);
+    }
+
+    Ok(())
+}
+
+/// Walks up from each folder until reaching the root folder or a folder that has been visited;
+/// reaching a folder of the current walk closes a cycle.
+fn _find_folder_cycles(ids: &[Uuid], parent_ids: &HashMap<Uuid, Option<Uuid>>) -> Vec<Vec<Uuid>> {
+    let mut cycles: Vec<Vec<Uuid>> = Vec::new();
+    let mut visited_ids: HashSet<Uuid> = HashSet::new();
+    for id in ids {
+        let mut path: Vec<Uuid> = Vec::new();
+        let mut current_id = Some(*id);
+        while let Some(id) = current_id {
+            if !visited_ids.insert(id) {
+                if let Some(start) = path.iter().position(|path_id| *path_id == id) {
+                    cycles.push(path[start..].to_vec());
+                }
+                break;
+            }
+            path.push(id);
+            current_id = parent_ids.get(&id).copied().flatten();
+        }
+    }
+    cycles
+}
+
+/// Reports every folder but the first of each group of siblings with the same name;
+/// repairing the duplicates renames them to "Name (2)", "Name (3)", etc.
+async fn _check_folder_names(
+    database: &DatabaseConnection,
+    repair: bool,
+    issues: &mut Vec<IntegrityIssueSchema>,
+    errors: &mut Vec<Error>,
+) -> Result<(), Error> {
+    let folders = folder_manager::get_all(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the folder table while checking the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    let mut siblings: BTreeMap<(Uuid, &str), Vec<Uuid>> = BTreeMap::new();
+    for folder in folders.iter() {
+        let parent_id = file_manager::convert_null_folder_id_to_root(folder.parent_id);
+        siblings
+            .entry((parent_id, folder.name.as_str()))
+            .or_default()
+            .push(folder.id);
+    }
+
+    for ((parent_id, name), mut ids) in siblings.into_iter() {
+        ids.sort();
+        let Some((kept_id, duplicate_ids)) = ids.split_first() else {
+            continue;
+        };
+        for id in duplicate_ids {
+            let mut issue = IntegrityIssueSchema::new(
+                IntegrityIssueType::DuplicateFolderName,
+                FOLDER,
+                *id,
+                Some(*kept_id),
+                "The folder has the same name as one of its siblings.",
+            );
+            if repair {
+                match _move_folder(database, *id, parent_id, name).await {
+                    Ok(()) => issue.repaired = true,
+                    Err(e) => errors.push(e),
+                }
+            }
+            issues.push(issue);
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves the folder into the parent under the first free variant of the name.
+async fn _move_folder(
+    database: &DatabaseConnection,
+    id: Uuid,
+    parent_id: Uuid,
+    name: &str,
+) -> Result<(), Error> {
+    let name = trash_service::_get_free_folder_name(database, parent_id, name).await?;
+    folder_manager::update(database, id, Some(parent_id), Some(name), None)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Folder not updated.")
+                .from_err(e)
+                .entity(FOLDER)
+                .with_id(&id)
+                .not_updated()
+        })?;
+    Ok(())
+}
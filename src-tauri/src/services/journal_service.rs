@@ -0,0 +1,229 @@
+use chrono::Utc;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
+
+use ::entity::journal::Model as JournalModel;
+
+use crate::database::{journal_manager, transaction_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    journal::{JournalChange, JournalOperation},
+};
+use crate::schema::journal::{JournalRecordResponseSchema, JournalResponseSchema};
+use crate::services::{entry_service, folder_service, trash_service, word_service};
+use crate::types::entity::JOURNAL;
+
+/// The number of changes that can be undone; older changes are forgotten.
+const MAX_JOURNAL_RECORDS: u64 = 200;
+
+/// Records a change, which becomes the next one to undo.
+/// Changes that have been undone can no longer be redone afterwards.
+pub async fn record<C>(con: &C, label: &str, change: JournalChange) -> Result<(), Error>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    if change.is_empty() {
+        return Ok(());
+    }
+    let undo = _serialize_operations(&change.undo)?;
+    let redo = _serialize_operations(&change.redo)?;
+
+    let txn = transaction_manager::begin(con).await?;
+    let not_created = |e: DbErr| {
+        ErrorBuilder::new()
+            .msg("Change not recorded in the journal.")
+            .from_err(e)
+            .entity(JOURNAL)
+            .not_created()
+    };
+    journal_manager::delete_undone(&txn)
+        .await
+        .map_err(not_created)?;
+    journal_manager::insert(&txn, Utc::now(), label, undo, redo)
+        .await
+        .map_err(not_created)?;
+    journal_manager::delete_oldest(&txn, MAX_JOURNAL_RECORDS)
+        .await
+        .map_err(not_created)?;
+    transaction_manager::end(txn).await
+}
+
+/// Returns the changes that the next undo and redo apply to.
+pub async fn get(database: &DatabaseConnection) -> Result<JournalResponseSchema, Error> {
+    let query_failed = |e: DbErr| {
+        ErrorBuilder::new()
+            .msg("Failed to query the journal table while fetching the next changes.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    };
+    let undo = journal_manager::get_last_done(database)
+        .await
+        .map_err(query_failed)?;
+    let redo = journal_manager::get_first_undone(database)
+        .await
+        .map_err(query_failed)?;
+
+    Ok(JournalResponseSchema {
+        undo: undo.as_ref().map(generate_response),
+        redo: redo.as_ref().map(generate_response),
+    })
+}
+
+/// Reverts the latest change that hasn't been undone yet.
+/// Returns the reverted change, or nothing if there was nothing to undo.
+pub async fn undo(
+    database: &DatabaseConnection,
+) -> Result<Option<JournalRecordResponseSchema>, Error> {
+    let record = journal_manager::get_last_done(database)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the journal table while fetching the change to undo.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let Some(record) = record else {
+        return Ok(None);
+    };
+
+    let operations = _deserialize_operations(&record, "undo", &record.undo)?;
+    _apply_all(database, &record, operations.into_iter().rev(), true).await?;
+
+    Ok(Some(generate_response(&record)))
+}
+
+/// Makes the earliest undone change again.
+/// Returns the change, or nothing if there was nothing to redo.
+pub async fn redo(
+    database: &DatabaseConnection,
+) -> Result<Option<JournalRecordResponseSchema>, Error> {
+    let record = journal_manager::get_first_undone(database)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the journal table while fetching the change to redo.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let Some(record) = record else {
+        return Ok(None);
+    };
+
+    let operations = _deserialize_operations(&record, "redo", &record.redo)?;
+    _apply_all(database, &record, operations.into_iter(), false).await?;
+
+    Ok(Some(generate_response(&record)))
+}
+
+/// Applies the operations and marks the record as undone or redone in a single transaction,
+/// so that a change is either replayed in full or not at all.
+async fn _apply_all(
+    database: &DatabaseConnection,
+    record: &JournalModel,
+    operations: impl Iterator<Item = JournalOperation>,
+    is_undone: bool,
+) -> Result<(), Error> {
+    let txn = transaction_manager::begin(database).await?;
+    for operation in operations {
+        if let Err(e) = _apply(&txn, operation).await {
+            // the transaction has to be rolled back before the record can be deleted
+            drop(txn);
+            // the change can't be replayed, e.g., because one of its items has been deleted for good;
+            // it is forgotten so that it doesn't block the changes that were made before it
+            let _ = journal_manager::delete(database, record.id).await;
+            return Err(e);
+        }
+    }
+    _set_undone(&txn, record, is_undone).await?;
+    transaction_manager::end(txn).await
+}
+
+/// Applies the operation without recording it in the journal.
+async fn _apply<C>(con: &C, operation: JournalOperation) -> Result<(), Error>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    match operation {
+        JournalOperation::UpdateEntry { entry } => {
+            let response = entry_service::_update_with_revision(con, *entry, false).await;
+            match response.errors.into_iter().next() {
+                Some(e) => Err(e),
+                None => Ok(()),
+            }
+        }
+        JournalOperation::TrashEntry { id } => trash_service::_trash_entry(con, id).await,
+        JournalOperation::RestoreEntry { id } => {
+            trash_service::_restore_entry(con, id).await.map(|_| ())
+        }
+        JournalOperation::UpdateFolder { folder } => {
+            let response = folder_service::_update(con, folder).await;
+            match response.errors.into_iter().next() {
+                Some(e) => Err(e),
+                None => Ok(()),
+            }
+        }
+        JournalOperation::TrashFolder { id } => {
+            trash_service::_trash_folder(con, id).await.map(|_| ())
+        }
+        JournalOperation::RestoreFolder { id } => {
+            trash_service::_restore_folder(con, id).await.map(|_| ())
+        }
+        JournalOperation::PutWord { word } => word_service::_put(con, word).await,
+        JournalOperation::DeleteWord { id } => word_service::_delete(con, id).await,
+    }
+}
+
+async fn _set_undone<C>(con: &C, record: &JournalModel, is_undone: bool) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    journal_manager::set_undone(con, record.id, is_undone)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Journal record not updated.")
+                .from_err(e)
+                .entity(JOURNAL)
+                .with_id(&record.id)
+                .not_updated()
+        })?;
+    Ok(())
+}
+
+fn _serialize_operations(operations: &[JournalOperation]) -> Result<serde_json::Value, Error> {
+    serde_json::to_value(operations).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to serialize the operations of a change.")
+            .from_err(e)
+            .entity(JOURNAL)
+            .attribute("operations")
+            .invalid()
+    })
+}
+
+fn _deserialize_operations(
+    record: &JournalModel,
+    key: &str,
+    value: &serde_json::Value,
+) -> Result<Vec<JournalOperation>, Error> {
+    serde_json::from_value(value.clone()).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to deserialize the operations of a change.")
+            .from_err(e)
+            .entity(JOURNAL)
+            .attribute(key)
+            .with_id(Some(&record.id))
+            .with_value(value)
+            .invalid()
+    })
+}
+
+fn generate_response(record: &JournalModel) -> JournalRecordResponseSchema {
+    JournalRecordResponseSchema {
+        id: record.id,
+        label: record.label.to_owned(),
+        created_at: record.created_at,
+    }
+}
@@ -1,30 +1,104 @@
 use sea_orm::ConnectionTrait;
 use uuid::Uuid;
 
+use ::entity::language::Model as LanguageModel;
+
 use crate::database::language_manager;
 use crate::model::errors::{Error, ErrorBuilder};
-use crate::schema::language::LanguageSchema;
+use crate::schema::language::{GrammarSchema, LanguageSchema};
 use crate::types::entity::LANGUAGE;
 
-pub async fn create<C>(con: &C, entry_id: Uuid) -> Result<(), Error>
+pub async fn create<C>(con: &C, entry_id: Uuid, properties: &LanguageSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let grammar = _serialize_grammar(&properties.grammar)?;
+    language_manager::insert(con, entry_id, grammar)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to create language record.")
+                .from_err(e)
+                .entity(LANGUAGE)
+                .not_created()
+        })?;
+
+    Ok(())
+}
+
+/// Updates the grammar of the language.
+/// Existing words and inflections aren't revalidated against the new grammar.
+pub async fn update<C>(con: &C, id: Uuid, properties: &LanguageSchema) -> Result<(), Error>
 where
     C: ConnectionTrait,
 {
-    language_manager::insert(con, entry_id).await.map_err(|e| {
+    let grammar = _serialize_grammar(&properties.grammar)?;
+    language_manager::update(con, id, grammar)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Language not updated.")
+                .from_err(e)
+                .entity(LANGUAGE)
+                .with_id(&id)
+                .not_updated()
+        })
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<LanguageSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    let language = language_manager::get(con, id).await.map_err(|e| {
         ErrorBuilder::new()
-            .msg("Failed to create language record.")
+            .msg("Failed to query the language table while fetching a language by ID.")
             .from_err(e)
-            .entity(LANGUAGE)
-            .not_created()
+            .db()
+            .query_failed()
     })?;
+    match language {
+        Some(language) => generate_response(&language),
+        None => Err(ErrorBuilder::new()
+            .msg("Language not found.")
+            .entity(LANGUAGE)
+            .with_id(&id)
+            .not_found()),
+    }
+}
 
-    Ok(())
+/// Returns the word types and grammatical categories that the language uses.
+pub async fn get_grammar<C>(con: &C, id: Uuid) -> Result<GrammarSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    Ok(get(con, id).await?.grammar)
 }
 
-pub async fn get() -> Result<LanguageSchema, Error> {
-    Ok(generate_response())
+fn _serialize_grammar(grammar: &GrammarSchema) -> Result<serde_json::Value, Error> {
+    serde_json::to_value(grammar).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to serialize the grammar of a language.")
+            .from_err(e)
+            .entity(LANGUAGE)
+            .attribute("grammar")
+            .invalid()
+    })
 }
 
-fn generate_response() -> LanguageSchema {
-    LanguageSchema {}
+fn generate_response(language: &LanguageModel) -> Result<LanguageSchema, Error> {
+    let grammar = match &language.grammar {
+        Some(grammar) => serde_json::from_value(grammar.clone()).map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to deserialize the grammar of a language.")
+                .from_err(e)
+                .entity(LANGUAGE)
+                .attribute("grammar")
+                .with_id(Some(&language.entry_id))
+                .with_value(grammar)
+                .invalid()
+        })?,
+        None => GrammarSchema::default(),
+    };
+    Ok(LanguageSchema { grammar })
 }
@@ -0,0 +1,462 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use ::entity::{map::Model as MapModel, map_pin::Model as MapPinModel};
+
+use crate::constants::MAP_DIR_NAME;
+use crate::database::{entry_manager, map_manager, map_pin_manager};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::map::{
+    MapPinCreateSchema, MapPinMoveSchema, MapPinResponseSchema, MapPinsOfEntryResponseSchema,
+    MapPointSchema, MapSchema,
+};
+use crate::services::entry_service;
+use crate::types::{
+    entity::{MAP, MAP_PIN},
+    map::PinShape,
+};
+
+const MAP_IMAGE_EXTENSIONS: [&str; 6] = ["png", "jpg", "jpeg", "gif", "webp", "svg"];
+
+pub async fn create<C>(con: &C, entry_id: Uuid, properties: &MapSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    _validate_dimensions(entry_id, properties)?;
+
+    map_manager::insert(con, entry_id, properties.width, properties.height)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Map not created.")
+                .from_err(e)
+                .entity(MAP)
+                .not_created()
+        })?;
+    Ok(())
+}
+
+/// Updates the dimensions of the map.
+/// Existing pins aren't moved, so pins may lie outside of a map that has shrunk.
+pub async fn update<C>(con: &C, id: Uuid, properties: &MapSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    _validate_dimensions(id, properties)?;
+
+    map_manager::update(con, id, properties.width, properties.height)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Map not updated.")
+                .from_err(e)
+                .entity(MAP)
+                .with_id(&id)
+                .not_updated()
+        })
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<MapSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    Ok(generate_response(&_get_map(con, id).await?))
+}
+
+/// Copies the image into the map directory of the project and sets it as the image of the map.
+/// The previous image of the map is removed.
+pub async fn set_image(
+    database: &DatabaseConnection,
+    folder_path: &str,
+    id: Uuid,
+    source_path: &str,
+) -> Result<MapSchema, Error> {
+    let map = _get_map(database, id).await?;
+
+    let source_path = Path::new(source_path);
+    let extension = source_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase())
+        .filter(|extension| MAP_IMAGE_EXTENSIONS.contains(&extension.as_str()));
+    let Some(extension) = extension else {
+        return Err(ErrorBuilder::new()
+            .msg("The image of a map must be a PNG, JPEG, GIF, WebP or SVG file.")
+            .entity(MAP)
+            .attribute("imagePath")
+            .with_id(Some(&id))
+            .with_value(&source_path.display())
+            .invalid());
+    };
+
+    fs::create_dir_all(_get_map_dir_path(folder_path)).map_err(|e| {
+        Error::FileSystemOperationFailed {
+            msg: "Failed to create the map directory.".to_owned(),
+            error: e.to_string(),
+        }
+    })?;
+    let image_path = format!("{MAP_DIR_NAME}/{id}.{extension}");
+    fs::copy(source_path, Path::new(folder_path).join(&image_path)).map_err(|e| {
+        Error::FileSystemOperationFailed {
+            msg: "Failed to copy the image of a map into the project folder.".to_owned(),
+            error: e.to_string(),
+        }
+    })?;
+
+    let updated_map = map_manager::update_image_path(database, id, Some(image_path))
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Map not updated.")
+                .from_err(e)
+                .entity(MAP)
+                .with_id(&id)
+                .not_updated()
+        })?;
+
+    // an image with the same extension has already been overwritten by the copy
+    if let Some(previous_path) = map.image_path
+        && Some(&previous_path) != updated_map.image_path.as_ref()
+    {
+        let _ = fs::remove_file(Path::new(folder_path).join(previous_path));
+    }
+
+    Ok(generate_response(&updated_map))
+}
+
+pub async fn add_pin(
+    database: &DatabaseConnection,
+    pin: MapPinCreateSchema,
+) -> Result<MapPinResponseSchema, Error> {
+    let map = _get_map(database, pin.map_id).await?;
+    _validate_points(None, &map, pin.shape, &pin.points)?;
+
+    let exists = entry_manager::exists(database, pin.target_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while checking the target of a map pin.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if !exists {
+        return Err(ErrorBuilder::new()
+            .msg("The target of a map pin must be an existing entry.")
+            .entity(MAP_PIN)
+            .attribute("targetId")
+            .with_value(&pin.target_id)
+            .invalid());
+    }
+
+    let points = _serialize_points(None, &pin.points)?;
+    let pin = map_pin_manager::insert(database, pin.map_id, pin.target_id, pin.shape, points)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Map pin not created.")
+                .from_err(e)
+                .entity(MAP_PIN)
+                .not_created()
+        })?;
+
+    generate_pin_response(&pin, false)
+}
+
+/// Moves the pin to new coordinates. The shape of the pin can't be changed.
+pub async fn move_pin(
+    database: &DatabaseConnection,
+    pin: MapPinMoveSchema,
+) -> Result<MapPinResponseSchema, Error> {
+    let existing_pin = _get_pin(database, pin.id).await?;
+    let map = _get_map(database, existing_pin.map_id).await?;
+    let shape = PinShape::from(existing_pin.shape);
+    _validate_points(Some(pin.id), &map, shape, &pin.points)?;
+
+    let points = _serialize_points(Some(pin.id), &pin.points)?;
+    let moved_pin = map_pin_manager::update_points(database, pin.id, points)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Map pin not updated.")
+                .from_err(e)
+                .entity(MAP_PIN)
+                .with_id(&pin.id)
+                .not_updated()
+        })?;
+
+    let dangling_ids = _get_dangling_target_ids(database, vec![moved_pin.target_id]).await?;
+    generate_pin_response(&moved_pin, !dangling_ids.is_empty())
+}
+
+pub async fn remove_pin(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    map_pin_manager::delete(database, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Map pin not deleted.")
+            .from_err(e)
+            .entity(MAP_PIN)
+            .with_id(&id)
+            .not_deleted()
+    })?;
+    Ok(())
+}
+
+pub async fn get_pins(
+    database: &DatabaseConnection,
+    map_id: Uuid,
+) -> Result<Vec<MapPinResponseSchema>, Error> {
+    _get_map(database, map_id).await?;
+    let pins = map_pin_manager::get_all_for_map(database, map_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the map pin table while fetching the pins of a map.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let target_ids = pins.iter().map(|pin| pin.target_id).collect();
+    let dangling_ids = _get_dangling_target_ids(database, target_ids).await?;
+
+    pins.iter()
+        .map(|pin| generate_pin_response(pin, dangling_ids.contains(&pin.target_id)))
+        .collect()
+}
+
+/// Returns every map that shows the entry, along with the pins that point to it.
+/// Maps are sorted by title.
+pub async fn get_maps_of_entry(
+    database: &DatabaseConnection,
+    entry_id: Uuid,
+) -> Result<Vec<MapPinsOfEntryResponseSchema>, Error> {
+    let pins = map_pin_manager::get_all_for_target(database, entry_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the map pin table while fetching the pins of an entry.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut pins_by_map: HashMap<Uuid, Vec<MapPinResponseSchema>> = HashMap::new();
+    for pin in pins.iter() {
+        pins_by_map
+            .entry(pin.map_id)
+            .or_default()
+            .push(generate_pin_response(pin, false)?);
+    }
+
+    let map_ids = pins_by_map.keys().copied().collect();
+    let maps = entry_manager::get_many(database, map_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while fetching the entries of maps.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut responses: Vec<MapPinsOfEntryResponseSchema> = maps
+        .iter()
+        .map(|map| MapPinsOfEntryResponseSchema {
+            map: entry_service::generate_insert_response(map),
+            pins: pins_by_map.remove(&map.id).unwrap_or_default(),
+        })
+        .collect();
+    responses.sort_by(|a, b| a.map.title.cmp(&b.map.title));
+
+    Ok(responses)
+}
+
+/// Returns the pins whose target entries have been deleted or moved to the trash.
+pub async fn get_dangling_pins(
+    database: &DatabaseConnection,
+) -> Result<Vec<MapPinResponseSchema>, Error> {
+    let pins = map_pin_manager::get_dangling(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the map pin table while fetching dangling pins.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    pins.iter()
+        .map(|pin| generate_pin_response(pin, true))
+        .collect()
+}
+
+async fn _get_map<C>(con: &C, id: Uuid) -> Result<MapModel, Error>
+where
+    C: ConnectionTrait,
+{
+    let map = map_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the map table while fetching a map by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    map.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Map not found.")
+            .entity(MAP)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+async fn _get_pin(database: &DatabaseConnection, id: Uuid) -> Result<MapPinModel, Error> {
+    let pin = map_pin_manager::get(database, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the map pin table while fetching a pin by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    pin.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Map pin not found.")
+            .entity(MAP_PIN)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+async fn _get_dangling_target_ids(
+    database: &DatabaseConnection,
+    target_ids: Vec<Uuid>,
+) -> Result<HashSet<Uuid>, Error> {
+    // pins that point into the trash dangle until their targets are restored
+    let existing_ids = entry_manager::get_untrashed_ids(database, target_ids.clone())
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry table while checking the targets of map pins.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    Ok(target_ids
+        .into_iter()
+        .filter(|id| !existing_ids.contains(id))
+        .collect())
+}
+
+fn _validate_dimensions(id: Uuid, properties: &MapSchema) -> Result<(), Error> {
+    for (name, value) in [("width", properties.width), ("height", properties.height)] {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(ErrorBuilder::new()
+                .msg("The dimensions of a map must be positive.")
+                .entity(MAP)
+                .attribute(name)
+                .with_id(Some(&id))
+                .with_value(&value)
+                .invalid());
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a point pin has exactly one point, that a polygon pin has at least three,
+/// and that every point lies on the map.
+fn _validate_points(
+    id: Option<Uuid>,
+    map: &MapModel,
+    shape: PinShape,
+    points: &[MapPointSchema],
+) -> Result<(), Error> {
+    let has_valid_count = match shape {
+        PinShape::Point => points.len() == 1,
+        PinShape::Polygon => points.len() >= 3,
+    };
+    if !has_valid_count {
+        return Err(ErrorBuilder::new()
+            .msg(&format!(
+                "A {} pin can't have {} points.",
+                shape.to_string().to_lowercase(),
+                points.len()
+            ))
+            .entity(MAP_PIN)
+            .attribute("points")
+            .with_id(id.as_ref())
+            .with_value(&points.len())
+            .invalid());
+    }
+
+    let outside_point = points.iter().find(|point| {
+        !(0.0..=map.width).contains(&point.x) || !(0.0..=map.height).contains(&point.y)
+    });
+    if let Some(point) = outside_point {
+        return Err(ErrorBuilder::new()
+            .msg("The points of a pin must lie on its map.")
+            .entity(MAP_PIN)
+            .attribute("points")
+            .with_id(id.as_ref())
+            .with_value(&format!("({}, {})", point.x, point.y))
+            .invalid());
+    }
+
+    Ok(())
+}
+
+fn _serialize_points(
+    id: Option<Uuid>,
+    points: &[MapPointSchema],
+) -> Result<serde_json::Value, Error> {
+    let points: Vec<[f64; 2]> = points.iter().map(|point| [point.x, point.y]).collect();
+    serde_json::to_value(points).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to serialize the points of a map pin.")
+            .from_err(e)
+            .entity(MAP_PIN)
+            .attribute("points")
+            .with_id(id.as_ref())
+            .invalid()
+    })
+}
+
+fn _get_map_dir_path(folder_path: &str) -> PathBuf {
+    Path::new(folder_path).join(MAP_DIR_NAME)
+}
+
+fn generate_response(map: &MapModel) -> MapSchema {
+    MapSchema {
+        width: map.width,
+        height: map.height,
+        image_path: map.image_path.clone(),
+    }
+}
+
+fn generate_pin_response(
+    pin: &MapPinModel,
+    is_dangling: bool,
+) -> Result<MapPinResponseSchema, Error> {
+    let points: Vec<[f64; 2]> = serde_json::from_value(pin.points.clone()).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to deserialize the points of a map pin.")
+            .from_err(e)
+            .entity(MAP_PIN)
+            .attribute("points")
+            .with_id(Some(&pin.id))
+            .with_value(&pin.points)
+            .invalid()
+    })?;
+    Ok(MapPinResponseSchema {
+        id: pin.id,
+        map_id: pin.map_id,
+        target_id: pin.target_id,
+        shape: PinShape::from(pin.shape),
+        points: points
+            .into_iter()
+            .map(|[x, y]| MapPointSchema { x, y })
+            .collect(),
+        is_dangling,
+    })
+}
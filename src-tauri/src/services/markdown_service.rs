@@ -0,0 +1,601 @@
+use pulldown_cmark::{CodeBlockKind, Event, LinkType, Options, Parser, Tag, TagEnd};
+use uuid::Uuid;
+
+use crate::model::text::{TextMark, TextNode};
+
+/// Renders a mention as Markdown, given the ID of the referenced entry and the label of the mention.
+pub type MentionRenderer<'a> = dyn Fn(Option<Uuid>, &str) -> String + 'a;
+/// Resolves the target of a wikilink or of a link to another Markdown file to the ID of an entry.
+pub type MentionResolver<'a> = dyn Fn(&str) -> Option<Uuid> + 'a;
+
+/// Characters that are escaped wherever they appear in text.
+const ESCAPED_CHARS: [char; 9] = ['\\', '`', '*', '_', '[', ']', '<', '>', '~'];
+/// Characters that are escaped when they appear at the start of a line,
+/// since they would otherwise start a heading, a list or a setext underline.
+const ESCAPED_LINE_START_CHARS: [char; 4] = ['#', '+', '-', '='];
+const HARD_BREAK: &str = "\\\n";
+const MARKDOWN_FILE_EXTENSION: &str = ".md";
+/// Node types that can directly contain inline nodes.
+const TEXTBLOCK_NODE_TYPES: [&str; 3] = ["paragraph", "heading", "codeBlock"];
+
+enum PendingLink {
+    /// A link that is converted into a mention of an entry.
+    Mention { id: Uuid, label: String },
+    /// An ordinary link, which is kept as a link mark on its text.
+    Mark,
+    /// A wikilink that doesn't resolve to any entry, which is kept as plain text.
+    Unresolved,
+}
+
+/// Builds a document node out of a stream of Markdown events.
+struct TextNodeBuilder {
+    /// Open block nodes, starting with the document.
+    stack: Vec<TextNode>,
+    marks: Vec<TextMark>,
+    /// Whether the innermost block is a paragraph that wraps loose inline content,
+    /// e.g., the text of a tight list item.
+    is_paragraph_implicit: bool,
+    link: Option<PendingLink>,
+    /// Source and alt text of an image that hasn't been closed yet.
+    image: Option<(String, String)>,
+    is_in_metadata: bool,
+}
+
+/// Converts an article into CommonMark.
+pub fn render(node: &TextNode, render_mention: &MentionRenderer) -> String {
+    let markdown = match _get_block(node) {
+        Some("doc") => _render_blocks(_get_content(node), render_mention),
+        _ => _render_block(node, render_mention).unwrap_or_default(),
+    };
+    if markdown.is_empty() {
+        markdown
+    } else {
+        format!("{markdown}\n")
+    }
+}
+
+/// Converts CommonMark into an article.
+/// Wikilinks and links to other Markdown files are converted into mentions if they can be resolved.
+/// Front matter is skipped.
+pub fn parse(markdown: &str, resolve_mention: &MentionResolver) -> TextNode {
+    let options = Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_WIKILINKS
+        | Options::ENABLE_YAML_STYLE_METADATA_BLOCKS;
+
+    let mut builder = TextNodeBuilder::new();
+    for event in Parser::new_ext(markdown, options) {
+        builder.push_event(event, resolve_mention);
+    }
+    builder.finish()
+}
+
+/// Escapes the Markdown syntax in plain text.
+pub fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if ESCAPED_CHARS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Formats a link destination, wrapping it in angle brackets if it contains spaces or parentheses.
+pub fn format_link_destination(destination: &str) -> String {
+    if destination.contains([' ', '(', ')']) {
+        format!("<{}>", destination.replace('<', "%3C").replace('>', "%3E"))
+    } else {
+        destination.to_owned()
+    }
+}
+
+fn _render_blocks(nodes: &[TextNode], render_mention: &MentionRenderer) -> String {
+    nodes
+        .iter()
+        .filter_map(|node| _render_block(node, render_mention))
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn _render_block(node: &TextNode, render_mention: &MentionRenderer) -> Option<String> {
+    let markdown = match _get_block(node) {
+        Some("paragraph") => {
+            _escape_line_starts(_render_inline(_get_content(node), render_mention).trim_start())
+        }
+        Some("heading") => {
+            let level = node
+                .get_attr("level")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(1)
+                .clamp(1, 6) as usize;
+            // headings can't span several lines
+            let text = _render_inline(_get_content(node), render_mention).replace(HARD_BREAK, " ");
+            format!("{} {}", "#".repeat(level), text.trim())
+        }
+        Some("blockquote") => _prefix_lines(
+            &_render_blocks(_get_content(node), render_mention),
+            "> ",
+            "> ",
+        ),
+        Some("bulletList") => _render_list(node, None, render_mention),
+        Some("orderedList") => {
+            let start = node.get_attr("start").and_then(|v| v.as_u64()).unwrap_or(1);
+            _render_list(node, Some(start), render_mention)
+        }
+        Some("codeBlock") => _render_code_block(node),
+        Some("horizontalRule") => "---".to_owned(),
+        // unknown block nodes are unwrapped
+        Some(_) => {
+            let content = _get_content(node);
+            if content.iter().all(|child| child.is_inline()) {
+                _escape_line_starts(_render_inline(content, render_mention).trim_start())
+            } else {
+                _render_blocks(content, render_mention)
+            }
+        }
+        None => _escape_line_starts(
+            _render_inline(std::slice::from_ref(node), render_mention).trim_start(),
+        ),
+    };
+
+    if markdown.trim().is_empty() {
+        None
+    } else {
+        Some(markdown)
+    }
+}
+
+fn _render_list(node: &TextNode, start: Option<u64>, render_mention: &MentionRenderer) -> String {
+    _get_content(node)
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = match start {
+                Some(start) => format!("{}.", start + i as u64),
+                None => "-".to_owned(),
+            };
+            let indent = " ".repeat(marker.len() + 1);
+            let content = _render_blocks(_get_content(item), render_mention);
+            if content.is_empty() {
+                marker
+            } else {
+                _prefix_lines(&content, &format!("{marker} "), &indent)
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn _render_code_block(node: &TextNode) -> String {
+    let code: String = _get_content(node)
+        .iter()
+        .filter_map(|child| child.text.as_deref())
+        .collect();
+    let language = node
+        .get_attr("language")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    // the fence has to be longer than any run of backticks in the code
+    let fence = "`".repeat(std::cmp::max(3, _get_longest_backtick_run(&code) + 1));
+    format!("{fence}{language}\n{code}\n{fence}")
+}
+
+fn _render_inline(nodes: &[TextNode], render_mention: &MentionRenderer) -> String {
+    let mut markdown = String::new();
+
+    for node in nodes.iter() {
+        if let Some(text) = &node.text {
+            markdown.push_str(&_render_text(
+                text,
+                node.marks.as_deref().unwrap_or_default(),
+            ));
+        } else if node.is_type("mention") {
+            let id = node
+                .get_attr("id")
+                .and_then(|v| v.as_str())
+                .and_then(|v| Uuid::parse_str(v).ok());
+            let label = node
+                .get_attr("label")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            markdown.push_str(&render_mention(id, label));
+        } else if node.is_type("hardBreak") {
+            markdown.push_str(HARD_BREAK);
+        } else if node.is_type("image") {
+            let src = node
+                .get_attr("src")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let alt = node
+                .get_attr("alt")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            markdown.push_str(&format!(
+                "![{}]({})",
+                escape_text(alt),
+                format_link_destination(src)
+            ));
+        } else {
+            markdown.push_str(&_render_inline(_get_content(node), render_mention));
+        }
+    }
+
+    markdown
+}
+
+fn _render_text(text: &str, marks: &[TextMark]) -> String {
+    // emphasis delimiters can't be adjacent to whitespace on the inside,
+    // so the surrounding whitespace is moved outside of the marks
+    let core = text.trim();
+    if core.is_empty() {
+        return text.to_owned();
+    }
+    let leading = &text[..text.len() - text.trim_start().len()];
+    let trailing = &text[text.trim_end().len()..];
+
+    let is_code = marks.iter().any(|mark| mark.mark_type == "code");
+    let mut markdown = if is_code {
+        _render_code_span(core)
+    } else {
+        escape_text(core)
+    };
+
+    for mark in marks.iter() {
+        markdown = match mark.mark_type.as_str() {
+            "bold" => format!("**{markdown}**"),
+            "italic" => format!("*{markdown}*"),
+            "strike" => format!("~~{markdown}~~"),
+            // CommonMark has no syntax for underlines, but it does allow inline HTML
+            "underline" => format!("<u>{markdown}</u>"),
+            _ => markdown,
+        };
+    }
+
+    let href = marks
+        .iter()
+        .find(|mark| mark.mark_type == "link")
+        .and_then(|mark| mark.attrs.as_ref())
+        .and_then(|attrs| attrs.get("href"));
+    if let Some(href) = href {
+        markdown = format!("[{markdown}]({})", format_link_destination(href));
+    }
+
+    format!("{leading}{markdown}{trailing}")
+}
+
+fn _render_code_span(code: &str) -> String {
+    let delimiter = "`".repeat(_get_longest_backtick_run(code) + 1);
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{delimiter} {code} {delimiter}")
+    } else {
+        format!("{delimiter}{code}{delimiter}")
+    }
+}
+
+fn _escape_line_starts(markdown: &str) -> String {
+    markdown
+        .split('\n')
+        .map(|line| {
+            let Some(first_char) = line.chars().next() else {
+                return line.to_owned();
+            };
+            if ESCAPED_LINE_START_CHARS.contains(&first_char) {
+                return format!("\\{line}");
+            }
+            // an ordered list marker is a run of digits followed by a period or parenthesis
+            let digit_count = line.chars().take_while(|c| c.is_ascii_digit()).count();
+            match line[digit_count..].chars().next() {
+                Some(c @ ('.' | ')')) if digit_count > 0 => {
+                    format!("{}\\{c}{}", &line[..digit_count], &line[digit_count + 1..])
+                }
+                _ => line.to_owned(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn _prefix_lines(markdown: &str, first_prefix: &str, prefix: &str) -> String {
+    markdown
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { first_prefix } else { prefix };
+            if line.is_empty() {
+                prefix.trim_end().to_owned()
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn _get_longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`')
+        .map(|run| run.len())
+        .max()
+        .unwrap_or(0)
+}
+
+fn _get_block(node: &TextNode) -> Option<&str> {
+    if node.is_inline() {
+        return None;
+    }
+    node.node_type.as_deref()
+}
+
+fn _get_content(node: &TextNode) -> &[TextNode] {
+    node.content.as_deref().unwrap_or_default()
+}
+
+impl TextNodeBuilder {
+    fn new() -> Self {
+        TextNodeBuilder {
+            stack: vec![TextNode::new_doc()],
+            marks: Vec::new(),
+            is_paragraph_implicit: false,
+            link: None,
+            image: None,
+            is_in_metadata: false,
+        }
+    }
+
+    fn finish(mut self) -> TextNode {
+        while self.stack.len() > 1 {
+            self.close_block();
+        }
+        self.stack.pop().unwrap_or_else(TextNode::new_doc)
+    }
+
+    fn push_event(&mut self, event: Event, resolve_mention: &MentionResolver) {
+        match event {
+            Event::Start(tag) => self.start_tag(tag, resolve_mention),
+            Event::End(tag) => self.end_tag(tag),
+            Event::Text(text) => self.push_text(&text),
+            Event::Code(code) => {
+                self.marks.push(_new_mark("code"));
+                self.push_text(&code);
+                self.marks.pop();
+            }
+            Event::InlineHtml(html) => match html.trim() {
+                // the renderer writes underlines as inline HTML
+                "<u>" => self.marks.push(_new_mark("underline")),
+                "</u>" => self.remove_mark("underline"),
+                html => self.push_text(html),
+            },
+            Event::Html(html) => self.push_text(html.trim_end_matches('\n')),
+            Event::SoftBreak => self.push_text(" "),
+            Event::HardBreak => self.push_inline(TextNode::new("hardBreak".to_owned())),
+            Event::Rule => {
+                self.open_block(TextNode::new("horizontalRule".to_owned()));
+                self.close_block();
+            }
+            // none of the extensions that emit the other events are enabled
+            _ => {}
+        }
+    }
+
+    fn start_tag(&mut self, tag: Tag, resolve_mention: &MentionResolver) {
+        match tag {
+            Tag::Paragraph | Tag::HtmlBlock => self.open_block(TextNode::new_paragraph()),
+            Tag::Heading { level, .. } => {
+                let mut node = TextNode::new("heading".to_owned());
+                node.set_attr("level", serde_json::Value::from(level as u64));
+                self.open_block(node);
+            }
+            Tag::BlockQuote(_) => self.open_block(TextNode::new("blockquote".to_owned())),
+            Tag::CodeBlock(kind) => {
+                let mut node = TextNode::new("codeBlock".to_owned());
+                if let CodeBlockKind::Fenced(info) = kind {
+                    let language = info.split_whitespace().next().unwrap_or_default();
+                    if !language.is_empty() {
+                        node.set_attr("language", serde_json::Value::from(language));
+                    }
+                }
+                self.open_block(node);
+            }
+            Tag::List(Some(start)) => {
+                let mut node = TextNode::new("orderedList".to_owned());
+                node.set_attr("start", serde_json::Value::from(start));
+                self.open_block(node);
+            }
+            Tag::List(None) => self.open_block(TextNode::new("bulletList".to_owned())),
+            Tag::Item => self.open_block(TextNode::new("listItem".to_owned())),
+            Tag::Emphasis => self.marks.push(_new_mark("italic")),
+            Tag::Strong => self.marks.push(_new_mark("bold")),
+            Tag::Strikethrough => self.marks.push(_new_mark("strike")),
+            Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            } => {
+                let is_wikilink = matches!(link_type, LinkType::WikiLink { .. });
+                let id = if is_wikilink || _is_markdown_file_link(&dest_url) {
+                    resolve_mention(&dest_url)
+                } else {
+                    None
+                };
+
+                self.link = Some(match id {
+                    Some(id) => PendingLink::Mention {
+                        id,
+                        label: String::new(),
+                    },
+                    None if is_wikilink => PendingLink::Unresolved,
+                    None => {
+                        let mut mark = _new_mark("link");
+                        mark.attrs = Some([("href".to_owned(), dest_url.to_string())].into());
+                        self.marks.push(mark);
+                        PendingLink::Mark
+                    }
+                });
+            }
+            Tag::Image { dest_url, .. } => self.image = Some((dest_url.to_string(), String::new())),
+            Tag::MetadataBlock(_) => self.is_in_metadata = true,
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph
+            | TagEnd::HtmlBlock
+            | TagEnd::Heading(_)
+            | TagEnd::BlockQuote(_)
+            | TagEnd::CodeBlock
+            | TagEnd::List(_)
+            | TagEnd::Item => self.close_block(),
+            TagEnd::Emphasis => self.remove_mark("italic"),
+            TagEnd::Strong => self.remove_mark("bold"),
+            TagEnd::Strikethrough => self.remove_mark("strike"),
+            TagEnd::Link => match self.link.take() {
+                Some(PendingLink::Mention { id, label }) => {
+                    self.push_inline(TextNode::new_reference(id, label.trim().to_owned()))
+                }
+                Some(PendingLink::Mark) => self.remove_mark("link"),
+                Some(PendingLink::Unresolved) | None => {}
+            },
+            TagEnd::Image => {
+                if let Some((src, alt)) = self.image.take() {
+                    let mut node = TextNode::new("image".to_owned());
+                    node.set_attr("src", serde_json::Value::from(src));
+                    node.set_attr("alt", serde_json::Value::from(alt));
+                    self.push_inline(node);
+                }
+            }
+            TagEnd::MetadataBlock(_) => self.is_in_metadata = false,
+            _ => {}
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if text.is_empty() || self.is_in_metadata {
+            return;
+        }
+        if let Some((_, alt)) = &mut self.image {
+            alt.push_str(text);
+            return;
+        }
+        if let Some(PendingLink::Mention { label, .. }) = &mut self.link {
+            label.push_str(text);
+            return;
+        }
+
+        let marks = if self.marks.is_empty() || self.is_in_code_block() {
+            None
+        } else {
+            Some(self.marks.clone())
+        };
+
+        // adjacent text with the same marks is merged into a single node
+        let parent = self.get_inline_container();
+        if let Some(previous) = parent
+            .content
+            .as_mut()
+            .and_then(|content| content.last_mut())
+            && previous.is_type("text")
+            && previous.marks == marks
+        {
+            previous.text.get_or_insert_default().push_str(text);
+            return;
+        }
+
+        let mut node = TextNode::new_text(text.to_owned());
+        node.marks = marks;
+        parent.add_child(node);
+    }
+
+    fn push_inline(&mut self, node: TextNode) {
+        self.get_inline_container().add_child(node);
+    }
+
+    /// Returns the innermost block if it can contain inline nodes.
+    /// Otherwise, the inline nodes are wrapped in a new paragraph.
+    fn get_inline_container(&mut self) -> &mut TextNode {
+        let is_textblock = self
+            .stack
+            .last()
+            .and_then(|node| node.node_type.as_deref())
+            .is_some_and(|node_type| TEXTBLOCK_NODE_TYPES.contains(&node_type));
+        if !is_textblock {
+            self.stack.push(TextNode::new_paragraph());
+            self.is_paragraph_implicit = true;
+        }
+        self.stack.last_mut().unwrap()
+    }
+
+    fn open_block(&mut self, node: TextNode) {
+        self.close_implicit_paragraph();
+        self.stack.push(node);
+    }
+
+    fn close_block(&mut self) {
+        self.close_implicit_paragraph();
+        self.pop_block();
+    }
+
+    fn close_implicit_paragraph(&mut self) {
+        if self.is_paragraph_implicit {
+            self.is_paragraph_implicit = false;
+            self.pop_block();
+        }
+    }
+
+    fn pop_block(&mut self) {
+        // the document itself is never popped
+        if self.stack.len() < 2 {
+            return;
+        }
+        let Some(mut node) = self.stack.pop() else {
+            return;
+        };
+
+        if node.is_type("codeBlock") {
+            // the parser terminates the code with a line break that isn't part of the code
+            let last_text = node
+                .content
+                .as_mut()
+                .and_then(|content| content.last_mut())
+                .and_then(|child| child.text.as_mut());
+            if let Some(text) = last_text
+                && text.ends_with('\n')
+            {
+                text.pop();
+            }
+        }
+
+        if let Some(parent) = self.stack.last_mut() {
+            parent.add_child(node);
+        }
+    }
+
+    fn remove_mark(&mut self, mark_type: &str) {
+        if let Some(i) = self
+            .marks
+            .iter()
+            .rposition(|mark| mark.mark_type == mark_type)
+        {
+            self.marks.remove(i);
+        }
+    }
+
+    fn is_in_code_block(&self) -> bool {
+        self.stack
+            .last()
+            .is_some_and(|node| node.is_type("codeBlock"))
+    }
+}
+
+fn _new_mark(mark_type: &str) -> TextMark {
+    TextMark {
+        mark_type: mark_type.to_owned(),
+        ..Default::default()
+    }
+}
+
+fn _is_markdown_file_link(destination: &str) -> bool {
+    let path = destination.split('#').next().unwrap_or_default();
+    !path.contains("://") && path.to_lowercase().ends_with(MARKDOWN_FILE_EXTENSION)
+}
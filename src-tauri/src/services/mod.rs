@@ -1,13 +1,36 @@
 pub mod config_service;
 
+pub mod backup_service;
 pub mod project_service;
 
+pub mod asset_service;
+pub mod custom_property_service;
+pub mod entry_alias_service;
+pub mod entry_date_service;
+pub mod entry_reference_service;
+pub mod entry_revision_service;
+pub mod entry_search_service;
 pub mod entry_service;
+pub mod entry_template_service;
 pub mod entry_text_service;
+pub mod export_service;
 pub mod file_service;
 pub mod folder_service;
+pub mod import_service;
+pub mod integrity_service;
+pub mod journal_service;
+pub mod markdown_service;
+pub mod tag_service;
+pub mod trash_service;
 
 pub mod language_service;
 pub mod word_service;
 
+pub mod calendar_service;
+
+pub mod event_service;
+pub mod genealogy_service;
+pub mod map_service;
+pub mod organization_service;
 pub mod person_service;
+pub mod place_service;
@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use ::entity::{
+    entry::Model as EntryModel, membership::Model as MembershipModel,
+    organization::Model as OrganizationModel,
+};
+
+use crate::database::{entry_manager, membership_manager, organization_manager, person_manager};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::{
+    calendar::CalendarDateSchema,
+    entry::EntryInfoResponseSchema,
+    organization::{
+        MembershipCreateSchema, MembershipResponseSchema, MembershipUpdateSchema,
+        OrganizationSchema,
+    },
+};
+use crate::services::{calendar_service, entry_service};
+use crate::types::entity::{MEMBERSHIP, ORGANIZATION};
+
+pub async fn create<C>(
+    con: &C,
+    entry_id: Uuid,
+    properties: &OrganizationSchema,
+) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    _validate_parent(con, entry_id, properties.parent_id).await?;
+
+    organization_manager::insert(con, entry_id, properties.parent_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Organization not created.")
+                .from_err(e)
+                .entity(ORGANIZATION)
+                .not_created()
+        })?;
+    Ok(())
+}
+
+pub async fn update<C>(con: &C, id: Uuid, properties: &OrganizationSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    _validate_parent(con, id, properties.parent_id).await?;
+
+    organization_manager::update(con, id, properties.parent_id)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Organization not updated.")
+                .from_err(e)
+                .entity(ORGANIZATION)
+                .with_id(&id)
+                .not_updated()
+        })
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<OrganizationSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    Ok(generate_response(&_get_organization(con, id).await?))
+}
+
+/// Returns the organizations that are directly part of the organization, sorted by title.
+pub async fn get_sub_organizations(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    _get_organization(database, id).await?;
+    let children = organization_manager::get_children(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the organization table while fetching sub-organizations.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let ids = children.iter().map(|child| child.entry_id).collect();
+    let mut entries = _get_entries(database, ids).await?;
+    entries.sort_by(|a, b| a.title.cmp(&b.title));
+
+    Ok(entries
+        .iter()
+        .map(entry_service::generate_insert_response)
+        .collect())
+}
+
+pub async fn add_membership(
+    database: &DatabaseConnection,
+    membership: MembershipCreateSchema,
+) -> Result<MembershipResponseSchema, Error> {
+    _get_organization(database, membership.organization_id).await?;
+    _validate_person(database, membership.person_id).await?;
+    let dates = calendar_service::get_period(
+        database,
+        MEMBERSHIP,
+        None,
+        &membership.start,
+        &membership.end,
+    )
+    .await?;
+
+    let membership = membership_manager::insert(
+        database,
+        membership.organization_id,
+        membership.person_id,
+        &membership.role,
+        membership.rank,
+        dates,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Membership not created.")
+            .from_err(e)
+            .entity(MEMBERSHIP)
+            .not_created()
+    })?;
+
+    Ok(_generate_membership_responses(database, vec![membership])
+        .await?
+        .remove(0))
+}
+
+pub async fn update_membership(
+    database: &DatabaseConnection,
+    membership: MembershipUpdateSchema,
+) -> Result<MembershipResponseSchema, Error> {
+    let id = membership.id;
+    let dates = calendar_service::get_period(
+        database,
+        MEMBERSHIP,
+        Some(id),
+        &membership.start,
+        &membership.end,
+    )
+    .await?;
+
+    let membership =
+        membership_manager::update(database, id, &membership.role, membership.rank, dates)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Membership not updated.")
+                    .from_err(e)
+                    .entity(MEMBERSHIP)
+                    .with_id(&id)
+                    .not_updated()
+            })?;
+
+    Ok(_generate_membership_responses(database, vec![membership])
+        .await?
+        .remove(0))
+}
+
+pub async fn remove_membership(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    membership_manager::delete(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Membership not deleted.")
+                .from_err(e)
+                .entity(MEMBERSHIP)
+                .with_id(&id)
+                .not_deleted()
+        })?;
+    Ok(())
+}
+
+/// Returns the memberships of the organization.
+/// Members are sorted by rank, then by name; members without a rank come last.
+pub async fn get_members(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<Vec<MembershipResponseSchema>, Error> {
+    _get_organization(database, id).await?;
+    let memberships = membership_manager::get_all_for_organization(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the membership table while fetching the members of an organization.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut responses = _generate_membership_responses(database, memberships).await?;
+    responses.sort_by(|a, b| {
+        let rank_a = a.rank.unwrap_or(i32::MAX);
+        let rank_b = b.rank.unwrap_or(i32::MAX);
+        (rank_a, &a.person.title).cmp(&(rank_b, &b.person.title))
+    });
+    Ok(responses)
+}
+
+/// Returns the memberships of the person, sorted by the title of the organization.
+pub async fn get_affiliations(
+    database: &DatabaseConnection,
+    person_id: Uuid,
+) -> Result<Vec<MembershipResponseSchema>, Error> {
+    _validate_person(database, person_id).await?;
+    let memberships = membership_manager::get_all_for_person(database, person_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the membership table while fetching the affiliations of a person.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    let mut responses = _generate_membership_responses(database, memberships).await?;
+    responses
+        .sort_by(|a, b| (&a.organization.title, &a.role).cmp(&(&b.organization.title, &b.role)));
+    Ok(responses)
+}
+
+async fn _get_organization<C>(con: &C, id: Uuid) -> Result<OrganizationModel, Error>
+where
+    C: ConnectionTrait,
+{
+    let organization = organization_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the organization table while fetching an organization by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    organization.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Organization not found.")
+            .entity(ORGANIZATION)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+/// Checks that the parent is an existing organization,
+/// and that the organization isn't being made part of itself or of one of its sub-organizations.
+async fn _validate_parent<C>(con: &C, id: Uuid, parent_id: Option<Uuid>) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let mut ancestor_id = parent_id;
+    while let Some(current_id) = ancestor_id {
+        let invalid = |msg: &str| {
+            ErrorBuilder::new()
+                .msg(msg)
+                .entity(ORGANIZATION)
+                .attribute("parentId")
+                .with_id(Some(&id))
+                .with_optional_value(&parent_id)
+                .invalid()
+        };
+        if current_id == id {
+            return Err(invalid(
+                "An organization cannot be part of itself or of one of its sub-organizations.",
+            ));
+        }
+        let ancestor = organization_manager::get(con, current_id)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the organization table while checking the parent of an organization.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?;
+        match ancestor {
+            Some(ancestor) => ancestor_id = ancestor.parent_id,
+            None => {
+                return Err(invalid(
+                    "The parent of an organization must be an existing organization.",
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn _validate_person(database: &DatabaseConnection, person_id: Uuid) -> Result<(), Error> {
+    let person = person_manager::get(database, person_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+            .msg("Failed to query the person table while checking the member of an organization.")
+            .from_err(e)
+            .db()
+            .query_failed()
+        })?;
+    if person.is_none() {
+        return Err(ErrorBuilder::new()
+            .msg("The members of an organization must be existing people.")
+            .entity(MEMBERSHIP)
+            .attribute("personId")
+            .with_value(&person_id)
+            .invalid());
+    }
+    Ok(())
+}
+
+async fn _get_entries(
+    database: &DatabaseConnection,
+    ids: Vec<Uuid>,
+) -> Result<Vec<EntryModel>, Error> {
+    entry_manager::get_many(database, ids).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while fetching the entries of an organization.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })
+}
+
+async fn _generate_membership_responses(
+    database: &DatabaseConnection,
+    memberships: Vec<MembershipModel>,
+) -> Result<Vec<MembershipResponseSchema>, Error> {
+    let ids = memberships
+        .iter()
+        .flat_map(|membership| [membership.organization_id, membership.person_id])
+        .collect();
+    let entries: HashMap<Uuid, EntryModel> = _get_entries(database, ids)
+        .await?
+        .into_iter()
+        .map(|entry| (entry.id, entry))
+        .collect();
+
+    Ok(memberships
+        .iter()
+        .filter_map(|membership| {
+            let organization = entries.get(&membership.organization_id)?;
+            let person = entries.get(&membership.person_id)?;
+            Some(generate_membership_response(
+                membership,
+                organization,
+                person,
+            ))
+        })
+        .collect())
+}
+
+fn generate_response(organization: &OrganizationModel) -> OrganizationSchema {
+    OrganizationSchema {
+        parent_id: organization.parent_id,
+    }
+}
+
+fn generate_membership_response(
+    membership: &MembershipModel,
+    organization: &EntryModel,
+    person: &EntryModel,
+) -> MembershipResponseSchema {
+    let to_date = |year: Option<i64>, month: Option<i32>, day: Option<i32>| {
+        Some(CalendarDateSchema {
+            calendar_id: membership.calendar_id?,
+            year: year?,
+            month: month? as u32,
+            day: day? as u32,
+        })
+    };
+    MembershipResponseSchema {
+        id: membership.id,
+        organization: entry_service::generate_insert_response(organization),
+        person: entry_service::generate_insert_response(person),
+        role: membership.role.clone(),
+        rank: membership.rank,
+        start: to_date(
+            membership.start_year,
+            membership.start_month,
+            membership.start_day,
+        ),
+        end: to_date(
+            membership.end_year,
+            membership.end_month,
+            membership.end_day,
+        ),
+    }
+}
@@ -1,4 +1,4 @@
-use sea_orm::{ConnectionTrait, DatabaseConnection};
+use sea_orm::ConnectionTrait;
 use uuid::Uuid;
 
 use ::entity::person::Model as Person;
@@ -41,8 +41,11 @@ where
         })
 }
 
-pub async fn get(database: &DatabaseConnection, id: Uuid) -> Result<PersonSchema, Error> {
-    let person = person_manager::get(database, id).await.map_err(|e| {
+pub async fn get<C>(con: &C, id: Uuid) -> Result<PersonSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    let person = person_manager::get(con, id).await.map_err(|e| {
         ErrorBuilder::new()
             .msg("Failed to query the person table while fetching a person by ID.")
             .from_err(e)
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use ::entity::place::Model as PlaceModel;
+
+use crate::database::{entry_manager, place_manager, place_manager::PlaceNode};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::{entry::EntryInfoResponseSchema, place::PlaceSchema};
+use crate::services::entry_service;
+use crate::types::entity::PLACE;
+
+pub async fn create<C>(con: &C, entry_id: Uuid, properties: &PlaceSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    _validate_parent(con, entry_id, properties.parent_id).await?;
+
+    place_manager::insert(con, entry_id, properties.place_type, properties.parent_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Place not created.")
+                .from_err(e)
+                .entity(PLACE)
+                .not_created()
+        })?;
+    Ok(())
+}
+
+pub async fn update<C>(con: &C, id: Uuid, properties: &PlaceSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    _validate_parent(con, id, properties.parent_id).await?;
+
+    place_manager::update(con, id, properties.place_type, properties.parent_id)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Place not updated.")
+                .from_err(e)
+                .entity(PLACE)
+                .with_id(&id)
+                .not_updated()
+        })
+}
+
+pub async fn get<C>(con: &C, id: Uuid) -> Result<PlaceSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    let place = place_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the place table while fetching a place by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    match place {
+        Some(place) => Ok(generate_response(&place)),
+        None => Err(ErrorBuilder::new()
+            .msg("Place not found.")
+            .entity(PLACE)
+            .with_id(&id)
+            .not_found()),
+    }
+}
+
+/// Returns every place located in the place, e.g., the regions, cities and buildings of a continent.
+/// Places are sorted by depth, then by title.
+pub async fn get_descendants(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    get(database, id).await?;
+    let nodes = _get_descendants(database, id).await?;
+    _generate_hierarchy_response(database, nodes).await
+}
+
+/// Returns every place that the place is located in, from its parent up to the top-level place.
+pub async fn get_ancestors(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    get(database, id).await?;
+    let nodes = place_manager::get_ancestors(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the place table while fetching the ancestors of a place.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    _generate_hierarchy_response(database, nodes).await
+}
+
+async fn _get_descendants<C>(con: &C, id: Uuid) -> Result<Vec<PlaceNode>, Error>
+where
+    C: ConnectionTrait,
+{
+    place_manager::get_descendants(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the place table while fetching the descendants of a place.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })
+}
+
+/// Checks that the parent is an existing place,
+/// and that the place isn't being located in itself or in one of its own descendants.
+async fn _validate_parent<C>(con: &C, id: Uuid, parent_id: Option<Uuid>) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let Some(parent_id) = parent_id else {
+        return Ok(());
+    };
+
+    let parent = place_manager::get(con, parent_id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the place table while checking the parent of a place.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    if parent.is_none() {
+        return Err(ErrorBuilder::new()
+            .msg("The parent of a place must be an existing place.")
+            .entity(PLACE)
+            .attribute("parentId")
+            .with_id(Some(&id))
+            .with_value(&parent_id)
+            .invalid());
+    }
+
+    let descendants = _get_descendants(con, id).await?;
+    if parent_id == id || descendants.iter().any(|node| node.entry_id == parent_id) {
+        return Err(ErrorBuilder::new()
+            .msg("A place cannot be located in itself or in one of its descendants.")
+            .entity(PLACE)
+            .attribute("parentId")
+            .with_id(Some(&id))
+            .with_value(&parent_id)
+            .invalid());
+    }
+
+    Ok(())
+}
+
+async fn _generate_hierarchy_response(
+    database: &DatabaseConnection,
+    nodes: Vec<PlaceNode>,
+) -> Result<Vec<EntryInfoResponseSchema>, Error> {
+    let ids: Vec<Uuid> = nodes.iter().map(|node| node.entry_id).collect();
+    let entries = entry_manager::get_many(database, ids).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while fetching the entries of places.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let entries: HashMap<Uuid, _> = entries.into_iter().map(|entry| (entry.id, entry)).collect();
+
+    let mut nodes: Vec<(i32, _)> = nodes
+        .iter()
+        .filter_map(|node| Some((node.depth, entries.get(&node.entry_id)?)))
+        .collect();
+    nodes.sort_by(|(depth_a, a), (depth_b, b)| (depth_a, &a.title).cmp(&(depth_b, &b.title)));
+
+    Ok(nodes
+        .into_iter()
+        .map(|(_, entry)| entry_service::generate_insert_response(entry))
+        .collect())
+}
+
+fn generate_response(place: &PlaceModel) -> PlaceSchema {
+    PlaceSchema {
+        place_type: place.place_type.into(),
+        parent_id: place.parent_id,
+    }
+}
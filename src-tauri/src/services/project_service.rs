@@ -1,18 +1,27 @@
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, DbErr};
 use std::fs;
 use tokio::sync::MutexGuard;
 use uuid::Uuid;
 
 use crate::constants::PROJECT_CONFIG_FILE_NAME;
-use crate::database::setup::setup_db;
+use crate::database::{
+    asset_manager, entry_manager, entry_reference_manager, folder_manager, setup::setup_db,
+    tag_manager, word_manager,
+};
 use crate::model::{
     errors::{Error, ErrorBuilder},
     project::{DatabaseConfig, Project},
     state::StateData,
 };
-use crate::schema::project::{ProjectConfigFileSchema, ProjectResponseSchema};
-use crate::services::config_service;
-use crate::types::entity::PROJECT;
+use crate::schema::{
+    backup::BackupConfigSchema,
+    common::DiagnosticResponseSchema,
+    project::{
+        EntryTypeCountSchema, ProjectConfigFileSchema, ProjectResponseSchema, ProjectStatsSchema,
+    },
+};
+use crate::services::{config_service, entry_reference_service, entry_search_service};
+use crate::types::entity::{ENTRY_TYPES, PROJECT};
 
 pub async fn create(
     state: &mut MutexGuard<'_, StateData>,
@@ -43,6 +52,7 @@ pub async fn create(
         name: name.to_string(),
         folder_path: folder_path.to_string(),
         database: db_config,
+        backup_config: BackupConfigSchema::default(),
     };
 
     let id = state.add_project(project);
@@ -78,22 +88,11 @@ pub async fn load(
     let id = match state.get_project_id_of_path(&folder_path) {
         Some(id) => id,
         None => {
-            let config_file = _read_project_config_from_file(&folder_path)?;
-
-            let connection_string = Project::generate_connection_string(&folder_path, false);
-            let db_connection = setup_db(&connection_string).await?;
-
-            let project = Project {
-                id: Uuid::new_v4(),
-                name: config_file.name,
-                folder_path: folder_path.to_string(),
-                database: DatabaseConfig {
-                    in_memory: false,
-                    connection: db_connection,
-                },
-            };
-
-            state.add_project(project)
+            let response = open(&folder_path).await?;
+            for e in response.errors.iter() {
+                println!("Failed to open project at '{folder_path}': {e}");
+            }
+            state.add_project(response.data)
         }
     };
 
@@ -104,6 +103,39 @@ pub async fn load(
     Ok(generate_response(project))
 }
 
+/// Opens the project at the given path without adding it to the app state,
+/// e.g., for tools that work on a project folder outside of the app.
+/// The project is still usable if its indexes fail to sync, so those errors are returned alongside it.
+pub async fn open(folder_path: &str) -> Result<DiagnosticResponseSchema<Project>, Error> {
+    let config_file = _read_project_config_from_file(folder_path)?;
+
+    let connection_string = Project::generate_connection_string(folder_path, false);
+    let db_connection = setup_db(&connection_string).await?;
+
+    let mut errors: Vec<Error> = Vec::new();
+    if let Err(e) = entry_search_service::sync(&db_connection).await {
+        errors.push(e);
+    }
+    if let Err(e) = entry_reference_service::sync(&db_connection).await {
+        errors.push(e);
+    }
+
+    let project = Project {
+        id: Uuid::new_v4(),
+        name: config_file.name,
+        folder_path: folder_path.to_string(),
+        database: DatabaseConfig {
+            in_memory: false,
+            connection: db_connection,
+        },
+        backup_config: config_file.backup,
+    };
+    Ok(DiagnosticResponseSchema {
+        data: project,
+        errors,
+    })
+}
+
 pub async fn close(state: &mut MutexGuard<'_, StateData>, id: Uuid) -> Result<(), Error> {
     let project = match state.remove_project(id) {
         Some(project) => project,
@@ -152,12 +184,59 @@ pub async fn update(
     Ok(generate_response(project))
 }
 
-pub fn get_database<'a>(
+pub fn get_backup_config(
+    state: &MutexGuard<'_, StateData>,
+    id: Uuid,
+) -> Result<BackupConfigSchema, Error> {
+    match state.get_project(id) {
+        Some(project) => Ok(project.backup_config.clone()),
+        None => Err(ErrorBuilder::new()
+            .msg("Project not found.")
+            .entity(PROJECT)
+            .with_id(&id)
+            .not_found()),
+    }
+}
+
+pub async fn update_backup_config(
+    state: &mut MutexGuard<'_, StateData>,
+    id: Uuid,
+    backup_config: BackupConfigSchema,
+) -> Result<BackupConfigSchema, Error> {
+    if backup_config.interval_minutes == 0 {
+        return Err(ErrorBuilder::new()
+            .msg("The backup interval must be at least one minute.")
+            .entity(PROJECT)
+            .attribute("intervalMinutes")
+            .with_id(Some(&id))
+            .with_value(&backup_config.interval_minutes)
+            .invalid());
+    }
+
+    let project = match state.get_project_mut(id) {
+        Some(project) => project,
+        None => {
+            return Err(ErrorBuilder::new()
+                .msg("Project not found.")
+                .entity(PROJECT)
+                .with_id(&id)
+                .not_found());
+        }
+    };
+
+    project.backup_config = backup_config;
+
+    _write_project_config_to_file(project)?;
+
+    Ok(project.backup_config.clone())
+}
+
+pub fn get_project<'a>(
     state: &'a MutexGuard<'_, StateData>,
     project_id: Uuid,
-) -> Result<&'a DatabaseConnection, Error> {
+) -> Result<&'a Project, Error> {
     match state.get_project(project_id) {
-        Some(project) => Ok(&project.database.connection),
+        Some(project) => Ok(project),
         None => Err(ErrorBuilder::new()
             .msg("Project not found.")
             .entity(PROJECT)
@@ -166,6 +245,61 @@ pub fn get_database<'a>(
     }
 }
 
+pub fn get_database<'a>(
+    state: &'a MutexGuard<'_, StateData>,
+    project_id: Uuid,
+) -> Result<&'a DatabaseConnection, Error> {
+    Ok(&get_project(state, project_id)?.database.connection)
+}
+
+pub async fn get_stats(database: &DatabaseConnection) -> Result<ProjectStatsSchema, Error> {
+    let query_failed = |e: DbErr| {
+        ErrorBuilder::new()
+            .msg("Failed to query the database while counting the contents of the project.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    };
+
+    let mut entry_type_counts: Vec<EntryTypeCountSchema> = Vec::new();
+    for entity_type in ENTRY_TYPES {
+        let count = entry_manager::count_of_type(database, entity_type)
+            .await
+            .map_err(query_failed)?;
+        entry_type_counts.push(EntryTypeCountSchema { entity_type, count });
+    }
+
+    let folders = folder_manager::get_all_models(database)
+        .await
+        .map_err(query_failed)?;
+    let trashed_folder_count = folders
+        .iter()
+        .filter(|folder| folder.deleted_at.is_some())
+        .count() as u64;
+
+    Ok(ProjectStatsSchema {
+        entry_count: entry_type_counts.iter().map(|count| count.count).sum(),
+        entry_type_counts,
+        folder_count: folders.len() as u64 - trashed_folder_count,
+        word_count: word_manager::count(database).await.map_err(query_failed)?,
+        tag_count: tag_manager::get_all(database)
+            .await
+            .map_err(query_failed)?
+            .len() as u64,
+        asset_count: asset_manager::get_all(database)
+            .await
+            .map_err(query_failed)?
+            .len() as u64,
+        reference_count: entry_reference_manager::count(database)
+            .await
+            .map_err(query_failed)?,
+        trashed_entry_count: entry_manager::count_trashed(database)
+            .await
+            .map_err(query_failed)?,
+        trashed_folder_count,
+    })
+}
+
 fn _read_project_config_from_file(folder_path: &str) -> Result<ProjectConfigFileSchema, Error> {
     let config_file_path = format!("{folder_path}/{PROJECT_CONFIG_FILE_NAME}");
 
@@ -174,6 +308,7 @@ fn _read_project_config_from_file(folder_path: &str) -> Result<ProjectConfigFile
     if !exists {
         return Ok(ProjectConfigFileSchema {
             name: "New project".to_string(),
+            backup: BackupConfigSchema::default(),
         });
     }
 
@@ -199,6 +334,7 @@ fn _read_project_config_from_file(folder_path: &str) -> Result<ProjectConfigFile
 fn _write_project_config_to_file(project: &Project) -> Result<(), Error> {
     let config = ProjectConfigFileSchema {
         name: project.name.clone(),
+        backup: project.backup_config.clone(),
     };
 
     let config_text = match serde_json::to_string(&config) {
@@ -0,0 +1,488 @@
+use std::collections::HashSet;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use ::entity::tag::Model as TagModel;
+
+use crate::database::{
+    entry_manager, entry_tag_manager, folder_manager, folder_tag_manager, tag_manager,
+    transaction_manager,
+};
+use crate::model::errors::{Error, ErrorBuilder};
+use crate::schema::tag::{
+    TagAssignmentSchema, TagCreateSchema, TagMergeSchema, TagResponseSchema, TagUpdateSchema,
+};
+use crate::types::entity::{ENTRY, FOLDER, TAG};
+
+const TAG_NAME_SEPARATOR: char = '/';
+
+pub async fn create(
+    database: &DatabaseConnection,
+    tag: TagCreateSchema,
+) -> Result<TagResponseSchema, Error> {
+    let name = _normalize_name(None, &tag.name)?;
+    _validate_color(None, &tag.color)?;
+    _validate_name_is_free(database, None, &name).await?;
+
+    let tag = tag_manager::insert(database, &name, tag.color)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Tag not created.")
+                .from_err(e)
+                .entity(TAG)
+                .not_created()
+        })?;
+    Ok(generate_response(&tag))
+}
+
+/// Updates the name and colour of the tag.
+/// Renaming a tag also renames the tags nested in it, e.g., "faction/north" follows "faction".
+pub async fn update(
+    database: &DatabaseConnection,
+    tag: TagUpdateSchema,
+) -> Result<TagResponseSchema, Error> {
+    let id = tag.id;
+    let existing = _get_tag(database, id).await?;
+    let name = _normalize_name(Some(id), &tag.name)?;
+    _validate_color(Some(id), &tag.color)?;
+
+    if name != existing.name {
+        _validate_name_is_free(database, Some(id), &name).await?;
+        let nested_tags = tag_manager::get_nested(database, &existing.name)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the tag table while fetching nested tags.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?;
+        let nested_ids: HashSet<Uuid> = nested_tags.iter().map(|tag| tag.id).collect();
+        for nested_tag in nested_tags.iter() {
+            let nested_name = format!("{}{}", name, &nested_tag.name[existing.name.len()..]);
+            let conflict = tag_manager::get_by_name(database, &nested_name)
+                .await
+                .map_err(|e| {
+                    ErrorBuilder::new()
+                        .msg(
+                            "Failed to query the tag table while checking whether a name is taken.",
+                        )
+                        .from_err(e)
+                        .db()
+                        .query_failed()
+                })?;
+            if conflict.is_some_and(|conflict| !nested_ids.contains(&conflict.id)) {
+                return Err(ErrorBuilder::new()
+                    .msg("Renaming the tag would give one of its nested tags the name of an existing tag.")
+                    .entity(TAG)
+                    .attribute("name")
+                    .with_id(Some(&id))
+                    .with_value(&nested_name)
+                    .not_unique());
+            }
+        }
+    }
+
+    let txn = transaction_manager::begin(database).await?;
+
+    // nested tags are renamed first, in case the new name is nested in the old one
+    if name != existing.name {
+        tag_manager::rename_nested(&txn, &existing.name, &name)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to rename the nested tags of a tag.")
+                    .from_err(e)
+                    .entity(TAG)
+                    .with_id(&id)
+                    .not_updated()
+            })?;
+    }
+    let updated_tag = tag_manager::update(&txn, id, &name, tag.color)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Tag not updated.")
+                .from_err(e)
+                .entity(TAG)
+                .with_id(&id)
+                .not_updated()
+        })?;
+
+    transaction_manager::end(txn).await?;
+
+    Ok(generate_response(&updated_tag))
+}
+
+/// Deletes the tag along with its links to entries and folders.
+/// Nested tags are kept.
+pub async fn delete(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    tag_manager::delete(database, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Tag not deleted.")
+            .from_err(e)
+            .entity(TAG)
+            .with_id(&id)
+            .not_deleted()
+    })?;
+    Ok(())
+}
+
+/// Returns all of the tags sorted by name, so that nested tags follow the tags they're nested in.
+pub async fn get_all(database: &DatabaseConnection) -> Result<Vec<TagResponseSchema>, Error> {
+    let tags = tag_manager::get_all(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the tag table while fetching all tags.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    Ok(tags.iter().map(generate_response).collect())
+}
+
+/// Moves the entries and folders of the source tags to the target tag, then deletes the source tags.
+pub async fn merge(
+    database: &DatabaseConnection,
+    merge: TagMergeSchema,
+) -> Result<TagResponseSchema, Error> {
+    let target = _get_tag(database, merge.target_id).await?;
+    let source_ids: Vec<Uuid> = merge
+        .source_ids
+        .into_iter()
+        .filter(|id| *id != target.id)
+        .collect();
+    _validate_tag_ids(database, &source_ids).await?;
+
+    let txn = transaction_manager::begin(database).await?;
+
+    for source_id in source_ids.iter() {
+        _move_links(&txn, *source_id, target.id).await?;
+        tag_manager::delete(&txn, *source_id).await.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to delete a merged tag.")
+                .from_err(e)
+                .entity(TAG)
+                .with_id(source_id)
+                .not_deleted()
+        })?;
+    }
+
+    transaction_manager::end(txn).await?;
+
+    Ok(generate_response(&target))
+}
+
+/// Tags each of the entries and folders with each of the tags.
+pub async fn tag(
+    database: &DatabaseConnection,
+    assignment: TagAssignmentSchema,
+) -> Result<(), Error> {
+    _validate_assignment(database, &assignment).await?;
+
+    let txn = transaction_manager::begin(database).await?;
+
+    if !assignment.entry_ids.is_empty() {
+        entry_tag_manager::insert_many(&txn, assignment.tag_ids.clone(), assignment.entry_ids)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to tag entries.")
+                    .from_err(e)
+                    .entity(TAG)
+                    .not_updated()
+            })?;
+    }
+    if !assignment.folder_ids.is_empty() {
+        folder_tag_manager::insert_many(&txn, assignment.tag_ids, assignment.folder_ids)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to tag folders.")
+                    .from_err(e)
+                    .entity(TAG)
+                    .not_updated()
+            })?;
+    }
+
+    transaction_manager::end(txn).await
+}
+
+/// Removes each of the tags from each of the entries and folders.
+pub async fn untag(
+    database: &DatabaseConnection,
+    assignment: TagAssignmentSchema,
+) -> Result<(), Error> {
+    let txn = transaction_manager::begin(database).await?;
+
+    entry_tag_manager::delete_many(&txn, assignment.tag_ids.clone(), assignment.entry_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to untag entries.")
+                .from_err(e)
+                .entity(TAG)
+                .not_updated()
+        })?;
+    folder_tag_manager::delete_many(&txn, assignment.tag_ids, assignment.folder_ids)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to untag folders.")
+                .from_err(e)
+                .entity(TAG)
+                .not_updated()
+        })?;
+
+    transaction_manager::end(txn).await
+}
+
+/// Returns the tags of the entry itself, without those of its folders.
+pub async fn get_for_entry(
+    database: &DatabaseConnection,
+    entry_id: Uuid,
+) -> Result<Vec<TagResponseSchema>, Error> {
+    let links = entry_tag_manager::get_for_entries(database, vec![entry_id])
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the entry_tag table while fetching the tags of an entry.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    _get_tags(database, links.iter().map(|link| link.tag_id).collect()).await
+}
+
+pub async fn get_for_folder(
+    database: &DatabaseConnection,
+    folder_id: Uuid,
+) -> Result<Vec<TagResponseSchema>, Error> {
+    let links = folder_tag_manager::get_for_folders(database, vec![folder_id])
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the folder_tag table while fetching the tags of a folder.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    _get_tags(database, links.iter().map(|link| link.tag_id).collect()).await
+}
+
+async fn _move_links<C>(con: &C, source_id: Uuid, target_id: Uuid) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let query_failed = |e| {
+        ErrorBuilder::new()
+            .msg("Failed to move the entries and folders of a merged tag.")
+            .from_err(e)
+            .entity(TAG)
+            .with_id(&target_id)
+            .not_updated()
+    };
+
+    let entry_ids: Vec<Uuid> = entry_tag_manager::get_for_tag(con, source_id)
+        .await
+        .map_err(query_failed)?
+        .iter()
+        .map(|link| link.entry_id)
+        .collect();
+    if !entry_ids.is_empty() {
+        entry_tag_manager::insert_many(con, vec![target_id], entry_ids)
+            .await
+            .map_err(query_failed)?;
+    }
+
+    let folder_ids: Vec<Uuid> = folder_tag_manager::get_for_tag(con, source_id)
+        .await
+        .map_err(query_failed)?
+        .iter()
+        .map(|link| link.folder_id)
+        .collect();
+    if !folder_ids.is_empty() {
+        folder_tag_manager::insert_many(con, vec![target_id], folder_ids)
+            .await
+            .map_err(query_failed)?;
+    }
+    Ok(())
+}
+
+async fn _validate_assignment(
+    database: &DatabaseConnection,
+    assignment: &TagAssignmentSchema,
+) -> Result<(), Error> {
+    _validate_tag_ids(database, &assignment.tag_ids).await?;
+
+    let existing_entry_ids =
+        entry_manager::get_existing_ids(database, assignment.entry_ids.clone())
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the entry table while checking the entries to tag.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?;
+    if let Some(id) = _find_missing(&assignment.entry_ids, &existing_entry_ids) {
+        return Err(ErrorBuilder::new()
+            .msg("Entry not found.")
+            .entity(ENTRY)
+            .with_id(&id)
+            .not_found());
+    }
+
+    let existing_folder_ids =
+        folder_manager::get_existing_ids(database, assignment.folder_ids.clone())
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the folder table while checking the folders to tag.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?;
+    if let Some(id) = _find_missing(&assignment.folder_ids, &existing_folder_ids) {
+        return Err(ErrorBuilder::new()
+            .msg("Folder not found.")
+            .entity(FOLDER)
+            .with_id(&id)
+            .not_found());
+    }
+    Ok(())
+}
+
+async fn _validate_tag_ids(database: &DatabaseConnection, ids: &[Uuid]) -> Result<(), Error> {
+    let tags = tag_manager::get_many(database, ids.to_vec())
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the tag table while checking tags.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    let existing_ids: Vec<Uuid> = tags.iter().map(|tag| tag.id).collect();
+    match _find_missing(ids, &existing_ids) {
+        Some(id) => Err(ErrorBuilder::new()
+            .msg("Tag not found.")
+            .entity(TAG)
+            .with_id(&id)
+            .not_found()),
+        None => Ok(()),
+    }
+}
+
+fn _find_missing(ids: &[Uuid], existing_ids: &[Uuid]) -> Option<Uuid> {
+    ids.iter().find(|id| !existing_ids.contains(id)).copied()
+}
+
+async fn _validate_name_is_free(
+    database: &DatabaseConnection,
+    id: Option<Uuid>,
+    name: &str,
+) -> Result<(), Error> {
+    let existing = tag_manager::get_by_name(database, name)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the tag table while checking whether a name is taken.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+    if existing.is_some_and(|existing| Some(existing.id) != id) {
+        return Err(ErrorBuilder::new()
+            .msg("Tag names must be unique; merge the tags instead.")
+            .entity(TAG)
+            .attribute("name")
+            .with_id(id.as_ref())
+            .with_value(&name)
+            .not_unique());
+    }
+    Ok(())
+}
+
+/// Trims the parts of a nested name, e.g., " faction / north " becomes "faction/north".
+fn _normalize_name(id: Option<Uuid>, name: &str) -> Result<String, Error> {
+    let parts: Vec<&str> = name.split(TAG_NAME_SEPARATOR).map(str::trim).collect();
+    let invalid = |msg: &str| {
+        ErrorBuilder::new()
+            .msg(msg)
+            .entity(TAG)
+            .attribute("name")
+            .with_id(id.as_ref())
+            .with_value(&name)
+            .invalid()
+    };
+    if parts.iter().any(|part| part.is_empty()) {
+        return Err(invalid(
+            "Tag names and each of their nested parts can't be empty.",
+        ));
+    }
+    // double quotes delimit tag names in tag expressions
+    if name.contains('"') {
+        return Err(invalid("Tag names can't contain double quotes."));
+    }
+    Ok(parts.join(&TAG_NAME_SEPARATOR.to_string()))
+}
+
+fn _validate_color(id: Option<Uuid>, color: &Option<String>) -> Result<(), Error> {
+    let Some(color) = color else {
+        return Ok(());
+    };
+    let is_valid = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !is_valid {
+        return Err(ErrorBuilder::new()
+            .msg("Tag colours must be hex colours such as \"#a0c4ff\".")
+            .entity(TAG)
+            .attribute("color")
+            .with_id(id.as_ref())
+            .with_value(color)
+            .invalid());
+    }
+    Ok(())
+}
+
+async fn _get_tag(database: &DatabaseConnection, id: Uuid) -> Result<TagModel, Error> {
+    let tag = tag_manager::get(database, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the tag table while fetching a tag by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    tag.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Tag not found.")
+            .entity(TAG)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+async fn _get_tags(
+    database: &DatabaseConnection,
+    ids: Vec<Uuid>,
+) -> Result<Vec<TagResponseSchema>, Error> {
+    let tags = tag_manager::get_many(database, ids).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the tag table while fetching tags.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    Ok(tags.iter().map(generate_response).collect())
+}
+
+fn generate_response(tag: &TagModel) -> TagResponseSchema {
+    TagResponseSchema {
+        id: tag.id,
+        name: tag.name.clone(),
+        color: tag.color.clone(),
+    }
+}
@@ -0,0 +1,485 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, TransactionTrait};
+use uuid::Uuid;
+
+use ::entity::{entry::Model as EntryModel, folder::Model as FolderModel};
+
+use crate::constants::ROOT_FOLDER_ID;
+use crate::database::{entry_manager, file_manager, folder_manager, transaction_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    journal::{JournalChange, JournalOperation},
+};
+use crate::schema::{
+    file::BulkFileResponseSchema,
+    trash::{
+        TrashResponseSchema, TrashRestoreResponseSchema, TrashedEntryResponseSchema,
+        TrashedFolderResponseSchema,
+    },
+};
+use crate::services::{entry_service, file_service, folder_service, journal_service};
+use crate::types::entity::{ENTRY, FOLDER};
+
+/// Moves the entry to the trash.
+pub async fn trash_entry(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    let txn = transaction_manager::begin(database).await?;
+    let entry = _get_entry(&txn, id).await?;
+    if entry.deleted_at.is_some() {
+        return Ok(());
+    }
+    _trash_entry(&txn, id).await?;
+
+    let mut change = JournalChange::new();
+    change.push(
+        JournalOperation::RestoreEntry { id },
+        JournalOperation::TrashEntry { id },
+    );
+    journal_service::record(&txn, "Delete entry", change).await?;
+    transaction_manager::end(txn).await
+}
+
+/// Moves the entry to the trash without recording the change in the journal.
+pub async fn _trash_entry<C>(con: &C, id: Uuid) -> Result<(), Error>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let entry = _get_entry(con, id).await?;
+    if entry.deleted_at.is_some() {
+        return Ok(());
+    }
+
+    entry_manager::trash_many(con, vec![id], Utc::now())
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Entry not moved to the trash.")
+                .from_err(e)
+                .entity(ENTRY)
+                .with_id(&id)
+                .not_deleted()
+        })?;
+    Ok(())
+}
+
+/// Moves the folder to the trash along with its subfolders and entries.
+/// Returns the contents of the folder, which are no longer part of the file tree.
+pub async fn trash_folder(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<BulkFileResponseSchema, Error> {
+    let txn = transaction_manager::begin(database).await?;
+    let folder = _get_folder(&txn, id).await?;
+    let contents = _trash_folder(&txn, id).await?;
+    if folder.deleted_at.is_some() {
+        return Ok(contents);
+    }
+
+    let mut change = JournalChange::new();
+    change.push(
+        JournalOperation::RestoreFolder { id },
+        JournalOperation::TrashFolder { id },
+    );
+    journal_service::record(&txn, "Delete folder", change).await?;
+    transaction_manager::end(txn).await?;
+
+    Ok(contents)
+}
+
+/// Moves the folder to the trash without recording the change in the journal.
+pub async fn _trash_folder<C>(con: &C, id: Uuid) -> Result<BulkFileResponseSchema, Error>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let folder = _get_folder(con, id).await?;
+    let contents = file_service::get_folder_contents(con, id).await?;
+    if folder.deleted_at.is_some() {
+        return Ok(contents);
+    }
+
+    // everything in the folder shares its deletion time,
+    // which is how they are told apart from items that were deleted on their own
+    let deleted_at = Utc::now();
+    let txn = transaction_manager::begin(con).await?;
+    let not_deleted = |e: DbErr| {
+        ErrorBuilder::new()
+            .msg("Folder not moved to the trash.")
+            .from_err(e)
+            .entity(FOLDER)
+            .with_id(&id)
+            .not_deleted()
+    };
+    folder_manager::trash_many(&txn, contents.folders.clone(), deleted_at)
+        .await
+        .map_err(not_deleted)?;
+    entry_manager::trash_many(&txn, contents.entries.clone(), deleted_at)
+        .await
+        .map_err(not_deleted)?;
+    transaction_manager::end(txn).await?;
+
+    Ok(contents)
+}
+
+pub async fn get_all(database: &DatabaseConnection) -> Result<TrashResponseSchema, Error> {
+    let folders = folder_manager::get_trashed(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the folder table while fetching the trash.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    let entries = entry_manager::get_trashed(database).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the entry table while fetching the trash.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+
+    let deletion_times: HashMap<Uuid, DateTime<Utc>> = folders
+        .iter()
+        .filter_map(|folder| Some((folder.id, folder.deleted_at?)))
+        .collect();
+    // an item was deleted along with its parent if they were deleted at the same time
+    let is_deleted_with_parent = |parent_id: Option<Uuid>, deleted_at: Option<DateTime<Utc>>| {
+        parent_id
+            .and_then(|parent_id| deletion_times.get(&parent_id))
+            .is_some_and(|parent_deleted_at| Some(*parent_deleted_at) == deleted_at)
+    };
+
+    let mut entry_counts: HashMap<Uuid, usize> = HashMap::new();
+    let mut trashed_entries: Vec<TrashedEntryResponseSchema> = Vec::new();
+    for entry in entries.iter() {
+        let Some(deleted_at) = entry.deleted_at else {
+            continue;
+        };
+        if is_deleted_with_parent(entry.folder_id, entry.deleted_at) {
+            // counted against the folder that the deletion started from
+            let mut folder_id = entry.folder_id;
+            while let Some(parent_id) = folders
+                .iter()
+                .find(|folder| Some(folder.id) == folder_id)
+                .filter(|folder| is_deleted_with_parent(folder.parent_id, folder.deleted_at))
+                .map(|folder| folder.parent_id)
+            {
+                folder_id = parent_id;
+            }
+            if let Some(folder_id) = folder_id {
+                *entry_counts.entry(folder_id).or_default() += 1;
+            }
+        } else {
+            trashed_entries.push(TrashedEntryResponseSchema {
+                info: entry_service::generate_insert_response(entry),
+                deleted_at,
+            });
+        }
+    }
+
+    let trashed_folders = folders
+        .iter()
+        .filter(|folder| !is_deleted_with_parent(folder.parent_id, folder.deleted_at))
+        .filter_map(|folder| {
+            Some(TrashedFolderResponseSchema {
+                folder: folder_service::generate_response(folder),
+                deleted_at: folder.deleted_at?,
+                entry_count: entry_counts.get(&folder.id).copied().unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    Ok(TrashResponseSchema {
+        entries: trashed_entries,
+        folders: trashed_folders,
+    })
+}
+
+/// Takes the entry out of the trash and puts it back into its folder.
+/// If the folder no longer exists, then the entry is put in the root folder instead,
+/// and if its title has been taken in the meantime, then it is given a new one.
+pub async fn restore_entry(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<TrashRestoreResponseSchema, Error> {
+    let txn = transaction_manager::begin(database).await?;
+    let response = _restore_entry(&txn, id).await?;
+
+    let mut change = JournalChange::new();
+    change.push(
+        JournalOperation::TrashEntry { id },
+        JournalOperation::RestoreEntry { id },
+    );
+    journal_service::record(&txn, "Restore entry", change).await?;
+    transaction_manager::end(txn).await?;
+
+    Ok(response)
+}
+
+/// Takes the entry out of the trash without recording the change in the journal.
+pub async fn _restore_entry<C>(con: &C, id: Uuid) -> Result<TrashRestoreResponseSchema, Error>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let entry = _get_entry(con, id).await?;
+    if entry.deleted_at.is_none() {
+        return Err(ErrorBuilder::new()
+            .msg("Entry is not in the trash.")
+            .entity(ENTRY)
+            .with_id(&id)
+            .not_found());
+    }
+
+    let txn = transaction_manager::begin(con).await?;
+    let folder_id = _get_restored_parent_id(&txn, entry.folder_id).await?;
+    let title = _get_free_title(&txn, id, &entry.title).await?;
+    let entry = entry_manager::restore(&txn, id, folder_id, title)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Entry not restored from the trash.")
+                .from_err(e)
+                .entity(ENTRY)
+                .with_id(&id)
+                .not_updated()
+        })?;
+    transaction_manager::end(txn).await?;
+
+    Ok(TrashRestoreResponseSchema {
+        entries: vec![entry_service::generate_insert_response(&entry)],
+        folders: Vec::new(),
+    })
+}
+
+/// Takes the folder out of the trash along with the items that were deleted with it.
+/// Items in the folder that were deleted on their own stay in the trash.
+/// Collisions with the file tree are resolved in the same way as when restoring an entry.
+pub async fn restore_folder(
+    database: &DatabaseConnection,
+    id: Uuid,
+) -> Result<TrashRestoreResponseSchema, Error> {
+    let txn = transaction_manager::begin(database).await?;
+    let response = _restore_folder(&txn, id).await?;
+
+    let mut change = JournalChange::new();
+    change.push(
+        JournalOperation::TrashFolder { id },
+        JournalOperation::RestoreFolder { id },
+    );
+    journal_service::record(&txn, "Restore folder", change).await?;
+    transaction_manager::end(txn).await?;
+
+    Ok(response)
+}
+
+/// Takes the folder out of the trash without recording the change in the journal.
+pub async fn _restore_folder<C>(con: &C, id: Uuid) -> Result<TrashRestoreResponseSchema, Error>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    let folder = _get_folder(con, id).await?;
+    let Some(deleted_at) = folder.deleted_at else {
+        return Err(ErrorBuilder::new()
+            .msg("Folder is not in the trash.")
+            .entity(FOLDER)
+            .with_id(&id)
+            .not_found());
+    };
+
+    let contents = file_service::get_folder_contents(con, id).await?;
+
+    let txn = transaction_manager::begin(con).await?;
+    let query_failed = |e: DbErr| {
+        ErrorBuilder::new()
+            .msg("Failed to query the contents of a folder.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    };
+    let folders = folder_manager::get_many(&txn, contents.folders)
+        .await
+        .map_err(query_failed)?;
+    let entries = entry_manager::get_many_trashed(&txn, contents.entries)
+        .await
+        .map_err(query_failed)?;
+
+    let mut restored_folders = Vec::new();
+    for subfolder in folders
+        .iter()
+        .filter(|subfolder| subfolder.deleted_at == Some(deleted_at))
+    {
+        let parent_id = if subfolder.id == id {
+            _get_restored_parent_id(&txn, subfolder.parent_id).await?
+        } else {
+            file_manager::convert_null_folder_id_to_root(subfolder.parent_id)
+        };
+        let name = _get_free_folder_name(&txn, parent_id, &subfolder.name).await?;
+        let restored_folder = folder_manager::restore(&txn, subfolder.id, parent_id, &name)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Folder not restored from the trash.")
+                    .from_err(e)
+                    .entity(FOLDER)
+                    .with_id(&subfolder.id)
+                    .not_updated()
+            })?;
+        restored_folders.push(folder_service::generate_response(&restored_folder));
+    }
+
+    let mut restored_entries = Vec::new();
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.deleted_at == Some(deleted_at))
+    {
+        let title = _get_free_title(&txn, entry.id, &entry.title).await?;
+        let folder_id = file_manager::convert_null_folder_id_to_root(entry.folder_id);
+        let restored_entry = entry_manager::restore(&txn, entry.id, folder_id, title)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Entry not restored from the trash.")
+                    .from_err(e)
+                    .entity(ENTRY)
+                    .with_id(&entry.id)
+                    .not_updated()
+            })?;
+        restored_entries.push(entry_service::generate_insert_response(&restored_entry));
+    }
+    transaction_manager::end(txn).await?;
+
+    Ok(TrashRestoreResponseSchema {
+        entries: restored_entries,
+        folders: restored_folders,
+    })
+}
+
+/// Permanently deletes everything in the trash.
+pub async fn empty(database: &DatabaseConnection) -> Result<(), Error> {
+    let txn = transaction_manager::begin(database).await?;
+    entry_manager::delete_trashed(&txn).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to delete the entries in the trash.")
+            .from_err(e)
+            .entity(ENTRY)
+            .not_deleted()
+    })?;
+    folder_manager::delete_trashed(&txn).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to delete the folders in the trash.")
+            .from_err(e)
+            .entity(FOLDER)
+            .not_deleted()
+    })?;
+    transaction_manager::end(txn).await
+}
+
+async fn _get_entry<C>(con: &C, id: Uuid) -> Result<EntryModel, Error>
+where
+    C: ConnectionTrait,
+{
+    let entry = entry_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query entry table while fetching an entry by id.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    entry.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Entry not found.")
+            .entity(ENTRY)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+async fn _get_folder<C>(con: &C, id: Uuid) -> Result<FolderModel, Error>
+where
+    C: ConnectionTrait,
+{
+    let folder = folder_manager::get(con, id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the folder table while fetching a folder by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    folder.ok_or_else(|| {
+        ErrorBuilder::new()
+            .msg("Folder not found.")
+            .entity(FOLDER)
+            .with_id(&id)
+            .not_found()
+    })
+}
+
+/// Returns the original parent of a restored item, or the root folder if the parent is in the trash.
+async fn _get_restored_parent_id<C>(con: &C, parent_id: Option<Uuid>) -> Result<Uuid, Error>
+where
+    C: ConnectionTrait,
+{
+    let Some(parent_id) = parent_id else {
+        return Ok(ROOT_FOLDER_ID);
+    };
+    let parent = folder_manager::get(con, parent_id).await.map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Failed to query the folder table while fetching a folder by ID.")
+            .from_err(e)
+            .db()
+            .query_failed()
+    })?;
+    match parent {
+        Some(parent) if parent.deleted_at.is_none() => Ok(parent_id),
+        _ => Ok(ROOT_FOLDER_ID),
+    }
+}
+
+/// Returns the title, or the first of "Title (2)", "Title (3)", etc. that isn't taken.
+async fn _get_free_title<C>(con: &C, id: Uuid, title: &str) -> Result<String, Error>
+where
+    C: ConnectionTrait,
+{
+    let mut candidate = title.to_owned();
+    let mut suffix = 1;
+    loop {
+        let is_unique = entry_manager::is_title_unique_for_id(con, Some(id), &candidate)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the entry table while checking whether the title is unique.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?;
+        if is_unique {
+            return Ok(candidate);
+        }
+        suffix += 1;
+        candidate = format!("{title} ({suffix})");
+    }
+}
+
+/// Returns the name, or the first of "Name (2)", "Name (3)", etc. that isn't taken in the parent.
+pub async fn _get_free_folder_name<C>(con: &C, parent_id: Uuid, name: &str) -> Result<String, Error>
+where
+    C: ConnectionTrait,
+{
+    let mut candidate = name.to_owned();
+    let mut suffix = 1;
+    loop {
+        let is_unique = folder_manager::is_name_unique_at_location(con, parent_id, &candidate)
+            .await
+            .map_err(|e| {
+                ErrorBuilder::new()
+                    .msg("Failed to query the folder table while verifying whether a name is locally unique.")
+                    .from_err(e)
+                    .db()
+                    .query_failed()
+            })?;
+        if is_unique {
+            return Ok(candidate);
+        }
+        suffix += 1;
+        candidate = format!("{name} ({suffix})");
+    }
+}
@@ -1,22 +1,54 @@
-use sea_orm::{ConnectionTrait, DatabaseConnection};
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr};
 use uuid::Uuid;
 
+use ::entity::inflected_word::Model as InflectedWord;
 use ::entity::word::Model as Word;
 use serde_json;
 
-use crate::database::word_manager;
-use crate::model::errors::{Error, ErrorBuilder};
+use crate::database::{inflected_word_manager, transaction_manager, word_manager};
+use crate::model::{
+    errors::{Error, ErrorBuilder},
+    grammar::{self, Inflection},
+    journal::{JournalChange, JournalOperation},
+};
 use crate::schema::{
     common::DiagnosticResponseSchema,
-    word::{WordResponseSchema, WordUpsertResponseSchema, WordUpsertSchema},
+    word::{
+        InflectedWordCreateSchema, InflectedWordResponseSchema, InflectedWordUpdateSchema,
+        WordResponseSchema, WordUpsertResponseSchema, WordUpsertSchema,
+    },
 };
-use crate::types::entity::WORD;
+use crate::services::{journal_service, language_service};
+use crate::types::entity::{INFLECTED_WORD, WORD};
 use crate::types::grammar::WordType;
 
 pub async fn bulk_upsert(
     database: &DatabaseConnection,
     words: Vec<WordUpsertSchema>,
 ) -> Result<Vec<DiagnosticResponseSchema<WordUpsertResponseSchema>>, Error> {
+    let txn = transaction_manager::begin(database).await?;
+    let previous_words = _get_journal_states(&txn, &words).await?;
+    let responses = _bulk_upsert(&txn, words).await?;
+
+    let upserted_words: Vec<WordUpsertResponseSchema> =
+        responses.iter().map(|r| r.data.clone()).collect();
+    let change = _get_upsert_change(&txn, &previous_words, &upserted_words).await?;
+    journal_service::record(&txn, "Update words", change).await?;
+    transaction_manager::end(txn).await?;
+
+    Ok(responses)
+}
+
+/// Creates or updates the words without recording the change in the journal.
+pub async fn _bulk_upsert<C>(
+    con: &C,
+    words: Vec<WordUpsertSchema>,
+) -> Result<Vec<DiagnosticResponseSchema<WordUpsertResponseSchema>>, Error>
+where
+    C: ConnectionTrait,
+{
     let mut responses: Vec<DiagnosticResponseSchema<WordUpsertResponseSchema>> = Vec::new();
 
     for word in words {
@@ -28,11 +60,11 @@ pub async fn bulk_upsert(
         let result: Result<Word, Error> = match word.id {
             Some(_) => {
                 updated = true;
-                _update(database, word, &mut errors).await
+                _update(con, word, &mut errors).await
             }
             None => {
                 created = true;
-                _create(database, word, &mut errors).await
+                _create(con, word, &mut errors).await
             }
         };
 
@@ -56,6 +88,85 @@ pub async fn bulk_upsert(
     Ok(responses)
 }
 
+/// Returns the current state of the existing words among `words`,
+/// so that the changes made to them can be undone.
+pub async fn _get_journal_states<C>(
+    con: &C,
+    words: &[WordUpsertSchema],
+) -> Result<HashMap<Uuid, WordResponseSchema>, Error>
+where
+    C: ConnectionTrait,
+{
+    let mut states = HashMap::new();
+    for id in words.iter().filter_map(|word| word.id) {
+        let word = word_manager::get(con, id).await.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the word table while fetching a word by ID.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+        if let Some(word) = word {
+            states.insert(id, generate_response(&word)?);
+        }
+    }
+    Ok(states)
+}
+
+/// Returns the change made by an upsert, given the state of the words before it.
+pub async fn _get_upsert_change<C>(
+    con: &C,
+    previous_words: &HashMap<Uuid, WordResponseSchema>,
+    upserted_words: &[WordUpsertResponseSchema],
+) -> Result<JournalChange, Error>
+where
+    C: ConnectionTrait,
+{
+    let mut change = JournalChange::new();
+    for upserted_word in upserted_words {
+        if !upserted_word.status.created && !upserted_word.status.updated {
+            continue;
+        }
+        let Some(id) = upserted_word.id else {
+            continue;
+        };
+
+        let undo = match previous_words.get(&id) {
+            Some(word) => JournalOperation::PutWord { word: word.clone() },
+            None => JournalOperation::DeleteWord { id },
+        };
+        let word = get(con, id).await?;
+        change.push(undo, JournalOperation::PutWord { word });
+    }
+    Ok(change)
+}
+
+/// Puts the word back in the given state, creating it again if it has been deleted.
+pub async fn _put<C>(con: &C, word: WordResponseSchema) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let translations = _serialize_translations(&Some(word.translations))?.unwrap_or_default();
+    word_manager::upsert(
+        con,
+        word.id,
+        word.language_id,
+        word.word_type,
+        word.spelling,
+        word.definition,
+        translations,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Word not updated.")
+            .from_err(e)
+            .entity(WORD)
+            .with_id(&word.id)
+            .not_updated()
+    })
+}
+
 async fn _create<C>(con: &C, word: WordUpsertSchema, errors: &mut Vec<Error>) -> Result<Word, Error>
 where
     C: ConnectionTrait,
@@ -75,6 +186,14 @@ where
             .invalid());
     }
 
+    _validate_word_type(
+        con,
+        None,
+        word.language_id.unwrap(),
+        word.word_type.unwrap(),
+    )
+    .await?;
+
     let translations = match _serialize_translations(&word.translations) {
         Ok(t) => t,
         Err(e) => {
@@ -113,6 +232,26 @@ where
             .with_optional_value(&word.id)
             .invalid());
     }
+    let id = word.id.unwrap();
+
+    if word.word_type.is_some() || word.language_id.is_some() {
+        let existing_word = word_manager::get(con, id).await.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the word table while fetching a word by ID.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+        // updating a nonexistent word fails below
+        if let Some(existing_word) = existing_word {
+            // the type of the word must be used by its language, even if only one of them changes
+            let language_id = word.language_id.unwrap_or(existing_word.language_id);
+            let word_type = word
+                .word_type
+                .unwrap_or(WordType::from(existing_word.word_type));
+            _validate_word_type(con, Some(id), language_id, word_type).await?;
+        }
+    }
 
     let translations = match _serialize_translations(&word.translations) {
         Ok(t) => t,
@@ -122,23 +261,84 @@ where
         }
     };
 
-    word_manager::update(
+    let result = word_manager::update(
         con,
-        word.id.unwrap(),
+        id,
         word.language_id,
         word.word_type,
         word.spelling,
         word.definition,
         translations,
+        word.expected_revision,
     )
-    .await
-    .map_err(|e| {
+    .await;
+    match (result, word.expected_revision) {
+        (Err(DbErr::RecordNotUpdated), Some(expected_revision)) => {
+            Err(_get_revision_conflict(con, id, expected_revision).await)
+        }
+        (result, _) => result.map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Word not updated.")
+                .from_err(e)
+                .entity(WORD)
+                .with_optional_id(&word.id)
+                .not_updated()
+        }),
+    }
+}
+
+/// Explains why an update that was based on the expected revision didn't apply,
+/// i.e., because the word has been written since.
+async fn _get_revision_conflict<C>(con: &C, id: Uuid, expected_revision: i32) -> Error
+where
+    C: ConnectionTrait,
+{
+    let current_word = match word_manager::get(con, id).await {
+        Ok(Some(current_word)) => current_word,
+        Ok(None) => {
+            return ErrorBuilder::new()
+                .msg("Word not found.")
+                .entity(WORD)
+                .with_id(&id)
+                .not_found();
+        }
+        Err(e) => {
+            return ErrorBuilder::new()
+                .msg("Failed to query the word table while fetching a word by ID.")
+                .from_err(e)
+                .db()
+                .query_failed();
+        }
+    };
+    match generate_response(&current_word) {
+        Ok(current) => ErrorBuilder::new()
+            .msg("The word has been changed since it was last read.")
+            .entity(WORD)
+            .with_id(&id)
+            .revision_conflict(expected_revision, current_word.revision, &current),
+        Err(e) => e,
+    }
+}
+
+async fn _validate_word_type<C>(
+    con: &C,
+    id: Option<Uuid>,
+    language_id: Uuid,
+    word_type: WordType,
+) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    let grammar = language_service::get_grammar(con, language_id).await?;
+    grammar::validate_word_type(&grammar, word_type).map_err(|e| {
         ErrorBuilder::new()
-            .msg("Word not updated.")
+            .msg("The language doesn't use the type of the word.")
             .from_err(e)
             .entity(WORD)
-            .with_optional_id(&word.id)
-            .not_updated()
+            .attribute("word_type")
+            .with_id(id.as_ref())
+            .with_value(&word_type)
+            .invalid()
     })
 }
 
@@ -159,8 +359,11 @@ pub fn _serialize_translations(
     }
 }
 
-pub async fn get(database: &DatabaseConnection, id: Uuid) -> Result<WordResponseSchema, Error> {
-    let word = word_manager::get(database, id).await.map_err(|e| {
+pub async fn get<C>(con: &C, id: Uuid) -> Result<WordResponseSchema, Error>
+where
+    C: ConnectionTrait,
+{
+    let word = word_manager::get(con, id).await.map_err(|e| {
         ErrorBuilder::new()
             .msg("Failed to query the word table while fetching a word by ID.")
             .from_err(e)
@@ -204,8 +407,27 @@ pub async fn get_all_for_language(
     Ok(word_responses)
 }
 
+/// Deletes the word along with its inflected forms.
+/// Undoing the deletion restores the word, but not its inflected forms.
 pub async fn delete(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
-    word_manager::delete(database, id).await.map_err(|e| {
+    let txn = transaction_manager::begin(database).await?;
+    let word = get(&txn, id).await?;
+    _delete(&txn, id).await?;
+
+    let mut change = JournalChange::new();
+    change.push(
+        JournalOperation::PutWord { word },
+        JournalOperation::DeleteWord { id },
+    );
+    journal_service::record(&txn, "Delete word", change).await?;
+    transaction_manager::end(txn).await
+}
+
+pub async fn _delete<C>(con: &C, id: Uuid) -> Result<(), Error>
+where
+    C: ConnectionTrait,
+{
+    word_manager::delete(con, id).await.map_err(|e| {
         ErrorBuilder::new()
             .msg("Word not deleted.")
             .from_err(e)
@@ -216,6 +438,133 @@ pub async fn delete(database: &DatabaseConnection, id: Uuid) -> Result<(), Error
     Ok(())
 }
 
+pub async fn create_inflected_word(
+    database: &DatabaseConnection,
+    inflected_word: InflectedWordCreateSchema,
+) -> Result<InflectedWordResponseSchema, Error> {
+    // raises an error if the word doesn't exist
+    let word = get(database, inflected_word.word_id).await?;
+    _validate_inflection(database, None, word.language_id, &inflected_word.inflection).await?;
+
+    let inflected_word = inflected_word_manager::insert(
+        database,
+        inflected_word.word_id,
+        inflected_word.spelling,
+        inflected_word.inflection,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Inflected word not created.")
+            .from_err(e)
+            .entity(INFLECTED_WORD)
+            .not_created()
+    })?;
+
+    Ok(generate_inflected_word_response(&inflected_word))
+}
+
+pub async fn update_inflected_word(
+    database: &DatabaseConnection,
+    inflected_word: InflectedWordUpdateSchema,
+) -> Result<InflectedWordResponseSchema, Error> {
+    let id = inflected_word.id;
+
+    if let Some(inflection) = &inflected_word.inflection {
+        let existing_inflected_word =
+            inflected_word_manager::get(database, id)
+                .await
+                .map_err(|e| {
+                    ErrorBuilder::new()
+                        .msg(
+                            "Failed to query the inflected_word table while fetching a form by ID.",
+                        )
+                        .from_err(e)
+                        .db()
+                        .query_failed()
+                })?;
+        // updating a nonexistent form fails below
+        if let Some(existing_inflected_word) = existing_inflected_word {
+            let word = get(database, existing_inflected_word.word_id).await?;
+            _validate_inflection(database, Some(id), word.language_id, inflection).await?;
+        }
+    }
+
+    let inflected_word = inflected_word_manager::update(
+        database,
+        id,
+        inflected_word.spelling,
+        inflected_word.inflection,
+    )
+    .await
+    .map_err(|e| {
+        ErrorBuilder::new()
+            .msg("Inflected word not updated.")
+            .from_err(e)
+            .entity(INFLECTED_WORD)
+            .with_id(&id)
+            .not_updated()
+    })?;
+
+    Ok(generate_inflected_word_response(&inflected_word))
+}
+
+/// Returns the inflected forms of the word, ordered by their grammatical categories.
+pub async fn get_inflected_words(
+    database: &DatabaseConnection,
+    word_id: Uuid,
+) -> Result<Vec<InflectedWordResponseSchema>, Error> {
+    // raises an error if the word doesn't exist
+    get(database, word_id).await?;
+
+    let inflected_words = inflected_word_manager::get_all_for_word(database, word_id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Failed to query the inflected_word table while fetching the forms of a word.")
+                .from_err(e)
+                .db()
+                .query_failed()
+        })?;
+
+    Ok(inflected_words
+        .iter()
+        .map(generate_inflected_word_response)
+        .collect())
+}
+
+async fn _validate_inflection(
+    database: &DatabaseConnection,
+    id: Option<Uuid>,
+    language_id: Uuid,
+    inflection: &Inflection,
+) -> Result<(), Error> {
+    let grammar = language_service::get_grammar(database, language_id).await?;
+    inflection.validate(&grammar).map_err(|e| {
+        ErrorBuilder::new()
+            .msg("The language doesn't use one of the grammatical categories of the form.")
+            .from_err(e)
+            .entity(INFLECTED_WORD)
+            .attribute("inflection")
+            .with_id(id.as_ref())
+            .invalid()
+    })
+}
+
+pub async fn delete_inflected_word(database: &DatabaseConnection, id: Uuid) -> Result<(), Error> {
+    inflected_word_manager::delete(database, id)
+        .await
+        .map_err(|e| {
+            ErrorBuilder::new()
+                .msg("Inflected word not deleted.")
+                .from_err(e)
+                .entity(INFLECTED_WORD)
+                .with_id(&id)
+                .not_deleted()
+        })?;
+    Ok(())
+}
+
 fn generate_response(word: &Word) -> Result<WordResponseSchema, Error> {
     Ok(WordResponseSchema {
         id: word.id,
@@ -224,6 +573,7 @@ fn generate_response(word: &Word) -> Result<WordResponseSchema, Error> {
         spelling: word.spelling.to_string(),
         definition: word.definition.to_string(),
         translations: _convert_translations_to_vec(word.id, &word.translations)?,
+        revision: word.revision,
     })
 }
 
@@ -261,3 +611,24 @@ fn _convert_translations_to_vec(
 
     Ok(array)
 }
+
+fn generate_inflected_word_response(inflected_word: &InflectedWord) -> InflectedWordResponseSchema {
+    InflectedWordResponseSchema {
+        id: inflected_word.id,
+        word_id: inflected_word.word_id,
+        spelling: inflected_word.spelling.to_owned(),
+        inflection: Inflection {
+            case: inflected_word.case.into(),
+            voice: inflected_word.voice.into(),
+            mood: inflected_word.mood.into(),
+            tense: inflected_word.tense.into(),
+            aspect: inflected_word.aspect.into(),
+            definiteness: inflected_word.definiteness.into(),
+            number: inflected_word.number.into(),
+            person: inflected_word.person.into(),
+            gender: inflected_word.gender.into(),
+            animacy: inflected_word.animacy.into(),
+            verb_form: inflected_word.verb_form.into(),
+        },
+    }
+}
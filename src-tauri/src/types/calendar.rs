@@ -0,0 +1,44 @@
+use std::convert::From;
+
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::utils::CodedEnum;
+
+/// How precisely the date of an event is known.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, EnumIter, Serialize_repr, Deserialize_repr,
+)]
+#[repr(i8)]
+pub enum DatePrecision {
+    #[default]
+    Exact = 0,
+    Circa = 1,
+    MonthOnly = 2,
+    YearOnly = 3,
+}
+
+impl From<i8> for DatePrecision {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+impl std::fmt::Display for DatePrecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl CodedEnum for DatePrecision {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
@@ -0,0 +1,48 @@
+use std::convert::From;
+
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::utils::CodedEnum;
+
+/// The type of the values of a custom property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, EnumIter, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+pub enum CustomPropertyType {
+    Text = 1,
+    Number = 2,
+    /// A date in one of the project's calendars.
+    Date = 3,
+    Boolean = 4,
+    /// The ID of another entry.
+    Entry = 5,
+    /// A list of values of the item type of the property.
+    List = 6,
+    /// One of the options of the property.
+    Enum = 7,
+}
+
+impl From<i8> for CustomPropertyType {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+impl std::fmt::Display for CustomPropertyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl CodedEnum for CustomPropertyType {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
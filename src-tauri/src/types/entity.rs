@@ -17,25 +17,76 @@ pub enum EntityType {
     Project = 2,
     Folder = 3,
     Entry = 4,
+    Revision = 5,
+    Alias = 6,
+    Tag = 7,
+    CustomProperty = 8,
+    Template = 9,
 
     // Dictionary
     Language = 10,
     Word = 11,
+    InflectedWord = 12,
 
     // Calendar
-    // TODO
+    Calendar = 20,
+    Date = 21,
 
     // Encyclopedia
     Person = 30,
+    Event = 31,
+    Place = 32,
+    Organization = 33,
+    Membership = 34,
+    PersonRelationship = 35,
+
+    // Atlas
+    Map = 40,
+    MapPin = 41,
+
+    // Media
+    Asset = 50,
+
+    // History
+    Journal = 60,
 }
 
 pub const SESSION: EntityType = EntityType::Session;
 pub const PROJECT: EntityType = EntityType::Project;
 pub const FOLDER: EntityType = EntityType::Folder;
 pub const ENTRY: EntityType = EntityType::Entry;
+pub const REVISION: EntityType = EntityType::Revision;
+pub const ALIAS: EntityType = EntityType::Alias;
+pub const TAG: EntityType = EntityType::Tag;
+pub const CUSTOM_PROPERTY: EntityType = EntityType::CustomProperty;
+pub const TEMPLATE: EntityType = EntityType::Template;
 pub const LANGUAGE: EntityType = EntityType::Language;
 pub const WORD: EntityType = EntityType::Word;
+pub const INFLECTED_WORD: EntityType = EntityType::InflectedWord;
+pub const CALENDAR: EntityType = EntityType::Calendar;
+pub const DATE: EntityType = EntityType::Date;
 pub const PERSON: EntityType = EntityType::Person;
+pub const EVENT: EntityType = EntityType::Event;
+pub const PLACE: EntityType = EntityType::Place;
+pub const ORGANIZATION: EntityType = EntityType::Organization;
+pub const MEMBERSHIP: EntityType = EntityType::Membership;
+pub const PERSON_RELATIONSHIP: EntityType = EntityType::PersonRelationship;
+pub const MAP: EntityType = EntityType::Map;
+pub const MAP_PIN: EntityType = EntityType::MapPin;
+pub const ASSET: EntityType = EntityType::Asset;
+pub const JOURNAL: EntityType = EntityType::Journal;
+
+/// The types of entities that are entries, i.e., that have a title and an article.
+pub const ENTRY_TYPES: [EntityType; 8] = [
+    ENTRY,
+    LANGUAGE,
+    CALENDAR,
+    PERSON,
+    EVENT,
+    PLACE,
+    MAP,
+    ORGANIZATION,
+];
 
 impl std::fmt::Display for EntityType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
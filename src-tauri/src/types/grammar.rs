@@ -7,7 +7,7 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::utils::CodedEnum;
 
-#[derive(Copy, Clone, Debug, EnumIter, Serialize_repr, Deserialize_repr)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
 #[repr(i8)]
 #[derive(Default)]
 pub enum WordType {
@@ -17,8 +17,10 @@ pub enum WordType {
     Determiner = 11,
     Preposition = 12,
     Conjunction = 13,
+    Adposition = 14,
     Pronoun = 21,
     Noun = 31,
+    Numeral = 32,
     Adjective = 41,
     Adverb = 51,
     Verb = 61,
@@ -43,8 +45,10 @@ impl std::fmt::Display for WordType {
             WordType::Determiner => "Determiner",
             WordType::Preposition => "Preposition",
             WordType::Conjunction => "Conjunction",
+            WordType::Adposition => "Adposition",
             WordType::Pronoun => "Pronoun",
             WordType::Noun => "Noun",
+            WordType::Numeral => "Numeral",
             WordType::Adjective => "Adjective",
             WordType::Adverb => "Adverb",
             WordType::Verb => "Verb",
@@ -59,7 +63,7 @@ impl CodedEnum for WordType {
     }
 }
 
-#[derive(Copy, Clone, Debug, EnumIter, Serialize_repr, Deserialize_repr)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
 #[repr(i8)]
 #[derive(Default)]
 pub enum GrammaticalNumber {
@@ -94,7 +98,7 @@ impl From<i8> for GrammaticalNumber {
     }
 }
 
-#[derive(Copy, Clone, Debug, EnumIter, Serialize_repr, Deserialize_repr)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
 #[repr(i8)]
 #[derive(Default)]
 pub enum GrammaticalGender {
@@ -122,7 +126,7 @@ impl From<i8> for GrammaticalGender {
     }
 }
 
-#[derive(Copy, Clone, Debug, EnumIter, Serialize_repr, Deserialize_repr)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
 #[repr(i8)]
 #[derive(Default)]
 pub enum GrammaticalPerson {
@@ -150,7 +154,7 @@ impl From<i8> for GrammaticalPerson {
     }
 }
 
-#[derive(Copy, Clone, Debug, EnumIter, Serialize_repr, Deserialize_repr)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
 #[repr(i8)]
 #[derive(Default)]
 pub enum VerbForm {
@@ -177,15 +181,21 @@ impl From<i8> for VerbForm {
     }
 }
 
-#[derive(Copy, Clone, Debug, EnumIter, Serialize_repr, Deserialize_repr)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
 #[repr(i8)]
 #[derive(Default)]
 pub enum VerbTense {
     #[default]
     None = 0,
     Present = 1,
+    NonPast = 2,
+    NonFuture = 3,
+    RemotePast = 10,
     Past = 11,
+    RecentPast = 12,
+    NearFuture = 20,
     Future = 21,
+    RemoteFuture = 22,
 }
 
 impl CodedEnum for VerbTense {
@@ -204,3 +214,207 @@ impl From<i8> for VerbTense {
         panic!("Not implemented")
     }
 }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+#[derive(Default)]
+pub enum GrammaticalCase {
+    #[default]
+    None = 0,
+    Nominative = 1,
+    Absolutive = 2,
+    Accusative = 11,
+    Ergative = 12,
+    Genitive = 21,
+    Dative = 22,
+    Locative = 31,
+    Lative = 32,
+    Ablative = 33,
+    Adpositional = 41,
+    Instrumental = 42,
+}
+
+impl CodedEnum for GrammaticalCase {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
+
+impl From<i8> for GrammaticalCase {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+#[derive(Default)]
+pub enum GrammaticalVoice {
+    #[default]
+    None = 0,
+    Active = 1,
+    Passive = 2,
+    AntiPassive = 3,
+    Middle = 4,
+}
+
+impl CodedEnum for GrammaticalVoice {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
+
+impl From<i8> for GrammaticalVoice {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+#[derive(Default)]
+pub enum GrammaticalMood {
+    #[default]
+    None = 0,
+    Indicative = 1,
+    Declarative = 2,
+    Energetic = 3,
+    Generic = 4,
+    Interrogative = 5,
+    Imperative = 6,
+    Precative = 7,
+    Benedictive = 8,
+    Prescriptive = 9,
+    Hortative = 10,
+    Jussive = 11,
+    Permissive = 12,
+    Prohibitive = 13,
+    Admonitive = 14,
+    Necessitative = 15,
+    Optative = 16,
+    Desiderative = 17,
+    Mirative = 18,
+    Subjunctive = 19,
+    Dubitative = 20,
+    Potential = 21,
+    Conditional = 22,
+    Eventive = 23,
+    Hypothetical = 24,
+    Presumptive = 25,
+    Inferential = 26,
+    Concessive = 27,
+}
+
+impl CodedEnum for GrammaticalMood {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
+
+impl From<i8> for GrammaticalMood {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+#[derive(Default)]
+pub enum GrammaticalAspect {
+    #[default]
+    None = 0,
+    Perfective = 1,
+    Imperfective = 2,
+    Progressive = 11,
+    Habitual = 12,
+}
+
+impl CodedEnum for GrammaticalAspect {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
+
+impl From<i8> for GrammaticalAspect {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+#[derive(Default)]
+pub enum GrammaticalDefiniteness {
+    #[default]
+    None = 0,
+    Definite = 1,
+    Indefinite = 2,
+}
+
+impl CodedEnum for GrammaticalDefiniteness {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
+
+impl From<i8> for GrammaticalDefiniteness {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, EnumIter, Serialize_repr, Deserialize_repr)]
+#[repr(i8)]
+#[derive(Default)]
+pub enum GrammaticalAnimacy {
+    #[default]
+    None = 0,
+    Person = 1,
+    Animal = 2,
+    Plant = 3,
+    Force = 4,
+    ConcreteObject = 11,
+    AbstractObject = 12,
+}
+
+impl CodedEnum for GrammaticalAnimacy {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
+
+impl From<i8> for GrammaticalAnimacy {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
@@ -0,0 +1,43 @@
+use std::convert::From;
+
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::utils::CodedEnum;
+
+/// The shape of a pin on a map.
+/// Points are placed at a single coordinate, while polygons outline an area.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, EnumIter, Serialize_repr, Deserialize_repr,
+)]
+#[repr(i8)]
+pub enum PinShape {
+    #[default]
+    Point = 0,
+    Polygon = 1,
+}
+
+impl From<i8> for PinShape {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+impl std::fmt::Display for PinShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl CodedEnum for PinShape {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
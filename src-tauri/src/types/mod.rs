@@ -1,2 +1,7 @@
+pub mod calendar;
+pub mod custom_property;
 pub mod entity;
 pub mod grammar;
+pub mod map;
+pub mod person;
+pub mod place;
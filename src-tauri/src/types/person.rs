@@ -0,0 +1,44 @@
+use std::convert::From;
+
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::utils::CodedEnum;
+
+/// How two people are related within a family.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, EnumIter, Serialize_repr, Deserialize_repr,
+)]
+#[repr(i8)]
+pub enum PersonRelationshipType {
+    /// The person is a parent of the relative.
+    #[default]
+    Parent = 1,
+    /// The person and the relative are partners, e.g., spouses.
+    Partner = 2,
+}
+
+impl From<i8> for PersonRelationshipType {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+impl std::fmt::Display for PersonRelationshipType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl CodedEnum for PersonRelationshipType {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
@@ -0,0 +1,55 @@
+use std::convert::From;
+
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::utils::CodedEnum;
+
+/// What kind of geographical feature a place is.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, EnumIter, Serialize_repr, Deserialize_repr,
+)]
+#[repr(i8)]
+pub enum PlaceType {
+    #[default]
+    None = 0,
+
+    // Regions
+    Continent = 1,
+    Country = 2,
+    Region = 3,
+
+    // Settlements
+    City = 11,
+    Town = 12,
+    Village = 13,
+
+    // Sites
+    Building = 21,
+    Landmark = 22,
+}
+
+impl From<i8> for PlaceType {
+    fn from(code: i8) -> Self {
+        for value in Self::iter() {
+            if code == value.code() {
+                return value;
+            }
+        }
+        panic!("Not implemented")
+    }
+}
+
+impl std::fmt::Display for PlaceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl CodedEnum for PlaceType {
+    fn code(&self) -> i8 {
+        *self as i8
+    }
+}
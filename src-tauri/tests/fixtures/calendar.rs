@@ -0,0 +1,84 @@
+use rstest::*;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        calendar::{CalendarDateSchema, CalendarSchema, EraSchema, LeapRuleSchema, MonthSchema},
+        entry::{EntryCreateSchema, EntryProperties},
+    },
+    types::entity::CALENDAR,
+};
+
+use crate::fixtures::entry::create_entry_payload;
+
+fn month(name: &str, days: u32, leap_days: u32) -> MonthSchema {
+    MonthSchema {
+        name: name.to_owned(),
+        days,
+        leap_days,
+    }
+}
+
+fn era(name: &str, abbreviation: &str, start_year: i64, is_reversed: bool) -> EraSchema {
+    EraSchema {
+        name: name.to_owned(),
+        abbreviation: abbreviation.to_owned(),
+        start_year,
+        is_reversed,
+    }
+}
+
+pub fn date(calendar_id: Uuid, year: i64, month: u32, day: u32) -> CalendarDateSchema {
+    CalendarDateSchema {
+        calendar_id,
+        year,
+        month,
+        day,
+    }
+}
+
+#[fixture]
+pub fn calendar_name() -> String {
+    "Reckoning of the Reach".to_string()
+}
+
+/// A calendar of 4 months and 121 days, with a leap day every 4 years.
+#[fixture]
+pub fn calendar_properties() -> CalendarSchema {
+    CalendarSchema {
+        months: vec![
+            month("Frostmoon", 30, 0),
+            month("Thawmoon", 30, 1),
+            month("Sunmoon", 31, 0),
+            month("Ashmoon", 30, 0),
+        ],
+        weekdays: vec![
+            "Firstday".to_owned(),
+            "Seconday".to_owned(),
+            "Thirdday".to_owned(),
+            "Restday".to_owned(),
+        ],
+        leap_rules: vec![LeapRuleSchema {
+            interval: 4,
+            offset: 0,
+            is_leap: true,
+        }],
+        eras: vec![
+            era("Before the Ash", "BA", 1, true),
+            era("Ashen Era", "AE", 1, false),
+        ],
+        epoch_offset: 0,
+    }
+}
+
+#[fixture]
+pub fn create_calendar_payload(
+    mut create_entry_payload: EntryCreateSchema,
+    calendar_name: String,
+    calendar_properties: CalendarSchema,
+) -> EntryCreateSchema {
+    create_entry_payload.entity_type = CALENDAR;
+    create_entry_payload.title = calendar_name.to_string();
+    create_entry_payload.properties = EntryProperties::Calendar(calendar_properties);
+    create_entry_payload
+}
@@ -18,7 +18,7 @@ pub fn entry_title() -> String {
 
 #[fixture]
 pub fn entry_properties() -> EntryProperties {
-    EntryProperties::Language(LanguageSchema {})
+    EntryProperties::Language(LanguageSchema::default())
 }
 
 #[fixture]
@@ -48,6 +48,7 @@ pub fn create_entry_payload(
         folder_id,
         title: entry_title,
         properties: entry_properties,
+        template_id: None,
     }
 }
 
@@ -60,5 +61,7 @@ pub fn update_entry_payload() -> EntryUpdateSchema {
         properties: None,
         text: None,
         words: None,
+        custom_properties: None,
+        expected_revision: None,
     }
 }
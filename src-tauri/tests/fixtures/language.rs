@@ -17,7 +17,7 @@ pub fn language_name() -> String {
 
 #[fixture]
 pub fn language_properties() -> LanguageSchema {
-    LanguageSchema {}
+    LanguageSchema::default()
 }
 
 #[fixture]
@@ -6,11 +6,13 @@ use hellebore::{
     model::{config::AppConfig, project::Project},
 };
 
+pub mod calendar;
 pub mod config;
 pub mod entry;
 pub mod folder;
 pub mod language;
 pub mod person;
+pub mod place;
 pub mod project;
 pub mod word;
 
@@ -0,0 +1,36 @@
+use rstest::*;
+
+use hellebore::{
+    schema::{
+        entry::{EntryCreateSchema, EntryProperties},
+        place::PlaceSchema,
+    },
+    types::{entity::PLACE, place::PlaceType},
+};
+
+use crate::fixtures::entry::create_entry_payload;
+
+#[fixture]
+pub fn place_name() -> String {
+    "Ashen Reach".to_string()
+}
+
+#[fixture]
+pub fn place_properties() -> PlaceSchema {
+    PlaceSchema {
+        place_type: PlaceType::Region,
+        parent_id: None,
+    }
+}
+
+#[fixture]
+pub fn create_place_payload(
+    mut create_entry_payload: EntryCreateSchema,
+    place_name: String,
+    place_properties: PlaceSchema,
+) -> EntryCreateSchema {
+    create_entry_payload.entity_type = PLACE;
+    create_entry_payload.title = place_name;
+    create_entry_payload.properties = EntryProperties::Place(place_properties);
+    create_entry_payload
+}
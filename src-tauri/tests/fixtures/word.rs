@@ -45,6 +45,7 @@ pub fn create_word_payload(
         spelling: Some(word_spelling),
         definition: Some(word_definition),
         translations: Some(word_translations),
+        expected_revision: None,
     }
 }
 
@@ -57,6 +58,7 @@ pub fn update_word_payload(word_id: Uuid, word_spelling: String) -> WordUpsertSc
         spelling: Some(format!("{}-modified", word_spelling)),
         definition: None,
         translations: None,
+        expected_revision: None,
     }
 }
 
@@ -0,0 +1,124 @@
+use std::{env, fs, path::PathBuf};
+
+use rstest::*;
+use uuid::Uuid;
+
+use hellebore::{
+    model::{errors::Error, text::TextNode},
+    schema::entry::EntryUpdateSchema,
+    services::{asset_service, entry_service, entry_text_service},
+};
+
+use crate::{
+    fixtures::{database, entry::update_entry_payload, folder::folder_id},
+    utils::db::create_generic_entry,
+};
+
+fn create_project_folder() -> PathBuf {
+    let folder_path = env::temp_dir().join(format!("hellebore-asset-tests-{}", Uuid::new_v4()));
+    fs::create_dir_all(&folder_path).unwrap();
+    folder_path
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_import_asset() {
+    let database = database().await;
+    let folder_path = create_project_folder();
+    let folder_path_str = folder_path.to_str().unwrap();
+    let portrait_path = folder_path.join("Portrait.PNG");
+    let copy_path = folder_path.join("copy.png");
+    fs::write(&portrait_path, b"portrait").unwrap();
+    fs::write(&copy_path, b"portrait").unwrap();
+
+    let asset =
+        asset_service::import(&database, folder_path_str, portrait_path.to_str().unwrap()).await;
+    assert!(asset.is_ok());
+    let asset = asset.unwrap();
+    assert_eq!(asset.name, "Portrait.PNG");
+    assert_eq!(asset.mime_type, "image/png");
+    assert_eq!(asset.size, 8);
+    assert_eq!(asset.path, format!("assets/{}.png", asset.hash));
+    assert_eq!(
+        fs::read(folder_path.join(&asset.path)).unwrap(),
+        b"portrait"
+    );
+
+    // files with the same content are only stored once
+    let copy = asset_service::import(&database, folder_path_str, copy_path.to_str().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(copy.id, asset.id);
+
+    let renamed = asset_service::rename(&database, asset.id, " Queen Ada ").await;
+    assert!(renamed.is_ok());
+    assert_eq!(renamed.unwrap().name, "Queen Ada");
+    assert!(
+        asset_service::rename(&database, asset.id, "")
+            .await
+            .is_err()
+    );
+
+    let response = asset_service::delete(&database, folder_path_str, asset.id).await;
+    assert!(response.is_ok());
+    assert!(!folder_path.join(&asset.path).exists());
+    assert!(asset_service::get_all(&database).await.unwrap().is_empty());
+
+    let response = asset_service::import(&database, folder_path_str, "missing.png").await;
+    assert!(response.is_err());
+
+    fs::remove_dir_all(&folder_path).unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_entry_references_asset(folder_id: Uuid, mut update_entry_payload: EntryUpdateSchema) {
+    let database = database().await;
+    let folder_path = create_project_folder();
+    let source_path = folder_path.join("coat-of-arms.svg");
+    fs::write(&source_path, b"<svg></svg>").unwrap();
+    let asset = asset_service::import(
+        &database,
+        folder_path.to_str().unwrap(),
+        source_path.to_str().unwrap(),
+    )
+    .await
+    .unwrap();
+    let entry =
+        create_generic_entry(&database, folder_id, "House Ada".to_owned(), "".to_owned()).await;
+
+    let text = TextNode::new_doc().with_child(
+        TextNode::new_paragraph()
+            .with_child(TextNode::new_image(asset.id))
+            .with_child(TextNode::new_image(Uuid::new_v4())),
+    );
+    update_entry_payload.id = entry.id;
+    update_entry_payload.text = Some(serde_json::to_string(&text).unwrap());
+    let response = entry_service::update(&database, update_entry_payload).await;
+    assert!(response.errors.is_empty());
+
+    let entries = asset_service::get_referencing_entries(&database, asset.id).await;
+    assert!(entries.is_ok());
+    let entries = entries.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].id, entry.id);
+
+    // images are pointed at the file of their asset, and missing assets are reported
+    let mut errors: Vec<Error> = Vec::new();
+    let synced_text = entry_text_service::sync_text(
+        &database,
+        entry.id,
+        &serde_json::to_string(&text).unwrap(),
+        &mut errors,
+    )
+    .await;
+    let images = synced_text.content.unwrap()[0].content.clone().unwrap();
+    assert_eq!(
+        images[0].get_attr("src").and_then(|src| src.as_str()),
+        Some(asset.path.as_str())
+    );
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], Error::BadEntryAssetId { .. }));
+
+    fs::remove_dir_all(&folder_path).unwrap();
+}
@@ -0,0 +1,242 @@
+use std::fs;
+use std::path::Path;
+
+use rstest::*;
+use sea_orm::Database;
+
+use crate::fixtures::{
+    entry::create_entry_payload,
+    project::{TempProjectDir, project_name, temp_project_dir},
+};
+use hellebore::{
+    constants::{BACKUP_DIR_NAME, DEFAULT_DB_FILE_NAME},
+    database::setup::setup_db,
+    model::{config::AppConfig, errors::Error, project::Project, state::State},
+    schema::{
+        backup::{BackupConfigSchema, BackupKind},
+        entry::EntryCreateSchema,
+    },
+    services::{backup_service, entry_service, project_service},
+};
+
+fn create_state() -> State {
+    State::new(AppConfig {
+        recent_project_paths: vec![],
+    })
+}
+
+fn create_backup_file(folder_path: &str, file_name: &str) {
+    let backup_dir_path = Path::new(folder_path).join(BACKUP_DIR_NAME);
+    fs::create_dir_all(&backup_dir_path).unwrap();
+    fs::write(backup_dir_path.join(file_name), "").unwrap();
+}
+
+fn get_backup_file_names(folder_path: &str) -> Vec<String> {
+    backup_service::get_all(folder_path)
+        .unwrap()
+        .into_iter()
+        .map(|backup| backup.file_name)
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_backup_project(
+    project_name: String,
+    temp_project_dir: TempProjectDir,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let state = create_state();
+    let mut state = state.lock().await;
+
+    let project = project_service::create(&mut state, &project_name, temp_project_dir.path(), true)
+        .await
+        .unwrap();
+    let database = project_service::get_database(&state, project.id).unwrap();
+    entry_service::create(database, create_entry_payload)
+        .await
+        .unwrap();
+
+    let response = backup_service::create(&state, project.id).await;
+
+    assert!(response.is_ok());
+    let backup = response.unwrap();
+    assert_eq!(backup.kind, BackupKind::Manual);
+    assert!(backup.size > 0);
+    assert_eq!(
+        get_backup_file_names(temp_project_dir.path()),
+        vec![backup.file_name.clone()]
+    );
+
+    // the backup is a complete SQLite database rather than an empty file
+    let backup_path = Path::new(temp_project_dir.path())
+        .join(BACKUP_DIR_NAME)
+        .join(&backup.file_name);
+    let contents = fs::read(backup_path).unwrap();
+    assert!(contents.starts_with(b"SQLite format 3\0"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_due_backups(project_name: String, temp_project_dir: TempProjectDir) {
+    let state = create_state();
+    let mut state = state.lock().await;
+
+    let project = project_service::create(&mut state, &project_name, temp_project_dir.path(), true)
+        .await
+        .unwrap();
+
+    let response = backup_service::create_due_backups(state.projects.values()).await;
+    assert!(response.data.is_empty());
+
+    let backup_config = BackupConfigSchema {
+        is_automatic: true,
+        ..Default::default()
+    };
+    project_service::update_backup_config(&mut state, project.id, backup_config)
+        .await
+        .unwrap();
+
+    let response = backup_service::create_due_backups(state.projects.values()).await;
+    assert!(response.errors.is_empty());
+    assert_eq!(response.data.len(), 1);
+    assert_eq!(response.data[0].kind, BackupKind::Automatic);
+
+    // the next backup isn't due until the interval has passed
+    let response = backup_service::create_due_backups(state.projects.values()).await;
+    assert!(response.data.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_prune_backups(temp_project_dir: TempProjectDir) {
+    let folder_path = temp_project_dir.path();
+    for file_name in [
+        "auto-20260105T180000000Z.sqlite",
+        "auto-20260105T120000000Z.sqlite",
+        "auto-20260104T120000000Z.sqlite",
+        "auto-20260103T120000000Z.sqlite",
+        "auto-20251225T120000000Z.sqlite",
+        "auto-20251201T120000000Z.sqlite",
+        "manual-20251101T120000000Z.sqlite",
+        "notes.txt",
+    ] {
+        create_backup_file(folder_path, file_name);
+    }
+
+    let backup_config = BackupConfigSchema {
+        keep_last: 1,
+        keep_daily: 2,
+        keep_weekly: 3,
+        ..Default::default()
+    };
+    let response = backup_service::prune(folder_path, &backup_config);
+
+    assert!(response.is_ok());
+    assert_eq!(response.unwrap().len(), 3);
+    assert_eq!(
+        get_backup_file_names(folder_path),
+        vec![
+            // the latest backup, which is also the latest of its day and of its week
+            "auto-20260105T180000000Z.sqlite",
+            // the latest backup of the previous day
+            "auto-20260104T120000000Z.sqlite",
+            // the latest backup of an earlier week
+            "auto-20251225T120000000Z.sqlite",
+            // manual backups are never pruned
+            "manual-20251101T120000000Z.sqlite",
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_restore_project(
+    project_name: String,
+    temp_project_dir: TempProjectDir,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let state = create_state();
+    let mut state = state.lock().await;
+    let folder_path = temp_project_dir.path();
+
+    let project = project_service::create(&mut state, &project_name, folder_path, true)
+        .await
+        .unwrap();
+    let backup = backup_service::create(&state, project.id).await.unwrap();
+    project_service::close(&mut state, project.id)
+        .await
+        .unwrap();
+
+    // the connection is kept open, so the entry may only be in the journal files of the database
+    let database = setup_db(&Project::generate_connection_string(folder_path, false))
+        .await
+        .unwrap();
+    entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap();
+
+    let response = backup_service::restore(&state, folder_path, &backup.file_name).await;
+
+    assert!(response.is_ok());
+    let safety_copy = response.unwrap();
+    assert!(safety_copy.is_some());
+    let safety_copy = safety_copy.unwrap();
+    assert_eq!(safety_copy.kind, BackupKind::PreRestore);
+
+    let backup_dir_path = Path::new(folder_path).join(BACKUP_DIR_NAME);
+    let db_path = Path::new(folder_path).join(DEFAULT_DB_FILE_NAME);
+    assert_eq!(
+        fs::read(&db_path).unwrap(),
+        fs::read(backup_dir_path.join(&backup.file_name)).unwrap()
+    );
+
+    let safety_copy_path = backup_dir_path.join(&safety_copy.file_name);
+    let safety_copy_db = Database::connect(format!("sqlite://{}", safety_copy_path.display()))
+        .await
+        .unwrap();
+    let entries = entry_service::get_all(&safety_copy_db).await.unwrap();
+    assert_eq!(entries.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_restoring_open_project(
+    project_name: String,
+    temp_project_dir: TempProjectDir,
+) {
+    let state = create_state();
+    let mut state = state.lock().await;
+    let folder_path = temp_project_dir.path();
+
+    let project = project_service::create(&mut state, &project_name, folder_path, true)
+        .await
+        .unwrap();
+    let backup = backup_service::create(&state, project.id).await.unwrap();
+
+    let response = backup_service::restore(&state, folder_path, &backup.file_name).await;
+
+    assert!(response.is_err());
+    match response.unwrap_err() {
+        Error::NotUpdated { id, .. } => assert_eq!(id, project.id.to_string()),
+        err => panic!("Unexpected error: {err:?}"),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_restoring_path_outside_of_backup_dir(temp_project_dir: TempProjectDir) {
+    let state = create_state();
+    let state = state.lock().await;
+    let folder_path = temp_project_dir.path();
+    create_backup_file(folder_path, "manual-20260105T180000000Z.sqlite");
+
+    let response = backup_service::restore(
+        &state,
+        folder_path,
+        &format!("../{BACKUP_DIR_NAME}/manual-20260105T180000000Z.sqlite"),
+    )
+    .await;
+
+    assert!(response.is_err());
+}
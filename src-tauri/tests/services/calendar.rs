@@ -0,0 +1,294 @@
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        calendar::{CalendarSchema, EntryDateCreateSchema, EntryDateUpdateSchema, MonthSchema},
+        entry::{EntryCreateSchema, EntryProperties, EntryUpdateSchema},
+    },
+    services::{calendar_service, entry_date_service, entry_service},
+};
+
+use crate::fixtures::{
+    calendar::{calendar_properties, create_calendar_payload, date},
+    database,
+    entry::{create_entry_payload, update_entry_payload},
+};
+
+async fn create_calendar(database: &DatabaseConnection, payload: EntryCreateSchema) -> Uuid {
+    entry_service::create(database, payload).await.unwrap().id
+}
+
+fn generate_update_payload(
+    mut update_entry_payload: EntryUpdateSchema,
+    id: Uuid,
+    properties: CalendarSchema,
+) -> EntryUpdateSchema {
+    update_entry_payload.id = id;
+    update_entry_payload.properties = Some(EntryProperties::Calendar(properties));
+    update_entry_payload
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_calendar(
+    create_calendar_payload: EntryCreateSchema,
+    calendar_properties: CalendarSchema,
+) {
+    let database = database().await;
+    let id = create_calendar(&database, create_calendar_payload).await;
+
+    let response = entry_service::get_properties(&database, id).await;
+
+    assert!(response.is_ok());
+    match response.unwrap().properties {
+        EntryProperties::Calendar(properties) => assert_eq!(properties, calendar_properties),
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_creating_calendar_with_duplicate_month_names(
+    mut create_calendar_payload: EntryCreateSchema,
+    mut calendar_properties: CalendarSchema,
+) {
+    let database = database().await;
+    calendar_properties.months.push(MonthSchema {
+        name: "frostmoon".to_owned(),
+        days: 10,
+        leap_days: 0,
+    });
+    create_calendar_payload.properties = EntryProperties::Calendar(calendar_properties);
+
+    let response = entry_service::create(&database, create_calendar_payload).await;
+
+    assert!(response.is_err());
+    // the entry isn't created without its calendar
+    assert!(entry_service::get_all(&database).await.unwrap().is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_describe_date(create_calendar_payload: EntryCreateSchema) {
+    let database = database().await;
+    let id = create_calendar(&database, create_calendar_payload).await;
+
+    let response = calendar_service::describe_date(&database, date(id, 412, 1, 3)).await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    // 411 years of 121 days, 102 of which are leap years
+    assert_eq!(response.day_number, 411 * 121 + 102 + 2);
+    assert_eq!(response.weekday, Some("Restday".to_owned()));
+    assert_eq!(response.formatted, "3rd of Frostmoon, 412 AE");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_describing_nonexistent_date(create_calendar_payload: EntryCreateSchema) {
+    let database = database().await;
+    let id = create_calendar(&database, create_calendar_payload).await;
+
+    // Thawmoon only has a 31st day in leap years
+    let leap_day = calendar_service::describe_date(&database, date(id, 412, 2, 31)).await;
+    assert!(leap_day.is_ok());
+    let response = calendar_service::describe_date(&database, date(id, 413, 2, 31)).await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_parse_date(create_calendar_payload: EntryCreateSchema) {
+    let database = database().await;
+    let id = create_calendar(&database, create_calendar_payload).await;
+
+    let response = calendar_service::parse_date(&database, id, "3rd of frostmoon, 412 ae").await;
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert_eq!((response.year, response.month, response.day), (412, 1, 3));
+
+    // reversed eras count backwards from their start
+    let response = calendar_service::parse_date(&database, id, "30th of Ashmoon, 10 BA").await;
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert_eq!((response.year, response.month, response.day), (-9, 4, 30));
+    assert_eq!(response.formatted, "30th of Ashmoon, 10 BA");
+
+    let response = calendar_service::parse_date(&database, id, "3rd of Snowmoon, 412 AE").await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_convert_date(create_calendar_payload: EntryCreateSchema) {
+    let database = database().await;
+    let source_id = create_calendar(&database, create_calendar_payload.clone()).await;
+
+    let mut payload = create_calendar_payload;
+    payload.title = "Imperial Count".to_owned();
+    payload.properties = EntryProperties::Calendar(CalendarSchema {
+        months: vec![
+            MonthSchema {
+                name: "First Season".to_owned(),
+                days: 100,
+                leap_days: 0,
+            },
+            MonthSchema {
+                name: "Second Season".to_owned(),
+                days: 100,
+                leap_days: 0,
+            },
+        ],
+        weekdays: vec![],
+        leap_rules: vec![],
+        eras: vec![],
+        epoch_offset: 40_000,
+    });
+    let target_id = create_calendar(&database, payload).await;
+
+    let response =
+        calendar_service::convert_date(&database, date(source_id, 412, 1, 3), target_id).await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert_eq!(response.calendar_id, target_id);
+    assert_eq!((response.year, response.month, response.day), (50, 1, 36));
+    assert_eq!(response.day_number, 49_835);
+    assert_eq!(response.weekday, None);
+    assert_eq!(response.formatted, "36th of First Season, 50");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_entry_dates(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = create_calendar(&database, create_calendar_payload).await;
+    let entry_id = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap()
+        .id;
+
+    let death = entry_date_service::create(
+        &database,
+        EntryDateCreateSchema {
+            entry_id,
+            label: "Death".to_owned(),
+            date: date(calendar_id, 470, 3, 1),
+        },
+    )
+    .await
+    .unwrap();
+    let birth = entry_date_service::create(
+        &database,
+        EntryDateCreateSchema {
+            entry_id,
+            label: "Birth".to_owned(),
+            date: date(calendar_id, 412, 1, 3),
+        },
+    )
+    .await
+    .unwrap();
+
+    let response = entry_date_service::update(
+        &database,
+        EntryDateUpdateSchema {
+            id: death.id,
+            label: None,
+            date: Some(date(calendar_id, 480, 3, 1)),
+        },
+    )
+    .await;
+    assert!(response.is_ok());
+    assert_eq!(response.unwrap().label, "Death");
+
+    let dates = entry_date_service::get_all_for_entry(&database, entry_id)
+        .await
+        .unwrap();
+    let ids: Vec<Uuid> = dates.iter().map(|date| date.id).collect();
+    assert_eq!(ids, vec![birth.id, death.id]);
+    assert_eq!(dates[1].date.formatted, "1st of Sunmoon, 480 AE");
+
+    entry_date_service::delete(&database, birth.id)
+        .await
+        .unwrap();
+    let dates = entry_date_service::get_all_for_entry(&database, entry_id)
+        .await
+        .unwrap();
+    assert_eq!(dates.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_update_calendar_recomputes_day_numbers(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+    mut calendar_properties: CalendarSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let calendar_id = create_calendar(&database, create_calendar_payload).await;
+    let entry_id = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap()
+        .id;
+    let entry_date = entry_date_service::create(
+        &database,
+        EntryDateCreateSchema {
+            entry_id,
+            label: "Birth".to_owned(),
+            date: date(calendar_id, 412, 1, 3),
+        },
+    )
+    .await
+    .unwrap();
+
+    calendar_properties.epoch_offset = 1_000;
+    let payload = generate_update_payload(update_entry_payload, calendar_id, calendar_properties);
+    let response = entry_service::update(&database, payload).await;
+    assert!(response.errors.is_empty());
+
+    let dates = entry_date_service::get_all_for_entry(&database, entry_id)
+        .await
+        .unwrap();
+    assert_eq!(dates[0].date.day_number, entry_date.date.day_number + 1_000);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_updating_calendar_that_drops_a_date(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+    mut calendar_properties: CalendarSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let calendar_id = create_calendar(&database, create_calendar_payload).await;
+    let entry_id = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap()
+        .id;
+    entry_date_service::create(
+        &database,
+        EntryDateCreateSchema {
+            entry_id,
+            label: "Leap day".to_owned(),
+            date: date(calendar_id, 412, 2, 31),
+        },
+    )
+    .await
+    .unwrap();
+
+    calendar_properties.leap_rules.clear();
+    let payload = generate_update_payload(update_entry_payload, calendar_id, calendar_properties);
+    let response = entry_service::update(&database, payload).await;
+
+    assert!(!response.errors.is_empty());
+    assert!(!response.data.properties.updated);
+    let calendar = calendar_service::get(&database, calendar_id).await.unwrap();
+    assert_eq!(calendar.leap_rules.len(), 1);
+}
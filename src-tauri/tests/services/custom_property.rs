@@ -0,0 +1,358 @@
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use serde_json::json;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        custom_property::{
+            CustomPropertyCreateSchema, CustomPropertyUpdateSchema, CustomPropertyValueSchema,
+        },
+        entry::{EntryCreateSchema, EntryUpdateSchema},
+    },
+    services::{custom_property_service, entry_service},
+    types::{
+        custom_property::CustomPropertyType,
+        entity::{ENTRY, EntityType, PERSON},
+    },
+};
+
+use crate::{
+    fixtures::{calendar::create_calendar_payload, database, entry::update_entry_payload},
+    utils::db::create_generic_entry,
+};
+
+async fn create_property(
+    database: &DatabaseConnection,
+    entity_type: EntityType,
+    name: &str,
+    property_type: CustomPropertyType,
+    item_type: Option<CustomPropertyType>,
+    options: &[&str],
+) -> Uuid {
+    custom_property_service::create(
+        database,
+        CustomPropertyCreateSchema {
+            entity_type,
+            name: name.to_owned(),
+            property_type,
+            item_type,
+            options: options.iter().map(|option| option.to_string()).collect(),
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+fn set_values(
+    mut update_entry_payload: EntryUpdateSchema,
+    id: Uuid,
+    values: Vec<(Uuid, serde_json::Value)>,
+) -> EntryUpdateSchema {
+    update_entry_payload.id = id;
+    update_entry_payload.custom_properties = Some(
+        values
+            .into_iter()
+            .map(|(property_id, value)| CustomPropertyValueSchema { property_id, value })
+            .collect(),
+    );
+    update_entry_payload
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_set_custom_property_values(
+    update_entry_payload: EntryUpdateSchema,
+    create_calendar_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let entry =
+        create_generic_entry(&database, Uuid::nil(), "Sword".to_owned(), "".to_owned()).await;
+    let owner = create_generic_entry(&database, Uuid::nil(), "Ada".to_owned(), "".to_owned()).await;
+
+    let material = create_property(
+        &database,
+        ENTRY,
+        "Material",
+        CustomPropertyType::Enum,
+        None,
+        &["iron", "steel"],
+    )
+    .await;
+    let weight = create_property(
+        &database,
+        ENTRY,
+        "Weight",
+        CustomPropertyType::Number,
+        None,
+        &[],
+    )
+    .await;
+    let forged = create_property(
+        &database,
+        ENTRY,
+        "Forged",
+        CustomPropertyType::Date,
+        None,
+        &[],
+    )
+    .await;
+    let owners = create_property(
+        &database,
+        ENTRY,
+        "Owners",
+        CustomPropertyType::List,
+        Some(CustomPropertyType::Entry),
+        &[],
+    )
+    .await;
+    let is_cursed = create_property(
+        &database,
+        ENTRY,
+        "Cursed",
+        CustomPropertyType::Boolean,
+        None,
+        &[],
+    )
+    .await;
+
+    let forged_date = json!({ "calendarId": calendar_id, "year": 412, "month": 1, "day": 3 });
+    let payload = set_values(
+        update_entry_payload.clone(),
+        entry.id,
+        vec![
+            (material, json!("steel")),
+            (weight, json!(1.5)),
+            (forged, forged_date.clone()),
+            (owners, json!([owner.id])),
+            (is_cursed, json!(true)),
+        ],
+    );
+    let response = entry_service::update(&database, payload).await;
+    assert!(response.errors.is_empty());
+    assert!(response.data.custom_properties.updated);
+
+    // null clears a value, and the other values are left as they are
+    let payload = set_values(
+        update_entry_payload,
+        entry.id,
+        vec![(is_cursed, json!(null))],
+    );
+    let response = entry_service::update(&database, payload).await;
+    assert!(response.errors.is_empty());
+
+    let response = entry_service::get_properties(&database, entry.id).await;
+    assert!(response.is_ok());
+    let values: Vec<(String, serde_json::Value)> = response
+        .unwrap()
+        .custom_properties
+        .into_iter()
+        .map(|value| (value.name, value.value))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            ("Material".to_owned(), json!("steel")),
+            ("Weight".to_owned(), json!(1.5)),
+            ("Forged".to_owned(), forged_date),
+            ("Owners".to_owned(), json!([owner.id])),
+            ("Cursed".to_owned(), json!(null)),
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_invalid_custom_property_value(update_entry_payload: EntryUpdateSchema) {
+    let database = database().await;
+    let entry =
+        create_generic_entry(&database, Uuid::nil(), "Sword".to_owned(), "".to_owned()).await;
+    let material = create_property(
+        &database,
+        ENTRY,
+        "Material",
+        CustomPropertyType::Enum,
+        None,
+        &["iron", "steel"],
+    )
+    .await;
+    let weight = create_property(
+        &database,
+        ENTRY,
+        "Weight",
+        CustomPropertyType::Number,
+        None,
+        &[],
+    )
+    .await;
+    let owners = create_property(
+        &database,
+        ENTRY,
+        "Owners",
+        CustomPropertyType::List,
+        Some(CustomPropertyType::Entry),
+        &[],
+    )
+    .await;
+    let title = create_property(
+        &database,
+        PERSON,
+        "Title",
+        CustomPropertyType::Text,
+        None,
+        &[],
+    )
+    .await;
+
+    for (property_id, value) in [
+        (material, json!("bronze")),
+        (weight, json!("heavy")),
+        (owners, json!([Uuid::new_v4()])),
+        // the property belongs to people
+        (title, json!("Sir")),
+    ] {
+        let payload = set_values(
+            update_entry_payload.clone(),
+            entry.id,
+            vec![(weight, json!(2)), (property_id, value)],
+        );
+        let response = entry_service::update(&database, payload).await;
+        assert!(!response.errors.is_empty());
+        assert!(!response.data.custom_properties.updated);
+    }
+
+    // none of the values are set when one of them is invalid
+    let response = entry_service::get_properties(&database, entry.id)
+        .await
+        .unwrap();
+    assert!(
+        response
+            .custom_properties
+            .iter()
+            .all(|value| value.value.is_null())
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_removing_used_option(update_entry_payload: EntryUpdateSchema) {
+    let database = database().await;
+    let entry =
+        create_generic_entry(&database, Uuid::nil(), "Sword".to_owned(), "".to_owned()).await;
+    let materials = create_property(
+        &database,
+        ENTRY,
+        "Materials",
+        CustomPropertyType::List,
+        Some(CustomPropertyType::Enum),
+        &["iron", "steel", "silver"],
+    )
+    .await;
+    let payload = set_values(
+        update_entry_payload,
+        entry.id,
+        vec![(materials, json!(["steel", "silver"]))],
+    );
+    assert!(
+        entry_service::update(&database, payload)
+            .await
+            .errors
+            .is_empty()
+    );
+
+    let update = |options: &[&str]| CustomPropertyUpdateSchema {
+        id: materials,
+        name: "Materials".to_owned(),
+        options: options.iter().map(|option| option.to_string()).collect(),
+    };
+    let response = custom_property_service::update(&database, update(&["iron", "steel"])).await;
+    assert!(response.is_err());
+
+    let response =
+        custom_property_service::update(&database, update(&["steel", "silver", "gold"])).await;
+    assert!(response.is_ok());
+    assert_eq!(response.unwrap().options, vec!["steel", "silver", "gold"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_invalid_custom_property_definition() {
+    let database = database().await;
+    create_property(
+        &database,
+        ENTRY,
+        "Material",
+        CustomPropertyType::Text,
+        None,
+        &[],
+    )
+    .await;
+
+    for (entity_type, name, property_type, item_type, options) in [
+        // names are unique per entity type
+        (ENTRY, " Material ", CustomPropertyType::Text, None, vec![]),
+        (ENTRY, "", CustomPropertyType::Text, None, vec![]),
+        (
+            EntityType::Word,
+            "Gloss",
+            CustomPropertyType::Text,
+            None,
+            vec![],
+        ),
+        (ENTRY, "Origin", CustomPropertyType::Enum, None, vec![]),
+        (
+            ENTRY,
+            "Origin",
+            CustomPropertyType::Enum,
+            None,
+            vec!["north", "north"],
+        ),
+        (
+            ENTRY,
+            "Origin",
+            CustomPropertyType::Text,
+            None,
+            vec!["north"],
+        ),
+        (ENTRY, "Origin", CustomPropertyType::List, None, vec![]),
+        (
+            ENTRY,
+            "Origin",
+            CustomPropertyType::List,
+            Some(CustomPropertyType::List),
+            vec![],
+        ),
+    ] {
+        let response = custom_property_service::create(
+            &database,
+            CustomPropertyCreateSchema {
+                entity_type,
+                name: name.to_owned(),
+                property_type,
+                item_type,
+                options: options.into_iter().map(str::to_owned).collect(),
+            },
+        )
+        .await;
+        assert!(response.is_err());
+    }
+
+    // the same name can be used by other entity types
+    let response = custom_property_service::create(
+        &database,
+        CustomPropertyCreateSchema {
+            entity_type: PERSON,
+            name: "Material".to_owned(),
+            property_type: CustomPropertyType::Text,
+            item_type: None,
+            options: vec![],
+        },
+    )
+    .await;
+    assert!(response.is_ok());
+}
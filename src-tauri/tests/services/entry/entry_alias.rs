@@ -0,0 +1,193 @@
+use hellebore::{
+    model::{errors::Error, text::TextNode},
+    schema::entry::{EntryCreateSchema, EntryMentionSearchSchema, EntryUpdateSchema},
+    services::{entry_alias_service, entry_service, entry_text_service, trash_service},
+};
+use rstest::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        database,
+        entry::{create_entry_payload, update_entry_payload},
+        folder::folder_id,
+    },
+    utils::db::create_generic_entry,
+};
+
+#[rstest]
+#[tokio::test]
+async fn test_search_mentions_by_alias(folder_id: Uuid) {
+    let database = database().await;
+    let wanderer = create_generic_entry(
+        &database,
+        folder_id,
+        "Aldric the Wanderer".to_owned(),
+        "".to_owned(),
+    )
+    .await;
+    create_generic_entry(&database, folder_id, "Greyhold".to_owned(), "".to_owned()).await;
+
+    let alias = entry_alias_service::add(&database, wanderer.id, "Greycloak").await;
+    assert!(alias.is_ok());
+    assert_eq!(alias.unwrap().entry_id, wanderer.id);
+
+    let suggestions = entry_alias_service::search_mentions(
+        &database,
+        EntryMentionSearchSchema {
+            keyword: "grey".to_owned(),
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+
+    let labels: Vec<(String, Option<String>)> = suggestions
+        .into_iter()
+        .map(|suggestion| (suggestion.info.title, suggestion.alias))
+        .collect();
+    assert_eq!(
+        labels,
+        vec![
+            (
+                "Aldric the Wanderer".to_owned(),
+                Some("Greycloak".to_owned())
+            ),
+            ("Greyhold".to_owned(), None),
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_non_unique_alias(
+    folder_id: Uuid,
+    mut create_entry_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let wanderer =
+        create_generic_entry(&database, folder_id, "Aldric".to_owned(), "".to_owned()).await;
+    let greyhold =
+        create_generic_entry(&database, folder_id, "Greyhold".to_owned(), "".to_owned()).await;
+    entry_alias_service::add(&database, wanderer.id, "Greycloak")
+        .await
+        .unwrap();
+
+    // aliases can't repeat titles or other aliases
+    assert!(
+        entry_alias_service::add(&database, wanderer.id, "Greyhold")
+            .await
+            .is_err()
+    );
+    assert!(
+        entry_alias_service::add(&database, greyhold.id, "Greycloak")
+            .await
+            .is_err()
+    );
+
+    // titles can't repeat the aliases of other entries
+    create_entry_payload.title = "Greycloak".to_owned();
+    assert!(
+        entry_service::create(&database, create_entry_payload)
+            .await
+            .is_err()
+    );
+    update_entry_payload.id = greyhold.id;
+    update_entry_payload.title = Some("Greycloak".to_owned());
+    let response = entry_service::update(&database, update_entry_payload).await;
+    assert!(!response.data.title.is_unique);
+
+    // the alias is free again once it's removed
+    let aliases = entry_alias_service::get_all_for_entry(&database, wanderer.id)
+        .await
+        .unwrap();
+    entry_alias_service::remove(&database, aliases[0].id)
+        .await
+        .unwrap();
+    assert!(
+        entry_alias_service::add(&database, greyhold.id, "Greycloak")
+            .await
+            .is_ok()
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_aliases_of_trashed_entries(
+    folder_id: Uuid,
+    mut create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let wanderer =
+        create_generic_entry(&database, folder_id, "Aldric".to_owned(), "".to_owned()).await;
+    entry_alias_service::add(&database, wanderer.id, "Greycloak")
+        .await
+        .unwrap();
+
+    trash_service::trash_entry(&database, wanderer.id)
+        .await
+        .unwrap();
+
+    let suggestions = entry_alias_service::search_mentions(
+        &database,
+        EntryMentionSearchSchema {
+            keyword: "grey".to_owned(),
+            limit: 10,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(suggestions.is_empty());
+
+    // like titles, aliases are free while their entries are in the trash
+    create_entry_payload.title = "Greycloak".to_owned();
+    assert!(
+        entry_service::create(&database, create_entry_payload)
+            .await
+            .is_ok()
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_sync_text_with_aliased_reference(folder_id: Uuid) {
+    let database = database().await;
+    let entry =
+        create_generic_entry(&database, folder_id, "Aldric".to_owned(), "".to_owned()).await;
+    entry_alias_service::add(&database, entry.id, "Greycloak")
+        .await
+        .unwrap();
+
+    let mention = |label: &str, alias: &str| {
+        let mut mention = TextNode::new_reference(entry.id, label.to_owned());
+        mention.set_attr("alias", Value::String(alias.to_owned()));
+        mention
+    };
+    let entry_text_node = TextNode::new_doc().with_child(
+        TextNode::new_paragraph()
+            .with_child(mention("old label", "Greycloak"))
+            .with_child(mention("old label", "Stale Alias")),
+    );
+
+    let entry_text_json = serde_json::to_string(&entry_text_node).unwrap();
+    let mut errors: Vec<Error> = Vec::new();
+    let synced_text_node =
+        entry_text_service::sync_text(&database, entry.id, &entry_text_json, &mut errors).await;
+
+    let mentions = synced_text_node.content.unwrap()[0]
+        .content
+        .clone()
+        .unwrap();
+    assert_eq!(
+        mentions[0].get_attr("label"),
+        Some(&Value::String("Greycloak".to_owned()))
+    );
+    assert_eq!(
+        mentions[1].get_attr("label"),
+        Some(&Value::String("Aldric".to_owned()))
+    );
+    assert_eq!(mentions[1].get_attr("alias"), Some(&Value::Null));
+    assert!(errors.is_empty());
+}
@@ -0,0 +1,223 @@
+use hellebore::{
+    model::text::TextNode,
+    schema::entry::{EntryCreateSchema, EntryUpdateSchema},
+    services::{entry_revision_service, entry_service},
+};
+use rstest::*;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        database,
+        entry::{create_entry_payload, entry_title, update_entry_payload},
+    },
+    utils::db::update_entry_text,
+};
+
+fn generate_text_node(text: &str) -> TextNode {
+    TextNode::new_doc()
+        .with_child(TextNode::new_paragraph().with_child(TextNode::new_text(text.to_owned())))
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_entry_revisions(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    entry_title: String,
+) {
+    let database = database().await;
+    let entry = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap();
+
+    update_entry_text(
+        &database,
+        update_entry_payload,
+        entry.id,
+        &generate_text_node("The first draft."),
+    )
+    .await;
+
+    let response = entry_revision_service::get_all(&database, entry.id).await;
+
+    assert!(response.is_ok());
+    let revisions = response.unwrap();
+
+    // the state preceding the first update is kept alongside the updated state
+    assert_eq!(revisions.len(), 2);
+    assert!(revisions[0].created_at >= revisions[1].created_at);
+    for revision in revisions.iter() {
+        assert_eq!(revision.entry_id, entry.id);
+        assert_eq!(revision.title, entry_title);
+    }
+
+    let revision = entry_revision_service::get(&database, revisions[0].id)
+        .await
+        .unwrap();
+    assert!(revision.errors.is_empty());
+    assert_eq!(revision.data.text, generate_text_node("The first draft."));
+
+    let revision = entry_revision_service::get(&database, revisions[1].id)
+        .await
+        .unwrap();
+    assert_eq!(revision.data.text, TextNode::new_doc());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_merge_consecutive_entry_revisions(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap();
+
+    update_entry_text(
+        &database,
+        update_entry_payload.clone(),
+        entry.id,
+        &generate_text_node("The first draft."),
+    )
+    .await;
+    update_entry_text(
+        &database,
+        update_entry_payload,
+        entry.id,
+        &generate_text_node("The second draft."),
+    )
+    .await;
+
+    let revisions = entry_revision_service::get_all(&database, entry.id)
+        .await
+        .unwrap();
+    assert_eq!(revisions.len(), 2);
+
+    let revision = entry_revision_service::get(&database, revisions[0].id)
+        .await
+        .unwrap();
+    assert_eq!(revision.data.text, generate_text_node("The second draft."));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_record_entry_revisions_of_atomic_update(
+    create_entry_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap();
+
+    update_entry_payload.id = entry.id;
+    update_entry_payload.text =
+        Some(serde_json::to_string(&generate_text_node("The first draft.")).unwrap());
+    let response = entry_service::update_atomically(&database, update_entry_payload).await;
+    assert!(response.errors.is_empty());
+
+    let revisions = entry_revision_service::get_all(&database, entry.id)
+        .await
+        .unwrap();
+    assert_eq!(revisions.len(), 2);
+
+    let revision = entry_revision_service::get(&database, revisions[0].id)
+        .await
+        .unwrap();
+    assert_eq!(revision.data.text, generate_text_node("The first draft."));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_ignore_entry_update_without_tracked_changes(
+    create_entry_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap();
+
+    update_entry_payload.id = entry.id;
+    update_entry_payload.folder_id = Some(entry.folder_id);
+    entry_service::update(&database, update_entry_payload).await;
+
+    let revisions = entry_revision_service::get_all(&database, entry.id)
+        .await
+        .unwrap();
+    assert!(revisions.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_restore_entry_revision(
+    create_entry_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+    entry_title: String,
+) {
+    let database = database().await;
+    let entry = entry_service::create(&database, create_entry_payload)
+        .await
+        .unwrap();
+
+    update_entry_payload.title = Some("Botched Entry".to_owned());
+    update_entry_text(
+        &database,
+        update_entry_payload,
+        entry.id,
+        &generate_text_node("A botched edit."),
+    )
+    .await;
+
+    let revisions = entry_revision_service::get_all(&database, entry.id)
+        .await
+        .unwrap();
+    let original_revision_id = revisions[1].id;
+
+    let response = entry_revision_service::restore(&database, original_revision_id).await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert!(response.errors.is_empty());
+    assert!(response.data.title.updated);
+    assert!(response.data.text.updated);
+
+    let article = entry_service::get_text(&database, entry.id).await.unwrap();
+    assert_eq!(article.data.info.title, entry_title);
+    assert_eq!(article.data.text, TextNode::new_doc());
+
+    // the restored state is never merged into the latest revision,
+    // so the botched edit can itself be restored
+    let revisions = entry_revision_service::get_all(&database, entry.id)
+        .await
+        .unwrap();
+    assert_eq!(revisions.len(), 3);
+    assert_eq!(revisions[0].title, entry_title);
+    assert_eq!(revisions[1].title, "Botched Entry");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_getting_revisions_of_nonexistent_entry() {
+    let database = database().await;
+    let response = entry_revision_service::get_all(&database, Uuid::new_v4()).await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_getting_nonexistent_revision() {
+    let database = database().await;
+    let response = entry_revision_service::get(&database, Uuid::new_v4()).await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_restoring_nonexistent_revision() {
+    let database = database().await;
+    let response = entry_revision_service::restore(&database, Uuid::new_v4()).await;
+    assert!(response.is_err());
+}
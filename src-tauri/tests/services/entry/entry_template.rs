@@ -0,0 +1,174 @@
+use hellebore::{
+    model::text::TextNode,
+    schema::{
+        custom_property::{CustomPropertyCreateSchema, CustomPropertyValueSchema},
+        entry::{EntryCreateSchema, EntryProperties},
+        entry_template::EntryTemplateCreateSchema,
+        person::PersonSchema,
+    },
+    services::{
+        custom_property_service, entry_reference_service, entry_service, entry_template_service,
+    },
+    types::{
+        custom_property::CustomPropertyType,
+        entity::{ENTRY, PERSON},
+    },
+};
+use rstest::*;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        database, entry::create_entry_payload, folder::folder_id, person::create_person_payload,
+    },
+    utils::db::create_generic_entry,
+};
+
+fn template_text(text: &str) -> TextNode {
+    TextNode::new_doc()
+        .with_child(TextNode::new_paragraph().with_child(TextNode::new_text(text.to_owned())))
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_entry_from_template(
+    folder_id: Uuid,
+    mut create_person_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let guild = create_generic_entry(&database, folder_id, "Guild".to_owned(), "".to_owned()).await;
+    let rank = custom_property_service::create(
+        &database,
+        CustomPropertyCreateSchema {
+            entity_type: PERSON,
+            name: "Rank".to_owned(),
+            property_type: CustomPropertyType::Text,
+            item_type: None,
+            options: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    let text = TextNode::new_doc().with_child(
+        TextNode::new_paragraph()
+            .with_child(TextNode::new_text("Member of ".to_owned()))
+            .with_child(TextNode::new_reference(guild.id, "Guild".to_owned())),
+    );
+    let template = entry_template_service::create(
+        &database,
+        EntryTemplateCreateSchema {
+            entity_type: PERSON,
+            name: "Guild member".to_owned(),
+            text: text.clone(),
+            properties: None,
+            custom_properties: vec![CustomPropertyValueSchema {
+                property_id: rank.id,
+                value: json!("Apprentice"),
+            }],
+        },
+    )
+    .await;
+    assert!(template.is_ok());
+
+    create_person_payload.template_id = Some(template.unwrap().id);
+    let entry = entry_service::create(&database, create_person_payload).await;
+    assert!(entry.is_ok());
+    let id = entry.unwrap().id;
+
+    let article = entry_service::get_text(&database, id).await.unwrap();
+    assert_eq!(article.data.text, text);
+
+    // the properties given on creation are kept, since the template doesn't have any
+    let properties = entry_service::get_properties(&database, id).await.unwrap();
+    match properties.properties {
+        EntryProperties::Person(person) => assert_eq!(person.name, "John Doe"),
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+    assert_eq!(properties.custom_properties[0].value, json!("Apprentice"));
+
+    let backlinks = entry_reference_service::get_backlinks(&database, guild.id)
+        .await
+        .unwrap();
+    assert_eq!(backlinks.len(), 1);
+    assert_eq!(backlinks[0].info.id, id);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_entry_with_template_properties(mut create_person_payload: EntryCreateSchema) {
+    let database = database().await;
+    let template = entry_template_service::create(
+        &database,
+        EntryTemplateCreateSchema {
+            entity_type: PERSON,
+            name: "Stranger".to_owned(),
+            text: template_text("Nobody knows where they came from."),
+            properties: Some(EntryProperties::Person(PersonSchema {
+                name: "Unknown".to_owned(),
+            })),
+            custom_properties: vec![],
+        },
+    )
+    .await
+    .unwrap();
+
+    create_person_payload.template_id = Some(template.id);
+    let id = entry_service::create(&database, create_person_payload)
+        .await
+        .unwrap()
+        .id;
+
+    let properties = entry_service::get_properties(&database, id).await.unwrap();
+    match properties.properties {
+        EntryProperties::Person(person) => assert_eq!(person.name, "Unknown"),
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_invalid_template(
+    mut create_entry_payload: EntryCreateSchema,
+    create_person_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let template = |name: &str, properties: Option<EntryProperties>| EntryTemplateCreateSchema {
+        entity_type: PERSON,
+        name: name.to_owned(),
+        text: template_text("Biography"),
+        properties,
+        custom_properties: vec![],
+    };
+    let person_template = entry_template_service::create(&database, template("Person", None))
+        .await
+        .unwrap();
+
+    // names are unique per entity type
+    let response = entry_template_service::create(&database, template(" Person ", None)).await;
+    assert!(response.is_err());
+
+    // the properties must match the entity type of the template
+    let properties = create_entry_payload.properties.clone();
+    let response =
+        entry_template_service::create(&database, template("Language", Some(properties))).await;
+    assert!(response.is_err());
+
+    // the template must match the type of the new entry
+    create_entry_payload.entity_type = ENTRY;
+    create_entry_payload.template_id = Some(person_template.id);
+    let response = entry_service::create(&database, create_entry_payload).await;
+    assert!(response.is_err());
+
+    // nothing is created when the template doesn't exist
+    let title = create_person_payload.title.clone();
+    let mut payload = create_person_payload;
+    payload.template_id = Some(Uuid::new_v4());
+    let response = entry_service::create(&database, payload).await;
+    assert!(response.is_err());
+    let is_unique = entry_service::validate_title(&database, None, &title)
+        .await
+        .unwrap();
+    assert!(is_unique.data);
+}
@@ -0,0 +1,291 @@
+use hellebore::{
+    model::text::TextNode,
+    schema::entry::{EntryCreateSchema, EntryUpdateSchema},
+    services::{entry_reference_service, entry_service, trash_service},
+};
+use rstest::*;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        database,
+        entry::{create_entry_payload, update_entry_payload},
+        folder::folder_id,
+    },
+    utils::db::{create_entry_with_title, create_generic_entry, update_entry_text},
+};
+
+fn generate_mention_paragraph(before: &str, id: Uuid, label: &str, after: &str) -> TextNode {
+    TextNode::new_paragraph()
+        .with_child(TextNode::new_text(before.to_owned()))
+        .with_child(TextNode::new_reference(id, label.to_owned()))
+        .with_child(TextNode::new_text(after.to_owned()))
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_entry_backlinks(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let target_id =
+        create_entry_with_title(&database, create_entry_payload.clone(), "Isolde").await;
+    let source_id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let text = TextNode::new_doc()
+        .with_child(generate_mention_paragraph(
+            "The treaty was signed by ",
+            target_id,
+            "Isolde",
+            " in the spring.",
+        ))
+        .with_child(generate_mention_paragraph(
+            "It was later revoked by ",
+            target_id,
+            "Isolde",
+            ".",
+        ));
+    update_entry_text(&database, update_entry_payload, source_id, &text).await;
+
+    let response = entry_reference_service::get_backlinks(&database, target_id).await;
+
+    assert!(response.is_ok());
+    let backlinks = response.unwrap();
+    assert_eq!(backlinks.len(), 1);
+    assert_eq!(backlinks[0].info.id, source_id);
+    assert_eq!(backlinks[0].info.title, "Salt Treaty");
+    assert_eq!(backlinks[0].mention_count, 2);
+    assert_eq!(
+        backlinks[0].snippets,
+        vec![
+            "The treaty was signed by Isolde in the spring.",
+            "It was later revoked by Isolde.",
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_entry_backlinks_truncates_snippets(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let target_id =
+        create_entry_with_title(&database, create_entry_payload.clone(), "Isolde").await;
+    let source_id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let padding = "salt ".repeat(100);
+    let text = TextNode::new_doc().with_child(generate_mention_paragraph(
+        &padding, target_id, "Isolde", &padding,
+    ));
+    update_entry_text(&database, update_entry_payload, source_id, &text).await;
+
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+
+    let snippet = &backlinks[0].snippets[0];
+    assert!(snippet.starts_with('…'));
+    assert!(snippet.ends_with('…'));
+    assert!(snippet.contains("Isolde"));
+    assert!(snippet.chars().count() < padding.chars().count());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_entry_backlinks_after_mention_is_removed(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let target_id =
+        create_entry_with_title(&database, create_entry_payload.clone(), "Isolde").await;
+    let source_id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let text = TextNode::new_doc().with_child(generate_mention_paragraph(
+        "Signed by ",
+        target_id,
+        "Isolde",
+        ".",
+    ));
+    update_entry_text(&database, update_entry_payload.clone(), source_id, &text).await;
+
+    let text = TextNode::new_doc().with_child(
+        TextNode::new_paragraph().with_child(TextNode::new_text("Signed by nobody.".to_owned())),
+    );
+    update_entry_text(&database, update_entry_payload, source_id, &text).await;
+
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert!(backlinks.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_entry_backlinks_after_source_is_deleted(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let target_id =
+        create_entry_with_title(&database, create_entry_payload.clone(), "Isolde").await;
+    let source_id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let text = TextNode::new_doc().with_child(generate_mention_paragraph(
+        "Signed by ",
+        target_id,
+        "Isolde",
+        ".",
+    ));
+    update_entry_text(&database, update_entry_payload, source_id, &text).await;
+
+    entry_service::delete(&database, source_id).await.unwrap();
+
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert!(backlinks.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_entry_backlinks_after_source_is_trashed(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let target_id =
+        create_entry_with_title(&database, create_entry_payload.clone(), "Isolde").await;
+    let source_id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let text = TextNode::new_doc().with_child(generate_mention_paragraph(
+        "Signed by ",
+        target_id,
+        "Isolde",
+        ".",
+    ));
+    update_entry_text(&database, update_entry_payload, source_id, &text).await;
+
+    trash_service::trash_entry(&database, source_id)
+        .await
+        .unwrap();
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert!(backlinks.is_empty());
+
+    trash_service::restore_entry(&database, source_id)
+        .await
+        .unwrap();
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert_eq!(backlinks.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_entry_backlinks_ignores_self_references(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let target_id = create_entry_with_title(&database, create_entry_payload, "Isolde").await;
+
+    let text = TextNode::new_doc().with_child(generate_mention_paragraph(
+        "",
+        target_id,
+        "Isolde",
+        " was a queen.",
+    ));
+    update_entry_text(&database, update_entry_payload, target_id, &text).await;
+
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert!(backlinks.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_getting_backlinks_of_nonexistent_entry() {
+    let database = database().await;
+    let response = entry_reference_service::get_backlinks(&database, Uuid::new_v4()).await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_rebuild_references(folder_id: Uuid, create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let target_id = create_entry_with_title(&database, create_entry_payload, "Isolde").await;
+
+    // inserting the entry directly bypasses the reference table
+    let text = TextNode::new_doc().with_child(generate_mention_paragraph(
+        "Signed by ",
+        target_id,
+        "Isolde",
+        ".",
+    ));
+    let source = create_generic_entry(
+        &database,
+        folder_id,
+        "Salt Treaty".to_owned(),
+        serde_json::to_string(&text).unwrap(),
+    )
+    .await;
+
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert!(backlinks.is_empty());
+
+    let response = entry_reference_service::sync(&database).await;
+    assert!(response.is_ok());
+
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert_eq!(backlinks.len(), 1);
+    assert_eq!(backlinks[0].info.id, source.id);
+    assert_eq!(backlinks[0].snippets, vec!["Signed by Isolde."]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_sync_references_only_once(folder_id: Uuid, create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let target_id = create_entry_with_title(&database, create_entry_payload, "Isolde").await;
+    // a project without any mentions is synced like any other
+    entry_reference_service::sync(&database).await.unwrap();
+
+    let text = TextNode::new_doc().with_child(generate_mention_paragraph(
+        "Signed by ",
+        target_id,
+        "Isolde",
+        ".",
+    ));
+    create_generic_entry(
+        &database,
+        folder_id,
+        "Salt Treaty".to_owned(),
+        serde_json::to_string(&text).unwrap(),
+    )
+    .await;
+
+    // the references have already been built, so they aren't rebuilt on the next load
+    entry_reference_service::sync(&database).await.unwrap();
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert!(backlinks.is_empty());
+
+    entry_reference_service::rebuild(&database).await.unwrap();
+    let backlinks = entry_reference_service::get_backlinks(&database, target_id)
+        .await
+        .unwrap();
+    assert_eq!(backlinks.len(), 1);
+}
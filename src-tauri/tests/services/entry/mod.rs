@@ -1,6 +1,11 @@
 mod create_entry;
 mod delete_entry;
+mod entry_alias;
+mod entry_revision;
+mod entry_template;
 mod get_entry;
+mod get_entry_backlinks;
 mod search_entry;
+mod search_entry_text;
 mod sync_entry_text;
 mod update_entry;
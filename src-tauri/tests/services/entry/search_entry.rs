@@ -11,6 +11,7 @@ use crate::{
 pub fn search_entry_payload() -> EntrySearchSchema {
     EntrySearchSchema {
         keyword: "".to_owned(),
+        tags: None,
         before: None,
         after: None,
         limit: 2,
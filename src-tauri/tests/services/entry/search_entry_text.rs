@@ -0,0 +1,336 @@
+use hellebore::{
+    model::text::TextNode,
+    schema::entry::{EntryCreateSchema, EntryTextSearchSchema, EntryUpdateSchema},
+    services::{entry_search_service, entry_service},
+};
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        database,
+        entry::{create_entry_payload, update_entry_payload},
+        folder::folder_id,
+    },
+    utils::db::create_generic_entry,
+};
+
+#[fixture]
+pub fn search_entry_text_payload() -> EntryTextSearchSchema {
+    EntryTextSearchSchema {
+        keyword: "".to_owned(),
+        limit: 10,
+        offset: 0,
+    }
+}
+
+fn generate_text(paragraphs: &[&str]) -> String {
+    let mut doc = TextNode::new_doc();
+    for paragraph in paragraphs {
+        doc.add_child(
+            TextNode::new_paragraph().with_child(TextNode::new_text(paragraph.to_string())),
+        );
+    }
+    serde_json::to_string(&doc).unwrap()
+}
+
+async fn create_entry_with_text(
+    database: &DatabaseConnection,
+    mut create_payload: EntryCreateSchema,
+    mut update_payload: EntryUpdateSchema,
+    title: &str,
+    text: String,
+) -> Uuid {
+    create_payload.title = title.to_owned();
+    let entry = entry_service::create(database, create_payload)
+        .await
+        .unwrap();
+
+    update_payload.id = entry.id;
+    update_payload.text = Some(text);
+    let response = entry_service::update(database, update_payload).await;
+    assert!(response.errors.is_empty());
+
+    entry.id
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_matches_body(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    let id = create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload,
+        "Isolde",
+        generate_text(&["The queen signed the salt treaty in the spring."]),
+    )
+    .await;
+
+    search_entry_text_payload.keyword = "salt treaty".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload).await;
+
+    assert!(results.is_ok());
+    let results = results.unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].info.id, id);
+    assert_eq!(results[0].info.title, "Isolde");
+    assert!(results[0].snippet.contains("<mark>salt</mark>"));
+    assert!(results[0].snippet.contains("<mark>treaty</mark>"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_highlights_title(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload,
+        "Salt Treaty",
+        generate_text(&["A trade agreement."]),
+    )
+    .await;
+
+    search_entry_text_payload.keyword = "salt".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].highlighted_title, "<mark>Salt</mark> Treaty");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_matches_prefix_of_last_word(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload,
+        "Isolde",
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    search_entry_text_payload.keyword = "salt tre".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_does_not_match(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload,
+        "Isolde",
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    search_entry_text_payload.keyword = "pepper".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+
+    assert!(results.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_ignores_query_syntax(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload,
+        "Isolde",
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    search_entry_text_payload.keyword = "\"salt\" OR (treaty".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload).await;
+
+    assert!(results.is_ok());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_with_empty_keyword(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload,
+        "Isolde",
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+
+    assert!(results.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_reflects_updated_text(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    let id = create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload.clone(),
+        "Isolde",
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    let mut payload = update_entry_payload;
+    payload.id = id;
+    payload.text = Some(generate_text(&["The queen tore up the pepper accord."]));
+    entry_service::update(&database, payload).await;
+
+    search_entry_text_payload.keyword = "salt".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload.clone())
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+
+    search_entry_text_payload.keyword = "pepper".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entry_text_excludes_deleted_entry(
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    let id = create_entry_with_text(
+        &database,
+        create_entry_payload,
+        update_entry_payload,
+        "Isolde",
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    entry_service::delete(&database, id).await.unwrap();
+
+    search_entry_text_payload.keyword = "salt".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+
+    assert!(results.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_sync_search_index(
+    folder_id: Uuid,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+
+    // inserting the entry directly bypasses the search index
+    let entry = create_generic_entry(
+        &database,
+        folder_id,
+        "Isolde".to_owned(),
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    search_entry_text_payload.keyword = "salt".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload.clone())
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+
+    let response = entry_search_service::sync(&database).await;
+    assert!(response.is_ok());
+
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].info.id, entry.id);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_sync_search_index_only_once(
+    folder_id: Uuid,
+    mut search_entry_text_payload: EntryTextSearchSchema,
+) {
+    let database = database().await;
+    // an empty project is synced like any other
+    entry_search_service::sync(&database).await.unwrap();
+
+    create_generic_entry(
+        &database,
+        folder_id,
+        "Isolde".to_owned(),
+        generate_text(&["The queen signed the salt treaty."]),
+    )
+    .await;
+
+    // the index has already been built, so it isn't rebuilt on the next load
+    entry_search_service::sync(&database).await.unwrap();
+    search_entry_text_payload.keyword = "salt".to_owned();
+    let results = entry_search_service::search(&database, search_entry_text_payload.clone())
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+
+    entry_search_service::rebuild(&database).await.unwrap();
+    let results = entry_search_service::search(&database, search_entry_text_payload)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+}
@@ -1,8 +1,16 @@
 use hellebore::{
-    schema::{entry::EntryUpdateSchema, folder::FolderCreateSchema},
-    services::{entry_service, folder_service},
+    database::entry_manager,
+    model::errors::Error,
+    schema::{
+        custom_property::CustomPropertyValueSchema,
+        entry::{EntryUpdateMode, EntryUpdateSchema},
+        folder::FolderCreateSchema,
+    },
+    services::{entry_revision_service, entry_service, folder_service},
 };
 use rstest::*;
+use sea_orm::DbErr;
+use serde_json::json;
 use uuid::Uuid;
 
 use crate::{
@@ -202,3 +210,183 @@ async fn test_noop_on_updating_entry_with_empty_payload(update_entry_payload: En
     assert!(!response.data.text.updated);
     assert!(response.errors.is_empty());
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_atomic_update_is_rolled_back_on_error(
+    folder_id: Uuid,
+    entry_title: String,
+    entry_text: String,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = create_generic_entry(&database, folder_id, entry_title, entry_text).await;
+
+    update_entry_payload.id = entry.id;
+    update_entry_payload.title = Some("new title".to_owned());
+    update_entry_payload.custom_properties = Some(vec![CustomPropertyValueSchema {
+        property_id: Uuid::new_v4(),
+        value: json!("unknown"),
+    }]);
+    let response = entry_service::update_atomically(&database, update_entry_payload).await;
+
+    assert!(!response.errors.is_empty());
+    assert!(!response.data.title.updated);
+    assert!(!response.data.custom_properties.updated);
+    let updated_entry = get_entry(&database, entry.id).await.unwrap();
+    assert_eq!(updated_entry.title, entry.title);
+    // the revisions of the update are rolled back along with it
+    let revisions = entry_revision_service::get_all(&database, entry.id)
+        .await
+        .unwrap();
+    assert!(revisions.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_bulk_update_rejects_duplicate_ids(
+    folder_id: Uuid,
+    entry_title: String,
+    entry_text: String,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = create_generic_entry(&database, folder_id, entry_title, entry_text).await;
+
+    update_entry_payload.id = entry.id;
+    update_entry_payload.title = Some("first title".to_owned());
+    let mut duplicate_payload = update_entry_payload.clone();
+    duplicate_payload.title = Some("second title".to_owned());
+    let responses = entry_service::bulk_update(
+        &database,
+        vec![update_entry_payload, duplicate_payload],
+        EntryUpdateMode::Partial,
+    )
+    .await;
+
+    assert_eq!(responses.len(), 2);
+    assert!(responses[0].data.title.updated);
+    assert!(responses[0].errors.is_empty());
+    assert!(!responses[1].data.title.updated);
+    assert!(matches!(
+        responses[1].errors[0],
+        Error::AttributeNotUnique { .. }
+    ));
+    let entry = get_entry(&database, entry.id).await.unwrap();
+    assert_eq!(entry.title, "first title");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_bulk_update_in_batch_mode_is_all_or_nothing(
+    folder_id: Uuid,
+    entry_text: String,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry_1 = create_generic_entry(
+        &database,
+        folder_id,
+        "entry1".to_owned(),
+        entry_text.clone(),
+    )
+    .await;
+    let entry_2 = create_generic_entry(&database, folder_id, "entry2".to_owned(), entry_text).await;
+
+    let mut first_payload = update_entry_payload.clone();
+    first_payload.id = entry_1.id;
+    first_payload.title = Some("renamed".to_owned());
+    // the title is taken by the first update of the batch
+    let mut second_payload = update_entry_payload;
+    second_payload.id = entry_2.id;
+    second_payload.title = Some("renamed".to_owned());
+    let responses = entry_service::bulk_update(
+        &database,
+        vec![first_payload, second_payload],
+        EntryUpdateMode::Batch,
+    )
+    .await;
+
+    assert!(!responses[0].data.title.updated);
+    assert!(!responses[1].errors.is_empty());
+    let entry = get_entry(&database, entry_1.id).await.unwrap();
+    assert_eq!(entry.title, entry_1.title);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_updating_entry_with_stale_revision(
+    folder_id: Uuid,
+    entry_title: String,
+    entry_text: String,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = create_generic_entry(&database, folder_id, entry_title, entry_text).await;
+    let info = entry_service::get_info(&database, entry.id).await.unwrap();
+    assert_eq!(info.revision, 0);
+
+    update_entry_payload.id = entry.id;
+    update_entry_payload.title = Some("first title".to_owned());
+    update_entry_payload.expected_revision = Some(info.revision);
+    let response = entry_service::update(&database, update_entry_payload.clone()).await;
+    assert!(response.errors.is_empty());
+    let info = entry_service::get_info(&database, entry.id).await.unwrap();
+    assert_eq!(info.revision, 1);
+
+    // the second update is based on the revision that the first one replaced
+    update_entry_payload.title = Some("second title".to_owned());
+    let response = entry_service::update(&database, update_entry_payload).await;
+    assert!(!response.data.title.updated);
+    match &response.errors[0] {
+        Error::RevisionConflict {
+            expected_revision,
+            revision,
+            current,
+            ..
+        } => {
+            assert_eq!(*expected_revision, 0);
+            assert_eq!(*revision, 1);
+            assert_eq!(current["info"]["title"], "first title");
+        }
+        error => panic!("Unexpected error: {error:?}"),
+    }
+    let entry = get_entry(&database, entry.id).await.unwrap();
+    assert_eq!(entry.title, "first title");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_stale_revision_is_rejected_by_the_write(
+    folder_id: Uuid,
+    entry_title: String,
+    entry_text: String,
+) {
+    let database = database().await;
+    let entry = create_generic_entry(&database, folder_id, entry_title, entry_text).await;
+    entry_manager::update(
+        &database,
+        entry.id,
+        None,
+        Some("first title".to_owned()),
+        None,
+        Some(0),
+    )
+    .await
+    .unwrap();
+
+    // a write that comes in after the revision was checked doesn't apply either
+    let result = entry_manager::update(
+        &database,
+        entry.id,
+        None,
+        Some("second title".to_owned()),
+        None,
+        Some(0),
+    )
+    .await;
+    assert!(matches!(result, Err(DbErr::RecordNotUpdated)));
+    let entry = get_entry(&database, entry.id).await.unwrap();
+    assert_eq!(entry.title, "first title");
+    assert_eq!(entry.revision, 1);
+}
@@ -0,0 +1,344 @@
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        calendar::{CalendarDateSchema, CalendarSchema},
+        entry::{EntryCreateSchema, EntryProperties, EntryUpdateSchema},
+        event::{EventSchema, TimelineQuerySchema},
+        folder::FolderCreateSchema,
+    },
+    services::{entry_service, event_service, folder_service, trash_service},
+    types::{calendar::DatePrecision, entity::EVENT},
+};
+
+use crate::{
+    fixtures::{
+        calendar::{calendar_properties, create_calendar_payload, date},
+        database,
+        entry::{create_entry_payload, update_entry_payload},
+        folder::folder_create_payload,
+    },
+    utils::db::create_generic_entry,
+};
+
+fn event_properties(start: CalendarDateSchema, end: Option<CalendarDateSchema>) -> EventSchema {
+    EventSchema {
+        start,
+        end,
+        precision: DatePrecision::Exact,
+        participant_ids: vec![],
+        location_id: None,
+    }
+}
+
+async fn create_event(
+    database: &DatabaseConnection,
+    mut payload: EntryCreateSchema,
+    title: &str,
+    properties: EventSchema,
+) -> Uuid {
+    payload.entity_type = EVENT;
+    payload.title = title.to_owned();
+    payload.properties = EntryProperties::Event(properties);
+    entry_service::create(database, payload).await.unwrap().id
+}
+
+async fn get_timeline_titles(
+    database: &DatabaseConnection,
+    query: TimelineQuerySchema,
+) -> Vec<String> {
+    event_service::get_timeline(database, query)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|event| event.info.title)
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_event(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let participant =
+        create_generic_entry(&database, Uuid::nil(), "Ada".to_owned(), "".to_owned()).await;
+    let location =
+        create_generic_entry(&database, Uuid::nil(), "Reach".to_owned(), "".to_owned()).await;
+
+    let mut properties = event_properties(
+        date(calendar_id, 412, 1, 3),
+        Some(date(calendar_id, 412, 2, 10)),
+    );
+    properties.participant_ids = vec![participant.id, participant.id];
+    properties.location_id = Some(location.id);
+    let id = create_event(&database, create_entry_payload, "Siege", properties.clone()).await;
+
+    let response = entry_service::get_properties(&database, id).await;
+
+    assert!(response.is_ok());
+    properties.participant_ids = vec![participant.id];
+    match response.unwrap().properties {
+        EntryProperties::Event(event) => assert_eq!(event, properties),
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_creating_event_that_ends_before_it_starts(
+    create_calendar_payload: EntryCreateSchema,
+    mut create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    create_entry_payload.entity_type = EVENT;
+    create_entry_payload.properties = EntryProperties::Event(event_properties(
+        date(calendar_id, 412, 2, 10),
+        Some(date(calendar_id, 412, 1, 3)),
+    ));
+
+    let response = entry_service::create(&database, create_entry_payload).await;
+
+    assert!(response.is_err());
+    // only the calendar remains
+    assert_eq!(entry_service::get_all(&database).await.unwrap().len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_creating_event_with_nonexistent_participant(
+    create_calendar_payload: EntryCreateSchema,
+    mut create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let mut properties = event_properties(date(calendar_id, 412, 1, 3), None);
+    properties.participant_ids = vec![Uuid::new_v4()];
+    create_entry_payload.entity_type = EVENT;
+    create_entry_payload.properties = EntryProperties::Event(properties);
+
+    let response = entry_service::create(&database, create_entry_payload).await;
+
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_imprecise_dates_span_their_period(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let mut properties = event_properties(
+        date(calendar_id, 412, 2, 5),
+        Some(date(calendar_id, 413, 1, 1)),
+    );
+    properties.precision = DatePrecision::YearOnly;
+    let id = create_event(&database, create_entry_payload, "Famine", properties).await;
+
+    let event = event_service::get(&database, id).await.unwrap();
+    assert_eq!(event.start, date(calendar_id, 412, 1, 1));
+    assert_eq!(event.end, Some(date(calendar_id, 413, 4, 30)));
+
+    let timeline = event_service::get_timeline(&database, TimelineQuerySchema::default())
+        .await
+        .unwrap();
+    assert_eq!(timeline[0].start.formatted, "412 AE");
+    assert_eq!(timeline[0].end.as_ref().unwrap().formatted, "413 AE");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_timeline_by_date_range(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let events = [
+        ("Coronation", date(calendar_id, 420, 1, 1), None),
+        ("Founding", date(calendar_id, 1, 1, 1), None),
+        (
+            "Long War",
+            date(calendar_id, 400, 3, 1),
+            Some(date(calendar_id, 415, 1, 1)),
+        ),
+        ("Armistice", date(calendar_id, 415, 1, 1), None),
+    ];
+    for (title, start, end) in events.into_iter() {
+        let properties = event_properties(start, end);
+        create_event(&database, create_entry_payload.clone(), title, properties).await;
+    }
+
+    let titles = get_timeline_titles(&database, TimelineQuerySchema::default()).await;
+    assert_eq!(
+        titles,
+        vec!["Founding", "Long War", "Armistice", "Coronation"]
+    );
+
+    // events that overlap with the range are included
+    let query = TimelineQuerySchema {
+        start: Some(date(calendar_id, 410, 1, 1)),
+        end: Some(date(calendar_id, 419, 1, 1)),
+        ..Default::default()
+    };
+    let titles = get_timeline_titles(&database, query).await;
+    assert_eq!(titles, vec!["Long War", "Armistice"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_timeline_by_folder_and_participant(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+    folder_create_payload: FolderCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let folder = folder_service::create(&database, folder_create_payload)
+        .await
+        .unwrap();
+    let subfolder = folder_service::create(
+        &database,
+        FolderCreateSchema {
+            parent_id: folder.id,
+            name: "subfolder".to_owned(),
+        },
+    )
+    .await
+    .unwrap();
+    let participant =
+        create_generic_entry(&database, Uuid::nil(), "Ada".to_owned(), "".to_owned()).await;
+
+    let mut properties = event_properties(date(calendar_id, 412, 1, 3), None);
+    create_event(
+        &database,
+        create_entry_payload.clone(),
+        "Duel",
+        properties.clone(),
+    )
+    .await;
+    let mut payload = create_entry_payload;
+    payload.folder_id = subfolder.id;
+    properties.participant_ids = vec![participant.id];
+    create_event(&database, payload, "Ambush", properties).await;
+
+    let query = TimelineQuerySchema {
+        folder_id: Some(folder.id),
+        ..Default::default()
+    };
+    assert_eq!(get_timeline_titles(&database, query).await, vec!["Ambush"]);
+
+    let query = TimelineQuerySchema {
+        participant_id: Some(participant.id),
+        ..Default::default()
+    };
+    let timeline = event_service::get_timeline(&database, query).await.unwrap();
+    assert_eq!(timeline.len(), 1);
+    assert_eq!(timeline[0].participant_ids, vec![participant.id]);
+
+    // events on the same day are listed by title
+    let titles = get_timeline_titles(&database, TimelineQuerySchema::default()).await;
+    assert_eq!(titles, vec!["Ambush", "Duel"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_timeline_without_trashed_events(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let properties = event_properties(date(calendar_id, 412, 1, 3), None);
+    create_event(
+        &database,
+        create_entry_payload.clone(),
+        "Duel",
+        properties.clone(),
+    )
+    .await;
+    let ambush_id = create_event(&database, create_entry_payload, "Ambush", properties).await;
+
+    trash_service::trash_entry(&database, ambush_id)
+        .await
+        .unwrap();
+
+    let titles = get_timeline_titles(&database, TimelineQuerySchema::default()).await;
+    assert_eq!(titles, vec!["Duel"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_update_calendar_recomputes_event_day_numbers(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+    mut calendar_properties: CalendarSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let properties = event_properties(date(calendar_id, 412, 1, 3), None);
+    create_event(&database, create_entry_payload, "Siege", properties).await;
+
+    calendar_properties.epoch_offset = 1_000;
+    update_entry_payload.id = calendar_id;
+    update_entry_payload.properties = Some(EntryProperties::Calendar(calendar_properties));
+    let response = entry_service::update(&database, update_entry_payload).await;
+    assert!(response.errors.is_empty());
+
+    let timeline = event_service::get_timeline(&database, TimelineQuerySchema::default())
+        .await
+        .unwrap();
+    assert_eq!(timeline[0].start.day_number, 49_835 + 1_000);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_deleting_calendar_of_event(
+    create_calendar_payload: EntryCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let properties = event_properties(date(calendar_id, 412, 1, 3), None);
+    let id = create_event(&database, create_entry_payload, "Siege", properties).await;
+
+    assert!(entry_service::delete(&database, calendar_id).await.is_err());
+
+    entry_service::delete(&database, id).await.unwrap();
+    assert!(entry_service::delete(&database, calendar_id).await.is_ok());
+}
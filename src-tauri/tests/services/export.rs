@@ -0,0 +1,199 @@
+use std::fs;
+use std::path::Path;
+
+use hellebore::{
+    model::text::TextNode,
+    schema::{
+        entry::{EntryCreateSchema, EntryUpdateSchema},
+        export::{MarkdownExportSchema, MentionStyle},
+        folder::FolderCreateSchema,
+    },
+    services::{entry_service, export_service, folder_service},
+};
+use rstest::*;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        database,
+        entry::{create_entry_payload, update_entry_payload},
+        folder::folder_create_payload,
+        person::create_person_payload,
+        project::{TempProjectDir, temp_project_dir},
+    },
+    utils::db::{create_entry_with_title, update_entry_text},
+};
+
+fn generate_export_payload(path: &Path, mention_style: MentionStyle) -> MarkdownExportSchema {
+    MarkdownExportSchema {
+        folder_path: path.to_string_lossy().to_string(),
+        mention_style,
+    }
+}
+
+fn generate_mention_text(id: Uuid, label: &str) -> TextNode {
+    TextNode::new_doc().with_child(
+        TextNode::new_paragraph()
+            .with_child(TextNode::new_text("Signed by ".to_owned()))
+            .with_child(TextNode::new_reference(id, label.to_owned()))
+            .with_child(TextNode::new_text(".".to_owned())),
+    )
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_export_markdown(
+    temp_project_dir: TempProjectDir,
+    mut folder_create_payload: FolderCreateSchema,
+    mut create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    folder_create_payload.name = "Treaties".to_owned();
+    let folder = folder_service::create(&database, folder_create_payload)
+        .await
+        .unwrap();
+    create_entry_payload.folder_id = folder.id;
+    let id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let mut heading = TextNode::new("heading".to_owned());
+    heading.set_attr("level", serde_json::Value::from(1));
+    let text = TextNode::new_doc()
+        .with_child(heading.with_child(TextNode::new_text("Terms".to_owned())))
+        .with_child(
+            TextNode::new_paragraph().with_child(TextNode::new_text("No salt tax.".to_owned())),
+        );
+    update_entry_text(&database, update_entry_payload, id, &text).await;
+
+    let export_path = Path::new(temp_project_dir.path()).join("export");
+    let response = export_service::export_markdown(
+        &database,
+        generate_export_payload(&export_path, MentionStyle::RelativeLink),
+    )
+    .await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert!(response.errors.is_empty());
+    assert_eq!(response.data.folder_count, 1);
+    assert_eq!(response.data.entry_count, 1);
+
+    let markdown = fs::read_to_string(export_path.join("Treaties").join("Salt Treaty.md")).unwrap();
+    assert_eq!(
+        markdown,
+        format!(
+            "---\nid: {id}\ntitle: \"Salt Treaty\"\ntype: Entry\n---\n\n# Terms\n\nNo salt tax.\n"
+        )
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_export_markdown_writes_person_front_matter(
+    temp_project_dir: TempProjectDir,
+    create_person_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let id = entry_service::create(&database, create_person_payload)
+        .await
+        .unwrap()
+        .id;
+
+    let export_path = Path::new(temp_project_dir.path()).join("export");
+    export_service::export_markdown(
+        &database,
+        generate_export_payload(&export_path, MentionStyle::RelativeLink),
+    )
+    .await
+    .unwrap();
+
+    let markdown = fs::read_to_string(export_path.join("John Doe.md")).unwrap();
+    assert_eq!(
+        markdown,
+        format!("---\nid: {id}\ntitle: \"John Doe\"\ntype: Person\nname: \"John Doe\"\n---\n")
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_export_markdown_with_relative_links(
+    temp_project_dir: TempProjectDir,
+    mut folder_create_payload: FolderCreateSchema,
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    folder_create_payload.name = "People".to_owned();
+    let folder = folder_service::create(&database, folder_create_payload)
+        .await
+        .unwrap();
+    let mut target_payload = create_entry_payload.clone();
+    target_payload.folder_id = folder.id;
+    let target_id = create_entry_with_title(&database, target_payload, "Isolde (Queen)").await;
+    let source_id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let text = generate_mention_text(target_id, "Isolde");
+    update_entry_text(&database, update_entry_payload, source_id, &text).await;
+
+    let export_path = Path::new(temp_project_dir.path()).join("export");
+    export_service::export_markdown(
+        &database,
+        generate_export_payload(&export_path, MentionStyle::RelativeLink),
+    )
+    .await
+    .unwrap();
+
+    let markdown = fs::read_to_string(export_path.join("Salt Treaty.md")).unwrap();
+    assert!(markdown.ends_with("Signed by [Isolde (Queen)](<People/Isolde (Queen).md>).\n"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_export_markdown_with_wikilinks(
+    temp_project_dir: TempProjectDir,
+    create_entry_payload: EntryCreateSchema,
+    update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let target_id =
+        create_entry_with_title(&database, create_entry_payload.clone(), "Isolde: Queen").await;
+    let source_id = create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let text = generate_mention_text(target_id, "Isolde");
+    update_entry_text(&database, update_entry_payload, source_id, &text).await;
+
+    let export_path = Path::new(temp_project_dir.path()).join("export");
+    export_service::export_markdown(
+        &database,
+        generate_export_payload(&export_path, MentionStyle::Wikilink),
+    )
+    .await
+    .unwrap();
+
+    // the colon isn't allowed in file names, so the link has to keep the title as its label
+    assert!(export_path.join("Isolde_ Queen.md").exists());
+    let markdown = fs::read_to_string(export_path.join("Salt Treaty.md")).unwrap();
+    assert!(markdown.ends_with("Signed by [[Isolde_ Queen|Isolde: Queen]].\n"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_exporting_markdown_to_nonempty_directory(
+    temp_project_dir: TempProjectDir,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    create_entry_with_title(&database, create_entry_payload, "Salt Treaty").await;
+
+    let export_path = Path::new(temp_project_dir.path());
+    fs::write(export_path.join("notes.txt"), "").unwrap();
+
+    let response = export_service::export_markdown(
+        &database,
+        generate_export_payload(export_path, MentionStyle::RelativeLink),
+    )
+    .await;
+
+    assert!(response.is_err());
+    assert!(!export_path.join("Salt Treaty.md").exists());
+}
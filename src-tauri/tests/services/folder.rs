@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 use hellebore::{
     constants::ROOT_FOLDER_ID,
+    model::errors::Error,
     schema::folder::{FolderCreateSchema, FolderResponseSchema, FolderUpdateSchema},
     services::folder_service,
 };
@@ -160,6 +161,7 @@ async fn test_update_folder(folder_create_payload: FolderCreateSchema, parent_fo
         id: folder.id,
         parent_id: Some(folder.parent_id),
         name: Some(updated_name.clone()),
+        expected_revision: None,
     };
     let response = folder_service::update(&database, update_payload).await;
 
@@ -173,6 +175,42 @@ async fn test_update_folder(folder_create_payload: FolderCreateSchema, parent_fo
     assert_eq!(updated.parent_id, parent_folder_id);
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_error_on_updating_folder_with_stale_revision(
+    folder_create_payload: FolderCreateSchema,
+) {
+    let database = database().await;
+    let folder = folder_service::create(&database, folder_create_payload)
+        .await
+        .unwrap();
+    assert_eq!(folder.revision, 0);
+
+    let update_payload = |name: &str| FolderUpdateSchema {
+        id: folder.id,
+        parent_id: None,
+        name: Some(name.to_owned()),
+        expected_revision: Some(folder.revision),
+    };
+    let response = folder_service::update(&database, update_payload("Renamed")).await;
+    assert!(response.errors.is_empty());
+
+    let response = folder_service::update(&database, update_payload("Renamed again")).await;
+    assert!(!response.data.name_changed);
+    match &response.errors[0] {
+        Error::RevisionConflict {
+            revision, current, ..
+        } => {
+            assert_eq!(*revision, 1);
+            assert_eq!(current["name"], "Renamed");
+        }
+        error => panic!("Unexpected error: {error:?}"),
+    }
+    let folder = folder_service::get(&database, folder.id).await.unwrap();
+    assert_eq!(folder.name, "Renamed");
+    assert_eq!(folder.revision, 1);
+}
+
 #[rstest]
 #[case(true)]
 #[case(false)]
@@ -206,6 +244,7 @@ async fn test_error_on_updating_folder_with_same_name_as_sibling(
         id: folder_1.id,
         parent_id: None,
         name: Some(folder_2.name),
+        expected_revision: None,
     };
     let response = folder_service::update(&database, update_payload).await;
     assert!(!response.errors.is_empty());
@@ -224,6 +263,7 @@ async fn test_bulk_update_updates_folder(folder_create_payload: FolderCreateSche
         id: folder.id,
         parent_id: Some(ROOT_FOLDER_ID),
         name: Some(new_name.clone()),
+        expected_revision: None,
     };
 
     let responses = folder_service::bulk_update(&database, vec![payload]).await;
@@ -269,11 +309,13 @@ async fn test_bulk_update_updates_multiple_folders() {
             id: folder_a.id,
             parent_id: Some(ROOT_FOLDER_ID),
             name: Some("renamed a".to_owned()),
+            expected_revision: None,
         },
         FolderUpdateSchema {
             id: folder_b.id,
             parent_id: Some(ROOT_FOLDER_ID),
             name: Some("renamed b".to_owned()),
+            expected_revision: None,
         },
     ];
 
@@ -312,11 +354,13 @@ async fn test_bulk_update_partial_failure() {
             id: bad_id,
             parent_id: Some(ROOT_FOLDER_ID),
             name: Some("nonexistent".to_owned()),
+            expected_revision: None,
         },
         FolderUpdateSchema {
             id: valid.id,
             parent_id: Some(ROOT_FOLDER_ID),
             name: Some("renamed".to_owned()),
+            expected_revision: None,
         },
     ];
 
@@ -0,0 +1,331 @@
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        calendar::CalendarDateSchema,
+        entry::EntryCreateSchema,
+        person::{PersonRelationshipCreateSchema, PersonRelationshipUpdateSchema},
+    },
+    services::{entry_service, genealogy_service, trash_service},
+    types::person::PersonRelationshipType,
+};
+
+use crate::{
+    fixtures::{calendar::create_calendar_payload, database, entry::create_entry_payload},
+    utils::db::{create_generic_entry, create_person},
+};
+
+fn relationship(
+    person_id: Uuid,
+    relative_id: Uuid,
+    relationship_type: PersonRelationshipType,
+) -> PersonRelationshipCreateSchema {
+    PersonRelationshipCreateSchema {
+        person_id,
+        relative_id,
+        relationship_type,
+        is_adoptive: false,
+        start: None,
+        end: None,
+    }
+}
+
+async fn add_parent(database: &DatabaseConnection, parent_id: Uuid, child_id: Uuid) {
+    genealogy_service::add_relationship(
+        database,
+        relationship(parent_id, child_id, PersonRelationshipType::Parent),
+    )
+    .await
+    .unwrap();
+}
+
+async fn describe(
+    database: &DatabaseConnection,
+    person_id: Uuid,
+    relative_id: Uuid,
+) -> Option<String> {
+    genealogy_service::get_kinship(database, person_id, relative_id)
+        .await
+        .unwrap()
+        .description
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_ancestors_and_descendants(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let aldric = create_person(&database, create_entry_payload.clone(), "Aldric").await;
+    let bera = create_person(&database, create_entry_payload.clone(), "Bera").await;
+    let cato = create_person(&database, create_entry_payload.clone(), "Cato").await;
+    let dara = create_person(&database, create_entry_payload, "Dara").await;
+    add_parent(&database, aldric, cato).await;
+    add_parent(&database, bera, cato).await;
+    add_parent(&database, cato, dara).await;
+
+    let ancestors = genealogy_service::get_ancestors(&database, dara, 5)
+        .await
+        .unwrap();
+    let ancestors: Vec<(String, u32)> = ancestors
+        .into_iter()
+        .map(|ancestor| (ancestor.person.title, ancestor.generation))
+        .collect();
+    assert_eq!(
+        ancestors,
+        vec![
+            ("Cato".to_owned(), 1),
+            ("Aldric".to_owned(), 2),
+            ("Bera".to_owned(), 2)
+        ]
+    );
+
+    let descendants = genealogy_service::get_descendants(&database, aldric, 1)
+        .await
+        .unwrap();
+    assert_eq!(descendants.len(), 1);
+    assert_eq!(descendants[0].person.title, "Cato");
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_lineage_without_trashed_people(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let aldric = create_person(&database, create_entry_payload.clone(), "Aldric").await;
+    let cato = create_person(&database, create_entry_payload.clone(), "Cato").await;
+    let dara = create_person(&database, create_entry_payload, "Dara").await;
+    add_parent(&database, aldric, cato).await;
+    add_parent(&database, cato, dara).await;
+
+    trash_service::trash_entry(&database, cato).await.unwrap();
+
+    // the generations are still counted through the people in the trash
+    let ancestors = genealogy_service::get_ancestors(&database, dara, 5)
+        .await
+        .unwrap();
+    assert_eq!(ancestors.len(), 1);
+    assert_eq!(ancestors[0].person.id, aldric);
+    assert_eq!(ancestors[0].generation, 2);
+
+    let relationships = genealogy_service::get_relationships(&database, dara)
+        .await
+        .unwrap();
+    assert!(relationships.is_empty());
+
+    let tree = genealogy_service::get_family_tree(&database, dara, 2)
+        .await
+        .unwrap();
+    let people: Vec<Uuid> = tree.people.iter().map(|person| person.person.id).collect();
+    assert_eq!(people, vec![aldric, dara]);
+    assert!(tree.relationships.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_kinship(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let mut people = Vec::new();
+    for name in [
+        "Elder", "Fenna", "Galen", "Hale", "Iris", "Jory", "Kell", "Lio", "Stranger",
+    ] {
+        people.push(create_person(&database, create_entry_payload.clone(), name).await);
+    }
+    let [elder, fenna, galen, hale, iris, jory, kell, lio, stranger] = people[..] else {
+        unreachable!()
+    };
+    // Elder's children are Fenna and Galen; their children are first cousins
+    add_parent(&database, elder, fenna).await;
+    add_parent(&database, elder, galen).await;
+    add_parent(&database, fenna, hale).await;
+    add_parent(&database, galen, iris).await;
+    add_parent(&database, hale, jory).await;
+    add_parent(&database, iris, kell).await;
+    // Lio is Hale's half-sibling through Fenna
+    add_parent(&database, fenna, lio).await;
+    add_parent(&database, stranger, lio).await;
+
+    assert_eq!(
+        describe(&database, hale, hale).await.as_deref(),
+        Some("self")
+    );
+    assert_eq!(
+        describe(&database, jory, elder).await.as_deref(),
+        Some("great-grandparent")
+    );
+    assert_eq!(
+        describe(&database, elder, kell).await.as_deref(),
+        Some("great-grandchild")
+    );
+    assert_eq!(
+        describe(&database, fenna, galen).await.as_deref(),
+        Some("sibling")
+    );
+    assert_eq!(
+        describe(&database, hale, lio).await.as_deref(),
+        Some("half-sibling")
+    );
+    assert_eq!(
+        describe(&database, galen, hale).await.as_deref(),
+        Some("niece or nephew")
+    );
+    assert_eq!(
+        describe(&database, jory, galen).await.as_deref(),
+        Some("great-aunt or great-uncle")
+    );
+    assert_eq!(
+        describe(&database, hale, iris).await.as_deref(),
+        Some("first cousin")
+    );
+    assert_eq!(
+        describe(&database, jory, kell).await.as_deref(),
+        Some("second cousin")
+    );
+    assert_eq!(
+        describe(&database, hale, kell).await.as_deref(),
+        Some("first cousin once removed")
+    );
+    assert_eq!(describe(&database, kell, stranger).await, None);
+
+    let kinship = genealogy_service::get_kinship(&database, jory, kell)
+        .await
+        .unwrap();
+    assert_eq!(kinship.common_ancestors.len(), 1);
+    assert_eq!(kinship.common_ancestors[0].id, elder);
+
+    genealogy_service::add_relationship(
+        &database,
+        relationship(kell, stranger, PersonRelationshipType::Partner),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        describe(&database, stranger, kell).await.as_deref(),
+        Some("partner")
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_family_tree(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let mira = create_person(&database, create_entry_payload.clone(), "Mira").await;
+    let nils = create_person(&database, create_entry_payload.clone(), "Nils").await;
+    let orin = create_person(&database, create_entry_payload.clone(), "Orin").await;
+    let pell = create_person(&database, create_entry_payload, "Pell").await;
+    add_parent(&database, mira, orin).await;
+    add_parent(&database, orin, pell).await;
+    genealogy_service::add_relationship(
+        &database,
+        relationship(mira, nils, PersonRelationshipType::Partner),
+    )
+    .await
+    .unwrap();
+
+    let tree = genealogy_service::get_family_tree(&database, orin, 1)
+        .await
+        .unwrap();
+
+    assert_eq!(tree.root_id, orin);
+    let people: Vec<(String, i32)> = tree
+        .people
+        .into_iter()
+        .map(|person| (person.person.title, person.generation))
+        .collect();
+    assert_eq!(
+        people,
+        vec![
+            ("Mira".to_owned(), -1),
+            ("Nils".to_owned(), -1),
+            ("Orin".to_owned(), 0),
+            ("Pell".to_owned(), 1)
+        ]
+    );
+    assert_eq!(tree.relationships.len(), 3);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_update_person_relationship(
+    create_entry_payload: EntryCreateSchema,
+    create_calendar_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let quill = create_person(&database, create_entry_payload.clone(), "Quill").await;
+    let rook = create_person(&database, create_entry_payload.clone(), "Rook").await;
+    let sable = create_person(&database, create_entry_payload.clone(), "Sable").await;
+    let tamsin = create_person(&database, create_entry_payload, "Tamsin").await;
+    add_parent(&database, quill, tamsin).await;
+    add_parent(&database, rook, tamsin).await;
+
+    let mut adoption = relationship(sable, tamsin, PersonRelationshipType::Parent);
+    adoption.is_adoptive = true;
+    adoption.start = Some(CalendarDateSchema {
+        calendar_id,
+        year: 412,
+        month: 1,
+        day: 3,
+    });
+    let adoption = genealogy_service::add_relationship(&database, adoption)
+        .await
+        .unwrap();
+    assert!(adoption.is_adoptive);
+    assert_eq!(adoption.start.unwrap().year, 412);
+
+    // a third parent can only be adoptive
+    let response = genealogy_service::update_relationship(
+        &database,
+        PersonRelationshipUpdateSchema {
+            id: adoption.id,
+            is_adoptive: false,
+            start: adoption.start,
+            end: None,
+        },
+    )
+    .await;
+    assert!(response.is_err());
+
+    let relationships = genealogy_service::get_relationships(&database, tamsin)
+        .await
+        .unwrap();
+    assert_eq!(relationships.len(), 3);
+
+    genealogy_service::remove_relationship(&database, adoption.id)
+        .await
+        .unwrap();
+    let relationships = genealogy_service::get_relationships(&database, tamsin)
+        .await
+        .unwrap();
+    assert_eq!(relationships.len(), 2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_adding_invalid_relationship(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let ulla = create_person(&database, create_entry_payload.clone(), "Ulla").await;
+    let vorn = create_person(&database, create_entry_payload.clone(), "Vorn").await;
+    let wren = create_person(&database, create_entry_payload, "Wren").await;
+    let entry =
+        create_generic_entry(&database, Uuid::nil(), "Ledger".to_owned(), "".to_owned()).await;
+    add_parent(&database, ulla, vorn).await;
+    add_parent(&database, vorn, wren).await;
+
+    let invalid_relationships = [
+        // only people can be related
+        relationship(entry.id, ulla, PersonRelationshipType::Parent),
+        // nobody is their own partner
+        relationship(ulla, ulla, PersonRelationshipType::Partner),
+        // the relationship already exists
+        relationship(ulla, vorn, PersonRelationshipType::Parent),
+        // a person can't be their own ancestor
+        relationship(wren, ulla, PersonRelationshipType::Parent),
+    ];
+    for invalid_relationship in invalid_relationships {
+        let response = genealogy_service::add_relationship(&database, invalid_relationship).await;
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use std::fs;
+use std::path::Path;
+
+use hellebore::{
+    model::{errors::Error, text::TextNode},
+    schema::{entry::EntryCreateSchema, import::MarkdownImportSchema},
+    services::{
+        entry_reference_service, entry_service, folder_service, import_service, journal_service,
+    },
+};
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use crate::fixtures::{
+    database,
+    entry::create_entry_payload,
+    project::{TempProjectDir, temp_project_dir},
+};
+
+fn write_file(path: &Path, markdown: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, markdown).unwrap();
+}
+
+fn generate_import_payload(path: &Path) -> MarkdownImportSchema {
+    MarkdownImportSchema {
+        folder_path: path.to_string_lossy().to_string(),
+        folder_id: Uuid::nil(),
+    }
+}
+
+async fn get_entry_id(database: &DatabaseConnection, title: &str) -> Uuid {
+    let entries = entry_service::get_all(database).await.unwrap();
+    entries
+        .iter()
+        .find(|entry| entry.title == title)
+        .map(|entry| entry.id)
+        .unwrap()
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_import_markdown(temp_project_dir: TempProjectDir) {
+    let database = database().await;
+    let vault_path = Path::new(temp_project_dir.path());
+    write_file(
+        &vault_path.join("Salt Treaty.md"),
+        "---\ntags: [treaty]\n---\n# Terms\n\nNo salt tax.\n",
+    );
+    write_file(&vault_path.join("People").join("Isolde.md"), "A queen.\n");
+    write_file(&vault_path.join(".obsidian").join("Hidden.md"), "");
+    write_file(&vault_path.join("notes.txt"), "");
+
+    let response =
+        import_service::import_markdown(&database, generate_import_payload(vault_path)).await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert!(response.errors.is_empty());
+    assert_eq!(response.data.folder_count, 1);
+    assert_eq!(response.data.entry_count, 2);
+
+    let folders = folder_service::get_all(&database).await.unwrap();
+    assert_eq!(folders.len(), 1);
+    assert_eq!(folders[0].name, "People");
+
+    let isolde_id = get_entry_id(&database, "Isolde").await;
+    let isolde = entry_service::get_info(&database, isolde_id).await.unwrap();
+    assert_eq!(isolde.folder_id, folders[0].id);
+
+    // the front matter isn't part of the article
+    let treaty_id = get_entry_id(&database, "Salt Treaty").await;
+    let article = entry_service::get_text(&database, treaty_id).await.unwrap();
+    let mut heading = TextNode::new("heading".to_owned());
+    heading.set_attr("level", serde_json::Value::from(1));
+    assert_eq!(
+        article.data.text,
+        TextNode::new_doc()
+            .with_child(heading.with_child(TextNode::new_text("Terms".to_owned())))
+            .with_child(
+                TextNode::new_paragraph().with_child(TextNode::new_text("No salt tax.".to_owned()))
+            )
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_undo_and_redo_markdown_import(temp_project_dir: TempProjectDir) {
+    let database = database().await;
+    let vault_path = Path::new(temp_project_dir.path());
+    write_file(&vault_path.join("Salt Treaty.md"), "No salt tax.\n");
+    write_file(&vault_path.join("People").join("Isolde.md"), "A queen.\n");
+    write_file(
+        &vault_path.join("People").join("Royals").join("Tristan.md"),
+        "A knight.\n",
+    );
+
+    import_service::import_markdown(&database, generate_import_payload(vault_path))
+        .await
+        .unwrap();
+
+    // the import is undone as a whole
+    let journal = journal_service::get(&database).await.unwrap();
+    assert_eq!(journal.undo.unwrap().label, "Import");
+    journal_service::undo(&database).await.unwrap();
+    assert!(entry_service::get_all(&database).await.unwrap().is_empty());
+    assert!(folder_service::get_all(&database).await.unwrap().is_empty());
+    let journal = journal_service::get(&database).await.unwrap();
+    assert!(journal.undo.is_none());
+
+    journal_service::redo(&database).await.unwrap();
+    assert_eq!(entry_service::get_all(&database).await.unwrap().len(), 3);
+    assert_eq!(folder_service::get_all(&database).await.unwrap().len(), 2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_import_markdown_resolves_wikilinks(temp_project_dir: TempProjectDir) {
+    let database = database().await;
+    let vault_path = Path::new(temp_project_dir.path());
+    write_file(
+        &vault_path.join("Salt Treaty.md"),
+        "Signed by [[isolde|the queen]] and [[Nobody]].\n",
+    );
+    write_file(&vault_path.join("People").join("Isolde.md"), "A queen.\n");
+
+    let response = import_service::import_markdown(&database, generate_import_payload(vault_path))
+        .await
+        .unwrap();
+    assert!(response.errors.is_empty());
+
+    let isolde_id = get_entry_id(&database, "Isolde").await;
+    let treaty_id = get_entry_id(&database, "Salt Treaty").await;
+
+    let backlinks = entry_reference_service::get_backlinks(&database, isolde_id)
+        .await
+        .unwrap();
+    assert_eq!(backlinks.len(), 1);
+    assert_eq!(backlinks[0].info.id, treaty_id);
+
+    // unresolved wikilinks are kept as plain text
+    let article = entry_service::get_text(&database, treaty_id).await.unwrap();
+    assert_eq!(
+        article.data.text.to_plain_text(),
+        "Signed by Isolde and Nobody."
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_import_markdown_reports_title_collisions(
+    temp_project_dir: TempProjectDir,
+    create_entry_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let mut payload = create_entry_payload;
+    payload.title = "Isolde".to_owned();
+    let existing = entry_service::create(&database, payload).await.unwrap();
+
+    let vault_path = Path::new(temp_project_dir.path());
+    write_file(&vault_path.join("Isolde.md"), "A queen.\n");
+    write_file(
+        &vault_path.join("Salt Treaty.md"),
+        "Signed by [[Isolde]].\n",
+    );
+    write_file(
+        &vault_path.join("Treaties").join("Salt Treaty.md"),
+        "A copy.\n",
+    );
+
+    let response =
+        import_service::import_markdown(&database, generate_import_payload(vault_path)).await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert_eq!(response.data.entry_count, 1);
+    assert_eq!(response.errors.len(), 2);
+    for error in response.errors.iter() {
+        assert!(matches!(error, Error::AttributeNotUnique { .. }));
+    }
+
+    // the file nearest to the root of the vault is imported
+    let treaty_id = get_entry_id(&database, "Salt Treaty").await;
+    let treaty = entry_service::get_info(&database, treaty_id).await.unwrap();
+    assert_eq!(treaty.folder_id, Uuid::nil());
+
+    // links to a skipped file point at the existing entry with the same title
+    let backlinks = entry_reference_service::get_backlinks(&database, existing.id)
+        .await
+        .unwrap();
+    assert_eq!(backlinks.len(), 1);
+    assert_eq!(backlinks[0].info.id, treaty_id);
+}
+
+#[cfg(unix)]
+#[rstest]
+#[tokio::test]
+async fn test_import_markdown_skips_symlinks(temp_project_dir: TempProjectDir) {
+    let database = database().await;
+    let vault_path = Path::new(temp_project_dir.path());
+    write_file(&vault_path.join("People").join("Isolde.md"), "A queen.\n");
+    // a link back to the root of the vault would otherwise be walked forever
+    std::os::unix::fs::symlink(vault_path, vault_path.join("People").join("Vault")).unwrap();
+
+    let response =
+        import_service::import_markdown(&database, generate_import_payload(vault_path)).await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert!(response.errors.is_empty());
+    assert_eq!(response.data.folder_count, 1);
+    assert_eq!(response.data.entry_count, 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_importing_markdown_from_nonexistent_directory(
+    temp_project_dir: TempProjectDir,
+) {
+    let database = database().await;
+    let vault_path = Path::new(temp_project_dir.path()).join("missing");
+    let response =
+        import_service::import_markdown(&database, generate_import_payload(&vault_path)).await;
+    assert!(response.is_err());
+}
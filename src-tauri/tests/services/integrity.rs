@@ -0,0 +1,203 @@
+use rstest::*;
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use uuid::Uuid;
+
+use hellebore::{
+    constants::ROOT_FOLDER_ID,
+    database::{entry_manager, folder_manager},
+    model::text::TextNode,
+    schema::{
+        entry::EntryProperties,
+        folder::{FolderCreateSchema, FolderResponseSchema},
+        integrity::IntegrityIssueType,
+    },
+    services::{entry_service, folder_service, integrity_service},
+    types::entity::PERSON,
+};
+
+use crate::{fixtures::database, utils::db::create_generic_entry};
+
+/// Creates two folders and makes each of them the parent of the other.
+async fn create_folder_cycle(
+    database: &DatabaseConnection,
+) -> (FolderResponseSchema, FolderResponseSchema) {
+    let places = folder_service::create(
+        database,
+        FolderCreateSchema {
+            parent_id: ROOT_FOLDER_ID,
+            name: "Places".to_owned(),
+        },
+    )
+    .await
+    .unwrap();
+    let cities = folder_service::create(
+        database,
+        FolderCreateSchema {
+            parent_id: places.id,
+            name: "Cities".to_owned(),
+        },
+    )
+    .await
+    .unwrap();
+    folder_manager::update(database, places.id, Some(cities.id), None, None)
+        .await
+        .unwrap();
+    (places, cities)
+}
+
+fn text_with_mention(id: Uuid, label: &str) -> String {
+    let text = TextNode::new_doc().with_child(
+        TextNode::new_paragraph()
+            .with_child(TextNode::new_text("Friend of ".to_owned()))
+            .with_child(TextNode::new_reference(id, label.to_owned())),
+    );
+    serde_json::to_string(&text).unwrap()
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_check_project() {
+    let database = database().await;
+    let (places, _) = create_folder_cycle(&database).await;
+    let broken = create_generic_entry(
+        &database,
+        ROOT_FOLDER_ID,
+        "Broken".to_owned(),
+        "{not json".to_owned(),
+    )
+    .await;
+    let missing_id = Uuid::new_v4();
+    let ada = create_generic_entry(
+        &database,
+        ROOT_FOLDER_ID,
+        "Ada".to_owned(),
+        text_with_mention(missing_id, "Ghost"),
+    )
+    .await;
+    let ada_text = ada.text.clone();
+    // the person is created without its properties
+    let bob = entry_manager::insert(
+        &database,
+        PERSON,
+        ROOT_FOLDER_ID,
+        "Bob".to_owned(),
+        "".to_owned(),
+    )
+    .await
+    .unwrap();
+
+    let response = integrity_service::check(&database, false).await;
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert!(response.errors.is_empty());
+
+    let issues = response.data.issues;
+    assert_eq!(issues.len(), 4);
+    assert!(issues.iter().all(|issue| !issue.repaired));
+    let find_issue = |issue_type: IntegrityIssueType| {
+        issues
+            .iter()
+            .find(|issue| issue.issue_type == issue_type)
+            .unwrap()
+    };
+    assert_eq!(find_issue(IntegrityIssueType::InvalidText).id, broken.id);
+    let mention_issue = find_issue(IntegrityIssueType::MissingMentionTarget);
+    assert_eq!(mention_issue.id, ada.id);
+    assert_eq!(mention_issue.related_id, Some(missing_id));
+    assert_eq!(find_issue(IntegrityIssueType::MissingProperties).id, bob.id);
+    find_issue(IntegrityIssueType::FolderCycle);
+
+    // nothing is changed unless the issues are repaired
+    let ada = entry_manager::get(&database, ada.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(ada.text, ada_text);
+    let places = folder_service::get(&database, places.id).await.unwrap();
+    assert_ne!(places.parent_id, ROOT_FOLDER_ID);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_repair_project() {
+    let database = database().await;
+    let (places, cities) = create_folder_cycle(&database).await;
+    let ada = create_generic_entry(
+        &database,
+        ROOT_FOLDER_ID,
+        "Ada".to_owned(),
+        text_with_mention(Uuid::new_v4(), "Ghost"),
+    )
+    .await;
+    let bob = entry_manager::insert(
+        &database,
+        PERSON,
+        ROOT_FOLDER_ID,
+        "Bob".to_owned(),
+        "".to_owned(),
+    )
+    .await
+    .unwrap();
+    // sibling folders can only share a name if the database predates the unique index
+    database
+        .execute_unprepared("DROP INDEX index_folder_parent_id_name")
+        .await
+        .unwrap();
+    for _ in 0..2 {
+        folder_manager::insert(&database, ROOT_FOLDER_ID, "Notes")
+            .await
+            .unwrap();
+    }
+
+    let response = integrity_service::check(&database, true).await.unwrap();
+    assert!(response.errors.is_empty());
+    assert_eq!(response.data.issues.len(), 4);
+    assert!(response.data.issues.iter().all(|issue| issue.repaired));
+
+    let ada = entry_service::get_text(&database, ada.id).await.unwrap();
+    assert_eq!(ada.data.text.to_plain_text(), "Friend of Ghost");
+    let properties = entry_service::get_properties(&database, bob.id)
+        .await
+        .unwrap();
+    match properties.properties {
+        EntryProperties::Person(person) => assert_eq!(person.name, "Bob"),
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+
+    // one of the folders of the cycle is moved to the root folder
+    let places = folder_service::get(&database, places.id).await.unwrap();
+    let cities = folder_service::get(&database, cities.id).await.unwrap();
+    assert!(places.parent_id == ROOT_FOLDER_ID || cities.parent_id == ROOT_FOLDER_ID);
+    let mut names: Vec<String> = folder_service::get_all(&database)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|folder| folder.name.starts_with("Notes"))
+        .map(|folder| folder.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["Notes", "Notes (2)"]);
+
+    let response = integrity_service::check(&database, false).await.unwrap();
+    assert!(response.data.issues.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_check_entry_with_unknown_type() {
+    let database = database().await;
+    let entry =
+        create_generic_entry(&database, ROOT_FOLDER_ID, "Odd".to_owned(), "".to_owned()).await;
+    database
+        .execute_unprepared("UPDATE entry SET entity_type = 99 WHERE title = 'Odd'")
+        .await
+        .unwrap();
+
+    let response = integrity_service::check(&database, true).await.unwrap();
+    assert!(response.errors.is_empty());
+    let issues = response.data.issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].issue_type, IntegrityIssueType::UnknownEntityType);
+    assert_eq!(issues[0].id, entry.id);
+    assert!(!issues[0].repaired);
+}
@@ -0,0 +1,201 @@
+use rstest::*;
+use sea_orm::ConnectionTrait;
+
+use hellebore::{
+    constants::ROOT_FOLDER_ID,
+    schema::{
+        entry::{EntryCreateSchema, EntryUpdateSchema},
+        folder::FolderUpdateSchema,
+        word::WordUpsertSchema,
+    },
+    services::{entry_service, folder_service, journal_service, word_service},
+};
+
+use crate::{
+    fixtures::{
+        database, entry::update_entry_payload, folder::folder_create_payload,
+        language::create_language_payload, word::create_word_payload,
+    },
+    utils::db::upsert_word,
+};
+
+#[rstest]
+#[tokio::test]
+async fn test_undo_and_redo_folder_changes() {
+    let database = database().await;
+    let north = folder_service::create(
+        &database,
+        folder_create_payload(ROOT_FOLDER_ID, "North".to_owned()),
+    )
+    .await
+    .unwrap();
+    let folder = folder_service::create(
+        &database,
+        folder_create_payload(ROOT_FOLDER_ID, "Cities".to_owned()),
+    )
+    .await
+    .unwrap();
+    let response = folder_service::update(
+        &database,
+        FolderUpdateSchema {
+            id: folder.id,
+            parent_id: Some(north.id),
+            name: Some("Northern cities".to_owned()),
+            expected_revision: None,
+        },
+    )
+    .await;
+    assert!(response.errors.is_empty());
+
+    let journal = journal_service::get(&database).await.unwrap();
+    assert_eq!(journal.undo.unwrap().label, "Update folder");
+    assert!(journal.redo.is_none());
+
+    let undone = journal_service::undo(&database).await;
+    assert!(undone.is_ok());
+    assert_eq!(undone.unwrap().unwrap().label, "Update folder");
+    let restored = folder_service::get(&database, folder.id).await.unwrap();
+    assert_eq!(restored.name, "Cities");
+    assert_eq!(restored.parent_id, ROOT_FOLDER_ID);
+
+    // undoing the creation of a folder moves it to the trash
+    journal_service::undo(&database).await.unwrap();
+    let folders = folder_service::get_all(&database).await.unwrap();
+    assert_eq!(folders.len(), 1);
+    assert_eq!(folders[0].id, north.id);
+
+    journal_service::redo(&database).await.unwrap();
+    let redone = journal_service::redo(&database).await.unwrap();
+    assert_eq!(redone.unwrap().label, "Update folder");
+    let folder = folder_service::get(&database, folder.id).await.unwrap();
+    assert_eq!(folder.name, "Northern cities");
+    assert_eq!(folder.parent_id, north.id);
+
+    let redone = journal_service::redo(&database).await;
+    assert!(redone.is_ok());
+    assert!(redone.unwrap().is_none());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_undo_and_redo_entry_update(
+    create_language_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = entry_service::create(&database, create_language_payload)
+        .await
+        .unwrap();
+
+    update_entry_payload.id = entry.id;
+    update_entry_payload.title = Some("Old Tongue".to_owned());
+    let response = entry_service::update(&database, update_entry_payload.clone()).await;
+    assert!(response.errors.is_empty());
+
+    journal_service::undo(&database).await.unwrap();
+    let info = entry_service::get_info(&database, entry.id).await.unwrap();
+    assert_eq!(info.title, entry.title);
+
+    journal_service::redo(&database).await.unwrap();
+    let info = entry_service::get_info(&database, entry.id).await.unwrap();
+    assert_eq!(info.title, "Old Tongue");
+
+    // a new change replaces the changes that could be redone
+    journal_service::undo(&database).await.unwrap();
+    update_entry_payload.title = Some("High Tongue".to_owned());
+    entry_service::update(&database, update_entry_payload).await;
+    let journal = journal_service::get(&database).await.unwrap();
+    assert!(journal.redo.is_none());
+
+    // undoing the creation of an entry moves it to the trash
+    journal_service::undo(&database).await.unwrap();
+    journal_service::undo(&database).await.unwrap();
+    assert!(entry_service::get_all(&database).await.unwrap().is_empty());
+    let undone = journal_service::undo(&database).await.unwrap();
+    assert!(undone.is_none());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_undo_and_redo_word_changes(
+    create_language_payload: EntryCreateSchema,
+    mut create_word_payload: WordUpsertSchema,
+) {
+    let database = database().await;
+    let language = entry_service::create(&database, create_language_payload)
+        .await
+        .unwrap();
+    create_word_payload.language_id = Some(language.id);
+    let id = upsert_word(&database, &create_word_payload).await.unwrap();
+
+    word_service::delete(&database, id).await.unwrap();
+    assert!(word_service::get(&database, id).await.is_err());
+
+    // the deleted word comes back with the same ID
+    journal_service::undo(&database).await.unwrap();
+    let word = word_service::get(&database, id).await.unwrap();
+    assert_eq!(Some(word.spelling), create_word_payload.spelling);
+    assert_eq!(Some(word.translations), create_word_payload.translations);
+
+    journal_service::undo(&database).await.unwrap();
+    assert!(word_service::get(&database, id).await.is_err());
+
+    journal_service::redo(&database).await.unwrap();
+    assert!(word_service::get(&database, id).await.is_ok());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_undo_is_rolled_back_when_an_operation_fails(
+    create_language_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+    mut create_word_payload: WordUpsertSchema,
+) {
+    let database = database().await;
+    let language = entry_service::create(&database, create_language_payload.clone())
+        .await
+        .unwrap();
+    let other_language = entry_service::create(
+        &database,
+        EntryCreateSchema {
+            title: "Old Tongue".to_owned(),
+            ..create_language_payload
+        },
+    )
+    .await
+    .unwrap();
+
+    // undoing the update deletes the word before it reverts the title
+    create_word_payload.language_id = Some(other_language.id);
+    update_entry_payload.id = language.id;
+    update_entry_payload.title = Some("High Tongue".to_owned());
+    update_entry_payload.words = Some(vec![create_word_payload]);
+    let response = entry_service::update(&database, update_entry_payload).await;
+    assert!(response.errors.is_empty());
+    let word_id = response.data.words[0].id.unwrap();
+
+    // the title can no longer be reverted once the entry has been deleted for good
+    entry_service::delete(&database, language.id).await.unwrap();
+    let undone = journal_service::undo(&database).await;
+    assert!(undone.is_err());
+
+    // the word is kept, and the change that can't be undone is forgotten
+    assert!(word_service::get(&database, word_id).await.is_ok());
+    let journal = journal_service::get(&database).await.unwrap();
+    assert_eq!(journal.undo.unwrap().label, "Create entry");
+    assert!(journal.redo.is_none());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_entry_not_created_if_change_not_recorded(create_language_payload: EntryCreateSchema) {
+    let database = database().await;
+    database
+        .execute_unprepared("DROP TABLE journal")
+        .await
+        .unwrap();
+
+    let response = entry_service::create(&database, create_language_payload).await;
+    assert!(response.is_err());
+    assert!(entry_service::get_all(&database).await.unwrap().is_empty());
+}
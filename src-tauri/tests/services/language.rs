@@ -4,11 +4,15 @@ use uuid::Uuid;
 use hellebore::{
     database::language_manager,
     schema::{
-        entry::{EntryCreateSchema, EntryUpdateSchema},
+        entry::{EntryCreateSchema, EntryProperties, EntryUpdateSchema},
+        language::{GrammarSchema, LanguageSchema},
         word::{WordResponseSchema, WordUpsertSchema},
     },
     services::{entry_service, word_service},
-    types::entity::LANGUAGE,
+    types::{
+        entity::LANGUAGE,
+        grammar::{GrammaticalCase, GrammaticalNumber, WordType},
+    },
 };
 
 use crate::{
@@ -165,3 +169,45 @@ async fn test_delete_language(create_language_payload: EntryCreateSchema) {
     let language = language.unwrap();
     assert!(language.is_none());
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_update_language_grammar(
+    create_language_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let entry = entry_service::create(&database, create_language_payload)
+        .await
+        .unwrap();
+
+    let properties = LanguageSchema {
+        grammar: GrammarSchema {
+            word_types: Some(vec![WordType::Noun, WordType::Verb]),
+            cases: Some(vec![]),
+            numbers: Some(vec![GrammaticalNumber::Singular, GrammaticalNumber::Plural]),
+            ..Default::default()
+        },
+    };
+    update_entry_payload.id = entry.id;
+    update_entry_payload.properties = Some(EntryProperties::Language(properties.clone()));
+    let response = entry_service::update(&database, update_entry_payload).await;
+    assert!(response.errors.is_empty());
+
+    let response = entry_service::get_properties(&database, entry.id).await;
+
+    assert!(response.is_ok());
+    match response.unwrap().properties {
+        EntryProperties::Language(language) => {
+            assert_eq!(language, properties);
+            assert!(
+                !language
+                    .grammar
+                    .cases
+                    .unwrap()
+                    .contains(&GrammaticalCase::Nominative)
+            );
+        }
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+}
@@ -0,0 +1,273 @@
+use std::{env, fs};
+
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        entry::{EntryCreateSchema, EntryProperties},
+        map::{MapPinCreateSchema, MapPinMoveSchema, MapPointSchema, MapSchema},
+    },
+    services::{entry_service, map_service, trash_service},
+    types::{entity::MAP, map::PinShape},
+};
+
+use crate::{
+    fixtures::{database, entry::create_entry_payload},
+    utils::db::create_generic_entry,
+};
+
+async fn create_map(
+    database: &DatabaseConnection,
+    mut payload: EntryCreateSchema,
+    title: &str,
+) -> Uuid {
+    payload.entity_type = MAP;
+    payload.title = title.to_owned();
+    payload.properties = EntryProperties::Map(MapSchema {
+        width: 1000.0,
+        height: 800.0,
+        image_path: None,
+    });
+    entry_service::create(database, payload).await.unwrap().id
+}
+
+fn point_pin(map_id: Uuid, target_id: Uuid, x: f64, y: f64) -> MapPinCreateSchema {
+    MapPinCreateSchema {
+        map_id,
+        target_id,
+        shape: PinShape::Point,
+        points: vec![MapPointSchema { x, y }],
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_map(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let id = create_map(&database, create_entry_payload, "Eastmarch").await;
+
+    let response = entry_service::get_properties(&database, id).await;
+
+    assert!(response.is_ok());
+    match response.unwrap().properties {
+        EntryProperties::Map(map) => {
+            assert_eq!(map.width, 1000.0);
+            assert_eq!(map.height, 800.0);
+            assert!(map.image_path.is_none());
+        }
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_add_move_and_remove_map_pins(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let map_id = create_map(&database, create_entry_payload, "Eastmarch").await;
+    let city = create_generic_entry(
+        &database,
+        Uuid::nil(),
+        "Cinderford".to_owned(),
+        "".to_owned(),
+    )
+    .await;
+
+    let pin = map_service::add_pin(&database, point_pin(map_id, city.id, 10.0, 20.0)).await;
+    assert!(pin.is_ok());
+    let pin = pin.unwrap();
+
+    let moved_pin = map_service::move_pin(
+        &database,
+        MapPinMoveSchema {
+            id: pin.id,
+            points: vec![MapPointSchema { x: 30.0, y: 40.0 }],
+        },
+    )
+    .await;
+    assert!(moved_pin.is_ok());
+
+    let pins = map_service::get_pins(&database, map_id).await.unwrap();
+    assert_eq!(pins.len(), 1);
+    assert_eq!(pins[0].points, vec![MapPointSchema { x: 30.0, y: 40.0 }]);
+    assert!(!pins[0].is_dangling);
+
+    assert!(map_service::remove_pin(&database, pin.id).await.is_ok());
+    assert!(
+        map_service::get_pins(&database, map_id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_adding_invalid_map_pin(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let map_id = create_map(&database, create_entry_payload, "Eastmarch").await;
+    let city = create_generic_entry(
+        &database,
+        Uuid::nil(),
+        "Cinderford".to_owned(),
+        "".to_owned(),
+    )
+    .await;
+
+    // outside of the map
+    let response = map_service::add_pin(&database, point_pin(map_id, city.id, 1200.0, 20.0)).await;
+    assert!(response.is_err());
+
+    // nonexistent target
+    let response =
+        map_service::add_pin(&database, point_pin(map_id, Uuid::new_v4(), 1.0, 2.0)).await;
+    assert!(response.is_err());
+
+    // polygons need at least three points
+    let mut pin = point_pin(map_id, city.id, 1.0, 2.0);
+    pin.shape = PinShape::Polygon;
+    pin.points.push(MapPointSchema { x: 5.0, y: 5.0 });
+    let response = map_service::add_pin(&database, pin).await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_maps_of_entry(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let eastmarch = create_map(&database, create_entry_payload.clone(), "Eastmarch").await;
+    let reach = create_map(&database, create_entry_payload.clone(), "Ashen Reach").await;
+    create_map(&database, create_entry_payload, "Westmarch").await;
+    let city = create_generic_entry(
+        &database,
+        Uuid::nil(),
+        "Cinderford".to_owned(),
+        "".to_owned(),
+    )
+    .await;
+    let polygon = MapPinCreateSchema {
+        map_id: reach,
+        target_id: city.id,
+        shape: PinShape::Polygon,
+        points: vec![
+            MapPointSchema { x: 1.0, y: 1.0 },
+            MapPointSchema { x: 9.0, y: 1.0 },
+            MapPointSchema { x: 5.0, y: 8.0 },
+        ],
+    };
+    map_service::add_pin(&database, point_pin(eastmarch, city.id, 10.0, 20.0))
+        .await
+        .unwrap();
+    map_service::add_pin(&database, polygon).await.unwrap();
+
+    let maps = map_service::get_maps_of_entry(&database, city.id).await;
+
+    assert!(maps.is_ok());
+    let maps = maps.unwrap();
+    let titles: Vec<&str> = maps.iter().map(|map| map.map.title.as_str()).collect();
+    assert_eq!(titles, vec!["Ashen Reach", "Eastmarch"]);
+    assert_eq!(maps[0].pins[0].shape, PinShape::Polygon);
+    assert_eq!(maps[1].pins[0].shape, PinShape::Point);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_dangling_map_pins(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let map_id = create_map(&database, create_entry_payload, "Eastmarch").await;
+    let city = create_generic_entry(
+        &database,
+        Uuid::nil(),
+        "Cinderford".to_owned(),
+        "".to_owned(),
+    )
+    .await;
+    let village =
+        create_generic_entry(&database, Uuid::nil(), "Ashfall".to_owned(), "".to_owned()).await;
+    map_service::add_pin(&database, point_pin(map_id, city.id, 10.0, 20.0))
+        .await
+        .unwrap();
+    map_service::add_pin(&database, point_pin(map_id, village.id, 30.0, 40.0))
+        .await
+        .unwrap();
+
+    assert!(
+        map_service::get_dangling_pins(&database)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    entry_service::delete(&database, city.id).await.unwrap();
+
+    let dangling_pins = map_service::get_dangling_pins(&database).await.unwrap();
+    assert_eq!(dangling_pins.len(), 1);
+    assert_eq!(dangling_pins[0].target_id, city.id);
+    let pins = map_service::get_pins(&database, map_id).await.unwrap();
+    assert_eq!(pins.iter().filter(|pin| pin.is_dangling).count(), 1);
+
+    // pins dangle while their targets are in the trash
+    trash_service::trash_entry(&database, village.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        map_service::get_dangling_pins(&database)
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+    let pins = map_service::get_pins(&database, map_id).await.unwrap();
+    assert!(pins.iter().all(|pin| pin.is_dangling));
+
+    trash_service::restore_entry(&database, village.id)
+        .await
+        .unwrap();
+    let dangling_pins = map_service::get_dangling_pins(&database).await.unwrap();
+    assert_eq!(dangling_pins.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_set_map_image(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let map_id = create_map(&database, create_entry_payload, "Eastmarch").await;
+    let folder_path = env::temp_dir().join(format!("hellebore-map-tests-{}", Uuid::new_v4()));
+    fs::create_dir_all(&folder_path).unwrap();
+    let png_path = folder_path.join("eastmarch.PNG");
+    let jpg_path = folder_path.join("eastmarch.jpg");
+    fs::write(&png_path, b"png").unwrap();
+    fs::write(&jpg_path, b"jpg").unwrap();
+    let folder_path_str = folder_path.to_str().unwrap();
+
+    let map = map_service::set_image(
+        &database,
+        folder_path_str,
+        map_id,
+        png_path.to_str().unwrap(),
+    )
+    .await;
+    assert!(map.is_ok());
+    let png_image_path = map.unwrap().image_path.unwrap();
+    assert_eq!(png_image_path, format!("maps/{map_id}.png"));
+    assert_eq!(fs::read(folder_path.join(&png_image_path)).unwrap(), b"png");
+
+    // the previous image is replaced
+    let map = map_service::set_image(
+        &database,
+        folder_path_str,
+        map_id,
+        jpg_path.to_str().unwrap(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(map.image_path, Some(format!("maps/{map_id}.jpg")));
+    assert!(!folder_path.join(&png_image_path).exists());
+
+    let response =
+        map_service::set_image(&database, folder_path_str, map_id, "eastmarch.txt").await;
+    assert!(response.is_err());
+
+    fs::remove_dir_all(&folder_path).unwrap();
+}
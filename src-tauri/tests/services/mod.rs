@@ -1,10 +1,26 @@
+mod backup;
 mod config;
 
 mod project;
 
+mod asset;
+mod custom_property;
 mod entry;
+mod export;
 mod folder;
+mod import;
+mod integrity;
+mod journal;
 
+mod calendar;
+
+mod event;
+mod genealogy;
 mod language;
+mod map;
+mod organization;
 mod person;
+mod place;
+mod tag;
+mod trash;
 mod word;
@@ -0,0 +1,269 @@
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        calendar::CalendarDateSchema,
+        entry::{EntryCreateSchema, EntryProperties, EntryUpdateSchema},
+        organization::{MembershipCreateSchema, MembershipUpdateSchema, OrganizationSchema},
+    },
+    services::{entry_service, organization_service, trash_service},
+    types::entity::ORGANIZATION,
+};
+
+use crate::{
+    fixtures::{
+        calendar::create_calendar_payload,
+        database,
+        entry::{create_entry_payload, update_entry_payload},
+    },
+    utils::db::{create_generic_entry, create_person},
+};
+
+async fn create_organization(
+    database: &DatabaseConnection,
+    mut payload: EntryCreateSchema,
+    title: &str,
+    parent_id: Option<Uuid>,
+) -> Uuid {
+    payload.entity_type = ORGANIZATION;
+    payload.title = title.to_owned();
+    payload.properties = EntryProperties::Organization(OrganizationSchema { parent_id });
+    entry_service::create(database, payload).await.unwrap().id
+}
+
+fn membership(
+    organization_id: Uuid,
+    person_id: Uuid,
+    role: &str,
+    rank: Option<i32>,
+) -> MembershipCreateSchema {
+    MembershipCreateSchema {
+        organization_id,
+        person_id,
+        role: role.to_owned(),
+        rank,
+        start: None,
+        end: None,
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_sub_organizations(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let guild = create_organization(&database, create_entry_payload.clone(), "Guild", None).await;
+    let chapter = create_organization(
+        &database,
+        create_entry_payload.clone(),
+        "Western Chapter",
+        Some(guild),
+    )
+    .await;
+    create_organization(
+        &database,
+        create_entry_payload,
+        "Eastern Chapter",
+        Some(guild),
+    )
+    .await;
+
+    let response = entry_service::get_properties(&database, chapter).await;
+    assert!(response.is_ok());
+    match response.unwrap().properties {
+        EntryProperties::Organization(organization) => {
+            assert_eq!(organization.parent_id, Some(guild))
+        }
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+
+    let children = organization_service::get_sub_organizations(&database, guild).await;
+    assert!(children.is_ok());
+    let titles: Vec<String> = children
+        .unwrap()
+        .into_iter()
+        .map(|child| child.title)
+        .collect();
+    assert_eq!(titles, vec!["Eastern Chapter", "Western Chapter"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_making_organization_part_of_its_sub_organization(
+    create_entry_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let guild = create_organization(&database, create_entry_payload.clone(), "Guild", None).await;
+    let chapter =
+        create_organization(&database, create_entry_payload, "Chapter", Some(guild)).await;
+
+    update_entry_payload.id = guild;
+    update_entry_payload.properties = Some(EntryProperties::Organization(OrganizationSchema {
+        parent_id: Some(chapter),
+    }));
+    let response = entry_service::update(&database, update_entry_payload).await;
+
+    assert!(!response.errors.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_organization_members(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let guild = create_organization(&database, create_entry_payload.clone(), "Guild", None).await;
+    let ada = create_person(&database, create_entry_payload.clone(), "Ada").await;
+    let brom = create_person(&database, create_entry_payload.clone(), "Brom").await;
+    let cyra = create_person(&database, create_entry_payload, "Cyra").await;
+
+    for (person_id, role, rank) in [
+        (ada, "Apprentice", None),
+        (brom, "Treasurer", Some(2)),
+        (cyra, "Guildmaster", Some(1)),
+    ] {
+        let response = organization_service::add_membership(
+            &database,
+            membership(guild, person_id, role, rank),
+        )
+        .await;
+        assert!(response.is_ok());
+    }
+
+    let members = organization_service::get_members(&database, guild).await;
+
+    assert!(members.is_ok());
+    let roles: Vec<String> = members
+        .unwrap()
+        .into_iter()
+        .map(|member| member.role)
+        .collect();
+    assert_eq!(roles, vec!["Guildmaster", "Treasurer", "Apprentice"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_person_affiliations(
+    create_entry_payload: EntryCreateSchema,
+    create_calendar_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let guild = create_organization(&database, create_entry_payload.clone(), "Guild", None).await;
+    let order = create_organization(&database, create_entry_payload.clone(), "Order", None).await;
+    let ada = create_person(&database, create_entry_payload, "Ada").await;
+
+    let mut order_membership = membership(order, ada, "Knight", None);
+    order_membership.start = Some(CalendarDateSchema {
+        calendar_id,
+        year: 412,
+        month: 1,
+        day: 3,
+    });
+    let order_membership = organization_service::add_membership(&database, order_membership)
+        .await
+        .unwrap();
+    organization_service::add_membership(&database, membership(guild, ada, "Apprentice", None))
+        .await
+        .unwrap();
+
+    let response = organization_service::update_membership(
+        &database,
+        MembershipUpdateSchema {
+            id: order_membership.id,
+            role: "Knight-Captain".to_owned(),
+            rank: Some(1),
+            start: order_membership.start,
+            end: None,
+        },
+    )
+    .await;
+    assert!(response.is_ok());
+
+    let affiliations = organization_service::get_affiliations(&database, ada)
+        .await
+        .unwrap();
+    assert_eq!(affiliations.len(), 2);
+    assert_eq!(affiliations[0].organization.title, "Guild");
+    assert_eq!(affiliations[1].organization.title, "Order");
+    assert_eq!(affiliations[1].role, "Knight-Captain");
+    assert_eq!(affiliations[1].start, order_membership.start);
+
+    // memberships are removed along with their organization
+    entry_service::delete(&database, guild).await.unwrap();
+    let affiliations = organization_service::get_affiliations(&database, ada)
+        .await
+        .unwrap();
+    assert_eq!(affiliations.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_memberships_without_trashed_entries(create_entry_payload: EntryCreateSchema) {
+    let database = database().await;
+    let guild = create_organization(&database, create_entry_payload.clone(), "Guild", None).await;
+    let order = create_organization(&database, create_entry_payload.clone(), "Order", None).await;
+    let ada = create_person(&database, create_entry_payload.clone(), "Ada").await;
+    let brom = create_person(&database, create_entry_payload, "Brom").await;
+    for (organization_id, person_id) in [(guild, ada), (guild, brom), (order, ada)] {
+        organization_service::add_membership(
+            &database,
+            membership(organization_id, person_id, "Member", None),
+        )
+        .await
+        .unwrap();
+    }
+
+    trash_service::trash_entry(&database, brom).await.unwrap();
+    trash_service::trash_entry(&database, order).await.unwrap();
+
+    let members = organization_service::get_members(&database, guild)
+        .await
+        .unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].person.id, ada);
+    let affiliations = organization_service::get_affiliations(&database, ada)
+        .await
+        .unwrap();
+    assert_eq!(affiliations.len(), 1);
+    assert_eq!(affiliations[0].organization.id, guild);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_adding_invalid_membership(
+    create_entry_payload: EntryCreateSchema,
+    create_calendar_payload: EntryCreateSchema,
+) {
+    let database = database().await;
+    let calendar_id = entry_service::create(&database, create_calendar_payload)
+        .await
+        .unwrap()
+        .id;
+    let guild = create_organization(&database, create_entry_payload.clone(), "Guild", None).await;
+    let ada = create_person(&database, create_entry_payload, "Ada").await;
+    let entry =
+        create_generic_entry(&database, Uuid::nil(), "Ledger".to_owned(), "".to_owned()).await;
+
+    // only people can be members
+    let response =
+        organization_service::add_membership(&database, membership(guild, entry.id, "Clerk", None))
+            .await;
+    assert!(response.is_err());
+
+    // a membership can't end before it starts
+    let date = |year| CalendarDateSchema {
+        calendar_id,
+        year,
+        month: 1,
+        day: 3,
+    };
+    let mut invalid_membership = membership(guild, ada, "Apprentice", None);
+    invalid_membership.start = Some(date(412));
+    invalid_membership.end = Some(date(410));
+    let response = organization_service::add_membership(&database, invalid_membership).await;
+    assert!(response.is_err());
+}
@@ -0,0 +1,255 @@
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        entry::{EntryCreateSchema, EntryInfoResponseSchema, EntryProperties, EntryUpdateSchema},
+        place::PlaceSchema,
+    },
+    services::{entry_service, place_service, trash_service},
+    types::place::PlaceType,
+};
+
+use crate::{
+    fixtures::{database, entry::update_entry_payload, place::create_place_payload},
+    utils::db::create_generic_entry,
+};
+
+async fn create_place(
+    database: &DatabaseConnection,
+    mut payload: EntryCreateSchema,
+    title: &str,
+    place_type: PlaceType,
+    parent_id: Option<Uuid>,
+) -> Uuid {
+    payload.title = title.to_owned();
+    payload.properties = EntryProperties::Place(PlaceSchema {
+        place_type,
+        parent_id,
+    });
+    entry_service::create(database, payload).await.unwrap().id
+}
+
+fn titles(entries: Vec<EntryInfoResponseSchema>) -> Vec<String> {
+    entries.into_iter().map(|entry| entry.title).collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_place(create_place_payload: EntryCreateSchema) {
+    let database = database().await;
+    let reach = create_place(
+        &database,
+        create_place_payload.clone(),
+        "Ashen Reach",
+        PlaceType::Region,
+        None,
+    )
+    .await;
+    let properties = PlaceSchema {
+        place_type: PlaceType::City,
+        parent_id: Some(reach),
+    };
+    let mut payload = create_place_payload;
+    payload.title = "Cinderford".to_owned();
+    payload.properties = EntryProperties::Place(properties.clone());
+    let id = entry_service::create(&database, payload).await.unwrap().id;
+
+    let response = entry_service::get_properties(&database, id).await;
+
+    assert!(response.is_ok());
+    match response.unwrap().properties {
+        EntryProperties::Place(place) => assert_eq!(place, properties),
+        properties => panic!("Unexpected properties: {properties:?}"),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_place_descendants_and_ancestors(create_place_payload: EntryCreateSchema) {
+    let database = database().await;
+    let payload = create_place_payload;
+    let continent = create_place(
+        &database,
+        payload.clone(),
+        "Eastmarch",
+        PlaceType::Continent,
+        None,
+    )
+    .await;
+    let reach = create_place(
+        &database,
+        payload.clone(),
+        "Ashen Reach",
+        PlaceType::Region,
+        Some(continent),
+    )
+    .await;
+    let city = create_place(
+        &database,
+        payload.clone(),
+        "Cinderford",
+        PlaceType::City,
+        Some(reach),
+    )
+    .await;
+    create_place(
+        &database,
+        payload.clone(),
+        "Ember Keep",
+        PlaceType::Building,
+        Some(city),
+    )
+    .await;
+    create_place(
+        &database,
+        payload.clone(),
+        "Ashfall",
+        PlaceType::Village,
+        Some(reach),
+    )
+    .await;
+    create_place(&database, payload, "Westmarch", PlaceType::Continent, None).await;
+
+    let descendants = place_service::get_descendants(&database, reach).await;
+    assert!(descendants.is_ok());
+    assert_eq!(
+        titles(descendants.unwrap()),
+        vec!["Ashfall", "Cinderford", "Ember Keep"]
+    );
+
+    let ancestors = place_service::get_ancestors(&database, city).await;
+    assert!(ancestors.is_ok());
+    assert_eq!(titles(ancestors.unwrap()), vec!["Ashen Reach", "Eastmarch"]);
+
+    let ancestors = place_service::get_ancestors(&database, continent).await;
+    assert!(ancestors.unwrap().is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_get_place_hierarchy_without_trashed_places(
+    create_place_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let continent = create_place(
+        &database,
+        create_place_payload.clone(),
+        "Eastmarch",
+        PlaceType::Continent,
+        None,
+    )
+    .await;
+    let reach = create_place(
+        &database,
+        create_place_payload.clone(),
+        "Ashen Reach",
+        PlaceType::Region,
+        Some(continent),
+    )
+    .await;
+    let city = create_place(
+        &database,
+        create_place_payload,
+        "Cinderford",
+        PlaceType::City,
+        Some(reach),
+    )
+    .await;
+
+    trash_service::trash_entry(&database, reach).await.unwrap();
+
+    let descendants = place_service::get_descendants(&database, continent).await;
+    assert_eq!(titles(descendants.unwrap()), vec!["Cinderford"]);
+    let ancestors = place_service::get_ancestors(&database, city).await;
+    assert_eq!(titles(ancestors.unwrap()), vec!["Eastmarch"]);
+
+    // places in the trash still count as descendants, so that restoring them can't close a cycle
+    update_entry_payload.id = continent;
+    update_entry_payload.properties = Some(EntryProperties::Place(PlaceSchema {
+        place_type: PlaceType::Continent,
+        parent_id: Some(city),
+    }));
+    let response = entry_service::update(&database, update_entry_payload).await;
+    assert!(!response.errors.is_empty());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_creating_place_in_non_place(mut create_place_payload: EntryCreateSchema) {
+    let database = database().await;
+    let entry = create_generic_entry(&database, Uuid::nil(), "Ada".to_owned(), "".to_owned()).await;
+    create_place_payload.properties = EntryProperties::Place(PlaceSchema {
+        place_type: PlaceType::City,
+        parent_id: Some(entry.id),
+    });
+
+    let response = entry_service::create(&database, create_place_payload).await;
+
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_locating_place_in_its_descendant(
+    create_place_payload: EntryCreateSchema,
+    mut update_entry_payload: EntryUpdateSchema,
+) {
+    let database = database().await;
+    let reach = create_place(
+        &database,
+        create_place_payload.clone(),
+        "Ashen Reach",
+        PlaceType::Region,
+        None,
+    )
+    .await;
+    let city = create_place(
+        &database,
+        create_place_payload,
+        "Cinderford",
+        PlaceType::City,
+        Some(reach),
+    )
+    .await;
+
+    update_entry_payload.id = reach;
+    update_entry_payload.properties = Some(EntryProperties::Place(PlaceSchema {
+        place_type: PlaceType::Region,
+        parent_id: Some(city),
+    }));
+    let response = entry_service::update(&database, update_entry_payload).await;
+
+    assert!(!response.errors.is_empty());
+    let properties = place_service::get(&database, reach).await.unwrap();
+    assert!(properties.parent_id.is_none());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_deleting_parent_place_orphans_its_children(create_place_payload: EntryCreateSchema) {
+    let database = database().await;
+    let reach = create_place(
+        &database,
+        create_place_payload.clone(),
+        "Ashen Reach",
+        PlaceType::Region,
+        None,
+    )
+    .await;
+    let city = create_place(
+        &database,
+        create_place_payload,
+        "Cinderford",
+        PlaceType::City,
+        Some(reach),
+    )
+    .await;
+
+    entry_service::delete(&database, reach).await.unwrap();
+
+    let properties = place_service::get(&database, city).await.unwrap();
+    assert!(properties.parent_id.is_none());
+}
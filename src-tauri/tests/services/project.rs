@@ -5,15 +5,25 @@ use rstest::*;
 use uuid::Uuid;
 
 use hellebore::{
-    constants::PROJECT_CONFIG_FILE_NAME,
+    constants::{DEFAULT_DB_FILE_NAME, PROJECT_CONFIG_FILE_NAME, ROOT_FOLDER_ID},
+    database::entry_manager,
     model::{config::AppConfig, errors::Error, state::State},
-    schema::project::ProjectConfigFileSchema,
-    services::project_service,
-    types::entity::PROJECT,
+    schema::{
+        backup::BackupConfigSchema, folder::FolderCreateSchema, project::ProjectConfigFileSchema,
+    },
+    services::{folder_service, project_service, trash_service},
+    types::entity::{ENTRY, EntityType, PERSON, PROJECT},
 };
 
-use crate::fixtures::project::{
-    TempProjectDir, missing_project_id, project_name, temp_project_dir, updated_project_name,
+use crate::{
+    fixtures::{
+        database,
+        project::{
+            TempProjectDir, missing_project_id, project_name, temp_project_dir,
+            updated_project_name,
+        },
+    },
+    utils::db::create_generic_entry,
 };
 
 fn create_state() -> State {
@@ -66,6 +76,23 @@ async fn test_error_on_loading_project_without_folder_path() {
     }
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_open_project_without_config_file(temp_project_dir: TempProjectDir) {
+    let response = project_service::open(temp_project_dir.path()).await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert!(response.errors.is_empty());
+    let project = response.data;
+    assert_eq!(project.name, "New project");
+    assert_eq!(project.backup_config, BackupConfigSchema::default());
+    assert!(!project.database.in_memory);
+
+    let db_path = format!("{}/{}", temp_project_dir.path(), DEFAULT_DB_FILE_NAME);
+    assert!(Path::new(&db_path).exists());
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_update_project(
@@ -201,3 +228,52 @@ async fn test_error_on_getting_database_for_missing_project(missing_project_id:
         err => panic!("Unexpected error: {err:?}"),
     }
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_get_project_stats() {
+    let database = database().await;
+    folder_service::create(
+        &database,
+        FolderCreateSchema {
+            parent_id: ROOT_FOLDER_ID,
+            name: "Characters".to_owned(),
+        },
+    )
+    .await
+    .unwrap();
+    create_generic_entry(&database, ROOT_FOLDER_ID, "Ada".to_owned(), "".to_owned()).await;
+    let note =
+        create_generic_entry(&database, ROOT_FOLDER_ID, "Note".to_owned(), "".to_owned()).await;
+    entry_manager::insert(
+        &database,
+        PERSON,
+        ROOT_FOLDER_ID,
+        "Bob".to_owned(),
+        "".to_owned(),
+    )
+    .await
+    .unwrap();
+    trash_service::trash_entry(&database, note.id)
+        .await
+        .unwrap();
+
+    let response = project_service::get_stats(&database).await;
+
+    assert!(response.is_ok());
+    let stats = response.unwrap();
+    assert_eq!(stats.entry_count, 2);
+    assert_eq!(stats.trashed_entry_count, 1);
+    assert_eq!(stats.folder_count, 1);
+    assert_eq!(stats.trashed_folder_count, 0);
+    assert_eq!(stats.word_count, 0);
+    let count_of_type = |entity_type: EntityType| {
+        stats
+            .entry_type_counts
+            .iter()
+            .find(|count| count.entity_type == entity_type)
+            .map(|count| count.count)
+    };
+    assert_eq!(count_of_type(ENTRY), Some(1));
+    assert_eq!(count_of_type(PERSON), Some(1));
+}
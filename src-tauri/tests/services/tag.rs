@@ -0,0 +1,225 @@
+use rstest::*;
+use sea_orm::DatabaseConnection;
+use uuid::Uuid;
+
+use hellebore::{
+    schema::{
+        entry::EntrySearchSchema,
+        folder::FolderCreateSchema,
+        tag::{TagAssignmentSchema, TagCreateSchema, TagMergeSchema, TagUpdateSchema},
+    },
+    services::{entry_service, folder_service, tag_service},
+};
+
+use crate::{
+    fixtures::{database, folder::folder_create_payload},
+    utils::db::create_generic_entry,
+};
+
+async fn create_tag(database: &DatabaseConnection, name: &str) -> Uuid {
+    tag_service::create(
+        database,
+        TagCreateSchema {
+            name: name.to_owned(),
+            color: None,
+        },
+    )
+    .await
+    .unwrap()
+    .id
+}
+
+async fn tag_entries(database: &DatabaseConnection, tag_id: Uuid, entry_ids: Vec<Uuid>) {
+    tag_service::tag(
+        database,
+        TagAssignmentSchema {
+            tag_ids: vec![tag_id],
+            entry_ids,
+            folder_ids: Vec::new(),
+        },
+    )
+    .await
+    .unwrap();
+}
+
+async fn search(database: &DatabaseConnection, tags: &str) -> Vec<String> {
+    let query = EntrySearchSchema {
+        keyword: "".to_owned(),
+        tags: Some(tags.to_owned()),
+        before: None,
+        after: None,
+        limit: 10,
+    };
+    entry_service::search(database, query)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|entry| entry.title)
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_rename_tag_with_nested_tags() {
+    let database = database().await;
+    let faction = create_tag(&database, "faction").await;
+    create_tag(&database, "faction / north").await;
+    create_tag(&database, "faction/north/coast").await;
+    create_tag(&database, "factional").await;
+
+    let response = tag_service::update(
+        &database,
+        TagUpdateSchema {
+            id: faction,
+            name: "allegiance".to_owned(),
+            color: Some("#a0c4ff".to_owned()),
+        },
+    )
+    .await;
+    assert!(response.is_ok());
+    assert_eq!(response.unwrap().color, Some("#a0c4ff".to_owned()));
+
+    let names: Vec<String> = tag_service::get_all(&database)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "allegiance",
+            "allegiance/north",
+            "allegiance/north/coast",
+            "factional"
+        ]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_merge_tags() {
+    let database = database().await;
+    let villain = create_tag(&database, "villain").await;
+    let antagonist = create_tag(&database, "antagonist").await;
+    let brute =
+        create_generic_entry(&database, Uuid::nil(), "Brute".to_owned(), "".to_owned()).await;
+    let schemer =
+        create_generic_entry(&database, Uuid::nil(), "Schemer".to_owned(), "".to_owned()).await;
+    tag_entries(&database, villain, vec![brute.id, schemer.id]).await;
+    tag_entries(&database, antagonist, vec![schemer.id]).await;
+
+    let response = tag_service::merge(
+        &database,
+        TagMergeSchema {
+            source_ids: vec![villain],
+            target_id: antagonist,
+        },
+    )
+    .await;
+    assert!(response.is_ok());
+
+    let tags = tag_service::get_all(&database).await.unwrap();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(
+        search(&database, "antagonist").await,
+        vec!["Brute", "Schemer"]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_search_entries_by_tag_expression(folder_create_payload: FolderCreateSchema) {
+    let database = database().await;
+    let north = create_tag(&database, "faction/north").await;
+    let south = create_tag(&database, "faction/south").await;
+    let deceased = create_tag(&database, "deceased").await;
+
+    let folder = folder_service::create(&database, folder_create_payload)
+        .await
+        .unwrap();
+    let arden =
+        create_generic_entry(&database, Uuid::nil(), "Arden".to_owned(), "".to_owned()).await;
+    let bryn = create_generic_entry(&database, Uuid::nil(), "Bryn".to_owned(), "".to_owned()).await;
+    create_generic_entry(&database, folder.id, "Corin".to_owned(), "".to_owned()).await;
+    create_generic_entry(&database, Uuid::nil(), "Dell".to_owned(), "".to_owned()).await;
+
+    tag_entries(&database, north, vec![arden.id, bryn.id]).await;
+    tag_entries(&database, deceased, vec![bryn.id]).await;
+    // entries have the tags of their folders
+    tag_service::tag(
+        &database,
+        TagAssignmentSchema {
+            tag_ids: vec![south],
+            entry_ids: Vec::new(),
+            folder_ids: vec![folder.id],
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        search(&database, "faction/north").await,
+        vec!["Arden", "Bryn"]
+    );
+    assert_eq!(
+        search(&database, "faction").await,
+        vec!["Arden", "Bryn", "Corin"]
+    );
+    assert_eq!(
+        search(&database, "faction AND NOT deceased").await,
+        vec!["Arden", "Corin"]
+    );
+    assert_eq!(
+        search(&database, "faction/south OR (deceased \"faction/north\")").await,
+        vec!["Bryn", "Corin"]
+    );
+    assert_eq!(search(&database, "NOT faction").await, vec!["Dell"]);
+
+    tag_service::untag(
+        &database,
+        TagAssignmentSchema {
+            tag_ids: vec![north],
+            entry_ids: vec![arden.id],
+            folder_ids: Vec::new(),
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(search(&database, "faction/north").await, vec!["Bryn"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_invalid_tags() {
+    let database = database().await;
+    create_tag(&database, "faction").await;
+
+    for (name, color) in [
+        ("faction", None),
+        ("faction//north", None),
+        ("", None),
+        ("north", Some("blue")),
+    ] {
+        let response = tag_service::create(
+            &database,
+            TagCreateSchema {
+                name: name.to_owned(),
+                color: color.map(str::to_owned),
+            },
+        )
+        .await;
+        assert!(response.is_err());
+    }
+
+    for tags in ["faction AND", "(faction", "\"faction", "OR faction"] {
+        let query = EntrySearchSchema {
+            keyword: "".to_owned(),
+            tags: Some(tags.to_owned()),
+            before: None,
+            after: None,
+            limit: 10,
+        };
+        assert!(entry_service::search(&database, query).await.is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use rstest::*;
+use uuid::Uuid;
+
+use hellebore::{
+    constants::ROOT_FOLDER_ID,
+    schema::entry::EntrySearchSchema,
+    services::{entry_service, folder_service, trash_service},
+};
+
+use crate::{
+    fixtures::{database, folder::folder_create_payload},
+    utils::db::{create_generic_entry, get_entry},
+};
+
+fn search_payload(keyword: &str) -> EntrySearchSchema {
+    EntrySearchSchema {
+        keyword: keyword.to_owned(),
+        tags: None,
+        before: None,
+        after: None,
+        limit: 10,
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_trash_and_restore_entry() {
+    let database = database().await;
+    let folder = folder_service::create(
+        &database,
+        folder_create_payload(ROOT_FOLDER_ID, "Characters".to_owned()),
+    )
+    .await
+    .unwrap();
+    let entry = create_generic_entry(&database, folder.id, "Ada".to_owned(), "".to_owned()).await;
+
+    let response = trash_service::trash_entry(&database, entry.id).await;
+    assert!(response.is_ok());
+    assert!(entry_service::get_all(&database).await.unwrap().is_empty());
+    let results = entry_service::search(&database, search_payload("Ada"))
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+
+    let trash = trash_service::get_all(&database).await.unwrap();
+    assert_eq!(trash.entries.len(), 1);
+    assert_eq!(trash.entries[0].info.id, entry.id);
+    assert_eq!(trash.entries[0].info.folder_id, folder.id);
+
+    // the title is free while the entry is in the trash
+    let other = create_generic_entry(&database, Uuid::nil(), "Ada".to_owned(), "".to_owned()).await;
+
+    let restored = trash_service::restore_entry(&database, entry.id).await;
+    assert!(restored.is_ok());
+    let restored = restored.unwrap();
+    assert_eq!(restored.entries[0].title, "Ada (2)");
+    assert_eq!(restored.entries[0].folder_id, folder.id);
+    assert_eq!(entry_service::get_all(&database).await.unwrap().len(), 2);
+    assert!(get_entry(&database, other.id).await.is_some());
+
+    // entries that aren't in the trash can't be restored
+    let response = trash_service::restore_entry(&database, entry.id).await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_trash_and_restore_folder() {
+    let database = database().await;
+    let folder = folder_service::create(
+        &database,
+        folder_create_payload(ROOT_FOLDER_ID, "Places".to_owned()),
+    )
+    .await
+    .unwrap();
+    let subfolder = folder_service::create(
+        &database,
+        folder_create_payload(folder.id, "Cities".to_owned()),
+    )
+    .await
+    .unwrap();
+    let city = create_generic_entry(
+        &database,
+        subfolder.id,
+        "Eastmarch".to_owned(),
+        "".to_owned(),
+    )
+    .await;
+    let ruin =
+        create_generic_entry(&database, folder.id, "Old Keep".to_owned(), "".to_owned()).await;
+
+    // an entry that is deleted on its own stays in the trash when its folder is restored
+    trash_service::trash_entry(&database, ruin.id)
+        .await
+        .unwrap();
+    let contents = trash_service::trash_folder(&database, folder.id).await;
+    assert!(contents.is_ok());
+    assert_eq!(contents.unwrap().folders.len(), 2);
+    assert!(folder_service::get_all(&database).await.unwrap().is_empty());
+
+    let trash = trash_service::get_all(&database).await.unwrap();
+    assert_eq!(trash.folders.len(), 1);
+    assert_eq!(trash.folders[0].folder.id, folder.id);
+    assert_eq!(trash.folders[0].entry_count, 1);
+    assert_eq!(trash.entries.len(), 1);
+    assert_eq!(trash.entries[0].info.id, ruin.id);
+
+    // the name of the folder is taken in the meantime
+    folder_service::create(
+        &database,
+        folder_create_payload(ROOT_FOLDER_ID, "Places".to_owned()),
+    )
+    .await
+    .unwrap();
+
+    let restored = trash_service::restore_folder(&database, folder.id).await;
+    assert!(restored.is_ok());
+    let restored = restored.unwrap();
+    assert_eq!(restored.folders.len(), 2);
+    assert_eq!(restored.entries.len(), 1);
+    assert_eq!(restored.entries[0].id, city.id);
+    let folder = folder_service::get(&database, folder.id).await.unwrap();
+    assert_eq!(folder.name, "Places (2)");
+    assert_eq!(folder.parent_id, ROOT_FOLDER_ID);
+
+    let trash = trash_service::get_all(&database).await.unwrap();
+    assert!(trash.folders.is_empty());
+    assert_eq!(trash.entries.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_empty_trash() {
+    let database = database().await;
+    let folder = folder_service::create(
+        &database,
+        folder_create_payload(ROOT_FOLDER_ID, "Drafts".to_owned()),
+    )
+    .await
+    .unwrap();
+    let draft = create_generic_entry(&database, folder.id, "Draft".to_owned(), "".to_owned()).await;
+    let note = create_generic_entry(&database, Uuid::nil(), "Note".to_owned(), "".to_owned()).await;
+    let kept = create_generic_entry(&database, Uuid::nil(), "Kept".to_owned(), "".to_owned()).await;
+
+    trash_service::trash_folder(&database, folder.id)
+        .await
+        .unwrap();
+    trash_service::trash_entry(&database, note.id)
+        .await
+        .unwrap();
+
+    let response = trash_service::empty(&database).await;
+    assert!(response.is_ok());
+    assert!(get_entry(&database, draft.id).await.is_none());
+    assert!(get_entry(&database, note.id).await.is_none());
+    assert!(get_entry(&database, kept.id).await.is_some());
+    assert!(folder_service::get(&database, folder.id).await.is_err());
+
+    let trash = trash_service::get_all(&database).await.unwrap();
+    assert!(trash.entries.is_empty());
+    assert!(trash.folders.is_empty());
+}
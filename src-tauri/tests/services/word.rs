@@ -3,12 +3,17 @@ use uuid::Uuid;
 
 use hellebore::{
     database::{language_manager, word_manager},
+    model::{errors::Error, grammar::Inflection},
     schema::{
-        entry::EntryCreateSchema,
-        word::{WordResponseSchema, WordUpsertSchema},
+        entry::{EntryCreateSchema, EntryProperties},
+        language::{GrammarSchema, LanguageSchema},
+        word::{
+            InflectedWordCreateSchema, InflectedWordUpdateSchema, WordResponseSchema,
+            WordUpsertSchema,
+        },
     },
     services::{entry_service, word_service},
-    types::grammar::WordType,
+    types::grammar::{GrammaticalCase, GrammaticalNumber, WordType},
 };
 
 use crate::{
@@ -110,6 +115,7 @@ async fn test_update_word(
         spelling: Some(new_spelling.to_owned()),
         definition: Some(new_definition.to_owned()),
         translations: Some(new_translations.clone()),
+        expected_revision: None,
     };
 
     let responses = word_service::bulk_upsert(&db, vec![update_payload.clone()]).await;
@@ -158,6 +164,7 @@ async fn test_update_word_atomically(
         spelling: Some(new_spelling.to_owned()),
         definition: None,
         translations: None,
+        expected_revision: None,
     };
 
     let _ = word_service::bulk_upsert(&db, vec![update_payload.clone()]).await;
@@ -187,6 +194,44 @@ async fn test_update_word_atomically(
     validate_word_response(&word, &expected_word_response);
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_error_on_updating_word_with_stale_revision(
+    mut create_word_payload: WordUpsertSchema,
+    create_language_payload: EntryCreateSchema,
+) {
+    let db = database().await;
+    let language = entry_service::create(&db, create_language_payload)
+        .await
+        .unwrap();
+
+    create_word_payload.language_id = Some(language.id);
+    let id = upsert_word(&db, &create_word_payload).await.unwrap();
+
+    let update_payload = |spelling: &str| WordUpsertSchema {
+        id: Some(id),
+        spelling: Some(spelling.to_owned()),
+        expected_revision: Some(0),
+        ..Default::default()
+    };
+    let responses = word_service::bulk_upsert(&db, vec![update_payload("conducteur")])
+        .await
+        .unwrap();
+    assert!(responses[0].errors.is_empty());
+
+    let responses = word_service::bulk_upsert(&db, vec![update_payload("chauffeur")])
+        .await
+        .unwrap();
+    assert!(!responses[0].data.status.updated);
+    assert!(matches!(
+        responses[0].errors[0],
+        Error::RevisionConflict { revision: 1, .. }
+    ));
+    let word = word_service::get(&db, id).await.unwrap();
+    assert_eq!(word.spelling, "conducteur");
+    assert_eq!(word.revision, 1);
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_error_on_updating_nonexistent_word(create_language_payload: EntryCreateSchema) {
@@ -205,6 +250,7 @@ async fn test_error_on_updating_nonexistent_word(create_language_payload: EntryC
         spelling: Some(new_spelling.to_owned()),
         definition: Some(new_definition.to_owned()),
         translations: Some(new_translations.clone()),
+        expected_revision: None,
     };
 
     let responses = word_service::bulk_upsert(&db, vec![update_payload.clone()]).await;
@@ -356,3 +402,242 @@ async fn test_all_words_deleted_on_delete_language(
         .unwrap();
     assert_eq!(words.len(), 0);
 }
+
+fn inflected_word_payload(
+    word_id: Uuid,
+    spelling: &str,
+    case: GrammaticalCase,
+    number: GrammaticalNumber,
+) -> InflectedWordCreateSchema {
+    InflectedWordCreateSchema {
+        word_id,
+        spelling: spelling.to_owned(),
+        inflection: Inflection {
+            case,
+            number,
+            ..Default::default()
+        },
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_create_inflected_words(
+    create_language_payload: EntryCreateSchema,
+    mut create_word_payload: WordUpsertSchema,
+) {
+    let db = database().await;
+    let language = entry_service::create(&db, create_language_payload)
+        .await
+        .unwrap();
+    create_word_payload.language_id = Some(language.id);
+    let word_id = upsert_word(&db, &create_word_payload).await.unwrap();
+
+    let plural = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            word_id,
+            "rues",
+            GrammaticalCase::Nominative,
+            GrammaticalNumber::Plural,
+        ),
+    )
+    .await;
+    assert!(plural.is_ok());
+    let singular = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            word_id,
+            "rue",
+            GrammaticalCase::Nominative,
+            GrammaticalNumber::Singular,
+        ),
+    )
+    .await
+    .unwrap();
+
+    let inflected_words = word_service::get_inflected_words(&db, word_id)
+        .await
+        .unwrap();
+    let spellings: Vec<&str> = inflected_words
+        .iter()
+        .map(|inflected_word| inflected_word.spelling.as_str())
+        .collect();
+    assert_eq!(spellings, vec!["rue", "rues"]);
+    assert_eq!(inflected_words[0].id, singular.id);
+    assert_eq!(inflected_words[0].inflection, singular.inflection);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_update_inflected_word(
+    create_language_payload: EntryCreateSchema,
+    mut create_word_payload: WordUpsertSchema,
+) {
+    let db = database().await;
+    let language = entry_service::create(&db, create_language_payload)
+        .await
+        .unwrap();
+    create_word_payload.language_id = Some(language.id);
+    let word_id = upsert_word(&db, &create_word_payload).await.unwrap();
+    let inflected_word = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            word_id,
+            "rue",
+            GrammaticalCase::Nominative,
+            GrammaticalNumber::Plural,
+        ),
+    )
+    .await
+    .unwrap();
+
+    let response = word_service::update_inflected_word(
+        &db,
+        InflectedWordUpdateSchema {
+            id: inflected_word.id,
+            spelling: Some("rues".to_owned()),
+            inflection: None,
+        },
+    )
+    .await;
+
+    assert!(response.is_ok());
+    let response = response.unwrap();
+    assert_eq!(response.spelling, "rues");
+    assert_eq!(response.inflection.number, GrammaticalNumber::Plural);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_creating_inflected_word_for_nonexistent_word() {
+    let db = database().await;
+    let response = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            Uuid::new_v4(),
+            "rues",
+            GrammaticalCase::Nominative,
+            GrammaticalNumber::Plural,
+        ),
+    )
+    .await;
+    assert!(response.is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_inflected_words_deleted_on_delete_word(
+    create_language_payload: EntryCreateSchema,
+    mut create_word_payload: WordUpsertSchema,
+) {
+    let db = database().await;
+    let language = entry_service::create(&db, create_language_payload)
+        .await
+        .unwrap();
+    create_word_payload.language_id = Some(language.id);
+    let word_id = upsert_word(&db, &create_word_payload).await.unwrap();
+    let inflected_word = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            word_id,
+            "rues",
+            GrammaticalCase::Nominative,
+            GrammaticalNumber::Plural,
+        ),
+    )
+    .await
+    .unwrap();
+
+    word_service::delete(&db, word_id).await.unwrap();
+
+    let response = word_service::delete_inflected_word(&db, inflected_word.id).await;
+    assert!(response.is_err());
+}
+
+fn declare_grammar(create_language_payload: &mut EntryCreateSchema) {
+    create_language_payload.properties = EntryProperties::Language(LanguageSchema {
+        grammar: GrammarSchema {
+            word_types: Some(vec![WordType::Noun]),
+            cases: Some(vec![]),
+            numbers: Some(vec![GrammaticalNumber::Singular, GrammaticalNumber::Plural]),
+            ..Default::default()
+        },
+    });
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_creating_word_of_undeclared_type(
+    mut create_language_payload: EntryCreateSchema,
+    mut create_word_payload: WordUpsertSchema,
+) {
+    let db = database().await;
+    declare_grammar(&mut create_language_payload);
+    let language = entry_service::create(&db, create_language_payload)
+        .await
+        .unwrap();
+    create_word_payload.language_id = Some(language.id);
+    assert!(upsert_word(&db, &create_word_payload).await.is_some());
+
+    create_word_payload.word_type = Some(WordType::Verb);
+    let responses = word_service::bulk_upsert(&db, vec![create_word_payload])
+        .await
+        .unwrap();
+
+    let response = responses.first().unwrap();
+    assert!(response.data.id.is_none());
+    assert!(!response.data.status.created);
+    assert_eq!(response.errors.len(), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_error_on_creating_inflected_word_with_undeclared_category(
+    mut create_language_payload: EntryCreateSchema,
+    mut create_word_payload: WordUpsertSchema,
+) {
+    let db = database().await;
+    declare_grammar(&mut create_language_payload);
+    let language = entry_service::create(&db, create_language_payload)
+        .await
+        .unwrap();
+    create_word_payload.language_id = Some(language.id);
+    let word_id = upsert_word(&db, &create_word_payload).await.unwrap();
+
+    let response = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            word_id,
+            "rues",
+            GrammaticalCase::None,
+            GrammaticalNumber::Plural,
+        ),
+    )
+    .await;
+    assert!(response.is_ok());
+
+    // the language doesn't use cases, nor the dual number
+    let response = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            word_id,
+            "rues",
+            GrammaticalCase::Nominative,
+            GrammaticalNumber::Plural,
+        ),
+    )
+    .await;
+    assert!(response.is_err());
+    let response = word_service::create_inflected_word(
+        &db,
+        inflected_word_payload(
+            word_id,
+            "rue",
+            GrammaticalCase::None,
+            GrammaticalNumber::Dual,
+        ),
+    )
+    .await;
+    assert!(response.is_err());
+}
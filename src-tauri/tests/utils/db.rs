@@ -3,9 +3,14 @@ use sea_orm::*;
 use uuid::Uuid;
 
 use hellebore::database::entry_manager;
-use hellebore::schema::word::WordUpsertSchema;
-use hellebore::services::word_service;
-use hellebore::types::entity::ENTRY;
+use hellebore::model::text::TextNode;
+use hellebore::schema::{
+    entry::{EntryCreateSchema, EntryProperties, EntryUpdateSchema},
+    person::PersonSchema,
+    word::WordUpsertSchema,
+};
+use hellebore::services::{entry_service, word_service};
+use hellebore::types::entity::{ENTRY, PERSON};
 
 use ::entity::entry as entry_entity;
 use ::entity::word as word_entity;
@@ -33,6 +38,40 @@ pub async fn create_generic_entries(
     .await
 }
 
+pub async fn create_entry_with_title(
+    database: &DatabaseConnection,
+    mut payload: EntryCreateSchema,
+    title: &str,
+) -> Uuid {
+    payload.title = title.to_owned();
+    entry_service::create(database, payload).await.unwrap().id
+}
+
+pub async fn update_entry_text(
+    database: &DatabaseConnection,
+    mut payload: EntryUpdateSchema,
+    id: Uuid,
+    text: &TextNode,
+) {
+    payload.id = id;
+    payload.text = Some(serde_json::to_string(text).unwrap());
+    let response = entry_service::update(database, payload).await;
+    assert!(response.errors.is_empty());
+}
+
+pub async fn create_person(
+    database: &DatabaseConnection,
+    mut payload: EntryCreateSchema,
+    name: &str,
+) -> Uuid {
+    payload.entity_type = PERSON;
+    payload.title = name.to_owned();
+    payload.properties = EntryProperties::Person(PersonSchema {
+        name: name.to_owned(),
+    });
+    entry_service::create(database, payload).await.unwrap().id
+}
+
 pub async fn get_entry(database: &DatabaseConnection, id: Uuid) -> Option<entry_entity::Model> {
     let entry = entry_manager::get(database, id).await;
     assert!(entry.is_ok());
@@ -40,10 +79,10 @@ pub async fn get_entry(database: &DatabaseConnection, id: Uuid) -> Option<entry_
 }
 
 pub async fn upsert_word(db: &DatabaseConnection, word_payload: &WordUpsertSchema) -> Option<Uuid> {
-    let responses = word_service::bulk_upsert(&db, vec![word_payload.clone()])
+    let responses = word_service::bulk_upsert(db, vec![word_payload.clone()])
         .await
         .unwrap();
-    let response = responses.get(0).unwrap();
+    let response = responses.first().unwrap();
     response.data.id
 }
 
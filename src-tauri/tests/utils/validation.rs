@@ -21,8 +21,8 @@ pub fn validate_entry_model(
     title: &str,
     text: &str,
 ) {
-    if id.is_some() {
-        assert_eq!(id.unwrap(), entry.id);
+    if let Some(id) = id {
+        assert_eq!(id, entry.id);
     }
     assert_eq!(
         folder_id,
@@ -48,8 +48,8 @@ pub fn validate_entry_info_response(
     entity_type: EntityType,
     title: &str,
 ) {
-    if id.is_some() {
-        assert_eq!(id.unwrap(), response.id);
+    if let Some(id) = id {
+        assert_eq!(id, response.id);
     }
     assert_eq!(folder_id, response.folder_id);
     assert_eq!(entity_type, response.entity_type);
@@ -65,7 +65,7 @@ pub fn validate_language_property_response(
 ) {
     validate_entry_info_response(&response.info, id, folder_id, entity_type, title);
     match &response.properties {
-        EntryProperties::Language(props) => _validate_language_properties(&props),
+        EntryProperties::Language(props) => _validate_language_properties(props),
         _ => panic!("Got wrong entry type; expected language properties."),
     }
 }
@@ -93,7 +93,7 @@ pub fn validate_person_property_response(
 ) {
     validate_entry_info_response(&response.info, id, folder_id, entity_type, title);
     match &response.properties {
-        EntryProperties::Person(props) => _validate_person_properties(&props, name),
+        EntryProperties::Person(props) => _validate_person_properties(props, name),
         _ => panic!("Got wrong entry type; expected person properties."),
     }
 }